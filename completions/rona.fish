@@ -1,6 +1,6 @@
 # Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
 function __fish_rona_global_optspecs
-	string join \n v/verbose config= h/help V/version
+	string join \n v/verbose q/quiet config= offline profile= json no-pager no-emoji trace-git no-wizard h/help V/version
 end
 
 function __fish_rona_needs_command
@@ -25,45 +25,549 @@ function __fish_rona_using_subcommand
 end
 
 complete -c rona -n "__fish_rona_needs_command" -l config -d 'Use the custom config file path instead of default' -r
+complete -c rona -n "__fish_rona_needs_command" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
 complete -c rona -n "__fish_rona_needs_command" -s v -l verbose -d 'Verbose output - show detailed information about operations'
+complete -c rona -n "__fish_rona_needs_command" -s q -l quiet -d 'Quiet output - only show errors'
+complete -c rona -n "__fish_rona_needs_command" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_needs_command" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_needs_command" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_needs_command" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_needs_command" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_needs_command" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
 complete -c rona -n "__fish_rona_needs_command" -s h -l help -d 'Print help'
 complete -c rona -n "__fish_rona_needs_command" -s V -l version -d 'Print version'
 complete -c rona -n "__fish_rona_needs_command" -f -a "add-with-exclude" -d 'Add all files to the `git add` command and exclude the patterns passed as positional arguments'
+complete -c rona -n "__fish_rona_needs_command" -f -a "branch" -d 'Create branches named from a template (`from-issue`)'
+complete -c rona -n "__fish_rona_needs_command" -f -a "checkout" -d 'Check out a local or remote branch, picked with a fuzzy filter'
+complete -c rona -n "__fish_rona_needs_command" -f -a "cherry-pick" -d 'Apply commits of another branch, their `[N] (type on branch)` headers getting the next commit numbers and the current branch'
 complete -c rona -n "__fish_rona_needs_command" -f -a "commit" -d 'Directly commit the file with the text in `commit_message.md`'
 complete -c rona -n "__fish_rona_needs_command" -f -a "completion" -d 'Generate shell completions for your shell'
+complete -c rona -n "__fish_rona_needs_command" -f -a "conflicts" -d 'Resolve the conflicted files of a stopped merge or rebase, then continue or abort it'
+complete -c rona -n "__fish_rona_needs_command" -f -a "diff" -d 'Show the staged changes about to be committed, with a files/insertions/deletions summary'
+complete -c rona -n "__fish_rona_needs_command" -f -a "doctor" -d 'Check the environment (git, repository, identity, signing, editor, config, hooks, remotes) and suggest fixes'
+complete -c rona -n "__fish_rona_needs_command" -f -a "fetch" -d 'Fetch from a remote, or all remotes, and summarize the changed branches'
+complete -c rona -n "__fish_rona_needs_command" -f -a "flow" -d 'Stage, generate and edit the message, commit and optionally push in one command'
 complete -c rona -n "__fish_rona_needs_command" -f -a "generate" -d 'Directly generate the `commit_message.md` file'
+complete -c rona -n "__fish_rona_needs_command" -f -a "hooks" -d 'Generate git hooks running rona (e.g. `commit-msg` validating plain `git commit` messages)'
+complete -c rona -n "__fish_rona_needs_command" -f -a "history" -d 'Show the rona invocations that moved HEAD, or every recorded operation with `--ops`, from the audit log'
+complete -c rona -n "__fish_rona_needs_command" -f -a "info" -d 'Show the branch, upstream, last commit and push, stashes and pending commit message'
 complete -c rona -n "__fish_rona_needs_command" -f -a "init" -d 'Initialize the rona configuration file'
+complete -c rona -n "__fish_rona_needs_command" -f -a "lint" -d 'Validate a commit message (subject length, empty bullets, typos), failing if it has issues'
 complete -c rona -n "__fish_rona_needs_command" -f -a "list-status" -d 'List files from git status (for shell completion on the -a)'
+complete -c rona -n "__fish_rona_needs_command" -f -a "mv" -d 'Move or rename files with `git mv`, moving their entries in the pending commit message'
+complete -c rona -n "__fish_rona_needs_command" -f -a "open" -d 'Open the remote repository, current branch, a file or a new pull request page in the browser'
 complete -c rona -n "__fish_rona_needs_command" -f -a "push" -d 'Push to a git repository'
+complete -c rona -n "__fish_rona_needs_command" -f -a "rebase" -d 'Rebase onto a revision, picking, squashing, fixing up, dropping and reordering the commits with `-i`'
+complete -c rona -n "__fish_rona_needs_command" -f -a "release" -d 'Bump the version, create a release commit and tag, then push both'
+complete -c rona -n "__fish_rona_needs_command" -f -a "revert" -d 'Revert a commit, generating a rona-format revert message to edit and commit'
+complete -c rona -n "__fish_rona_needs_command" -f -a "rewrite" -d 'Reword the commits of BASE..HEAD into the rona format, keeping their bodies, through an interactive rebase'
+complete -c rona -n "__fish_rona_needs_command" -f -a "rm" -d 'Remove files with `git rm`, turning their entries in the pending commit message into `deleted` ones'
+complete -c rona -n "__fish_rona_needs_command" -f -a "serve" -d 'Answer JSON-RPC requests from an editor plugin (status, generateMessage, commit, push)'
 complete -c rona -n "__fish_rona_needs_command" -f -a "set-editor" -d 'Set the editor to use for editing the commit message'
+complete -c rona -n "__fish_rona_needs_command" -f -a "submodule" -d 'Run a `git submodule` command (e.g. `rona submodule update --init --recursive`)'
+complete -c rona -n "__fish_rona_needs_command" -f -a "squash" -d 'Squash the last N commits into one, merging their messages'
+complete -c rona -n "__fish_rona_needs_command" -f -a "tag" -d 'Create an annotated tag from an edited message, list tags with their messages, or push tags'
+complete -c rona -n "__fish_rona_needs_command" -f -a "wip" -d 'Stage everything and commit it as a `wip: <timestamp>` checkpoint, leaving `commit_message.md` untouched'
+complete -c rona -n "__fish_rona_needs_command" -f -a "unadd" -d 'Unstage the staged files matching the patterns passed as positional arguments (`git restore --staged`)'
+complete -c rona -n "__fish_rona_needs_command" -f -a "unstage" -d 'Unstage the staged files matching the patterns passed as positional arguments (`git restore --staged`)'
+complete -c rona -n "__fish_rona_needs_command" -f -a "watch" -d 'Watch the working tree, showing what would be staged and optionally recording checkpoints on a WIP branch'
 complete -c rona -n "__fish_rona_needs_command" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
 complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -l dry-run -d 'Show what would be added without actually adding files'
+complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -l fuzzy -d 'Fuzzy-match the arguments against changed files instead of using glob patterns'
+complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
 complete -c rona -n "__fish_rona_using_subcommand add-with-exclude" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -f -a "from-issue" -d 'Create and check out a branch named from an issue number or a title'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -f -a "describe" -d 'Edit the description of a branch (`branch.<name>.description`) in the editor'
+complete -c rona -n "__fish_rona_using_subcommand branch; and not __fish_seen_subcommand_from from-issue describe help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -s t -l type -d 'The `{type}` of the branch name' -r
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -l no-checkout -d 'Create the branch without checking it out'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -l dry-run -d 'Show the branch name without creating it'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from from-issue" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -s m -l message -d 'Set the description without opening the editor' -r
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -l dry-run -d 'Show the change without making it'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from describe" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from help" -f -a "from-issue" -d 'Create and check out a branch named from an issue number or a title'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from help" -f -a "describe" -d 'Edit the description of a branch (`branch.<name>.description`) in the editor'
+complete -c rona -n "__fish_rona_using_subcommand branch; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rona -n "__fish_rona_using_subcommand checkout" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand checkout" -l dry-run -d 'Show the branch that would be checked out without checking it out'
+complete -c rona -n "__fish_rona_using_subcommand checkout" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand checkout" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand checkout" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand checkout" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand checkout" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand checkout" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand checkout" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -s t -l type -d 'The commit type of the commits without a rona header or a conventional commit type, which keep their message otherwise' -r
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -l no-edit -d 'Commit the adapted messages without opening the editor'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -l dry-run -d 'Show what would be applied and committed without changing anything'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -s u -l unsigned -d 'Create unsigned commits (default is to auto-detect GPG availability and sign if possible)'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand cherry-pick" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l co-author -d 'Add a `Co-authored-by` trailer (repeatable)' -r
+complete -c rona -n "__fish_rona_using_subcommand commit" -l author -d 'Author of the commit, instead of your git identity' -r
+complete -c rona -n "__fish_rona_using_subcommand commit" -l date -d 'Author date of the commit (`YYYY-MM-DD[ HH:MM[:SS]]` or RFC 3339)' -r
+complete -c rona -n "__fish_rona_using_subcommand commit" -l message-file -d 'Commit with the message of a file instead of `commit_message.md`' -r -F
+complete -c rona -n "__fish_rona_using_subcommand commit" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
 complete -c rona -n "__fish_rona_using_subcommand commit" -s p -l push -d 'Whether to push the commit after committing'
 complete -c rona -n "__fish_rona_using_subcommand commit" -l dry-run -d 'Show what would be committed without actually committing'
 complete -c rona -n "__fish_rona_using_subcommand commit" -s u -l unsigned -d 'Create unsigned commit (default is to auto-detect GPG availability and sign if possible)'
+complete -c rona -n "__fish_rona_using_subcommand commit" -s s -l signoff -d 'Add a `Signed-off-by` trailer with your git identity'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l allow-secrets -d 'Commit even if the staged changes contain possible secrets (see `scan_secrets`)'
+complete -c rona -n "__fish_rona_using_subcommand commit" -s n -l no-verify -d 'Bypass the `pre-commit` and `commit-msg` hooks'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l force -d 'Run even if another rona process is using the commit message'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l strict -d 'Refuse to commit on a protected branch (see `protected_branches`) instead of asking'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l stdin -d 'Commit with the message read from standard input instead of `commit_message.md`'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand commit" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
 complete -c rona -n "__fish_rona_using_subcommand commit" -s h -l help -d 'Print help'
-complete -c rona -n "__fish_rona_using_subcommand completion" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand completion" -s o -l output -d 'Write the completions to a file instead of stdout: the standard location of the shell if no path is given' -r -F
+complete -c rona -n "__fish_rona_using_subcommand completion" -l list -d 'Print the dynamic values of a kind, one per line (used by the completion scripts)' -r -f -a "files\t'Files with changes in the git status'
+commit-types\t'Configured commit types'
+remotes\t'Configured remotes'
+branches\t'Local branches'"
+complete -c rona -n "__fish_rona_using_subcommand completion" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand completion" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand completion" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand completion" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand completion" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand completion" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand completion" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand completion" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l continue -d 'Continue the merge or rebase, every conflict being resolved'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l abort -d 'Abort the merge or rebase'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l dry-run -d 'Show what would be staged and run without changing anything'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand conflicts" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand diff" -l against -d 'Compare the staged changes against a ref instead of `HEAD`' -r
+complete -c rona -n "__fish_rona_using_subcommand diff" -l color -d 'When to color the diff' -r -f -a "auto\t'Color when writing to a terminal'
+always\t'Always color'
+never\t'Never color'"
+complete -c rona -n "__fish_rona_using_subcommand diff" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand diff" -s w -l word-diff -d 'Highlight changed words instead of whole lines'
+complete -c rona -n "__fish_rona_using_subcommand diff" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand diff" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand diff" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand diff" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand diff" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand diff" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand diff" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rona -n "__fish_rona_using_subcommand doctor" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand doctor" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand doctor" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand doctor" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand doctor" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand doctor" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand doctor" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand doctor" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l all -d 'Fetch all remotes'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l prune -d 'Remove remote-tracking branches deleted on the remote'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l tags -d 'Fetch all tags'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l dry-run -d 'Show what would be fetched without actually fetching'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand fetch" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand flow" -s t -l type -d 'The commit type, skipping the commit type prompt (e.g. `feat`)' -r
+complete -c rona -n "__fish_rona_using_subcommand flow" -s m -l message -d 'The subject completing the header, over the issue title' -r
+complete -c rona -n "__fish_rona_using_subcommand flow" -l issue -d 'Reference an issue (`Closes #N` footer, title as message); select it interactively if no number is given' -r
+complete -c rona -n "__fish_rona_using_subcommand flow" -l breaking -d 'Describe a breaking change in a `BREAKING CHANGE` footer (asked otherwise when prompting)' -r
+complete -c rona -n "__fish_rona_using_subcommand flow" -l ticket -d 'Reference this ticket (e.g. `PROJ-1234`) instead of the one found in the branch name' -r
+complete -c rona -n "__fish_rona_using_subcommand flow" -l co-author -d 'Add a `Co-authored-by` trailer (repeatable)' -r
+complete -c rona -n "__fish_rona_using_subcommand flow" -l skip -d 'Skip a stage of the workflow (repeatable)' -r -f -a "add\t'Staging the files, committing what is already staged'
+generate\t'Generating the message, committing the existing `commit_message.md`'
+commit\t'Committing, leaving the generated message for `rona commit`'"
+complete -c rona -n "__fish_rona_using_subcommand flow" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand flow" -s i -l interactive -d 'Complete the message in the terminal instead of the editor'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l no-edit -d 'Write the message without opening the editor or prompting (requires `--type`)'
+complete -c rona -n "__fish_rona_using_subcommand flow" -s n -l no-commit-number -d 'No commit number'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l ai -d 'Pre-fill the subject and file descriptions with an AI suggestion (requires the `ai` feature)'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l no-ticket -d 'Do not reference the ticket found in the branch name'
+complete -c rona -n "__fish_rona_using_subcommand flow" -s u -l unsigned -d 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)'
+complete -c rona -n "__fish_rona_using_subcommand flow" -s s -l signoff -d 'Add a `Signed-off-by` trailer with your git identity'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l force -d 'Run even if another rona process is using the commit message, and overwrite the edits of a pending message without asking'
+complete -c rona -n "__fish_rona_using_subcommand flow" -s p -l push -d 'Push the commit to the upstream of the branch'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l dry-run -d 'Show every step of the workflow without changing anything'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand flow" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand flow" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rona -n "__fish_rona_using_subcommand generate" -s t -l type -d 'The commit type, skipping the commit type prompt (e.g. `feat`)' -r
+complete -c rona -n "__fish_rona_using_subcommand generate" -s m -l message -d 'The subject completing the header, over the issue title' -r
+complete -c rona -n "__fish_rona_using_subcommand generate" -l issue -d 'Reference an issue (`Closes #N` footer, title as message); select it interactively if no number is given' -r
+complete -c rona -n "__fish_rona_using_subcommand generate" -l breaking -d 'Describe a breaking change in a `BREAKING CHANGE` footer (asked otherwise when prompting)' -r
+complete -c rona -n "__fish_rona_using_subcommand generate" -l ticket -d 'Reference this ticket (e.g. `PROJ-1234`) instead of the one found in the branch name' -r
+complete -c rona -n "__fish_rona_using_subcommand generate" -l co-author -d 'Add a `Co-authored-by` trailer (repeatable)' -r
+complete -c rona -n "__fish_rona_using_subcommand generate" -l from-diff -d 'List the files changed by a patch file (`-` for stdin) instead of the staged files' -r -F
+complete -c rona -n "__fish_rona_using_subcommand generate" -l range -d 'List the files changed in a commit range (`A..B`, or a single commit) instead of the staged files' -r
+complete -c rona -n "__fish_rona_using_subcommand generate" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
 complete -c rona -n "__fish_rona_using_subcommand generate" -l dry-run -d 'Show what would be generated without creating files'
 complete -c rona -n "__fish_rona_using_subcommand generate" -s i -l interactive -d 'Interactive mode - input the commit message directly in the terminal'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l no-edit -d 'Write the message without opening the editor or prompting (requires `--type`)'
 complete -c rona -n "__fish_rona_using_subcommand generate" -s n -l no-commit-number -d 'No commit number'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l ai -d 'Pre-fill the subject and file descriptions with an AI suggestion (requires the `ai` feature)'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l no-ticket -d 'Do not reference the ticket found in the branch name'
+complete -c rona -n "__fish_rona_using_subcommand generate" -s s -l signoff -d 'Add a `Signed-off-by` trailer with your git identity'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l force -d 'Run even if another rona process is using the commit message, and overwrite the edits of a pending message without asking'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l update -d 'Update the file entries of the pending message to the staged files, keeping what was written in it'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand generate" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
 complete -c rona -n "__fish_rona_using_subcommand generate" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -f -a "generate" -d 'Write a hook into the hooks directory (honouring `core.hooksPath`)'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and not __fish_seen_subcommand_from generate help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -l force -d 'Overwrite the hook if it already exists'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -l dry-run -d 'Show what would be written without writing it'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from generate" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from help" -f -a "generate" -d 'Write a hook into the hooks directory (honouring `core.hooksPath`)'
+complete -c rona -n "__fish_rona_using_subcommand hooks; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rona -n "__fish_rona_using_subcommand history" -s n -l limit -d 'Number of entries shown, the most recent last' -r
+complete -c rona -n "__fish_rona_using_subcommand history" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand history" -l ops -d 'Show every recorded operation (add, commit, push, tag, ...) with its result'
+complete -c rona -n "__fish_rona_using_subcommand history" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand history" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand history" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand history" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand history" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand history" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand history" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand info" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand info" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand info" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand info" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand info" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand info" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand info" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand info" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand init" -l preset -d 'Scaffold the project setup of a preset instead of only the configuration' -r -f -a "minimal\t'Only `.rona.toml`'
+standard\t'`.rona.toml`, `.commitignore` and the commit template'
+full\t'Everything, including the git hook'"
+complete -c rona -n "__fish_rona_using_subcommand init" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
 complete -c rona -n "__fish_rona_using_subcommand init" -l dry-run -d 'Show what would be initialized without creating files'
-complete -c rona -n "__fish_rona_using_subcommand init" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand init" -l scaffold -d 'Pick the project setup items to scaffold interactively'
+complete -c rona -n "__fish_rona_using_subcommand init" -l global -d 'Create the configuration file in ~/.config/rona.toml without prompting'
+complete -c rona -n "__fish_rona_using_subcommand init" -l project -d 'Create the configuration file in ./.rona.toml without prompting'
+complete -c rona -n "__fish_rona_using_subcommand init" -l force -d 'Overwrite an existing configuration file'
+complete -c rona -n "__fish_rona_using_subcommand init" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand init" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand init" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand init" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand init" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand init" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand init" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rona -n "__fish_rona_using_subcommand lint" -l file -d 'The message file to validate, `#` comment lines excluded (defaults to the commit message file)' -r -F
+complete -c rona -n "__fish_rona_using_subcommand lint" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand lint" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand lint" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand lint" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand lint" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand lint" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand lint" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand lint" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l staged -d 'List files with staged changes'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l unstaged -d 'List tracked files with unstaged changes'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l untracked -d 'List untracked files'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l deleted -d 'List deleted files, staged or not'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -s z -l null -d 'Separate the files with NUL characters instead of newlines'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand list-status" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
 complete -c rona -n "__fish_rona_using_subcommand list-status" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand mv" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand mv" -s f -l force -d 'Overwrite the destination if it exists'
+complete -c rona -n "__fish_rona_using_subcommand mv" -l dry-run -d 'Show what would be moved without moving anything'
+complete -c rona -n "__fish_rona_using_subcommand mv" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand mv" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand mv" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand mv" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand mv" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand mv" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand mv" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand open" -l file -d 'Open a file on the current branch' -r -F
+complete -c rona -n "__fish_rona_using_subcommand open" -l remote -d 'The remote whose repository is opened' -r
+complete -c rona -n "__fish_rona_using_subcommand open" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand open" -l pr -d 'Open the page creating a pull request from the current branch'
+complete -c rona -n "__fish_rona_using_subcommand open" -l branch -d 'Open the current branch'
+complete -c rona -n "__fish_rona_using_subcommand open" -l dry-run -d 'Show the URL that would be opened without opening it'
+complete -c rona -n "__fish_rona_using_subcommand open" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand open" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand open" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand open" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand open" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand open" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand open" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand push" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
 complete -c rona -n "__fish_rona_using_subcommand push" -l dry-run -d 'Show what would be pushed without actually pushing'
+complete -c rona -n "__fish_rona_using_subcommand push" -l force-unsafe -d 'Force the push without lease (`--force` and `-f` are pushed as `--force-with-lease`)'
+complete -c rona -n "__fish_rona_using_subcommand push" -l all-remotes -d 'Push the current branch to every configured remote, one after the other'
+complete -c rona -n "__fish_rona_using_subcommand push" -l strict -d 'Refuse to push a protected branch (see `protected_branches`) instead of asking'
+complete -c rona -n "__fish_rona_using_subcommand push" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand push" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand push" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand push" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand push" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand push" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
 complete -c rona -n "__fish_rona_using_subcommand push" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand rebase" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand rebase" -s i -l interactive -d 'Choose what happens to each commit of `BASE..HEAD` from prompts'
+complete -c rona -n "__fish_rona_using_subcommand rebase" -l dry-run -d 'Show the rebase todo list without rebasing'
+complete -c rona -n "__fish_rona_using_subcommand rebase" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand rebase" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand rebase" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand rebase" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand rebase" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand rebase" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand rebase" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand release" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand release" -l dry-run -d 'Show every step of the release without changing anything'
+complete -c rona -n "__fish_rona_using_subcommand release" -s s -l sign -d 'Create a GPG-signed tag instead of a plain annotated one'
+complete -c rona -n "__fish_rona_using_subcommand release" -s u -l unsigned -d 'Create an unsigned release commit'
+complete -c rona -n "__fish_rona_using_subcommand release" -l no-push -d 'Do not push the release commit and tag'
+complete -c rona -n "__fish_rona_using_subcommand release" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand release" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand release" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand release" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand release" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand release" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand release" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand revert" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand revert" -l dry-run -d 'Show what would be reverted without changing anything'
+complete -c rona -n "__fish_rona_using_subcommand revert" -l no-edit -d 'Commit the revert message without opening the editor'
+complete -c rona -n "__fish_rona_using_subcommand revert" -s u -l unsigned -d 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)'
+complete -c rona -n "__fish_rona_using_subcommand revert" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand revert" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand revert" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand revert" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand revert" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand revert" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand revert" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -s t -l type -d 'The commit type of the commits without a conventional commit type, skipping the prompt' -r
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -l no-edit -d 'Use the rona-format messages without prompting (requires `--type` for commits without a conventional type)'
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -l dry-run -d 'Show the rebase todo list without rewriting'
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand rewrite" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand rm" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand rm" -s r -d 'Remove directories recursively'
+complete -c rona -n "__fish_rona_using_subcommand rm" -l cached -d 'Only remove the files from the index, keeping them in the working tree'
+complete -c rona -n "__fish_rona_using_subcommand rm" -s f -l force -d 'Remove files with changes'
+complete -c rona -n "__fish_rona_using_subcommand rm" -l dry-run -d 'Show what would be removed without removing anything'
+complete -c rona -n "__fish_rona_using_subcommand rm" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand rm" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand rm" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand rm" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand rm" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand rm" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand rm" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand serve" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand serve" -l stdio -d 'Read requests from stdin and write responses to stdout, one JSON object per line'
+complete -c rona -n "__fish_rona_using_subcommand serve" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand serve" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand serve" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand serve" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand serve" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand serve" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand serve" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand set-editor" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
 complete -c rona -n "__fish_rona_using_subcommand set-editor" -l dry-run -d 'Show what would be changed without modifying config'
+complete -c rona -n "__fish_rona_using_subcommand set-editor" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand set-editor" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand set-editor" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand set-editor" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand set-editor" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand set-editor" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
 complete -c rona -n "__fish_rona_using_subcommand set-editor" -s h -l help -d 'Print help'
-complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude commit completion generate init list-status push set-editor help" -f -a "add-with-exclude" -d 'Add all files to the `git add` command and exclude the patterns passed as positional arguments'
-complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude commit completion generate init list-status push set-editor help" -f -a "commit" -d 'Directly commit the file with the text in `commit_message.md`'
-complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude commit completion generate init list-status push set-editor help" -f -a "completion" -d 'Generate shell completions for your shell'
-complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude commit completion generate init list-status push set-editor help" -f -a "generate" -d 'Directly generate the `commit_message.md` file'
-complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude commit completion generate init list-status push set-editor help" -f -a "init" -d 'Initialize the rona configuration file'
-complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude commit completion generate init list-status push set-editor help" -f -a "list-status" -d 'List files from git status (for shell completion on the -a)'
-complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude commit completion generate init list-status push set-editor help" -f -a "push" -d 'Push to a git repository'
-complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude commit completion generate init list-status push set-editor help" -f -a "set-editor" -d 'Set the editor to use for editing the commit message'
-complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude commit completion generate init list-status push set-editor help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rona -n "__fish_rona_using_subcommand submodule" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand submodule" -l dry-run -d 'Show the git command without running it'
+complete -c rona -n "__fish_rona_using_subcommand submodule" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand submodule" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand submodule" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand submodule" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand submodule" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand submodule" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand submodule" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand squash" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand squash" -l dry-run -d 'Show what would be squashed without changing anything'
+complete -c rona -n "__fish_rona_using_subcommand squash" -l no-edit -d 'Commit the merged message without opening the editor'
+complete -c rona -n "__fish_rona_using_subcommand squash" -s u -l unsigned -d 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)'
+complete -c rona -n "__fish_rona_using_subcommand squash" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand squash" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand squash" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand squash" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand squash" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand squash" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand squash" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand tag" -s m -l message -d 'The tag message, skipping the editor' -r
+complete -c rona -n "__fish_rona_using_subcommand tag" -s l -l list -d 'List the tags matching the glob pattern (all by default), newest first, with the subject of their message' -r
+complete -c rona -n "__fish_rona_using_subcommand tag" -l remote -d 'The remote tags are pushed to' -r
+complete -c rona -n "__fish_rona_using_subcommand tag" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand tag" -s s -l sign -d 'Create a GPG-signed tag instead of a plain annotated one'
+complete -c rona -n "__fish_rona_using_subcommand tag" -l push -d 'Push the created tag, or every tag when no NAME is given'
+complete -c rona -n "__fish_rona_using_subcommand tag" -l dry-run -d 'Show the tag message, tag and push without changing anything'
+complete -c rona -n "__fish_rona_using_subcommand tag" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand tag" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand tag" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand tag" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand tag" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand tag" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand tag" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand wip" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand wip" -l pop -d 'Soft-reset the last checkpoint commit, its changes going back to the index'
+complete -c rona -n "__fish_rona_using_subcommand wip" -l dry-run -d 'Show what would be staged and committed without changing anything'
+complete -c rona -n "__fish_rona_using_subcommand wip" -s u -l unsigned -d 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)'
+complete -c rona -n "__fish_rona_using_subcommand wip" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand wip" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand wip" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand wip" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand wip" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand wip" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand wip" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand unadd" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand unadd" -s i -l interactive -d 'Pick the files to unstage from the staged files'
+complete -c rona -n "__fish_rona_using_subcommand unadd" -l dry-run -d 'Show what would be unstaged without unstaging anything'
+complete -c rona -n "__fish_rona_using_subcommand unadd" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand unadd" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand unadd" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand unadd" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand unadd" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand unadd" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand unadd" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand unstage" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand unstage" -s i -l interactive -d 'Pick the files to unstage from the staged files'
+complete -c rona -n "__fish_rona_using_subcommand unstage" -l dry-run -d 'Show what would be unstaged without unstaging anything'
+complete -c rona -n "__fish_rona_using_subcommand unstage" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand unstage" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand unstage" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand unstage" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand unstage" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand unstage" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand unstage" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand watch" -l wip-branch -d 'Record checkpoints of the working tree on a local branch (default: `wip/<branch>`), leaving HEAD and the index untouched' -r
+complete -c rona -n "__fish_rona_using_subcommand watch" -l interval -d 'Seconds between two checkpoints' -r
+complete -c rona -n "__fish_rona_using_subcommand watch" -l profile -d 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes' -r
+complete -c rona -n "__fish_rona_using_subcommand watch" -l offline -d 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)'
+complete -c rona -n "__fish_rona_using_subcommand watch" -l json -d 'Print dry-run plans as JSON instead of text'
+complete -c rona -n "__fish_rona_using_subcommand watch" -l no-pager -d 'Never pipe long output through the pager'
+complete -c rona -n "__fish_rona_using_subcommand watch" -l no-emoji -d 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)'
+complete -c rona -n "__fish_rona_using_subcommand watch" -l trace-git -d 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)'
+complete -c rona -n "__fish_rona_using_subcommand watch" -l no-wizard -d 'Never ask the first-run questions when no configuration file exists'
+complete -c rona -n "__fish_rona_using_subcommand watch" -s h -l help -d 'Print help'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "add-with-exclude" -d 'Add all files to the `git add` command and exclude the patterns passed as positional arguments'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "branch" -d 'Create branches named from a template (`from-issue`)'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "checkout" -d 'Check out a local or remote branch, picked with a fuzzy filter'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "cherry-pick" -d 'Apply commits of another branch, their `[N] (type on branch)` headers getting the next commit numbers and the current branch'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "commit" -d 'Directly commit the file with the text in `commit_message.md`'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "completion" -d 'Generate shell completions for your shell'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "conflicts" -d 'Resolve the conflicted files of a stopped merge or rebase, then continue or abort it'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "diff" -d 'Show the staged changes about to be committed, with a files/insertions/deletions summary'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "doctor" -d 'Check the environment (git, repository, identity, signing, editor, config, hooks, remotes) and suggest fixes'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "fetch" -d 'Fetch from a remote, or all remotes, and summarize the changed branches'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "flow" -d 'Stage, generate and edit the message, commit and optionally push in one command'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "generate" -d 'Directly generate the `commit_message.md` file'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "hooks" -d 'Generate git hooks running rona (e.g. `commit-msg` validating plain `git commit` messages)'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "history" -d 'Show the rona invocations that moved HEAD, or every recorded operation with `--ops`, from the audit log'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "info" -d 'Show the branch, upstream, last commit and push, stashes and pending commit message'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "init" -d 'Initialize the rona configuration file'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "lint" -d 'Validate a commit message (subject length, empty bullets, typos), failing if it has issues'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "list-status" -d 'List files from git status (for shell completion on the -a)'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "mv" -d 'Move or rename files with `git mv`, moving their entries in the pending commit message'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "open" -d 'Open the remote repository, current branch, a file or a new pull request page in the browser'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "push" -d 'Push to a git repository'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "rebase" -d 'Rebase onto a revision, picking, squashing, fixing up, dropping and reordering the commits with `-i`'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "release" -d 'Bump the version, create a release commit and tag, then push both'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "revert" -d 'Revert a commit, generating a rona-format revert message to edit and commit'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "rewrite" -d 'Reword the commits of BASE..HEAD into the rona format, keeping their bodies, through an interactive rebase'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "rm" -d 'Remove files with `git rm`, turning their entries in the pending commit message into `deleted` ones'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "serve" -d 'Answer JSON-RPC requests from an editor plugin (status, generateMessage, commit, push)'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "set-editor" -d 'Set the editor to use for editing the commit message'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "submodule" -d 'Run a `git submodule` command (e.g. `rona submodule update --init --recursive`)'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "squash" -d 'Squash the last N commits into one, merging their messages'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "tag" -d 'Create an annotated tag from an edited message, list tags with their messages, or push tags'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "wip" -d 'Stage everything and commit it as a `wip: <timestamp>` checkpoint, leaving `commit_message.md` untouched'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "unadd" -d 'Unstage the staged files matching the patterns passed as positional arguments (`git restore --staged`)'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "watch" -d 'Watch the working tree, showing what would be staged and optionally recording checkpoints on a WIP branch'
+complete -c rona -n "__fish_rona_using_subcommand help; and not __fish_seen_subcommand_from add-with-exclude branch checkout cherry-pick commit completion conflicts diff doctor fetch flow generate hooks history info init lint list-status mv open push rebase release revert rewrite rm serve set-editor submodule squash tag wip unadd watch help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rona -n "__fish_rona_using_subcommand help; and __fish_seen_subcommand_from branch" -f -a "from-issue" -d 'Create and check out a branch named from an issue number or a title'
+complete -c rona -n "__fish_rona_using_subcommand help; and __fish_seen_subcommand_from branch" -f -a "describe" -d 'Edit the description of a branch (`branch.<name>.description`) in the editor'
+complete -c rona -n "__fish_rona_using_subcommand help; and __fish_seen_subcommand_from hooks" -f -a "generate" -d 'Write a hook into the hooks directory (honouring `core.hooksPath`)'
 
 # === CUSTOM RONA COMPLETIONS ===
 # Helper function to get git status files
@@ -74,3 +578,11 @@ end
 # Command-specific completions
 # add-with-exclude: Complete with git status files
 complete -c rona -n '__fish_seen_subcommand_from add-with-exclude -a' -xa '(__rona_status_files)'
+# push: Complete with remotes and branches
+complete -c rona -n '__fish_seen_subcommand_from push -p' -xa '(rona completion --list remotes) (rona completion --list branches)'
+# checkout: Complete with branches
+complete -c rona -n '__fish_seen_subcommand_from checkout' -xa '(rona completion --list branches)'
+# unadd: Complete with staged files
+complete -c rona -n '__fish_seen_subcommand_from unadd unstage' -xa '(rona list-status --staged)'
+# open: Complete --remote with remotes
+complete -c rona -n '__fish_seen_subcommand_from open' -l remote -xa '(rona completion --list remotes)'
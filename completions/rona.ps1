@@ -22,113 +22,871 @@ Register-ArgumentCompleter -Native -CommandName 'rona' -ScriptBlock {
     $completions = @(switch ($command) {
         'rona' {
             [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Use the custom config file path instead of default')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
             [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Verbose output - show detailed information about operations')
             [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Verbose output - show detailed information about operations')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Quiet output - only show errors')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Quiet output - only show errors')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
             [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
             [CompletionResult]::new('add-with-exclude', 'add-with-exclude', [CompletionResultType]::ParameterValue, 'Add all files to the `git add` command and exclude the patterns passed as positional arguments')
+            [CompletionResult]::new('branch', 'branch', [CompletionResultType]::ParameterValue, 'Create branches named from a template (`from-issue`)')
+            [CompletionResult]::new('checkout', 'checkout', [CompletionResultType]::ParameterValue, 'Check out a local or remote branch, picked with a fuzzy filter')
+            [CompletionResult]::new('cherry-pick', 'cherry-pick', [CompletionResultType]::ParameterValue, 'Apply commits of another branch, their `[N] (type on branch)` headers getting the next commit numbers and the current branch')
             [CompletionResult]::new('commit', 'commit', [CompletionResultType]::ParameterValue, 'Directly commit the file with the text in `commit_message.md`')
             [CompletionResult]::new('completion', 'completion', [CompletionResultType]::ParameterValue, 'Generate shell completions for your shell')
+            [CompletionResult]::new('conflicts', 'conflicts', [CompletionResultType]::ParameterValue, 'Resolve the conflicted files of a stopped merge or rebase, then continue or abort it')
+            [CompletionResult]::new('diff', 'diff', [CompletionResultType]::ParameterValue, 'Show the staged changes about to be committed, with a files/insertions/deletions summary')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Check the environment (git, repository, identity, signing, editor, config, hooks, remotes) and suggest fixes')
+            [CompletionResult]::new('fetch', 'fetch', [CompletionResultType]::ParameterValue, 'Fetch from a remote, or all remotes, and summarize the changed branches')
+            [CompletionResult]::new('flow', 'flow', [CompletionResultType]::ParameterValue, 'Stage, generate and edit the message, commit and optionally push in one command')
             [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Directly generate the `commit_message.md` file')
+            [CompletionResult]::new('hooks', 'hooks', [CompletionResultType]::ParameterValue, 'Generate git hooks running rona (e.g. `commit-msg` validating plain `git commit` messages)')
+            [CompletionResult]::new('history', 'history', [CompletionResultType]::ParameterValue, 'Show the rona invocations that moved HEAD, or every recorded operation with `--ops`, from the audit log')
+            [CompletionResult]::new('info', 'info', [CompletionResultType]::ParameterValue, 'Show the branch, upstream, last commit and push, stashes and pending commit message')
             [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize the rona configuration file')
+            [CompletionResult]::new('lint', 'lint', [CompletionResultType]::ParameterValue, 'Validate a commit message (subject length, empty bullets, typos), failing if it has issues')
             [CompletionResult]::new('list-status', 'list-status', [CompletionResultType]::ParameterValue, 'List files from git status (for shell completion on the -a)')
+            [CompletionResult]::new('mv', 'mv', [CompletionResultType]::ParameterValue, 'Move or rename files with `git mv`, moving their entries in the pending commit message')
+            [CompletionResult]::new('open', 'open', [CompletionResultType]::ParameterValue, 'Open the remote repository, current branch, a file or a new pull request page in the browser')
             [CompletionResult]::new('push', 'push', [CompletionResultType]::ParameterValue, 'Push to a git repository')
+            [CompletionResult]::new('rebase', 'rebase', [CompletionResultType]::ParameterValue, 'Rebase onto a revision, picking, squashing, fixing up, dropping and reordering the commits with `-i`')
+            [CompletionResult]::new('release', 'release', [CompletionResultType]::ParameterValue, 'Bump the version, create a release commit and tag, then push both')
+            [CompletionResult]::new('revert', 'revert', [CompletionResultType]::ParameterValue, 'Revert a commit, generating a rona-format revert message to edit and commit')
+            [CompletionResult]::new('rewrite', 'rewrite', [CompletionResultType]::ParameterValue, 'Reword the commits of BASE..HEAD into the rona format, keeping their bodies, through an interactive rebase')
+            [CompletionResult]::new('rm', 'rm', [CompletionResultType]::ParameterValue, 'Remove files with `git rm`, turning their entries in the pending commit message into `deleted` ones')
+            [CompletionResult]::new('serve', 'serve', [CompletionResultType]::ParameterValue, 'Answer JSON-RPC requests from an editor plugin (status, generateMessage, commit, push)')
             [CompletionResult]::new('set-editor', 'set-editor', [CompletionResultType]::ParameterValue, 'Set the editor to use for editing the commit message')
+            [CompletionResult]::new('submodule', 'submodule', [CompletionResultType]::ParameterValue, 'Run a `git submodule` command (e.g. `rona submodule update --init --recursive`)')
+            [CompletionResult]::new('squash', 'squash', [CompletionResultType]::ParameterValue, 'Squash the last N commits into one, merging their messages')
+            [CompletionResult]::new('tag', 'tag', [CompletionResultType]::ParameterValue, 'Create an annotated tag from an edited message, list tags with their messages, or push tags')
+            [CompletionResult]::new('wip', 'wip', [CompletionResultType]::ParameterValue, 'Stage everything and commit it as a `wip: <timestamp>` checkpoint, leaving `commit_message.md` untouched')
+            [CompletionResult]::new('unadd', 'unadd', [CompletionResultType]::ParameterValue, 'Unstage the staged files matching the patterns passed as positional arguments (`git restore --staged`)')
+            [CompletionResult]::new('unstage', 'unstage', [CompletionResultType]::ParameterValue, 'Unstage the staged files matching the patterns passed as positional arguments (`git restore --staged`)')
+            [CompletionResult]::new('watch', 'watch', [CompletionResultType]::ParameterValue, 'Watch the working tree, showing what would be staged and optionally recording checkpoints on a WIP branch')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
         'rona;add-with-exclude' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
             [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be added without actually adding files')
+            [CompletionResult]::new('--fuzzy', '--fuzzy', [CompletionResultType]::ParameterName, 'Fuzzy-match the arguments against changed files instead of using glob patterns')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;branch' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('from-issue', 'from-issue', [CompletionResultType]::ParameterValue, 'Create and check out a branch named from an issue number or a title')
+            [CompletionResult]::new('describe', 'describe', [CompletionResultType]::ParameterValue, 'Edit the description of a branch (`branch.<name>.description`) in the editor')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rona;branch;from-issue' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'The `{type}` of the branch name')
+            [CompletionResult]::new('--type', '--type', [CompletionResultType]::ParameterName, 'The `{type}` of the branch name')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--no-checkout', '--no-checkout', [CompletionResultType]::ParameterName, 'Create the branch without checking it out')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show the branch name without creating it')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;branch;describe' {
+            [CompletionResult]::new('-m', '-m', [CompletionResultType]::ParameterName, 'Set the description without opening the editor')
+            [CompletionResult]::new('--message', '--message', [CompletionResultType]::ParameterName, 'Set the description without opening the editor')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show the change without making it')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;branch;help' {
+            [CompletionResult]::new('from-issue', 'from-issue', [CompletionResultType]::ParameterValue, 'Create and check out a branch named from an issue number or a title')
+            [CompletionResult]::new('describe', 'describe', [CompletionResultType]::ParameterValue, 'Edit the description of a branch (`branch.<name>.description`) in the editor')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rona;branch;help;from-issue' {
+            break
+        }
+        'rona;branch;help;describe' {
+            break
+        }
+        'rona;branch;help;help' {
+            break
+        }
+        'rona;checkout' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show the branch that would be checked out without checking it out')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;cherry-pick' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'The commit type of the commits without a rona header or a conventional commit type, which keep their message otherwise')
+            [CompletionResult]::new('--type', '--type', [CompletionResultType]::ParameterName, 'The commit type of the commits without a rona header or a conventional commit type, which keep their message otherwise')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--no-edit', '--no-edit', [CompletionResultType]::ParameterName, 'Commit the adapted messages without opening the editor')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be applied and committed without changing anything')
+            [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Create unsigned commits (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('--unsigned', '--unsigned', [CompletionResultType]::ParameterName, 'Create unsigned commits (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'rona;commit' {
+            [CompletionResult]::new('--co-author', '--co-author', [CompletionResultType]::ParameterName, 'Add a `Co-authored-by` trailer (repeatable)')
+            [CompletionResult]::new('--author', '--author', [CompletionResultType]::ParameterName, 'Author of the commit, instead of your git identity')
+            [CompletionResult]::new('--date', '--date', [CompletionResultType]::ParameterName, 'Author date of the commit (`YYYY-MM-DD[ HH:MM[:SS]]` or RFC 3339)')
+            [CompletionResult]::new('--message-file', '--message-file', [CompletionResultType]::ParameterName, 'Commit with the message of a file instead of `commit_message.md`')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
             [CompletionResult]::new('-p', '-p', [CompletionResultType]::ParameterName, 'Whether to push the commit after committing')
             [CompletionResult]::new('--push', '--push', [CompletionResultType]::ParameterName, 'Whether to push the commit after committing')
             [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be committed without actually committing')
             [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Create unsigned commit (default is to auto-detect GPG availability and sign if possible)')
             [CompletionResult]::new('--unsigned', '--unsigned', [CompletionResultType]::ParameterName, 'Create unsigned commit (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Add a `Signed-off-by` trailer with your git identity')
+            [CompletionResult]::new('--signoff', '--signoff', [CompletionResultType]::ParameterName, 'Add a `Signed-off-by` trailer with your git identity')
+            [CompletionResult]::new('--allow-secrets', '--allow-secrets', [CompletionResultType]::ParameterName, 'Commit even if the staged changes contain possible secrets (see `scan_secrets`)')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Bypass the `pre-commit` and `commit-msg` hooks')
+            [CompletionResult]::new('--no-verify', '--no-verify', [CompletionResultType]::ParameterName, 'Bypass the `pre-commit` and `commit-msg` hooks')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Run even if another rona process is using the commit message')
+            [CompletionResult]::new('--strict', '--strict', [CompletionResultType]::ParameterName, 'Refuse to commit on a protected branch (see `protected_branches`) instead of asking')
+            [CompletionResult]::new('--stdin', '--stdin', [CompletionResultType]::ParameterName, 'Commit with the message read from standard input instead of `commit_message.md`')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'rona;completion' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Write the completions to a file instead of stdout: the standard location of the shell if no path is given')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Write the completions to a file instead of stdout: the standard location of the shell if no path is given')
+            [CompletionResult]::new('--list', '--list', [CompletionResultType]::ParameterName, 'Print the dynamic values of a kind, one per line (used by the completion scripts)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rona;conflicts' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--continue', '--continue', [CompletionResultType]::ParameterName, 'Continue the merge or rebase, every conflict being resolved')
+            [CompletionResult]::new('--abort', '--abort', [CompletionResultType]::ParameterName, 'Abort the merge or rebase')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be staged and run without changing anything')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
+        'rona;diff' {
+            [CompletionResult]::new('--against', '--against', [CompletionResultType]::ParameterName, 'Compare the staged changes against a ref instead of `HEAD`')
+            [CompletionResult]::new('--color', '--color', [CompletionResultType]::ParameterName, 'When to color the diff')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('-w', '-w', [CompletionResultType]::ParameterName, 'Highlight changed words instead of whole lines')
+            [CompletionResult]::new('--word-diff', '--word-diff', [CompletionResultType]::ParameterName, 'Highlight changed words instead of whole lines')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rona;doctor' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;fetch' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--all', '--all', [CompletionResultType]::ParameterName, 'Fetch all remotes')
+            [CompletionResult]::new('--prune', '--prune', [CompletionResultType]::ParameterName, 'Remove remote-tracking branches deleted on the remote')
+            [CompletionResult]::new('--tags', '--tags', [CompletionResultType]::ParameterName, 'Fetch all tags')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be fetched without actually fetching')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;flow' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'The commit type, skipping the commit type prompt (e.g. `feat`)')
+            [CompletionResult]::new('--type', '--type', [CompletionResultType]::ParameterName, 'The commit type, skipping the commit type prompt (e.g. `feat`)')
+            [CompletionResult]::new('-m', '-m', [CompletionResultType]::ParameterName, 'The subject completing the header, over the issue title')
+            [CompletionResult]::new('--message', '--message', [CompletionResultType]::ParameterName, 'The subject completing the header, over the issue title')
+            [CompletionResult]::new('--issue', '--issue', [CompletionResultType]::ParameterName, 'Reference an issue (`Closes #N` footer, title as message); select it interactively if no number is given')
+            [CompletionResult]::new('--breaking', '--breaking', [CompletionResultType]::ParameterName, 'Describe a breaking change in a `BREAKING CHANGE` footer (asked otherwise when prompting)')
+            [CompletionResult]::new('--ticket', '--ticket', [CompletionResultType]::ParameterName, 'Reference this ticket (e.g. `PROJ-1234`) instead of the one found in the branch name')
+            [CompletionResult]::new('--co-author', '--co-author', [CompletionResultType]::ParameterName, 'Add a `Co-authored-by` trailer (repeatable)')
+            [CompletionResult]::new('--skip', '--skip', [CompletionResultType]::ParameterName, 'Skip a stage of the workflow (repeatable)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'Complete the message in the terminal instead of the editor')
+            [CompletionResult]::new('--interactive', '--interactive', [CompletionResultType]::ParameterName, 'Complete the message in the terminal instead of the editor')
+            [CompletionResult]::new('--no-edit', '--no-edit', [CompletionResultType]::ParameterName, 'Write the message without opening the editor or prompting (requires `--type`)')
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'No commit number')
+            [CompletionResult]::new('--no-commit-number', '--no-commit-number', [CompletionResultType]::ParameterName, 'No commit number')
+            [CompletionResult]::new('--ai', '--ai', [CompletionResultType]::ParameterName, 'Pre-fill the subject and file descriptions with an AI suggestion (requires the `ai` feature)')
+            [CompletionResult]::new('--no-ticket', '--no-ticket', [CompletionResultType]::ParameterName, 'Do not reference the ticket found in the branch name')
+            [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('--unsigned', '--unsigned', [CompletionResultType]::ParameterName, 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Add a `Signed-off-by` trailer with your git identity')
+            [CompletionResult]::new('--signoff', '--signoff', [CompletionResultType]::ParameterName, 'Add a `Signed-off-by` trailer with your git identity')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Run even if another rona process is using the commit message, and overwrite the edits of a pending message without asking')
+            [CompletionResult]::new('-p', '-p', [CompletionResultType]::ParameterName, 'Push the commit to the upstream of the branch')
+            [CompletionResult]::new('--push', '--push', [CompletionResultType]::ParameterName, 'Push the commit to the upstream of the branch')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show every step of the workflow without changing anything')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
         'rona;generate' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'The commit type, skipping the commit type prompt (e.g. `feat`)')
+            [CompletionResult]::new('--type', '--type', [CompletionResultType]::ParameterName, 'The commit type, skipping the commit type prompt (e.g. `feat`)')
+            [CompletionResult]::new('-m', '-m', [CompletionResultType]::ParameterName, 'The subject completing the header, over the issue title')
+            [CompletionResult]::new('--message', '--message', [CompletionResultType]::ParameterName, 'The subject completing the header, over the issue title')
+            [CompletionResult]::new('--issue', '--issue', [CompletionResultType]::ParameterName, 'Reference an issue (`Closes #N` footer, title as message); select it interactively if no number is given')
+            [CompletionResult]::new('--breaking', '--breaking', [CompletionResultType]::ParameterName, 'Describe a breaking change in a `BREAKING CHANGE` footer (asked otherwise when prompting)')
+            [CompletionResult]::new('--ticket', '--ticket', [CompletionResultType]::ParameterName, 'Reference this ticket (e.g. `PROJ-1234`) instead of the one found in the branch name')
+            [CompletionResult]::new('--co-author', '--co-author', [CompletionResultType]::ParameterName, 'Add a `Co-authored-by` trailer (repeatable)')
+            [CompletionResult]::new('--from-diff', '--from-diff', [CompletionResultType]::ParameterName, 'List the files changed by a patch file (`-` for stdin) instead of the staged files')
+            [CompletionResult]::new('--range', '--range', [CompletionResultType]::ParameterName, 'List the files changed in a commit range (`A..B`, or a single commit) instead of the staged files')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
             [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be generated without creating files')
             [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'Interactive mode - input the commit message directly in the terminal')
             [CompletionResult]::new('--interactive', '--interactive', [CompletionResultType]::ParameterName, 'Interactive mode - input the commit message directly in the terminal')
+            [CompletionResult]::new('--no-edit', '--no-edit', [CompletionResultType]::ParameterName, 'Write the message without opening the editor or prompting (requires `--type`)')
             [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'No commit number')
             [CompletionResult]::new('--no-commit-number', '--no-commit-number', [CompletionResultType]::ParameterName, 'No commit number')
+            [CompletionResult]::new('--ai', '--ai', [CompletionResultType]::ParameterName, 'Pre-fill the subject and file descriptions with an AI suggestion (requires the `ai` feature)')
+            [CompletionResult]::new('--no-ticket', '--no-ticket', [CompletionResultType]::ParameterName, 'Do not reference the ticket found in the branch name')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Add a `Signed-off-by` trailer with your git identity')
+            [CompletionResult]::new('--signoff', '--signoff', [CompletionResultType]::ParameterName, 'Add a `Signed-off-by` trailer with your git identity')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Run even if another rona process is using the commit message, and overwrite the edits of a pending message without asking')
+            [CompletionResult]::new('--update', '--update', [CompletionResultType]::ParameterName, 'Update the file entries of the pending message to the staged files, keeping what was written in it')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;hooks' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Write a hook into the hooks directory (honouring `core.hooksPath`)')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rona;hooks;generate' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Overwrite the hook if it already exists')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be written without writing it')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rona;hooks;help' {
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Write a hook into the hooks directory (honouring `core.hooksPath`)')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rona;hooks;help;generate' {
+            break
+        }
+        'rona;hooks;help;help' {
+            break
+        }
+        'rona;history' {
+            [CompletionResult]::new('-n', '-n', [CompletionResultType]::ParameterName, 'Number of entries shown, the most recent last')
+            [CompletionResult]::new('--limit', '--limit', [CompletionResultType]::ParameterName, 'Number of entries shown, the most recent last')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--ops', '--ops', [CompletionResultType]::ParameterName, 'Show every recorded operation (add, commit, push, tag, ...) with its result')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;info' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'rona;init' {
+            [CompletionResult]::new('--preset', '--preset', [CompletionResultType]::ParameterName, 'Scaffold the project setup of a preset instead of only the configuration')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
             [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be initialized without creating files')
+            [CompletionResult]::new('--scaffold', '--scaffold', [CompletionResultType]::ParameterName, 'Pick the project setup items to scaffold interactively')
+            [CompletionResult]::new('--global', '--global', [CompletionResultType]::ParameterName, 'Create the configuration file in ~/.config/rona.toml without prompting')
+            [CompletionResult]::new('--project', '--project', [CompletionResultType]::ParameterName, 'Create the configuration file in ./.rona.toml without prompting')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Overwrite an existing configuration file')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'rona;lint' {
+            [CompletionResult]::new('--file', '--file', [CompletionResultType]::ParameterName, 'The message file to validate, `#` comment lines excluded (defaults to the commit message file)')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'rona;list-status' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--staged', '--staged', [CompletionResultType]::ParameterName, 'List files with staged changes')
+            [CompletionResult]::new('--unstaged', '--unstaged', [CompletionResultType]::ParameterName, 'List tracked files with unstaged changes')
+            [CompletionResult]::new('--untracked', '--untracked', [CompletionResultType]::ParameterName, 'List untracked files')
+            [CompletionResult]::new('--deleted', '--deleted', [CompletionResultType]::ParameterName, 'List deleted files, staged or not')
+            [CompletionResult]::new('-z', '-z', [CompletionResultType]::ParameterName, 'Separate the files with NUL characters instead of newlines')
+            [CompletionResult]::new('--null', '--null', [CompletionResultType]::ParameterName, 'Separate the files with NUL characters instead of newlines')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;mv' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Overwrite the destination if it exists')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Overwrite the destination if it exists')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be moved without moving anything')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;open' {
+            [CompletionResult]::new('--file', '--file', [CompletionResultType]::ParameterName, 'Open a file on the current branch')
+            [CompletionResult]::new('--remote', '--remote', [CompletionResultType]::ParameterName, 'The remote whose repository is opened')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--pr', '--pr', [CompletionResultType]::ParameterName, 'Open the page creating a pull request from the current branch')
+            [CompletionResult]::new('--branch', '--branch', [CompletionResultType]::ParameterName, 'Open the current branch')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show the URL that would be opened without opening it')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'rona;push' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
             [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be pushed without actually pushing')
+            [CompletionResult]::new('--force-unsafe', '--force-unsafe', [CompletionResultType]::ParameterName, 'Force the push without lease (`--force` and `-f` are pushed as `--force-with-lease`)')
+            [CompletionResult]::new('--all-remotes', '--all-remotes', [CompletionResultType]::ParameterName, 'Push the current branch to every configured remote, one after the other')
+            [CompletionResult]::new('--strict', '--strict', [CompletionResultType]::ParameterName, 'Refuse to push a protected branch (see `protected_branches`) instead of asking')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;rebase' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'Choose what happens to each commit of `BASE..HEAD` from prompts')
+            [CompletionResult]::new('--interactive', '--interactive', [CompletionResultType]::ParameterName, 'Choose what happens to each commit of `BASE..HEAD` from prompts')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show the rebase todo list without rebasing')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;release' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show every step of the release without changing anything')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Create a GPG-signed tag instead of a plain annotated one')
+            [CompletionResult]::new('--sign', '--sign', [CompletionResultType]::ParameterName, 'Create a GPG-signed tag instead of a plain annotated one')
+            [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Create an unsigned release commit')
+            [CompletionResult]::new('--unsigned', '--unsigned', [CompletionResultType]::ParameterName, 'Create an unsigned release commit')
+            [CompletionResult]::new('--no-push', '--no-push', [CompletionResultType]::ParameterName, 'Do not push the release commit and tag')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;revert' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be reverted without changing anything')
+            [CompletionResult]::new('--no-edit', '--no-edit', [CompletionResultType]::ParameterName, 'Commit the revert message without opening the editor')
+            [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('--unsigned', '--unsigned', [CompletionResultType]::ParameterName, 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;rewrite' {
+            [CompletionResult]::new('-t', '-t', [CompletionResultType]::ParameterName, 'The commit type of the commits without a conventional commit type, skipping the prompt')
+            [CompletionResult]::new('--type', '--type', [CompletionResultType]::ParameterName, 'The commit type of the commits without a conventional commit type, skipping the prompt')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--no-edit', '--no-edit', [CompletionResultType]::ParameterName, 'Use the rona-format messages without prompting (requires `--type` for commits without a conventional type)')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show the rebase todo list without rewriting')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;rm' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'Remove directories recursively')
+            [CompletionResult]::new('--cached', '--cached', [CompletionResultType]::ParameterName, 'Only remove the files from the index, keeping them in the working tree')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Remove files with changes')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Remove files with changes')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be removed without removing anything')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;serve' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--stdio', '--stdio', [CompletionResultType]::ParameterName, 'Read requests from stdin and write responses to stdout, one JSON object per line')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'rona;set-editor' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
             [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be changed without modifying config')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;submodule' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show the git command without running it')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;squash' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be squashed without changing anything')
+            [CompletionResult]::new('--no-edit', '--no-edit', [CompletionResultType]::ParameterName, 'Commit the merged message without opening the editor')
+            [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('--unsigned', '--unsigned', [CompletionResultType]::ParameterName, 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;tag' {
+            [CompletionResult]::new('-m', '-m', [CompletionResultType]::ParameterName, 'The tag message, skipping the editor')
+            [CompletionResult]::new('--message', '--message', [CompletionResultType]::ParameterName, 'The tag message, skipping the editor')
+            [CompletionResult]::new('-l', '-l', [CompletionResultType]::ParameterName, 'List the tags matching the glob pattern (all by default), newest first, with the subject of their message')
+            [CompletionResult]::new('--list', '--list', [CompletionResultType]::ParameterName, 'List the tags matching the glob pattern (all by default), newest first, with the subject of their message')
+            [CompletionResult]::new('--remote', '--remote', [CompletionResultType]::ParameterName, 'The remote tags are pushed to')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Create a GPG-signed tag instead of a plain annotated one')
+            [CompletionResult]::new('--sign', '--sign', [CompletionResultType]::ParameterName, 'Create a GPG-signed tag instead of a plain annotated one')
+            [CompletionResult]::new('--push', '--push', [CompletionResultType]::ParameterName, 'Push the created tag, or every tag when no NAME is given')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show the tag message, tag and push without changing anything')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;wip' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--pop', '--pop', [CompletionResultType]::ParameterName, 'Soft-reset the last checkpoint commit, its changes going back to the index')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be staged and committed without changing anything')
+            [CompletionResult]::new('-u', '-u', [CompletionResultType]::ParameterName, 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('--unsigned', '--unsigned', [CompletionResultType]::ParameterName, 'Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;unadd' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'Pick the files to unstage from the staged files')
+            [CompletionResult]::new('--interactive', '--interactive', [CompletionResultType]::ParameterName, 'Pick the files to unstage from the staged files')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be unstaged without unstaging anything')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;unstage' {
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'Pick the files to unstage from the staged files')
+            [CompletionResult]::new('--interactive', '--interactive', [CompletionResultType]::ParameterName, 'Pick the files to unstage from the staged files')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be unstaged without unstaging anything')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'rona;watch' {
+            [CompletionResult]::new('--wip-branch', '--wip-branch', [CompletionResultType]::ParameterName, 'Record checkpoints of the working tree on a local branch (default: `wip/<branch>`), leaving HEAD and the index untouched')
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between two checkpoints')
+            [CompletionResult]::new('--profile', '--profile', [CompletionResultType]::ParameterName, 'Use the settings of a `[profile.<name>]` of the configuration, instead of the profile matching the remotes')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print dry-run plans as JSON instead of text')
+            [CompletionResult]::new('--no-pager', '--no-pager', [CompletionResultType]::ParameterName, 'Never pipe long output through the pager')
+            [CompletionResult]::new('--no-emoji', '--no-emoji', [CompletionResultType]::ParameterName, 'Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)')
+            [CompletionResult]::new('--trace-git', '--trace-git', [CompletionResultType]::ParameterName, 'Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)')
+            [CompletionResult]::new('--no-wizard', '--no-wizard', [CompletionResultType]::ParameterName, 'Never ask the first-run questions when no configuration file exists')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'rona;help' {
             [CompletionResult]::new('add-with-exclude', 'add-with-exclude', [CompletionResultType]::ParameterValue, 'Add all files to the `git add` command and exclude the patterns passed as positional arguments')
+            [CompletionResult]::new('branch', 'branch', [CompletionResultType]::ParameterValue, 'Create branches named from a template (`from-issue`)')
+            [CompletionResult]::new('checkout', 'checkout', [CompletionResultType]::ParameterValue, 'Check out a local or remote branch, picked with a fuzzy filter')
+            [CompletionResult]::new('cherry-pick', 'cherry-pick', [CompletionResultType]::ParameterValue, 'Apply commits of another branch, their `[N] (type on branch)` headers getting the next commit numbers and the current branch')
             [CompletionResult]::new('commit', 'commit', [CompletionResultType]::ParameterValue, 'Directly commit the file with the text in `commit_message.md`')
             [CompletionResult]::new('completion', 'completion', [CompletionResultType]::ParameterValue, 'Generate shell completions for your shell')
+            [CompletionResult]::new('conflicts', 'conflicts', [CompletionResultType]::ParameterValue, 'Resolve the conflicted files of a stopped merge or rebase, then continue or abort it')
+            [CompletionResult]::new('diff', 'diff', [CompletionResultType]::ParameterValue, 'Show the staged changes about to be committed, with a files/insertions/deletions summary')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Check the environment (git, repository, identity, signing, editor, config, hooks, remotes) and suggest fixes')
+            [CompletionResult]::new('fetch', 'fetch', [CompletionResultType]::ParameterValue, 'Fetch from a remote, or all remotes, and summarize the changed branches')
+            [CompletionResult]::new('flow', 'flow', [CompletionResultType]::ParameterValue, 'Stage, generate and edit the message, commit and optionally push in one command')
             [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Directly generate the `commit_message.md` file')
+            [CompletionResult]::new('hooks', 'hooks', [CompletionResultType]::ParameterValue, 'Generate git hooks running rona (e.g. `commit-msg` validating plain `git commit` messages)')
+            [CompletionResult]::new('history', 'history', [CompletionResultType]::ParameterValue, 'Show the rona invocations that moved HEAD, or every recorded operation with `--ops`, from the audit log')
+            [CompletionResult]::new('info', 'info', [CompletionResultType]::ParameterValue, 'Show the branch, upstream, last commit and push, stashes and pending commit message')
             [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize the rona configuration file')
+            [CompletionResult]::new('lint', 'lint', [CompletionResultType]::ParameterValue, 'Validate a commit message (subject length, empty bullets, typos), failing if it has issues')
             [CompletionResult]::new('list-status', 'list-status', [CompletionResultType]::ParameterValue, 'List files from git status (for shell completion on the -a)')
+            [CompletionResult]::new('mv', 'mv', [CompletionResultType]::ParameterValue, 'Move or rename files with `git mv`, moving their entries in the pending commit message')
+            [CompletionResult]::new('open', 'open', [CompletionResultType]::ParameterValue, 'Open the remote repository, current branch, a file or a new pull request page in the browser')
             [CompletionResult]::new('push', 'push', [CompletionResultType]::ParameterValue, 'Push to a git repository')
+            [CompletionResult]::new('rebase', 'rebase', [CompletionResultType]::ParameterValue, 'Rebase onto a revision, picking, squashing, fixing up, dropping and reordering the commits with `-i`')
+            [CompletionResult]::new('release', 'release', [CompletionResultType]::ParameterValue, 'Bump the version, create a release commit and tag, then push both')
+            [CompletionResult]::new('revert', 'revert', [CompletionResultType]::ParameterValue, 'Revert a commit, generating a rona-format revert message to edit and commit')
+            [CompletionResult]::new('rewrite', 'rewrite', [CompletionResultType]::ParameterValue, 'Reword the commits of BASE..HEAD into the rona format, keeping their bodies, through an interactive rebase')
+            [CompletionResult]::new('rm', 'rm', [CompletionResultType]::ParameterValue, 'Remove files with `git rm`, turning their entries in the pending commit message into `deleted` ones')
+            [CompletionResult]::new('serve', 'serve', [CompletionResultType]::ParameterValue, 'Answer JSON-RPC requests from an editor plugin (status, generateMessage, commit, push)')
             [CompletionResult]::new('set-editor', 'set-editor', [CompletionResultType]::ParameterValue, 'Set the editor to use for editing the commit message')
+            [CompletionResult]::new('submodule', 'submodule', [CompletionResultType]::ParameterValue, 'Run a `git submodule` command (e.g. `rona submodule update --init --recursive`)')
+            [CompletionResult]::new('squash', 'squash', [CompletionResultType]::ParameterValue, 'Squash the last N commits into one, merging their messages')
+            [CompletionResult]::new('tag', 'tag', [CompletionResultType]::ParameterValue, 'Create an annotated tag from an edited message, list tags with their messages, or push tags')
+            [CompletionResult]::new('wip', 'wip', [CompletionResultType]::ParameterValue, 'Stage everything and commit it as a `wip: <timestamp>` checkpoint, leaving `commit_message.md` untouched')
+            [CompletionResult]::new('unadd', 'unadd', [CompletionResultType]::ParameterValue, 'Unstage the staged files matching the patterns passed as positional arguments (`git restore --staged`)')
+            [CompletionResult]::new('watch', 'watch', [CompletionResultType]::ParameterValue, 'Watch the working tree, showing what would be staged and optionally recording checkpoints on a WIP branch')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
         'rona;help;add-with-exclude' {
             break
         }
+        'rona;help;branch' {
+            [CompletionResult]::new('from-issue', 'from-issue', [CompletionResultType]::ParameterValue, 'Create and check out a branch named from an issue number or a title')
+            [CompletionResult]::new('describe', 'describe', [CompletionResultType]::ParameterValue, 'Edit the description of a branch (`branch.<name>.description`) in the editor')
+            break
+        }
+        'rona;help;branch;from-issue' {
+            break
+        }
+        'rona;help;branch;describe' {
+            break
+        }
+        'rona;help;checkout' {
+            break
+        }
+        'rona;help;cherry-pick' {
+            break
+        }
         'rona;help;commit' {
             break
         }
         'rona;help;completion' {
             break
         }
+        'rona;help;conflicts' {
+            break
+        }
+        'rona;help;diff' {
+            break
+        }
+        'rona;help;doctor' {
+            break
+        }
+        'rona;help;fetch' {
+            break
+        }
+        'rona;help;flow' {
+            break
+        }
         'rona;help;generate' {
             break
         }
+        'rona;help;hooks' {
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Write a hook into the hooks directory (honouring `core.hooksPath`)')
+            break
+        }
+        'rona;help;hooks;generate' {
+            break
+        }
+        'rona;help;history' {
+            break
+        }
+        'rona;help;info' {
+            break
+        }
         'rona;help;init' {
             break
         }
+        'rona;help;lint' {
+            break
+        }
         'rona;help;list-status' {
             break
         }
+        'rona;help;mv' {
+            break
+        }
+        'rona;help;open' {
+            break
+        }
         'rona;help;push' {
             break
         }
+        'rona;help;rebase' {
+            break
+        }
+        'rona;help;release' {
+            break
+        }
+        'rona;help;revert' {
+            break
+        }
+        'rona;help;rewrite' {
+            break
+        }
+        'rona;help;rm' {
+            break
+        }
+        'rona;help;serve' {
+            break
+        }
         'rona;help;set-editor' {
             break
         }
+        'rona;help;submodule' {
+            break
+        }
+        'rona;help;squash' {
+            break
+        }
+        'rona;help;tag' {
+            break
+        }
+        'rona;help;wip' {
+            break
+        }
+        'rona;help;unadd' {
+            break
+        }
+        'rona;help;watch' {
+            break
+        }
         'rona;help;help' {
             break
         }
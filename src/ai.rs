@@ -0,0 +1,253 @@
+//! AI-Assisted Commit Message Drafting
+//!
+//! Sends the staged diff to an OpenAI-compatible chat completions endpoint and turns the
+//! suggestion into a [`CommitDraft`] pre-filling `commit_message.md` before the editor opens.
+//!
+//! Requests are made with `curl`, like the issue REST fallback, the API key passed in its
+//! configuration on stdin rather than on the command line, and the module is only
+//! compiled with the `ai` cargo feature.
+
+use std::env;
+
+use log::{debug, warn};
+use serde_json::{Value, json};
+
+use crate::{
+    config::ProjectConfig,
+    errors::{Result, RonaError},
+    git::CommitDraft,
+    utils,
+};
+
+/// API base URL used when no `ai_endpoint` is configured.
+pub const DEFAULT_AI_ENDPOINT: &str = "https://api.openai.com/v1";
+
+/// Model used when no `ai_model` is configured.
+pub const DEFAULT_AI_MODEL: &str = "gpt-4o-mini";
+
+/// Environment variables read, in order, when no `ai_api_key` is configured.
+const API_KEY_VARIABLES: [&str; 2] = ["RONA_AI_API_KEY", "OPENAI_API_KEY"];
+
+/// Maximum number of diff bytes sent to the provider.
+const MAX_DIFF_LENGTH: usize = 60_000;
+
+const SYSTEM_PROMPT: &str = "You write git commit messages. Given a staged diff, reply with a \
+JSON object only: {\"subject\": \"<imperative summary, at most 60 characters>\", \"files\": \
+{\"<path>\": \"<one sentence describing the changes of this file>\"}}. Use the paths exactly as \
+they appear in the diff.";
+
+/// An OpenAI-compatible chat completions provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AiProvider {
+    /// API base URL, `/chat/completions` being appended
+    pub endpoint: String,
+    /// Model name
+    pub model: String,
+    /// Bearer token
+    pub api_key: String,
+}
+
+impl AiProvider {
+    /// Builds the provider from the project configuration, falling back to the
+    /// `RONA_AI_API_KEY` and `OPENAI_API_KEY` environment variables for the key.
+    ///
+    /// # Errors
+    /// * If no API key is configured
+    pub fn from_config(config: &ProjectConfig) -> Result<Self> {
+        let api_key = config
+            .ai_api_key
+            .clone()
+            .or_else(|| {
+                API_KEY_VARIABLES
+                    .iter()
+                    .find_map(|name| env::var(name).ok())
+            })
+            .filter(|key| !key.trim().is_empty())
+            .ok_or_else(|| {
+                RonaError::InvalidInput(
+                    "No API key for `generate --ai`: set `ai_api_key` in your configuration \
+                     or the RONA_AI_API_KEY environment variable"
+                        .to_string(),
+                )
+            })?;
+
+        Ok(Self {
+            endpoint: config
+                .ai_endpoint
+                .clone()
+                .unwrap_or_else(|| DEFAULT_AI_ENDPOINT.to_string()),
+            model: config
+                .ai_model
+                .clone()
+                .unwrap_or_else(|| DEFAULT_AI_MODEL.to_string()),
+            api_key,
+        })
+    }
+
+    /// The chat completions URL.
+    #[must_use]
+    pub fn completions_url(&self) -> String {
+        format!("{}/chat/completions", self.endpoint.trim_end_matches('/'))
+    }
+
+    /// Builds the chat completions request for a diff.
+    #[must_use]
+    pub fn request_body(&self, diff: &str) -> Value {
+        json!({
+            "model": self.model,
+            "temperature": 0.2,
+            "messages": [
+                { "role": "system", "content": SYSTEM_PROMPT },
+                { "role": "user", "content": truncate_diff(diff) },
+            ],
+        })
+    }
+
+    /// Asks the provider for a commit message draft of the diff.
+    ///
+    /// # Errors
+    /// * If curl cannot be run or the request fails
+    /// * If the response is not a chat completion containing a draft
+    pub fn draft(&self, diff: &str) -> Result<CommitDraft> {
+        let url = self.completions_url();
+        debug!(
+            "Requesting a commit message draft from {url} ({})",
+            self.model
+        );
+
+        let authorization = format!("Authorization: Bearer {}", self.api_key);
+        let body = self.request_body(diff).to_string();
+        let output = utils::curl(
+            &["-sS", "--fail-with-body", "-X", "POST"],
+            &[
+                ("url", &url),
+                ("header", "Content-Type: application/json"),
+                ("header", &authorization),
+                ("data-binary", &body),
+            ],
+        )?;
+        if !output.status.success() {
+            warn!(
+                "AI request failed: {}{}",
+                String::from_utf8_lossy(&output.stderr).trim(),
+                String::from_utf8_lossy(&output.stdout).trim()
+            );
+            return Err(RonaError::CommandFailed {
+                command: format!("curl -X POST {url}"),
+            });
+        }
+
+        parse_completion(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Keeps the first [`MAX_DIFF_LENGTH`] bytes of a diff, on a character boundary.
+fn truncate_diff(diff: &str) -> &str {
+    if diff.len() <= MAX_DIFF_LENGTH {
+        return diff;
+    }
+
+    let end = (0..=MAX_DIFF_LENGTH)
+        .rev()
+        .find(|&index| diff.is_char_boundary(index))
+        .unwrap_or_default();
+    &diff[..end]
+}
+
+/// Extracts the commit message draft from a chat completion response.
+///
+/// # Errors
+/// * If the response has no message content or the content is not a draft
+pub fn parse_completion(response: &str) -> Result<CommitDraft> {
+    let invalid = |reason: &str| RonaError::InvalidInput(format!("Invalid AI response: {reason}"));
+
+    let response: Value = serde_json::from_str(response).map_err(|e| invalid(&e.to_string()))?;
+    let content = response["choices"][0]["message"]["content"]
+        .as_str()
+        .ok_or_else(|| invalid("no message content"))?;
+
+    parse_draft(content).map_err(|e| invalid(&e.to_string()))
+}
+
+/// Parses the JSON draft written by the model, ignoring a surrounding code fence.
+///
+/// # Errors
+/// * If the content is not a JSON draft
+pub fn parse_draft(content: &str) -> serde_json::Result<CommitDraft> {
+    let content = content.trim();
+    let content = content
+        .strip_prefix("```json")
+        .or_else(|| content.strip_prefix("```"))
+        .and_then(|rest| rest.strip_suffix("```"))
+        .unwrap_or(content);
+
+    let mut draft: CommitDraft = serde_json::from_str(content.trim())?;
+    draft.subject = draft
+        .subject
+        .map(|subject| subject.trim().to_string())
+        .filter(|subject| !subject.is_empty());
+
+    Ok(draft)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provider() -> AiProvider {
+        AiProvider {
+            endpoint: "http://localhost:8080/v1/".to_string(),
+            model: "local".to_string(),
+            api_key: "key".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_completions_url() {
+        assert_eq!(
+            provider().completions_url(),
+            "http://localhost:8080/v1/chat/completions"
+        );
+    }
+
+    #[test]
+    fn test_request_body() {
+        let body = provider().request_body("diff --git a/a.rs b/a.rs");
+
+        assert_eq!(body["model"], "local");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["messages"][1]["content"], "diff --git a/a.rs b/a.rs");
+    }
+
+    #[test]
+    fn test_truncate_diff() {
+        let diff = "é".repeat(MAX_DIFF_LENGTH);
+        let truncated = truncate_diff(&diff);
+
+        assert!(truncated.len() <= MAX_DIFF_LENGTH);
+        assert_eq!(truncate_diff("short"), "short");
+    }
+
+    #[test]
+    fn test_parse_completion() {
+        let response = json!({
+            "choices": [{
+                "message": {
+                    "role": "assistant",
+                    "content": "```json\n{\"subject\": \" Add diff command \", \"files\": {\"src/cli.rs\": \"Wire the diff command\"}}\n```"
+                }
+            }]
+        });
+
+        let draft = parse_completion(&response.to_string()).unwrap();
+
+        assert_eq!(draft.subject.as_deref(), Some("Add diff command"));
+        assert_eq!(draft.files["src/cli.rs"], "Wire the diff command");
+    }
+
+    #[test]
+    fn test_parse_completion_invalid() {
+        assert!(parse_completion("{\"choices\": []}").is_err());
+        assert!(parse_draft("not json").is_err());
+    }
+}
@@ -9,12 +9,39 @@
 //!
 //! The CLI supports several commands:
 //! - `add-with-exclude`: Add files to git while excluding specified patterns
+//! - `branch`: Create and check out a branch named from an issue (`from-issue`), or edit the description of a branch (`describe`)
+//! - `checkout`: Check out a local or remote branch picked with a fuzzy filter
+//! - `cherry-pick`: Apply commits of another branch, adapting their headers to the current branch
 //! - `commit`: Commit changes using the commit message from `commit_message.md`
+//! - `completion`: Generate shell completion scripts
+//! - `conflicts`: Resolve the conflicts of a stopped merge or rebase, then continue or abort it
+//! - `diff`: Show the staged changes with a files/insertions/deletions summary
+//! - `doctor`: Check git, the repository, signing, the editor, the config, hooks and remotes
+//! - `fetch`: Fetch from remotes and summarize the changed branches
+//! - `flow`: Stage, generate and edit the message, commit and optionally push in one command
 //! - `generate`: Generate a new commit message file
+//! - `history`: Show the rona invocations that moved HEAD, or every recorded operation (`--ops`)
+//! - `hooks`: Generate git hooks running rona (`commit-msg` validating messages)
+//! - `info`: Show the branch, upstream, last commit and push, stashes and pending commit message
 //! - `init`: Initialize Rona configuration
+//! - `lint`: Validate a commit message file, failing if it has issues
 //! - `list-status`: List git status files (for shell completion)
+//! - `mv`: Move files with `git mv`, moving their entries in the pending commit message
+//! - `open`: Open the remote repository, branch, file or pull request page in the browser
 //! - `push`: Push changes to remote repository
+//! - `rebase`: Rebase onto a revision, choosing what happens to each commit with `-i`
+//! - `release`: Bump the version, commit, tag and push a release
+//! - `revert`: Revert a commit, generating a rona-format revert message to edit and commit
+//! - `rewrite`: Reword the commits of a range into the rona format through an interactive rebase
+//! - `rm`: Remove files with `git rm`, marking their entries in the pending commit message as deleted
+//! - `serve`: Answer JSON-RPC requests from editor plugins on stdin/stdout
 //! - `set-editor`: Configure the editor for commit messages
+//! - `squash`: Squash the last commits into one with a merged message
+//! - `submodule`: Run `git submodule` (e.g. `update --init --recursive`)
+//! - `tag`: Create an annotated tag from an edited message, list tags or push them
+//! - `unadd`: Unstage the staged files matching patterns, or picked from a list
+//! - `watch`: Show what would be staged as the working tree changes, with optional WIP branch checkpoints
+//! - `wip`: Commit everything as a `wip: <timestamp>` checkpoint, or pop the last one
 //!
 //! # Features
 //!
@@ -25,24 +52,331 @@
 //! - Handles configuration management
 //!
 
-use clap::{Command as ClapCommand, CommandFactory, Parser, Subcommand, ValueHint, command};
+use chrono::{DateTime, Utc};
+use clap::{
+    Args, Command as ClapCommand, CommandFactory, Parser, Subcommand, ValueEnum, ValueHint,
+    builder::{OsStringValueParser, TypedValueParser},
+};
 use clap_complete::{Shell, generate};
 use glob::Pattern;
-use inquire::ui::{Attributes, Color, RenderConfig, StyleSheet, Styled};
-use inquire::{Select, Text};
-use std::{io, process::Command};
+use inquire::{Confirm, MultiSelect, Select, Text};
+use log::{debug, info, warn};
+use serde::Deserialize;
+use serde_json::json;
+use std::{
+    env,
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use crate::{
-    config::Config,
-    errors::Result,
+    config::{Config, ConfigLocation},
+    doctor::{CheckStatus, render_checks, run_checks},
+    errors::{GitError, Result, RonaError},
     git::{
-        COMMIT_MESSAGE_FILE_PATH, COMMIT_TYPES, create_needed_files, format_branch_name,
-        generate_commit_message, get_current_branch, get_current_commit_nb, get_status_files,
-        git_add_with_exclude_patterns, git_commit, git_push,
+        AuditRecord, COMMIT_TYPES, CommitChecks, CommitDraft, CommitNumbering, CommitSigning,
+        DEFAULT_BRANCH_TEMPLATE, DEFAULT_LARGE_FILE_THRESHOLD, DEFAULT_RELEASE_FILES, DiffColor,
+        DiffOptions, EmojiPolicy, FetchOptions, FinishAction, Issue, MessageChanges, MessageFiles,
+        MessageLock, RebaseAction, RebaseCommit, ReleaseOptions, RemoveOptions, RepositoryState,
+        RevertPreparation, RewriteEntry, ShallowClone, SquashPreparation, StatusSet,
+        StatusSnapshot, Trailer, adapt_message, append_trailers, apply_emoji_policy,
+        audit_log_path, authorship_args, backup_path, branch_description_path,
+        branch_name_from_template, branch_ticket, build_todo, bypasses_upstream_check,
+        check_wip_branch, cherry_pick_no_commit, commit_number_at, committed_files,
+        compare_messages, conventional_subject, count_ahead_behind, create_annotated_tag,
+        create_branch, create_needed_files, default_push_remote, discarded_remote_commits,
+        draft_from_message, exec, fetch_issue,
+        files::worktree_relative_path,
+        finish_operation, force_push_args, forces_push, format_branch_name, get_branch_description,
+        get_current_branch, get_default_branch, get_last_commit, get_last_push_time,
+        get_remote_url, get_stash_count, get_status_files, get_upstream,
+        git_add_with_exclude_patterns, git_add_with_fuzzy_exclusions, git_checkout, git_commit,
+        git_commit_checked, git_commit_with_message, git_fetch, git_mv, git_pull_rebase, git_push,
+        git_push_remotes, git_rebase, git_rebase_interactive, git_release, git_rm, git_submodule,
+        git_unshallow, git_unstage, git_unstage_patterns, git_wip, git_wip_pop,
+        has_conflict_markers, has_staged_changes, head_name,
+        hooks::{COMMIT_MSG_HOOK, write_hook},
+        is_head_detached, is_protected_branch, is_rona_header, is_shallow_repository,
+        is_upstream_in_reflog, list_branches, list_checkout_targets, list_conflicted_files,
+        list_open_issues, list_picked_commits, list_rebase_commits, list_remotes, list_tags,
+        lock_commit_message, mark_resolved, move_message_entries, next_commit_number,
+        normalize_version, operation_in_progress, parse_branch_description, parse_remote_url,
+        prepare_revert, prepare_squash, push_tags, read_audit_log, read_branch_description,
+        read_commit_message, read_reverted_commit, read_tag_message, remove_message_entries,
+        render_commit_message, repository_state, rerere_enabled, rewrite_commits, rewrite_message,
+        rewrite_message_path, set_branch_description, set_network_timeout, slugify, staged_diff,
+        suggest_commit_type, tag_message_path, update_commit_message,
+        validation::{
+            DEFAULT_MAX_SUBJECT_LENGTH, ValidationRules, lint_commit_message, strip_comments,
+        },
+        warn_large_files, with_branch_description, with_ticket, write_branch_description,
+        write_commit_message, write_tag_message,
     },
+    i18n::{self, Language, tr, tr_args},
+    logging::{self, Verbosity},
+    notification::{Notification, NotificationEvent, send_notification},
+    performance::join,
+    plan::{ActionKind, OutputFormat, Plan},
+    scaffold::{Preset, ScaffoldItem, scaffold as scaffold_project},
+    serve::{parse_params, serve},
     template::{TemplateVariables, process_template, validate_template},
+    theme::{Stream, Theme, colors_enabled, decorations_enabled},
+    utils::{
+        build_editor_command, find_project_root, format_age, open_in_browser, print_paged,
+        to_git_path,
+    },
+    watch::{WatchOptions, watch},
+    wizard::{is_first_run, run_wizard},
 };
 
+/// Options of the Generate command shaping the message.
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GenerateOptions {
+    /// The commit type, skipping the commit type prompt (e.g. `feat`)
+    #[arg(short = 't', long = "type", value_name = "TYPE")]
+    commit_type: Option<String>,
+
+    /// The subject completing the header, over the issue title
+    #[arg(short = 'm', long, conflicts_with = "interactive")]
+    message: Option<String>,
+
+    /// Write the message without opening the editor or prompting (requires `--type`)
+    #[arg(long, default_value_t = false, conflicts_with = "interactive")]
+    no_edit: bool,
+
+    /// No commit number
+    #[arg(short = 'n', long = "no-commit-number", default_value_t = false)]
+    no_commit_number: bool,
+
+    /// Reference an issue (`Closes #N` footer, title as message); select it interactively if no number is given
+    #[arg(long, value_name = "NUMBER", num_args = 0..=1, default_missing_value = "select")]
+    issue: Option<IssueSelection>,
+
+    /// Pre-fill the subject and file descriptions with an AI suggestion (requires the `ai` feature)
+    #[arg(long, default_value_t = false, conflicts_with = "interactive")]
+    ai: bool,
+
+    /// Describe a breaking change in a `BREAKING CHANGE` footer (asked otherwise when prompting)
+    #[arg(long, value_name = "DESCRIPTION")]
+    breaking: Option<String>,
+
+    /// Ticket of the message, instead of the one found in the branch name
+    #[command(flatten)]
+    ticket: TicketArgs,
+}
+
+/// Ticket added to generated messages, found in the branch name by `ticket_pattern` unless given
+#[derive(Args, Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct TicketArgs {
+    /// Reference this ticket (e.g. `PROJ-1234`) instead of the one found in the branch name
+    #[arg(long, value_name = "ID", conflicts_with = "no_ticket")]
+    ticket: Option<String>,
+
+    /// Do not reference the ticket found in the branch name
+    #[arg(long, default_value_t = false)]
+    no_ticket: bool,
+}
+
+/// Changes listed by `rona generate` instead of the staged files
+#[derive(Args, Default)]
+pub(crate) struct DiffSourceArgs {
+    /// List the files changed by a patch file (`-` for stdin) instead of the staged files
+    #[arg(
+        long,
+        value_name = "FILE",
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = ["range", "update"]
+    )]
+    from_diff: Option<PathBuf>,
+
+    /// List the files changed in a commit range (`A..B`, or a single commit) instead of the staged files
+    #[arg(long, value_name = "A..B", conflicts_with = "update")]
+    range: Option<String>,
+}
+
+impl DiffSourceArgs {
+    /// The files listed by `generate --from-diff` (a patch file, `-` reading it from stdin)
+    /// or `--range`, `None` for the staged files.
+    ///
+    /// # Errors
+    /// * `RonaError::InvalidInput` - If the patch cannot be read, or neither it nor the range
+    ///   changes any file
+    /// * If the range does not name commits
+    fn files(&self) -> Result<Option<MessageFiles>> {
+        let (files, source) = if let Some(path) = &self.from_diff {
+            let patch = if path.as_os_str() == "-" {
+                io::read_to_string(io::stdin())
+            } else {
+                std::fs::read_to_string(path)
+            }
+            .map_err(|e| {
+                RonaError::InvalidInput(format!("Cannot read the patch {}: {e}", path.display()))
+            })?;
+            (MessageFiles::from_patch(&patch), path.display().to_string())
+        } else if let Some(range) = &self.range {
+            (MessageFiles::from_range(range)?, range.clone())
+        } else {
+            return Ok(None);
+        };
+
+        if files.changed.is_empty() && files.deleted.is_empty() {
+            return Err(RonaError::InvalidInput(format!(
+                "{source} changes no files - nothing to list in the message"
+            )));
+        }
+        Ok(Some(files))
+    }
+}
+
+/// Issue referenced by `generate --issue`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IssueSelection {
+    /// A specific issue number
+    Number(u64),
+    /// Pick one of the open issues interactively
+    Select,
+}
+
+impl std::str::FromStr for IssueSelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        if value == "select" {
+            return Ok(Self::Select);
+        }
+
+        value
+            .trim_start_matches('#')
+            .parse()
+            .map(Self::Number)
+            .map_err(|_| format!("'{value}' is not an issue number"))
+    }
+}
+
+/// Actions of the `hooks` command.
+#[derive(Subcommand)]
+pub(crate) enum HooksAction {
+    /// Write a hook into the hooks directory (honouring `core.hooksPath`)
+    Generate {
+        /// The hook to generate
+        hook: HookKind,
+
+        /// Overwrite the hook if it already exists
+        #[arg(long, default_value_t = false)]
+        force: bool,
+
+        /// Show what would be written without writing it
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+    },
+}
+
+/// Actions of the `branch` command.
+#[derive(Subcommand)]
+pub(crate) enum BranchAction {
+    /// Create and check out a branch named from an issue number or a title
+    #[command(name = "from-issue")]
+    FromIssue(FromIssueArgs),
+
+    /// Edit the description of a branch (`branch.<name>.description`) in the editor
+    #[command(name = "describe")]
+    Describe(DescribeArgs),
+}
+
+/// Arguments of the `branch describe` command
+#[derive(Args)]
+pub(crate) struct DescribeArgs {
+    /// The branch described (defaults to the current branch)
+    #[arg(value_name = "BRANCH")]
+    branch: Option<String>,
+
+    /// Set the description without opening the editor
+    #[arg(short = 'm', long = "message", value_name = "DESCRIPTION")]
+    message: Option<String>,
+
+    /// Show the change without making it
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+/// Arguments of the `branch from-issue` command
+#[derive(Args)]
+pub(crate) struct FromIssueArgs {
+    /// An issue number (its title is fetched from GitHub/GitLab) or a title
+    #[arg(value_name = "ID|TITLE")]
+    issue: String,
+
+    /// The `{type}` of the branch name
+    #[arg(
+        short = 't',
+        long = "type",
+        value_name = "TYPE",
+        default_value = "feat"
+    )]
+    branch_type: String,
+
+    /// Create the branch without checking it out
+    #[arg(long, default_value_t = false)]
+    no_checkout: bool,
+
+    /// Show the branch name without creating it
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+/// Arguments of the `rm` command
+#[derive(Args)]
+pub(crate) struct RmArgs {
+    /// The files to remove
+    #[arg(value_name = "PATHS", required = true, value_hint = ValueHint::AnyPath)]
+    paths: Vec<String>,
+
+    #[command(flatten)]
+    options: RemoveOptions,
+
+    /// Show what would be removed without removing anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+/// Hooks generated by `hooks generate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum HookKind {
+    /// Validate the commit message with `rona lint`
+    #[value(name = "commit-msg")]
+    CommitMsg,
+}
+
+impl HookKind {
+    /// The git name of the hook.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::CommitMsg => "commit-msg",
+        }
+    }
+
+    /// The script of the hook.
+    const fn contents(self) -> &'static str {
+        match self {
+            Self::CommitMsg => COMMIT_MSG_HOOK,
+        }
+    }
+}
+
+/// Dynamic values listed by `completion --list` for the completion scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum CompletionKind {
+    /// Files with changes in the git status
+    Files,
+    /// Configured commit types
+    CommitTypes,
+    /// Configured remotes
+    Remotes,
+    /// Local branches
+    Branches,
+}
+
 /// CLI's commands
 #[derive(Subcommand)]
 pub(crate) enum CliCommand {
@@ -56,8 +390,27 @@ pub(crate) enum CliCommand {
         /// Show what would be added without actually adding files
         #[arg(long, default_value_t = false)]
         dry_run: bool,
+
+        /// Fuzzy-match the arguments against changed files instead of using glob patterns
+        #[arg(long, default_value_t = false)]
+        fuzzy: bool,
+    },
+
+    /// Create branches named from a template (`from-issue`).
+    #[command(name = "branch")]
+    Branch {
+        #[command(subcommand)]
+        action: BranchAction,
     },
 
+    /// Check out a local or remote branch, picked with a fuzzy filter.
+    #[command(name = "checkout")]
+    Checkout(CheckoutArgs),
+
+    /// Apply commits of another branch, their `[N] (type on branch)` headers getting the next commit numbers and the current branch.
+    #[command(name = "cherry-pick")]
+    CherryPick(CherryPickArgs),
+
     /// Directly commit the file with the text in `commit_message.md`.
     #[command(short_flag = 'c')]
     Commit {
@@ -73,6 +426,46 @@ pub(crate) enum CliCommand {
         #[arg(short = 'u', long = "unsigned", default_value_t = false)]
         unsigned: bool,
 
+        /// Add a `Signed-off-by` trailer with your git identity
+        #[arg(short = 's', long, default_value_t = false)]
+        signoff: bool,
+
+        /// Add a `Co-authored-by` trailer (repeatable)
+        #[arg(long = "co-author", value_name = "NAME <EMAIL>")]
+        co_authors: Vec<String>,
+
+        /// Commit even if the staged changes contain possible secrets (see `scan_secrets`)
+        #[arg(long, default_value_t = false)]
+        allow_secrets: bool,
+
+        /// Bypass the `pre-commit` and `commit-msg` hooks
+        #[arg(short = 'n', long, default_value_t = false)]
+        no_verify: bool,
+
+        /// Run even if another rona process is using the commit message
+        #[arg(long, default_value_t = false)]
+        force: bool,
+
+        /// Refuse to commit on a protected branch (see `protected_branches`) instead of asking
+        #[arg(long, default_value_t = false)]
+        strict: bool,
+
+        /// Author of the commit, instead of your git identity
+        #[arg(long, value_name = "NAME <EMAIL>")]
+        author: Option<String>,
+
+        /// Author date of the commit (`YYYY-MM-DD[ HH:MM[:SS]]` or RFC 3339)
+        #[arg(long, value_name = "DATE")]
+        date: Option<String>,
+
+        /// Commit with the message of a file instead of `commit_message.md`
+        #[arg(long, value_name = "PATH", conflicts_with = "stdin")]
+        message_file: Option<PathBuf>,
+
+        /// Commit with the message read from standard input instead of `commit_message.md`
+        #[arg(long, default_value_t = false)]
+        stdin: bool,
+
         /// Additional arguments to pass to the commit command
         #[arg(allow_hyphen_values = true)]
         args: Vec<String>,
@@ -81,11 +474,74 @@ pub(crate) enum CliCommand {
     /// Generate shell completions for your shell
     #[command(name = "completion")]
     Completion {
-        /// The shell to generate completions for
+        /// The shell to generate completions for (detected from `$SHELL` if not given)
         #[arg(value_enum)]
-        shell: Shell,
+        shell: Option<Shell>,
+
+        /// Write the completions to a file instead of stdout: the standard location of the
+        /// shell if no path is given
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            num_args = 0..=1,
+            default_missing_value = "",
+            value_parser = OsStringValueParser::new().map(PathBuf::from),
+            value_hint = ValueHint::FilePath
+        )]
+        output: Option<PathBuf>,
+
+        /// Print the dynamic values of a kind, one per line (used by the completion scripts)
+        #[arg(
+            long,
+            value_enum,
+            value_name = "KIND",
+            conflicts_with_all = ["shell", "output"]
+        )]
+        list: Option<CompletionKind>,
+    },
+
+    /// Resolve the conflicted files of a stopped merge or rebase, then continue or abort it.
+    #[command(name = "conflicts")]
+    Conflicts(ConflictsArgs),
+
+    /// Show the staged changes about to be committed, with a files/insertions/deletions summary.
+    #[command(name = "diff")]
+    Diff(DiffArgs),
+
+    /// Check the environment (git, repository, identity, signing, editor, config, hooks,
+    /// remotes) and suggest fixes.
+    #[command(name = "doctor")]
+    Doctor,
+
+    /// Fetch from a remote, or all remotes, and summarize the changed branches.
+    #[command(name = "fetch")]
+    Fetch {
+        /// The remote to fetch from (defaults to the upstream remote)
+        #[arg(conflicts_with = "all")]
+        remote: Option<String>,
+
+        /// Fetch all remotes
+        #[arg(long, default_value_t = false)]
+        all: bool,
+
+        /// Remove remote-tracking branches deleted on the remote
+        #[arg(long, default_value_t = false)]
+        prune: bool,
+
+        /// Fetch all tags
+        #[arg(long, default_value_t = false)]
+        tags: bool,
+
+        /// Show what would be fetched without actually fetching
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
     },
 
+    /// Stage, generate and edit the message, commit and optionally push in one command.
+    #[command(name = "flow")]
+    Flow(FlowArgs),
+
     /// Directly generate the `commit_message.md` file.
     #[command(short_flag = 'g')]
     Generate {
@@ -97,26 +553,84 @@ pub(crate) enum CliCommand {
         #[arg(short = 'i', long = "interactive", default_value_t = false)]
         interactive: bool,
 
-        /// No commit number
-        #[arg(short = 'n', long = "no-commit-number", default_value_t = false)]
-        no_commit_number: bool,
+        #[command(flatten)]
+        options: GenerateOptions,
+
+        /// Add a `Signed-off-by` trailer with your git identity
+        #[arg(short = 's', long, default_value_t = false)]
+        signoff: bool,
+
+        /// Add a `Co-authored-by` trailer (repeatable)
+        #[arg(long = "co-author", value_name = "NAME <EMAIL>")]
+        co_authors: Vec<String>,
+
+        /// Run even if another rona process is using the commit message, and overwrite
+        /// the edits of a pending message without asking
+        #[arg(long, default_value_t = false)]
+        force: bool,
+
+        /// Update the file entries of the pending message to the staged files, keeping what was written in it
+        #[arg(
+            long,
+            default_value_t = false,
+            conflicts_with_all = ["interactive", "commit_type", "message", "issue", "ai", "breaking", "ticket", "no_ticket", "signoff", "co_authors"]
+        )]
+        update: bool,
+
+        #[command(flatten)]
+        source: DiffSourceArgs,
+    },
+
+    /// Generate git hooks running rona (e.g. `commit-msg` validating plain `git commit` messages).
+    #[command(name = "hooks")]
+    Hooks {
+        #[command(subcommand)]
+        action: HooksAction,
     },
 
+    /// Show the rona invocations that moved HEAD, or every recorded operation with `--ops`, from the audit log.
+    #[command(name = "history")]
+    History(HistoryArgs),
+
+    /// Show the branch, upstream, last commit and push, stashes and pending commit message.
+    #[command(name = "info")]
+    Info,
+
     /// Initialize the rona configuration file.
     #[command(short_flag = 'i', name = "init")]
-    Initialize {
-        /// Editor to use for the commit message.
-        #[arg(default_value_t = String::from("nano"))]
-        editor: String,
+    Initialize(InitArgs),
+
+    /// Validate a commit message (subject length, empty bullets, typos), failing if it has issues.
+    #[command(name = "lint")]
+    Lint {
+        /// The message file to validate, `#` comment lines excluded (defaults to the commit message file)
+        #[arg(long, value_name = "FILE", value_hint = ValueHint::FilePath)]
+        file: Option<PathBuf>,
+    },
+
+    /// List files from git status (for shell completion on the -a)
+    #[command(short_flag = 'l')]
+    ListStatus(ListStatusArgs),
 
-        /// Show what would be initialized without creating files
+    /// Move or rename files with `git mv`, moving their entries in the pending commit message.
+    #[command(name = "mv")]
+    Mv {
+        /// The files or directories to move, then the new path or the directory to move them into
+        #[arg(value_name = "PATHS", num_args = 2.., required = true, value_hint = ValueHint::AnyPath)]
+        paths: Vec<String>,
+
+        /// Overwrite the destination if it exists
+        #[arg(short = 'f', long, default_value_t = false)]
+        force: bool,
+
+        /// Show what would be moved without moving anything
         #[arg(long, default_value_t = false)]
         dry_run: bool,
     },
 
-    /// List files from git status (for shell completion on the -a)
-    #[command(short_flag = 'l')]
-    ListStatus,
+    /// Open the remote repository, current branch, a file or a new pull request page in the browser.
+    #[command(name = "open")]
+    Open(OpenArgs),
 
     /// Push to a git repository.
     #[command(short_flag = 'p')]
@@ -125,11 +639,71 @@ pub(crate) enum CliCommand {
         #[arg(long, default_value_t = false)]
         dry_run: bool,
 
+        /// Force the push without lease (`--force` and `-f` are pushed as `--force-with-lease`)
+        #[arg(long, default_value_t = false)]
+        force_unsafe: bool,
+
+        /// Push the current branch to every configured remote, one after the other
+        #[arg(long, default_value_t = false)]
+        all_remotes: bool,
+
+        /// Refuse to push a protected branch (see `protected_branches`) instead of asking
+        #[arg(long, default_value_t = false)]
+        strict: bool,
+
         /// Additional arguments to pass to the push command
         #[arg(allow_hyphen_values = true)]
         args: Vec<String>,
     },
 
+    /// Rebase onto a revision, picking, squashing, fixing up, dropping and reordering the commits with `-i`.
+    #[command(name = "rebase")]
+    Rebase(RebaseArgs),
+
+    /// Bump the version, create a release commit and tag, then push both.
+    #[command(name = "release")]
+    Release {
+        /// The version to release (e.g. `1.2.0` or `v1.2.0`)
+        #[arg(value_name = "VERSION")]
+        version: String,
+
+        /// Show every step of the release without changing anything
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// Create a GPG-signed tag instead of a plain annotated one
+        #[arg(short = 's', long = "sign", default_value_t = false)]
+        sign: bool,
+
+        /// Create an unsigned release commit
+        #[arg(short = 'u', long = "unsigned", default_value_t = false)]
+        unsigned: bool,
+
+        /// Do not push the release commit and tag
+        #[arg(long = "no-push", default_value_t = false)]
+        no_push: bool,
+    },
+
+    /// Revert a commit, generating a rona-format revert message to edit and commit.
+    #[command(name = "revert")]
+    Revert(RevertArgs),
+
+    /// Reword the commits of BASE..HEAD into the rona format, keeping their bodies, through an interactive rebase.
+    #[command(name = "rewrite")]
+    Rewrite(RewriteArgs),
+
+    /// Remove files with `git rm`, turning their entries in the pending commit message into `deleted` ones.
+    #[command(name = "rm")]
+    Rm(RmArgs),
+
+    /// Answer JSON-RPC requests from an editor plugin (status, generateMessage, commit, push).
+    #[command(name = "serve")]
+    Serve {
+        /// Read requests from stdin and write responses to stdout, one JSON object per line
+        #[arg(long, required = true)]
+        stdio: bool,
+    },
+
     /// Set the editor to use for editing the commit message.
     #[command(short_flag = 's', name = "set-editor")]
     Set {
@@ -141,6 +715,189 @@ pub(crate) enum CliCommand {
         #[arg(long, default_value_t = false)]
         dry_run: bool,
     },
+
+    /// Run a `git submodule` command (e.g. `rona submodule update --init --recursive`).
+    #[command(name = "submodule")]
+    Submodule {
+        /// Show the git command without running it
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// Arguments to pass to `git submodule`
+        #[arg(allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Squash the last N commits into one, merging their messages.
+    #[command(name = "squash")]
+    Squash(SquashArgs),
+
+    /// Create an annotated tag from an edited message, list tags with their messages, or push tags.
+    #[command(name = "tag")]
+    Tag(TagArgs),
+
+    /// Stage everything and commit it as a `wip: <timestamp>` checkpoint, leaving `commit_message.md` untouched.
+    #[command(name = "wip")]
+    Wip {
+        /// Soft-reset the last checkpoint commit, its changes going back to the index
+        #[arg(long, default_value_t = false)]
+        pop: bool,
+
+        /// Show what would be staged and committed without changing anything
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)
+        #[arg(
+            short = 'u',
+            long = "unsigned",
+            default_value_t = false,
+            conflicts_with = "pop"
+        )]
+        unsigned: bool,
+    },
+
+    /// Unstage the staged files matching the patterns passed as positional arguments (`git restore --staged`).
+    #[command(name = "unadd", visible_alias = "unstage")]
+    Unadd(UnaddArgs),
+
+    /// Watch the working tree, showing what would be staged and optionally recording checkpoints on a WIP branch.
+    #[command(name = "watch")]
+    Watch(WatchArgs),
+}
+
+impl CliCommand {
+    /// Whether the command only previews its changes (`--dry-run`).
+    const fn dry_run(&self) -> bool {
+        match self {
+            Self::AddWithExclude { dry_run, .. }
+            | Self::Branch {
+                action:
+                    BranchAction::FromIssue(FromIssueArgs { dry_run, .. })
+                    | BranchAction::Describe(DescribeArgs { dry_run, .. }),
+            }
+            | Self::Checkout(CheckoutArgs { dry_run, .. })
+            | Self::CherryPick(CherryPickArgs { dry_run, .. })
+            | Self::Commit { dry_run, .. }
+            | Self::Conflicts(ConflictsArgs { dry_run, .. })
+            | Self::Fetch { dry_run, .. }
+            | Self::Flow(FlowArgs { dry_run, .. })
+            | Self::Generate { dry_run, .. }
+            | Self::Hooks {
+                action: HooksAction::Generate { dry_run, .. },
+            }
+            | Self::Initialize(InitArgs { dry_run, .. })
+            | Self::Mv { dry_run, .. }
+            | Self::Open(OpenArgs { dry_run, .. })
+            | Self::Push { dry_run, .. }
+            | Self::Rebase(RebaseArgs { dry_run, .. })
+            | Self::Release { dry_run, .. }
+            | Self::Revert(RevertArgs { dry_run, .. })
+            | Self::Rewrite(RewriteArgs { dry_run, .. })
+            | Self::Rm(RmArgs { dry_run, .. })
+            | Self::Set { dry_run, .. }
+            | Self::Squash(SquashArgs { dry_run, .. })
+            | Self::Unadd(UnaddArgs { dry_run, .. })
+            | Self::Submodule { dry_run, .. }
+            | Self::Tag(TagArgs { dry_run, .. })
+            | Self::Wip { dry_run, .. } => *dry_run,
+            Self::Completion { .. }
+            | Self::Diff(_)
+            | Self::Doctor
+            | Self::History(_)
+            | Self::Info
+            | Self::Lint { .. }
+            | Self::ListStatus(_)
+            | Self::Serve { .. }
+            | Self::Watch(_) => false,
+        }
+    }
+
+    /// Whether the command generates or commits messages, so the first-run wizard is
+    /// offered before it when no configuration exists (dry runs excluded).
+    const fn offers_wizard(&self) -> bool {
+        matches!(
+            self,
+            Self::AddWithExclude { .. }
+                | Self::Commit { .. }
+                | Self::Flow(_)
+                | Self::Generate { .. }
+                | Self::Release { .. }
+        ) && !self.dry_run()
+    }
+
+    /// `Some(strict)` when the command commits or pushes the current branch, which is
+    /// checked against `protected_branches` before it runs, `strict` refusing instead of
+    /// asking to confirm.
+    const fn protected_branch_check(&self) -> Option<bool> {
+        match self {
+            Self::Commit { strict, .. } | Self::Push { strict, .. } => Some(*strict),
+            Self::CherryPick(_)
+            | Self::Flow(_)
+            | Self::Release { .. }
+            | Self::Revert(_)
+            | Self::Rewrite(_)
+            | Self::Squash(_)
+            | Self::Wip { .. } => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Whether the command writes `[N]` commit numbers, checked against a shallow clone
+    /// before it runs.
+    const fn numbers_commits(&self) -> bool {
+        matches!(
+            self,
+            Self::CherryPick(_)
+                | Self::Flow(_)
+                | Self::Generate { .. }
+                | Self::Release { .. }
+                | Self::Revert(_)
+                | Self::Rewrite(_)
+                | Self::Squash(_)
+        )
+    }
+
+    /// Whether the command changes the repository, its invocations being recorded in the
+    /// audit log (dry runs excluded).
+    const fn audited(&self) -> bool {
+        match self {
+            Self::AddWithExclude { .. }
+            | Self::Branch { .. }
+            | Self::Checkout(_)
+            | Self::CherryPick(_)
+            | Self::Commit { .. }
+            | Self::Conflicts(_)
+            | Self::Fetch { .. }
+            | Self::Flow(_)
+            | Self::Mv { .. }
+            | Self::Push { .. }
+            | Self::Rebase(_)
+            | Self::Release { .. }
+            | Self::Revert(_)
+            | Self::Rewrite(_)
+            | Self::Rm(_)
+            | Self::Squash(_)
+            | Self::Submodule { .. }
+            | Self::Unadd(_)
+            | Self::Wip { .. } => !self.dry_run(),
+            Self::Tag(TagArgs { list, dry_run, .. }) => list.is_none() && !*dry_run,
+            Self::Completion { .. }
+            | Self::Diff(_)
+            | Self::Doctor
+            | Self::Generate { .. }
+            | Self::History(_)
+            | Self::Hooks { .. }
+            | Self::Info
+            | Self::Initialize(_)
+            | Self::Lint { .. }
+            | Self::ListStatus(_)
+            | Self::Open(_)
+            | Self::Serve { .. }
+            | Self::Set { .. }
+            | Self::Watch(_) => false,
+        }
+    }
 }
 
 #[derive(Parser)]
@@ -163,619 +920,5783 @@ pub(crate) struct Cli {
     #[arg(short, long, default_value = "false")]
     verbose: bool,
 
+    /// Quiet output - only show errors
+    #[arg(short, long, default_value = "false")]
+    quiet: bool,
+
     /// Use the custom config file path instead of default
     #[arg(long, value_name = "PATH")]
     config: Option<String>,
-}
-
-/// Build the CLI command structure for generating completions
-#[doc(hidden)]
-fn build_cli() -> ClapCommand {
-    Cli::command()
-}
 
-fn get_render_config() -> RenderConfig<'static> {
-    let mut render_config = RenderConfig::default();
+    /// Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)
+    #[arg(long, global = true, default_value_t = false)]
+    offline: bool,
 
-    // Prefix/icons
-    render_config.prompt_prefix = Styled::new("$").with_fg(Color::LightRed);
-    render_config.answered_prompt_prefix = Styled::new("✔").with_fg(Color::LightGreen);
-    render_config.highlighted_option_prefix = Styled::new("➠").with_fg(Color::LightBlue);
-    render_config.selected_checkbox = Styled::new("☑").with_fg(Color::LightGreen);
-    render_config.unselected_checkbox = Styled::new("☐").with_fg(Color::Black);
-    render_config.scroll_up_prefix = Styled::new("⇞").with_fg(Color::Black);
-    render_config.scroll_down_prefix = Styled::new("⇟").with_fg(Color::Black);
+    /// Use the settings of a `[profile.<name>]` of the configuration, instead of the profile
+    /// matching the remotes
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
 
-    // Input prompt label
-    render_config.prompt = StyleSheet::new()
-        .with_fg(Color::LightCyan)
-        .with_attr(Attributes::BOLD);
+    /// Output options shared by all commands
+    #[command(flatten)]
+    output: OutputArgs,
 
-    // Help under the input
-    render_config.help_message = StyleSheet::new()
-        .with_fg(Color::DarkYellow)
-        .with_attr(Attributes::ITALIC);
+    /// Diagnostic options shared by all commands
+    #[command(flatten)]
+    diagnostics: DiagnosticArgs,
 
-    // Validation error
-    render_config.error_message = render_config
-        .error_message
-        .with_prefix(Styled::new("❌").with_fg(Color::LightRed));
+    /// First-run options shared by all commands
+    #[command(flatten)]
+    setup: SetupArgs,
+}
 
-    // Shown after submit (echoed answer)
-    render_config.answer = StyleSheet::new()
-        .with_fg(Color::LightMagenta)
-        .with_attr(Attributes::BOLD);
+/// Arguments of the `rebase` command
+#[derive(Args)]
+pub(crate) struct RebaseArgs {
+    /// The revision to rebase onto (e.g. `main` or `HEAD~5`)
+    #[arg(value_name = "BASE")]
+    base: String,
 
-    // Optional: default/placeholder styles
-    render_config.default_value = StyleSheet::new().with_fg(Color::LightBlue);
-    render_config.placeholder = StyleSheet::new().with_fg(Color::Black);
+    /// Choose what happens to each commit of `BASE..HEAD` from prompts
+    #[arg(short = 'i', long, default_value_t = false)]
+    interactive: bool,
 
-    render_config
+    /// Show the rebase todo list without rebasing
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
 }
 
-/// Print custom fish shell completions that enhance the auto-generated ones
-#[doc(hidden)]
-fn print_fish_custom_completions() {
-    println!();
-    println!("# === CUSTOM RONA COMPLETIONS ===");
-    println!("# Helper function to get git status files");
-    println!("function __rona_status_files");
-    println!("    rona -l");
-    println!("end");
-    println!();
-    println!("# Command-specific completions");
-    println!("# add-with-exclude: Complete with git status files");
-    println!(
-        "complete -c rona -n '__fish_seen_subcommand_from add-with-exclude -a' -xa '(__rona_status_files)'"
-    );
-}
+/// Arguments of the `list-status` command
+#[derive(Args)]
+pub(crate) struct ListStatusArgs {
+    /// List files with staged changes
+    #[arg(long, default_value_t = false)]
+    staged: bool,
 
-/// Handle the `AddWithExclude` command which adds files to git while excluding specified patterns.
-///
-/// # Arguments
-/// * `exclude` - List of glob patterns for files to exclude from git add
-/// * `config` - Global configuration including verbose and dry-run settings
-///
-/// # Errors
-/// * If any glob pattern is invalid
-/// * If git add operation fails
-/// * If reading git status fails
-fn handle_add_with_exclude(exclude: &[String], config: &Config) -> Result<()> {
-    let patterns: Vec<Pattern> = exclude
-        .iter()
-        .map(|p| Pattern::new(p).expect("Invalid glob pattern"))
-        .collect();
+    #[command(flatten)]
+    worktree: WorktreeSets,
 
-    git_add_with_exclude_patterns(&patterns, config.verbose, config.dry_run)?;
-    Ok(())
+    /// Separate the files with NUL characters instead of newlines
+    #[arg(short = 'z', long, default_value_t = false)]
+    null: bool,
 }
 
-/// Handle the Commit command which commits changes using the message from `commit_message.md`.
-///
-/// # Arguments
-/// * `args` - Additional arguments to pass to git commit
-/// * `push` - Whether to push changes after committing
-/// * `unsigned` - Whether to create an unsigned commit (skips -S flag)
-/// * `config` - Global configuration including verbose and dry-run settings
-///
-/// # Errors
-/// * If git commit operation fails
-/// * If push is true and git push operation fails
-fn handle_commit(args: &[String], push: bool, unsigned: bool, config: &Config) -> Result<()> {
-    git_commit(args, unsigned, config.verbose, config.dry_run)?;
-
-    if push {
-        git_push(args, config.verbose, config.dry_run)?;
-    }
-    Ok(())
-}
+/// Working tree file sets of the `list-status` command
+#[derive(Args)]
+pub(crate) struct WorktreeSets {
+    /// List tracked files with unstaged changes
+    #[arg(long, default_value_t = false)]
+    unstaged: bool,
 
-/// Handle the Completion command
-#[doc(hidden)]
-fn handle_completion(shell: Shell) {
-    let mut cmd = build_cli();
-    generate(shell, &mut cmd, "rona", &mut io::stdout());
+    /// List untracked files
+    #[arg(long, default_value_t = false)]
+    untracked: bool,
 
-    // Add custom completions for fish shell
-    if matches!(shell, Shell::Fish) {
-        print_fish_custom_completions();
-    }
+    /// List deleted files, staged or not
+    #[arg(long, default_value_t = false)]
+    deleted: bool,
 }
 
-/// Handle the Generate command which creates a new commit message file.
-///
-/// # Arguments
-/// * `interactive` - Whether to prompt for commit message in terminal
-/// * `no_commit_number` - Whether to include commit number in message
-/// * `config` - Global configuration including verbose and dry-run settings
-///
-/// # Errors
-/// * If creating needed files fails
-/// * If generating commit message fails
-/// * If writing commit message fails
-/// * If launching editor fails (in non-interactive mode)
-fn handle_generate(interactive: bool, no_commit_number: bool, config: &Config) -> Result<()> {
-    if config.dry_run {
-        println!("Would create files: commit_message.md, .commitignore");
-        println!("Would add files to .git/info/exclude");
-        return Ok(());
+impl ListStatusArgs {
+    /// The file sets selected by the flags (none listing every changed file).
+    fn sets(&self) -> Vec<StatusSet> {
+        [
+            (self.staged, StatusSet::Staged),
+            (self.worktree.unstaged, StatusSet::Unstaged),
+            (self.worktree.untracked, StatusSet::Untracked),
+            (self.worktree.deleted, StatusSet::Deleted),
+        ]
+        .into_iter()
+        .filter_map(|(selected, set)| selected.then_some(set))
+        .collect()
     }
+}
 
-    create_needed_files()?;
+/// Arguments of the `init` command
+#[derive(Args)]
+pub(crate) struct InitArgs {
+    /// Editor to use for the commit message.
+    #[arg(default_value_t = String::from("nano"))]
+    editor: String,
 
-    let commit_types_vec = config.project_config.commit_types.as_ref().map_or_else(
-        || COMMIT_TYPES.to_vec(),
-        |v| v.iter().map(String::as_str).collect::<Vec<&str>>(),
-    );
+    /// Show what would be initialized without creating files
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
 
-    let commit_type = Select::new("Select commit type", commit_types_vec)
-        .with_starting_cursor(0)
-        .prompt()
-        .unwrap();
+    /// Scaffold the project setup of a preset instead of only the configuration
+    #[arg(long, value_enum, value_name = "NAME")]
+    preset: Option<Preset>,
 
-    generate_commit_message(commit_type, config.verbose, no_commit_number)?;
+    /// Pick the project setup items to scaffold interactively
+    #[arg(long, default_value_t = false, conflicts_with = "preset")]
+    scaffold: bool,
 
-    if interactive {
-        handle_interactive_mode(commit_type, no_commit_number, config)?;
-    } else {
-        handle_editor_mode(config)?;
-    }
-    Ok(())
+    #[command(flatten)]
+    file: ConfigFileArgs,
 }
 
-/// Handle interactive mode for generate command
-fn handle_interactive_mode(
-    commit_type: &str,
-    no_commit_number: bool,
-    config: &Config,
-) -> Result<()> {
-    use std::fs;
+/// Where `rona init` creates the configuration file, and whether it may overwrite it
+#[derive(Args)]
+pub(crate) struct ConfigFileArgs {
+    /// Create the configuration file in ~/.config/rona.toml without prompting
+    #[arg(long, conflicts_with_all = ["project", "preset", "scaffold"])]
+    global: bool,
 
-    println!("📝 Interactive mode: Enter your commit message.");
-    println!("💡 Tip: Keep it concise and descriptive.");
+    /// Create the configuration file in ./.rona.toml without prompting
+    #[arg(long, conflicts_with_all = ["preset", "scaffold"])]
+    project: bool,
 
-    let message: String = Text::new("Message").prompt().unwrap();
+    /// Overwrite an existing configuration file
+    #[arg(long, conflicts_with_all = ["preset", "scaffold"])]
+    force: bool,
+}
 
-    if message.trim().is_empty() {
-        println!("⚠️  Empty message provided. Exiting.");
-        return Ok(());
+impl ConfigFileArgs {
+    /// The location given by `--global` or `--project`, if any.
+    fn location(&self) -> Option<ConfigLocation> {
+        if self.global {
+            Some(ConfigLocation::Global)
+        } else {
+            self.project.then_some(ConfigLocation::Project)
+        }
     }
+}
 
-    let branch_name = format_branch_name(&COMMIT_TYPES, &get_current_branch()?);
-    let commit_number = if no_commit_number {
-        None
-    } else {
-        Some(get_current_commit_nb()? + 1)
-    };
+/// Arguments of the `unadd` command
+#[derive(Args)]
+pub(crate) struct UnaddArgs {
+    /// Patterns of the staged files to unstage (supports glob patterns like `"*.lock"`), every staged file if none is given
+    #[arg(value_name = "PATTERNS", value_hint = ValueHint::AnyPath)]
+    patterns: Vec<String>,
+
+    /// Pick the files to unstage from the staged files
+    #[arg(
+        short = 'i',
+        long,
+        default_value_t = false,
+        conflicts_with = "patterns"
+    )]
+    interactive: bool,
+
+    /// Show what would be unstaged without unstaging anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
 
-    // Get template from config or use default based on no_commit_number flag
-    let default_template = if no_commit_number {
-        "({commit_type} on {branch_name}) {message}"
+/// Arguments of the `checkout` command
+#[derive(Args)]
+pub(crate) struct CheckoutArgs {
+    /// The branch to check out (`<remote>/<branch>` or `<branch>` for remote branches),
+    /// or the text the picker is filtered with when no branch has this name
+    #[arg(value_name = "BRANCH")]
+    branch: Option<String>,
+
+    /// Show the branch that would be checked out without checking it out
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+/// Arguments of the `cherry-pick` command
+#[derive(Args)]
+pub(crate) struct CherryPickArgs {
+    /// The commits to apply: commits (e.g. `feat/parser~2` or a hash) or ranges (`A..B`)
+    #[arg(value_name = "COMMIT", required = true)]
+    commits: Vec<String>,
+
+    /// The commit type of the commits without a rona header or a conventional commit type, which keep their message otherwise
+    #[arg(short = 't', long = "type", value_name = "TYPE")]
+    commit_type: Option<String>,
+
+    /// Commit the adapted messages without opening the editor
+    #[arg(long, default_value_t = false)]
+    no_edit: bool,
+
+    /// Show what would be applied and committed without changing anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+
+    /// Create unsigned commits (default is to auto-detect GPG availability and sign if possible)
+    #[arg(short = 'u', long = "unsigned", default_value_t = false)]
+    unsigned: bool,
+}
+
+/// Arguments of the `conflicts` command
+#[derive(Args, Default)]
+pub(crate) struct ConflictsArgs {
+    /// Continue the merge or rebase, every conflict being resolved
+    #[arg(long = "continue", default_value_t = false, conflicts_with = "abort")]
+    continue_operation: bool,
+
+    /// Abort the merge or rebase
+    #[arg(long, default_value_t = false)]
+    abort: bool,
+
+    /// Show what would be staged and run without changing anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+/// Arguments of the `open` command
+#[derive(Args)]
+pub(crate) struct OpenArgs {
+    /// Open the page creating a pull request from the current branch
+    #[arg(long, conflicts_with_all = ["branch", "file"])]
+    pr: bool,
+
+    /// Open the current branch
+    #[arg(long, conflicts_with = "file")]
+    branch: bool,
+
+    /// Open a file on the current branch
+    #[arg(long, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    file: Option<String>,
+
+    /// The remote whose repository is opened
+    #[arg(long, default_value = "origin")]
+    remote: String,
+
+    /// Show the URL that would be opened without opening it
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+/// Arguments of the `squash` command
+#[derive(Args)]
+pub(crate) struct SquashArgs {
+    /// Number of commits to squash
+    #[arg(value_name = "N", value_parser = clap::value_parser!(u32).range(2..))]
+    count: u32,
+
+    /// Show what would be squashed without changing anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+
+    /// Commit the merged message without opening the editor
+    #[arg(long, default_value_t = false)]
+    no_edit: bool,
+
+    /// Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)
+    #[arg(short = 'u', long = "unsigned", default_value_t = false)]
+    unsigned: bool,
+}
+
+/// Arguments of the `revert` command
+#[derive(Args)]
+pub(crate) struct RevertArgs {
+    /// The commit to revert (e.g. `HEAD~2` or a hash)
+    #[arg(value_name = "REF")]
+    revision: String,
+
+    /// Show what would be reverted without changing anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+
+    /// Commit the revert message without opening the editor
+    #[arg(long, default_value_t = false)]
+    no_edit: bool,
+
+    /// Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)
+    #[arg(short = 'u', long = "unsigned", default_value_t = false)]
+    unsigned: bool,
+}
+
+/// Arguments of the `rewrite` command
+#[derive(Args)]
+pub(crate) struct RewriteArgs {
+    /// The revision the rewritten commits are on top of (e.g. `main` or `HEAD~5`)
+    #[arg(value_name = "BASE")]
+    base: String,
+
+    /// The commit type of the commits without a conventional commit type, skipping the prompt
+    #[arg(short = 't', long = "type", value_name = "TYPE")]
+    commit_type: Option<String>,
+
+    /// Use the rona-format messages without prompting (requires `--type` for commits without a conventional type)
+    #[arg(long, default_value_t = false)]
+    no_edit: bool,
+
+    /// Show the rebase todo list without rewriting
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+/// Arguments of the `diff` command
+#[derive(Args)]
+pub(crate) struct DiffArgs {
+    /// Compare the staged changes against a ref instead of `HEAD`
+    #[arg(long, value_name = "REF")]
+    against: Option<String>,
+
+    /// Highlight changed words instead of whole lines
+    #[arg(short = 'w', long, default_value_t = false)]
+    word_diff: bool,
+
+    /// When to color the diff
+    #[arg(long, value_enum, value_name = "WHEN", default_value_t = DiffColor::Auto)]
+    color: DiffColor,
+}
+
+/// Arguments of the `flow` command
+#[derive(Args)]
+pub(crate) struct FlowArgs {
+    /// Patterns of files to exclude from staging (supports glob patterns like `"node_modules/*"`)
+    #[arg(value_name = "PATTERNS", value_hint = ValueHint::AnyPath)]
+    exclude: Vec<String>,
+
+    /// Complete the message in the terminal instead of the editor
+    #[arg(short = 'i', long = "interactive", default_value_t = false)]
+    interactive: bool,
+
+    #[command(flatten)]
+    options: GenerateOptions,
+
+    #[command(flatten)]
+    commit: FlowCommitOptions,
+
+    /// Push the commit to the upstream of the branch
+    #[arg(short = 'p', long = "push", default_value_t = false)]
+    push: bool,
+
+    /// Skip a stage of the workflow (repeatable)
+    #[arg(long, value_name = "STAGE")]
+    skip: Vec<FlowStage>,
+
+    /// Show every step of the workflow without changing anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+impl FlowArgs {
+    /// Whether the stage is run (not skipped with `--skip`).
+    fn runs(&self, stage: FlowStage) -> bool {
+        !self.skip.contains(&stage)
+    }
+}
+
+/// Stages of the `flow` command that can be skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum FlowStage {
+    /// Staging the files, committing what is already staged
+    Add,
+    /// Generating the message, committing the existing `commit_message.md`
+    Generate,
+    /// Committing, leaving the generated message for `rona commit`
+    Commit,
+}
+
+/// Options of the `flow` command shaping the commit.
+#[derive(Args)]
+pub(crate) struct FlowCommitOptions {
+    /// Create an unsigned commit (default is to auto-detect GPG availability and sign if possible)
+    #[arg(short = 'u', long = "unsigned", default_value_t = false)]
+    unsigned: bool,
+
+    /// Add a `Signed-off-by` trailer with your git identity
+    #[arg(short = 's', long, default_value_t = false)]
+    signoff: bool,
+
+    /// Add a `Co-authored-by` trailer (repeatable)
+    #[arg(long = "co-author", value_name = "NAME <EMAIL>")]
+    co_authors: Vec<String>,
+
+    /// Run even if another rona process is using the commit message, and overwrite the
+    /// edits of a pending message without asking
+    #[arg(long, default_value_t = false)]
+    force: bool,
+}
+
+/// Arguments of the `tag` command
+#[derive(Args)]
+pub(crate) struct TagArgs {
+    /// The tag to create (e.g. `v1.2.0`)
+    #[arg(value_name = "NAME", required_unless_present_any = ["list", "push"])]
+    name: Option<String>,
+
+    /// The tag message, skipping the editor
+    #[arg(short = 'm', long, conflicts_with = "list")]
+    message: Option<String>,
+
+    /// Create a GPG-signed tag instead of a plain annotated one
+    #[arg(
+        short = 's',
+        long = "sign",
+        default_value_t = false,
+        conflicts_with = "list"
+    )]
+    sign: bool,
+
+    /// List the tags matching the glob pattern (all by default), newest first, with
+    /// the subject of their message
+    #[arg(
+        short = 'l',
+        long,
+        value_name = "PATTERN",
+        num_args = 0..=1,
+        default_missing_value = "*",
+        conflicts_with_all = ["name", "push"]
+    )]
+    list: Option<String>,
+
+    /// Push the created tag, or every tag when no NAME is given
+    #[arg(long, default_value_t = false)]
+    push: bool,
+
+    /// The remote tags are pushed to
+    #[arg(long, default_value = "origin")]
+    remote: String,
+
+    /// Show the tag message, tag and push without changing anything
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+/// Arguments of the `history` command
+#[derive(Args)]
+pub(crate) struct HistoryArgs {
+    /// Show every recorded operation (add, commit, push, tag, ...) with its result
+    #[arg(long, default_value_t = false)]
+    ops: bool,
+
+    /// Number of entries shown, the most recent last
+    #[arg(short = 'n', long, value_name = "COUNT", default_value_t = 20)]
+    limit: usize,
+}
+
+/// Arguments of the `watch` command
+#[derive(Args)]
+pub(crate) struct WatchArgs {
+    /// Patterns of files to exclude, in addition to the `wip_exclude` configuration
+    #[arg(value_name = "PATTERNS", value_hint = ValueHint::AnyPath)]
+    exclude: Vec<String>,
+
+    /// Record checkpoints of the working tree on a local branch (default: `wip/<branch>`),
+    /// leaving HEAD and the index untouched
+    #[arg(
+        long,
+        value_name = "BRANCH",
+        num_args = 0..=1,
+        default_missing_value = ""
+    )]
+    wip_branch: Option<String>,
+
+    /// Seconds between two checkpoints
+    #[arg(long, value_name = "SECONDS", default_value_t = 300, requires = "wip_branch", value_parser = clap::value_parser!(u64).range(1..))]
+    interval: u64,
+}
+
+/// Global options controlling how command output is printed
+#[derive(Args)]
+pub(crate) struct OutputArgs {
+    /// Print dry-run plans as JSON instead of text
+    #[arg(long, global = true, default_value_t = false)]
+    json: bool,
+
+    /// Never pipe long output through the pager
+    #[arg(long, global = true, default_value_t = false)]
+    no_pager: bool,
+
+    /// Use ASCII symbols instead of emoji (also enabled by `RONA_NO_EMOJI`)
+    #[arg(long, global = true, default_value_t = false)]
+    no_emoji: bool,
+}
+
+/// Diagnostic options shared by all commands, for bug reports
+#[derive(Args)]
+pub(crate) struct DiagnosticArgs {
+    /// Print each git command as it runs, then its exit code and duration (also enabled by `RONA_TRACE_GIT`)
+    #[arg(long, global = true, default_value_t = false)]
+    trace_git: bool,
+}
+
+/// Options of the first-run setup, shared by all commands
+#[derive(Args)]
+pub(crate) struct SetupArgs {
+    /// Never ask the first-run questions when no configuration file exists
+    #[arg(long, global = true, default_value_t = false)]
+    no_wizard: bool,
+}
+
+/// Build the CLI command structure for generating completions
+#[doc(hidden)]
+fn build_cli() -> ClapCommand {
+    Cli::command()
+}
+
+/// Custom bash completions wrapping the generated `_rona` function with dynamic values
+#[doc(hidden)]
+const BASH_CUSTOM_COMPLETIONS: &str = r#"
+# === CUSTOM RONA COMPLETIONS ===
+_rona_dynamic() {
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    local prev="${COMP_WORDS[COMP_CWORD-1]}"
+    local word values=""
+
+    if [[ "$prev" == "--remote" ]]; then
+        values="$(rona completion --list remotes 2>/dev/null)"
+    else
+        for word in "${COMP_WORDS[@]:1:COMP_CWORD-1}"; do
+            case "$word" in
+                add-with-exclude|-a) values="$(rona completion --list files 2>/dev/null)"; break ;;
+                push|-p) values="$(rona completion --list remotes 2>/dev/null) $(rona completion --list branches 2>/dev/null)"; break ;;
+                checkout) values="$(rona completion --list branches 2>/dev/null)"; break ;;
+                unadd|unstage) values="$(rona list-status --staged 2>/dev/null)"; break ;;
+                -*) ;;
+                *) break ;;
+            esac
+        done
+    fi
+
+    if [[ -n "$values" && "$cur" != -* ]]; then
+        COMPREPLY=( $(compgen -W "$values" -- "$cur") )
+        return 0
+    fi
+
+    _rona "$@"
+}
+
+complete -F _rona_dynamic -o bashdefault -o default rona"#;
+
+/// Custom zsh completions wrapping the generated `_rona` function with dynamic values
+#[doc(hidden)]
+const ZSH_CUSTOM_COMPLETIONS: &str = r#"
+# === CUSTOM RONA COMPLETIONS ===
+_rona_dynamic() {
+    local -a values
+    local word
+
+    if [[ "${words[CURRENT-1]}" == "--remote" ]]; then
+        values=(${(f)"$(rona completion --list remotes 2>/dev/null)"})
+    else
+        for word in "${(@)words[2,CURRENT-1]}"; do
+            case "$word" in
+                add-with-exclude|-a) values=(${(f)"$(rona completion --list files 2>/dev/null)"}); break ;;
+                push|-p) values=(${(f)"$(rona completion --list remotes 2>/dev/null)"} ${(f)"$(rona completion --list branches 2>/dev/null)"}); break ;;
+                checkout) values=(${(f)"$(rona completion --list branches 2>/dev/null)"}); break ;;
+                unadd|unstage) values=(${(f)"$(rona list-status --staged 2>/dev/null)"}); break ;;
+                -*) ;;
+                *) break ;;
+            esac
+        done
+    fi
+
+    if (( ${#values} )) && [[ "$PREFIX" != -* ]]; then
+        compadd -a values
+        return
+    fi
+
+    _rona "$@"
+}
+
+compdef _rona_dynamic rona"#;
+
+/// Custom fish completions enhancing the generated ones with dynamic values
+#[doc(hidden)]
+const FISH_CUSTOM_COMPLETIONS: &str = r"
+# === CUSTOM RONA COMPLETIONS ===
+# Helper function to get git status files
+function __rona_status_files
+    rona -l
+end
+
+# Command-specific completions
+# add-with-exclude: Complete with git status files
+complete -c rona -n '__fish_seen_subcommand_from add-with-exclude -a' -xa '(__rona_status_files)'
+# push: Complete with remotes and branches
+complete -c rona -n '__fish_seen_subcommand_from push -p' -xa '(rona completion --list remotes) (rona completion --list branches)'
+# checkout: Complete with branches
+complete -c rona -n '__fish_seen_subcommand_from checkout' -xa '(rona completion --list branches)'
+# unadd: Complete with staged files
+complete -c rona -n '__fish_seen_subcommand_from unadd unstage' -xa '(rona list-status --staged)'
+# open: Complete --remote with remotes
+complete -c rona -n '__fish_seen_subcommand_from open' -l remote -xa '(rona completion --list remotes)'";
+
+/// Standard location of the completion script of a shell, from the home directory and
+/// the environment (`XDG_CONFIG_HOME`, `XDG_DATA_HOME`, `BASH_COMPLETION_USER_DIR`,
+/// `ZDOTDIR`). `PowerShell` has none, its completions being sourced from the profile.
+#[doc(hidden)]
+fn default_completion_path(
+    shell: Shell,
+    home: &Path,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<PathBuf> {
+    let env_dir = |name: &str, default: &str| {
+        env(name)
+            .filter(|value| !value.is_empty())
+            .map_or_else(|| home.join(default), PathBuf::from)
+    };
+
+    match shell {
+        Shell::Bash => Some(
+            env("BASH_COMPLETION_USER_DIR")
+                .filter(|value| !value.is_empty())
+                .map_or_else(
+                    || env_dir("XDG_DATA_HOME", ".local/share").join("bash-completion"),
+                    PathBuf::from,
+                )
+                .join("completions/rona"),
+        ),
+        Shell::Fish => {
+            Some(env_dir("XDG_CONFIG_HOME", ".config").join("fish/completions/rona.fish"))
+        }
+        Shell::Zsh => Some(env_dir("ZDOTDIR", "").join(".zfunc/_rona")),
+        Shell::Elvish => Some(env_dir("XDG_CONFIG_HOME", ".config").join("elvish/lib/rona.elv")),
+        _ => None,
+    }
+}
+
+/// Handle the `AddWithExclude` command which adds files to git while excluding specified patterns.
+///
+/// # Arguments
+/// * `exclude` - List of glob patterns (or fuzzy queries) for files to exclude from git add
+/// * `fuzzy` - Whether to fuzzy-match the arguments instead of treating them as globs
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If any glob pattern is invalid
+/// * If git add operation fails
+/// * If reading git status fails
+fn handle_add_with_exclude(exclude: &[String], fuzzy: bool, config: &Config) -> Result<()> {
+    let plan = if fuzzy {
+        let queries: Vec<String> = exclude.iter().map(|q| to_git_path(q)).collect();
+        git_add_with_fuzzy_exclusions(&queries, config.dry_run)?
     } else {
-        "[{commit_number}] ({commit_type} on {branch_name}) {message}"
+        let patterns: Vec<Pattern> = exclude
+            .iter()
+            .map(|p| Pattern::new(&to_git_path(p)).expect("Invalid glob pattern"))
+            .collect();
+        git_add_with_exclude_patterns(&patterns, config.dry_run)?
     };
 
-    let template = config
+    check_large_files(&plan.targets(ActionKind::Stage), config)?;
+    report_plan(&plan, config)
+}
+
+/// Handle the `unadd` command which unstages the staged files matching patterns, or the
+/// ones picked from a list with `--interactive`.
+///
+/// # Errors
+/// * If any glob pattern is invalid
+/// * `RonaError::InvalidInput` - If `--interactive` is used without a terminal
+/// * If the prompt is cancelled, or reading the status or unstaging fails
+fn handle_unadd(args: &UnaddArgs, config: &Config) -> Result<()> {
+    let plan = if args.interactive {
+        if !io::stdin().is_terminal() {
+            return Err(RonaError::InvalidInput(
+                "unadd --interactive needs a terminal to pick the files - pass patterns instead"
+                    .to_string(),
+            ));
+        }
+
+        let staged = StatusSnapshot::read()?.files_in(&[StatusSet::Staged]);
+        if staged.is_empty() {
+            info!("No staged files to unstage");
+            return Ok(());
+        }
+        let files = MultiSelect::new(&tr("prompt.unstage"), staged).prompt()?;
+        git_unstage(&files, config.dry_run)?
+    } else {
+        let patterns = args
+            .patterns
+            .iter()
+            .map(|pattern| {
+                Pattern::new(&to_git_path(pattern)).map_err(|e| {
+                    RonaError::InvalidInput(format!("Invalid glob pattern '{pattern}': {e}"))
+                })
+            })
+            .collect::<Result<Vec<Pattern>>>()?;
+        git_unstage_patterns(&patterns, config.dry_run)?
+    };
+
+    report_plan(&plan, config)
+}
+
+/// Warns about the binary and large files among `paths` (relative to the project root),
+/// unless `check_large_files` is disabled.
+///
+/// # Errors
+/// * If the project root cannot be found or `git check-attr` cannot be run
+fn check_large_files(paths: &[&str], config: &Config) -> Result<()> {
+    if config.project_config.check_large_files == Some(false) {
+        return Ok(());
+    }
+    let threshold = config
         .project_config
-        .template
-        .as_deref()
-        .unwrap_or(default_template);
+        .large_file_threshold
+        .unwrap_or(DEFAULT_LARGE_FILE_THRESHOLD);
+
+    warn_large_files(&find_project_root()?, paths, threshold)
+}
+
+/// Acquires the commit message lock, unless `--force` is given or in dry-run mode.
+///
+/// # Errors
+/// * If another rona process is using the commit message
+fn lock_message(force: bool, config: &Config) -> Result<Option<MessageLock>> {
+    if force || config.dry_run {
+        return Ok(None);
+    }
+    lock_commit_message().map(Some)
+}
+
+/// Checks skipped with the Commit command flags.
+#[derive(Debug, Clone, Copy)]
+struct SkippedChecks {
+    /// Skip the secret scan of the staged changes (`--allow-secrets`)
+    secrets: bool,
+    /// Skip the `pre-commit` and `commit-msg` hooks (`--no-verify`)
+    hooks: bool,
+    /// Skip the commit message lock (`--force`)
+    lock: bool,
+}
+
+/// Handle the Commit command which commits changes using the message from `commit_message.md`,
+/// or the `message` given instead (`--message-file`, `--stdin`), which takes no lock.
+///
+/// # Arguments
+/// * `args` - Additional arguments to pass to git commit
+/// * `push` - Whether to push changes after committing
+/// * `unsigned` - Whether to create an unsigned commit (skips -S flag)
+/// * `skipped` - The checks skipped with `--allow-secrets`, `--no-verify` and `--force`
+/// * `trailers` - Trailers appended to the message
+/// * `message` - The message to commit instead of `commit_message.md`
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If another rona process is using the commit message (without `--force`)
+/// * If git commit operation fails
+/// * If push is true and git push operation fails
+fn handle_commit(
+    args: &[String],
+    push: bool,
+    unsigned: bool,
+    skipped: SkippedChecks,
+    trailers: &[Trailer],
+    message: Option<&str>,
+    config: &Config,
+) -> Result<()> {
+    if push && !config.dry_run {
+        config.ensure_online("git push")?;
+    }
+
+    let _lock = lock_message(skipped.lock || message.is_some(), config)?;
+    warn_detached_head(None)?;
+    if push {
+        warn_detached_head(Some(args))?;
+    }
+
+    let checks = CommitChecks {
+        validation: validation_rules(config),
+        scan_secrets: config.project_config.scan_secrets.unwrap_or(false) && !skipped.secrets,
+        no_verify: skipped.hooks,
+        ascii_subject: emoji_policy(config) == EmojiPolicy::Ascii,
+    };
+
+    let signing = commit_signing(unsigned, config);
+    let mut plan = match message {
+        Some(message) => {
+            git_commit_checked(message, args, checks, trailers, &signing, config.dry_run)?
+        }
+        None => git_commit(
+            &config.commit_message_path()?,
+            args,
+            checks,
+            trailers,
+            &signing,
+            config.dry_run,
+        )?,
+    };
+
+    if push {
+        let push_args = guard_push(args, &mut plan, config)?;
+        plan.extend(git_push(&push_args, config.dry_run)?);
+    }
+
+    let event = if push {
+        NotificationEvent::Push
+    } else {
+        NotificationEvent::Commit
+    };
+    notify(event, config)?;
+
+    report_plan(&plan, config)
+}
+
+/// The message given to the Commit command instead of `commit_message.md`: the content of
+/// `message_file`, or what is read from standard input with `stdin`.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the file or standard input cannot be read, or the
+///   message is empty
+fn external_message(message_file: Option<&Path>, stdin: bool) -> Result<Option<String>> {
+    let (message, source) = match message_file {
+        Some(path) => (std::fs::read_to_string(path), path.display().to_string()),
+        None if stdin => (
+            io::read_to_string(io::stdin()),
+            "standard input".to_string(),
+        ),
+        None => return Ok(None),
+    };
+
+    let message = message.map_err(|e| {
+        RonaError::InvalidInput(format!("Cannot read the commit message from {source}: {e}"))
+    })?;
+    if message.trim().is_empty() {
+        return Err(RonaError::InvalidInput(format!(
+            "The commit message read from {source} is empty"
+        )));
+    }
+    Ok(Some(message))
+}
+
+/// Sends the configured notifications of a commit or push, with the last commit subject.
+///
+/// Nothing is sent in dry-run mode or without a `[notifications]` section.
+///
+/// # Errors
+/// * If the branch or the last commit cannot be read
+fn notify(event: NotificationEvent, config: &Config) -> Result<()> {
+    let Some(notifications) = &config.project_config.notifications else {
+        return Ok(());
+    };
+    if config.dry_run {
+        return Ok(());
+    }
+
+    let notification = Notification {
+        event,
+        branch: head_name()?,
+        subject: get_last_commit()?
+            .map(|commit| commit.subject)
+            .unwrap_or_default(),
+    };
+    send_notification(&notification, notifications, config.offline);
+
+    Ok(())
+}
+
+/// Warns when HEAD is detached, with how to keep the commit on a branch, and when
+/// pushing (`push_args` given), how to push it without an explicit refspec.
+///
+/// # Errors
+/// * If the HEAD state cannot be read
+fn warn_detached_head(push_args: Option<&[String]>) -> Result<()> {
+    if !is_head_detached()? {
+        return Ok(());
+    }
+
+    let head = head_name()?;
+    match push_args {
+        // `HEAD:<branch>` names where to push, nothing to warn about
+        Some(args) if args.iter().any(|arg| arg.contains(':')) => {}
+        Some(_) => {
+            warn!("⚠️  HEAD is detached at {head}, there is no branch to push.");
+            warn!("   Create one first (`git switch -c <branch>`), or push to a remote branch:");
+            warn!("   rona push origin HEAD:<branch>");
+        }
+        None => {
+            warn!("⚠️  HEAD is detached at {head}, the commit will not be on any branch.");
+            warn!("   Keep it by creating a branch: `git switch -c <branch>`");
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the commit message validation rules, if validation is enabled in the config.
+fn validation_rules(config: &Config) -> Option<ValidationRules> {
+    config
+        .project_config
+        .validate_commit_message
+        .unwrap_or(false)
+        .then(|| configured_validation_rules(config))
+}
+
+/// Returns the commit message validation rules of the config.
+fn configured_validation_rules(config: &Config) -> ValidationRules {
+    ValidationRules {
+        max_subject_length: config
+            .project_config
+            .max_subject_length
+            .unwrap_or(DEFAULT_MAX_SUBJECT_LENGTH),
+        ascii_subject: emoji_policy(config) == EmojiPolicy::Ascii,
+    }
+}
+
+/// Returns the configured emoji policy of commit subjects.
+fn emoji_policy(config: &Config) -> EmojiPolicy {
+    config.project_config.emoji.unwrap_or_default()
+}
+
+/// Adds the context of the current branch to a generated message: its description
+/// (`rona branch describe`) under the header when `branch_description` is enabled, and
+/// the ticket given with `--ticket` or found in its name by `ticket_pattern`.
+///
+/// # Errors
+/// * If the current branch or its description cannot be read
+/// * If `ticket_pattern` is not a valid regular expression
+fn with_branch_context(message: String, ticket: &TicketArgs, config: &Config) -> Result<String> {
+    let project_config = &config.project_config;
+    let branch = match repository_state()? {
+        RepositoryState::Branch(branch) | RepositoryState::Unborn(branch) => Some(branch),
+        RepositoryState::Detached => None,
+    };
+
+    let description = match &branch {
+        Some(branch) if project_config.branch_description == Some(true) => {
+            get_branch_description(branch)?
+        }
+        _ => None,
+    };
+    let message = match description {
+        Some(description) => with_branch_description(&message, &description),
+        None => message,
+    };
+
+    let ticket = match (&ticket.ticket, &project_config.ticket_pattern, &branch) {
+        _ if ticket.no_ticket => None,
+        (Some(ticket), _, _) => Some(ticket.clone()),
+        (None, Some(pattern), Some(branch)) => branch_ticket(branch, pattern)?,
+        _ => None,
+    };
+    Ok(match ticket {
+        Some(ticket) => with_ticket(
+            &message,
+            &ticket,
+            project_config.ticket_position.unwrap_or_default(),
+        ),
+        None => message,
+    })
+}
+
+/// Returns the staged files listed in a generated commit message, grouped as configured.
+///
+/// # Errors
+/// * If reading git status fails
+fn staged_message_files(config: &Config) -> Result<MessageFiles> {
+    Ok(MessageFiles::staged(&StatusSnapshot::read()?)
+        .grouped(config.project_config.group_by.unwrap_or_default()))
+}
+
+/// Returns how commits are signed, `--unsigned` and `sign_commits = false` overriding the
+/// configured `signing_key`.
+fn commit_signing(unsigned: bool, config: &Config) -> CommitSigning {
+    CommitSigning::from_options(
+        unsigned || config.project_config.sign_commits == Some(false),
+        config.project_config.signing_key.as_deref(),
+    )
+}
+
+/// Returns the trailers closing the commit message: the configured ones, then the
+/// `--co-author` ones and the `--signoff` one last.
+///
+/// # Errors
+/// * If a co-author is not in the `Name <email>` format
+/// * If signing off without `user.name` or `user.email` in the git configuration
+fn commit_trailers(signoff: bool, co_authors: &[String], config: &Config) -> Result<Vec<Trailer>> {
+    let mut trailers = config
+        .project_config
+        .trailers
+        .as_ref()
+        .map(Trailer::from_config)
+        .unwrap_or_default();
+
+    for co_author in co_authors {
+        trailers.push(Trailer::co_author(co_author)?);
+    }
+
+    if signoff {
+        trailers.push(Trailer::signoff()?);
+    }
+
+    Ok(trailers)
+}
+
+/// Returns how commits are numbered, `--no-commit-number` overriding the config.
+fn commit_numbering(no_commit_number: bool, config: &Config) -> CommitNumbering {
+    if no_commit_number {
+        CommitNumbering::None
+    } else {
+        config.project_config.commit_number.unwrap_or_default()
+    }
+}
+
+/// Handle the Completion command
+///
+/// # Errors
+/// * If the values requested with `--list` cannot be read
+/// * If no shell is given and `$SHELL` is not a supported shell
+/// * If the completions cannot be written to the output file
+#[doc(hidden)]
+fn handle_completion(
+    shell: Option<Shell>,
+    output: Option<&Path>,
+    list: Option<CompletionKind>,
+    config: &Config,
+) -> Result<()> {
+    if let Some(kind) = list {
+        return print_completion_values(kind, config);
+    }
+
+    let Some(shell) = shell.or_else(Shell::from_env) else {
+        return Err(RonaError::InvalidInput(
+            "Could not detect the shell from $SHELL - pass it, e.g. `rona completion zsh`"
+                .to_string(),
+        ));
+    };
+
+    let mut script = Vec::new();
+    generate(shell, &mut build_cli(), "rona", &mut script);
+
+    // Add dynamic completions on top of the generated ones
+    match shell {
+        Shell::Fish => writeln!(script, "{FISH_CUSTOM_COMPLETIONS}")?,
+        Shell::Bash => writeln!(script, "{BASH_CUSTOM_COMPLETIONS}")?,
+        Shell::Zsh => writeln!(script, "{ZSH_CUSTOM_COMPLETIONS}")?,
+        _ => {}
+    }
+
+    let Some(output) = output else {
+        io::stdout().write_all(&script)?;
+        return Ok(());
+    };
+
+    // `--output` without a path writes to the standard location
+    let standard = output.as_os_str().is_empty();
+    let path = if standard {
+        dirs::home_dir()
+            .and_then(|home| default_completion_path(shell, &home, |name| std::env::var(name).ok()))
+            .ok_or_else(|| {
+                RonaError::InvalidInput(format!(
+                    "No standard completion location for {shell} - pass a path to --output"
+                ))
+            })?
+    } else {
+        output.to_path_buf()
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, script)?;
+
+    info!("✅ {shell} completions written to {}", path.display());
+    if shell == Shell::Zsh
+        && standard
+        && let Some(parent) = path.parent()
+    {
+        info!(
+            "   Make sure {} is in your fpath (fpath=({} $fpath) in .zshrc)",
+            parent.display(),
+            parent.display()
+        );
+    }
+    Ok(())
+}
+
+/// Prints the values of a completion kind, one per line.
+///
+/// # Errors
+/// * If the git status, remotes or branches cannot be read
+fn print_completion_values(kind: CompletionKind, config: &Config) -> Result<()> {
+    let values = match kind {
+        CompletionKind::Files => get_status_files()?,
+        CompletionKind::CommitTypes => config
+            .project_config
+            .commit_types
+            .clone()
+            .unwrap_or_else(|| COMMIT_TYPES.iter().map(ToString::to_string).collect()),
+        CompletionKind::Remotes => list_remotes()?,
+        CompletionKind::Branches => list_branches()?,
+    };
+
+    for value in values {
+        println!("{value}");
+    }
+    Ok(())
+}
+
+/// How the generated commit message is completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditMode {
+    /// Open the message in the editor
+    Editor,
+    /// Prompt for the message in the terminal (`--interactive`)
+    Interactive,
+    /// Leave the message as generated, without any prompt (`--no-edit`)
+    None,
+}
+
+impl EditMode {
+    /// The edit mode of the `--interactive` and `--no-edit` flags.
+    const fn from_flags(interactive: bool, no_edit: bool) -> Self {
+        if no_edit {
+            Self::None
+        } else if interactive {
+            Self::Interactive
+        } else {
+            Self::Editor
+        }
+    }
+}
+
+/// The path of the commit message file and the files listed in a generated message: the
+/// files of the patch or range of `source`, else the staged files (checked for large
+/// files), grouped as configured.
+///
+/// # Errors
+/// * If the patch or range cannot be read, or changes no files
+/// * If reading git status fails
+/// * If large files are staged and the check fails
+fn generation_files(source: &DiffSourceArgs, config: &Config) -> Result<(PathBuf, MessageFiles)> {
+    let files = source.files()?;
+    let listed = files.is_some();
+    let (message_path, status) = join(
+        || config.commit_message_path(),
+        || (!listed).then(StatusSnapshot::read).transpose(),
+    );
+
+    // The files of a patch or a range are not checked: the working tree may not have
+    // them as they were changed
+    let files = match status? {
+        Some(status) => {
+            let staged = MessageFiles::staged(&status);
+            let staged_paths: Vec<&str> = staged.changed.iter().map(String::as_str).collect();
+            check_large_files(&staged_paths, config)?;
+            staged
+        }
+        None => files.unwrap_or_default(),
+    };
+
+    Ok((
+        message_path?,
+        files.grouped(config.project_config.group_by.unwrap_or_default()),
+    ))
+}
+
+/// Stops `rona generate` while a merge, rebase, am, cherry-pick or revert is in progress, git
+/// writing the messages of its commits. From a terminal, the conflicts can be resolved and
+/// the operation continued (as by `rona conflicts`), or the operation aborted, instead.
+/// Returns whether an operation was in progress, no message being generated then; messages
+/// listing a patch or a range (`--from-diff`, `--range`) are generated anyway.
+///
+/// # Errors
+/// * `GitError::OperationInProgress` - If an operation is in progress without prompts, or
+///   in a dry run
+/// * If resolving, continuing or aborting the operation fails
+fn handle_operation_in_progress(
+    source: &DiffSourceArgs,
+    can_prompt: bool,
+    config: &Config,
+) -> Result<bool> {
+    let staged = source.from_diff.is_none() && source.range.is_none();
+    let Some(operation) = operation_in_progress()?.filter(|_| staged) else {
+        return Ok(false);
+    };
+    let conflicted = list_conflicted_files()?.len();
+    warn!("⚠️  A git {operation} is in progress ({conflicted} conflicted file(s))");
+
+    if !can_prompt || config.dry_run {
+        return Err(RonaError::Git(GitError::OperationInProgress {
+            operation: operation.to_string(),
+        }));
+    }
+
+    let choice = Select::new(
+        &tr_args("prompt.operation_in_progress", &[("operation", &operation)]),
+        vec![
+            tr_args("prompt.finish_operation", &[("operation", &operation)]),
+            tr_args("prompt.abort_operation", &[("operation", &operation)]),
+            tr("prompt.quit"),
+        ],
+    )
+    .raw_prompt()?;
+
+    match choice.index {
+        0 => handle_conflicts(&ConflictsArgs::default(), config)?,
+        1 => drop(finish_operation(operation, FinishAction::Abort, false)?),
+        _ => {}
+    }
+    Ok(true)
+}
+
+/// Handle the Generate command which creates a new commit message file.
+///
+/// Prompts are only shown when a terminal is available and `--no-edit` is not given,
+/// the commit type then being required.
+///
+/// # Arguments
+/// * `interactive` - Whether to prompt for commit message in terminal
+/// * `options` - Commit type, subject, issue and numbering of the message
+/// * `source` - Patch or range whose files are listed in the message, instead of the staged ones
+/// * `trailers` - Trailers closing the message
+/// * `skip_lock` - Whether to skip the commit message lock held until the message is edited
+/// * `force_overwrite` - Whether to discard the edits of a pending message without asking
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If another rona process is using the commit message (without `skip_lock`)
+/// * If creating needed files fails
+/// * If the commit type is unknown, or missing when no prompt can be shown
+/// * If generating commit message fails
+/// * If writing commit message fails
+/// * If launching editor fails (in non-interactive mode)
+fn handle_generate(
+    interactive: bool,
+    options: &GenerateOptions,
+    source: &DiffSourceArgs,
+    trailers: &[Trailer],
+    skip_lock: bool,
+    force_overwrite: bool,
+    config: &Config,
+) -> Result<()> {
+    let mode = EditMode::from_flags(interactive, options.no_edit);
+    let can_prompt = mode != EditMode::None && io::stdin().is_terminal();
+    if handle_operation_in_progress(source, can_prompt, config)? {
+        return Ok(());
+    }
+    let (message_path, staged) = generation_files(source, config)?;
+
+    if config.dry_run {
+        let project_root = find_project_root()?;
+        let mut excluded = vec![".commitignore".to_string()];
+        let target = match worktree_relative_path(&project_root, &message_path) {
+            Some(relative) => {
+                excluded.push(relative.clone());
+                relative
+            }
+            None => message_path.display().to_string(),
+        };
+
+        let mut plan = Plan::new();
+        plan.push(ActionKind::CreateFile, target, Vec::new());
+        plan.push(ActionKind::CreateFile, ".commitignore", Vec::new());
+        plan.push(ActionKind::GitExclude, ".git/info/exclude", excluded);
+
+        // The message is only known without prompts or lookups
+        if let Some(commit_type) = options.commit_type.as_deref()
+            && options.issue.is_none()
+            && !options.ai
+            && config.output_format == OutputFormat::Text
+        {
+            let previous = pending_message(&message_path)?;
+            let breaking = breaking_change(options.breaking.as_deref(), false)?;
+            let message = render_commit_message(
+                &staged,
+                commit_type,
+                options.message.as_deref(),
+                commit_numbering(options.no_commit_number, config),
+                None,
+                previous.as_deref().map(draft_from_message).as_ref(),
+                &with_breaking_change(trailers, breaking.as_deref()),
+            )?;
+            let message = with_branch_context(message, &options.ticket, config)?;
+            show_regeneration(
+                previous.as_deref(),
+                &apply_emoji_policy(&message, commit_type, emoji_policy(config)),
+            );
+        }
+        return report_plan(&plan, config);
+    }
+
+    let _lock = lock_message(skip_lock, config)?;
+
+    let commit_types_vec = config.project_config.commit_types.as_ref().map_or_else(
+        || COMMIT_TYPES.to_vec(),
+        |v| v.iter().map(String::as_str).collect::<Vec<&str>>(),
+    );
+    let suggested = if options.commit_type.is_none() && can_prompt {
+        suggested_commit_type(&staged, &commit_types_vec, config)
+    } else {
+        None
+    };
+    let commit_type = select_commit_type(
+        options.commit_type.as_deref(),
+        commit_types_vec,
+        suggested,
+        can_prompt,
+    )?;
+    let breaking = breaking_change(options.breaking.as_deref(), can_prompt)?;
+    let trailers = with_breaking_change(trailers, breaking.as_deref());
+
+    // The hand-written parts of a pending message are reused
+    let previous = pending_message(&message_path)?;
+    let draft = if options.ai {
+        draft_with_ai(config)?
+    } else {
+        previous.as_deref().map(draft_from_message)
+    };
+
+    create_needed_files(&message_path)?;
+
+    let issue = resolve_issue(options.issue, can_prompt, config)?;
+
+    let numbering = commit_numbering(options.no_commit_number, config);
+    let message = render_commit_message(
+        &staged,
+        commit_type,
+        options.message.as_deref(),
+        numbering,
+        issue.as_ref(),
+        draft.as_ref(),
+        &trailers,
+    )?;
+    let message = with_branch_context(message, &options.ticket, config)?;
+    let message = apply_emoji_policy(&message, commit_type, emoji_policy(config));
+    confirm_regeneration(
+        &message_path,
+        previous.as_deref(),
+        &message,
+        can_prompt,
+        force_overwrite,
+    )?;
+    write_commit_message(&message_path, &message)?;
+
+    match mode {
+        EditMode::Editor => handle_editor_mode(config)?,
+        EditMode::Interactive => {
+            handle_interactive_mode(commit_type, options, issue.as_ref(), &trailers, config)?;
+        }
+        EditMode::None => info!("✅ Commit message written to {}", message_path.display()),
+    }
+    Ok(())
+}
+
+/// Handle `generate --update`, which updates the file entries of the pending commit message
+/// to the staged files, keeping the rest of what was written in it.
+///
+/// # Arguments
+/// * `options` - The options of `generate`, `--no-edit` skipping the editor afterwards
+/// * `force` - Whether to run even if another rona process is using the commit message
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If there is no pending commit message
+/// * If another rona process is using the commit message
+/// * If the status cannot be read or the message cannot be written
+fn handle_update(options: &GenerateOptions, force: bool, config: &Config) -> Result<()> {
+    let message_path = config.commit_message_path()?;
+    let _lock = lock_message(force, config)?;
+
+    let Some(previous) = pending_message(&message_path)? else {
+        return Err(RonaError::InvalidInput(
+            "There is no pending commit message to update - run `rona generate` first".to_string(),
+        ));
+    };
+    let message =
+        update_commit_message(&previous, &MessageFiles::staged(&StatusSnapshot::read()?))?;
+    let changes = show_regeneration(Some(&previous), &message);
+
+    if config.dry_run {
+        let mut plan = Plan::new();
+        if !changes.is_empty() {
+            plan.push(
+                ActionKind::WriteMessage,
+                message_path.display().to_string(),
+                Vec::new(),
+            );
+        }
+        return report_plan(&plan, config);
+    }
+
+    if message != previous {
+        write_commit_message(&message_path, &message)?;
+    }
+    if options.no_edit {
+        info!("✅ Commit message updated in {}", message_path.display());
+        Ok(())
+    } else {
+        handle_editor_mode(config)
+    }
+}
+
+/// When the commit message file was last written, if it exists.
+fn message_edited_at(message_path: &Path) -> Option<i64> {
+    std::fs::metadata(message_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .map(|modified| DateTime::<Utc>::from(modified).timestamp())
+}
+
+/// The pending commit message: the message file, unless it was written before the last
+/// commit (it was committed) or is empty.
+///
+/// # Errors
+/// * If the last commit cannot be read
+fn pending_message(message_path: &Path) -> Result<Option<String>> {
+    let Some(edited) = message_edited_at(message_path) else {
+        return Ok(None);
+    };
+    if get_last_commit()?.is_some_and(|commit| edited < commit.timestamp) {
+        return Ok(None);
+    }
+
+    Ok(std::fs::read_to_string(message_path)
+        .ok()
+        .filter(|previous| !previous.trim().is_empty()))
+}
+
+/// Shows what replacing the `previous` pending commit message with `message` changes,
+/// returning the changes.
+fn show_regeneration(previous: Option<&str>, message: &str) -> MessageChanges {
+    let changes = previous.map_or_else(MessageChanges::default, |previous| {
+        compare_messages(previous, message)
+    });
+
+    if !changes.is_empty() {
+        info!(
+            "📝 Changes to the pending commit message:\n{}",
+            changes.render(colors_enabled(Stream::Stdout))
+        );
+    }
+    changes
+}
+
+/// Shows what regenerating the `previous` pending commit message changes, asking before
+/// discarding the edits made to it unless `force` is set.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If edits would be discarded without `force` and no
+///   prompt can be shown
+/// * `RonaError::UserCancelled` - If the edits are not to be discarded
+fn confirm_regeneration(
+    message_path: &Path,
+    previous: Option<&str>,
+    message: &str,
+    can_prompt: bool,
+    force: bool,
+) -> Result<()> {
+    let changes = show_regeneration(previous, message);
+    if changes.discarded.is_empty() {
+        return Ok(());
+    }
+
+    if force {
+        warn!(
+            "⚠️  Edits of the previous commit message are not kept, it is saved to {}",
+            backup_path(message_path).display()
+        );
+        return Ok(());
+    }
+
+    if !can_prompt {
+        return Err(RonaError::InvalidInput(format!(
+            "{} has edits the new message does not keep - pass --force to overwrite it",
+            message_path.display()
+        )));
+    }
+
+    let confirmed = Confirm::new(&tr("prompt.discard_edits"))
+        .with_default(false)
+        .prompt()?;
+
+    if confirmed {
+        Ok(())
+    } else {
+        Err(RonaError::UserCancelled)
+    }
+}
+
+/// Returns the commit type given with `--type`, or prompts for it with the
+/// `suggested` type pre-selected.
+///
+/// # Errors
+/// * If the given type is not one of the configured commit types
+/// * If no type is given and no prompt can be shown
+/// * If the prompt is cancelled
+fn select_commit_type<'a>(
+    given: Option<&str>,
+    commit_types: Vec<&'a str>,
+    suggested: Option<&str>,
+    can_prompt: bool,
+) -> Result<&'a str> {
+    match given {
+        Some(given) => commit_types
+            .iter()
+            .find(|commit_type| **commit_type == given)
+            .copied()
+            .ok_or_else(|| {
+                RonaError::InvalidInput(format!(
+                    "Unknown commit type '{given}' - expected one of: {}",
+                    commit_types.join(", ")
+                ))
+            }),
+        None if !can_prompt => Err(RonaError::InvalidInput(format!(
+            "No commit type given - pass --type <TYPE> (one of: {})",
+            commit_types.join(", ")
+        ))),
+        None => {
+            let cursor = commit_types
+                .iter()
+                .position(|commit_type| Some(*commit_type) == suggested)
+                .unwrap_or_default();
+
+            Select::new(&tr("prompt.commit_type"), commit_types)
+                .with_starting_cursor(cursor)
+                .prompt()
+                .map_err(RonaError::from)
+        }
+    }
+}
+
+/// The description of the breaking change made by the commit: the `--breaking` one, or the
+/// one asked for once the change is confirmed to be breaking.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the `--breaking` description is empty
+/// * If a prompt is cancelled
+fn breaking_change(given: Option<&str>, can_prompt: bool) -> Result<Option<String>> {
+    if let Some(given) = given {
+        if given.trim().is_empty() {
+            return Err(RonaError::InvalidInput(
+                "`--breaking` needs a description of what breaks".to_string(),
+            ));
+        }
+        return Ok(Some(given.to_string()));
+    }
+
+    if !can_prompt
+        || !Confirm::new(&tr("prompt.breaking"))
+            .with_default(false)
+            .prompt()?
+    {
+        return Ok(None);
+    }
+
+    let description = Text::new(&tr("prompt.breaking_description"))
+        .with_validator(inquire::required!())
+        .prompt()?;
+    Ok(Some(description))
+}
+
+/// The trailers closing the message, after the `BREAKING CHANGE` footer of a breaking change.
+fn with_breaking_change(trailers: &[Trailer], breaking: Option<&str>) -> Vec<Trailer> {
+    breaking
+        .map(Trailer::breaking_change)
+        .into_iter()
+        .chain(trailers.iter().cloned())
+        .collect()
+}
+
+/// Suggests the commit type of the files, pre-selected in the commit type prompt
+/// (see `suggest_commit_type`).
+fn suggested_commit_type<'a>(
+    files: &MessageFiles,
+    commit_types: &[&'a str],
+    config: &Config,
+) -> Option<&'a str> {
+    let paths: Vec<String> = files
+        .changed
+        .iter()
+        .chain(&files.submodules)
+        .chain(&files.deleted)
+        .cloned()
+        .collect();
+    let rules = config
+        .project_config
+        .type_rules
+        .as_deref()
+        .unwrap_or_default();
+
+    suggest_commit_type(&paths, rules, commit_types)
+}
+
+/// Asks the configured AI provider for a draft of the staged changes.
+///
+/// A failed request only falls back to the empty template, the editor step being there
+/// to write the message anyway.
+///
+/// # Errors
+/// * If no API key is configured
+/// * If the staged diff cannot be read
+#[cfg(feature = "ai")]
+fn draft_with_ai(config: &Config) -> Result<Option<CommitDraft>> {
+    use crate::ai::AiProvider;
+
+    config.ensure_online("AI drafting")?;
+    let provider = AiProvider::from_config(&config.project_config)?;
+    let (diff, stat) = staged_diff(&DiffOptions::default(), false)?;
+
+    if stat.is_empty() {
+        warn!("⚠️  No staged changes to describe, generating the empty template.");
+        return Ok(None);
+    }
+
+    info!("🤖 Drafting the commit message with {}...", provider.model);
+    match provider.draft(&diff) {
+        Ok(draft) => Ok(Some(draft)),
+        Err(e) => {
+            warn!("⚠️  Could not get an AI suggestion ({e}), generating the empty template.");
+            Ok(None)
+        }
+    }
+}
+
+/// Reports that `--ai` is not available in this build.
+///
+/// # Errors
+/// * Always, rona being built without the `ai` feature
+#[cfg(not(feature = "ai"))]
+fn draft_with_ai(_config: &Config) -> Result<Option<CommitDraft>> {
+    Err(RonaError::InvalidInput(
+        "`--ai` requires rona to be built with the `ai` feature (cargo install rona --features ai)"
+            .to_string(),
+    ))
+}
+
+/// Resolves the issue referenced by `--issue`, prompting for it when no number is given.
+///
+/// # Errors
+/// * If the open issues cannot be listed for interactive selection
+/// * `RonaError::OfflineMode` - If an issue is referenced in offline mode
+fn resolve_issue(
+    issue: Option<IssueSelection>,
+    can_prompt: bool,
+    config: &Config,
+) -> Result<Option<Issue>> {
+    let token = config.project_config.issue_token.as_deref();
+
+    match issue {
+        None => Ok(None),
+        Some(IssueSelection::Number(number)) => {
+            config.ensure_online("issue lookup")?;
+            let issue = fetch_issue(number, token);
+            if issue.title.is_none() {
+                warn!("⚠️  Could not fetch the title of issue #{number}, referencing it anyway.");
+            }
+            Ok(Some(issue))
+        }
+        Some(IssueSelection::Select) if !can_prompt => Err(RonaError::InvalidInput(
+            "No issue number given - pass --issue <NUMBER> when no prompt can be shown".to_string(),
+        )),
+        Some(IssueSelection::Select) => {
+            config.ensure_online("issue lookup")?;
+            let issues = list_open_issues(token)?;
+
+            if issues.is_empty() {
+                warn!("⚠️  No open issues found.");
+                return Ok(None);
+            }
+
+            let issue = Select::new(&tr("prompt.issue"), issues).prompt()?;
+            Ok(Some(issue))
+        }
+    }
+}
+
+/// Handle interactive mode for generate command
+fn handle_interactive_mode(
+    commit_type: &str,
+    options: &GenerateOptions,
+    issue: Option<&Issue>,
+    trailers: &[Trailer],
+    config: &Config,
+) -> Result<()> {
+    info!("📝 Interactive mode: Enter your commit message.");
+    info!("💡 Tip: Keep it concise and descriptive.");
+
+    let initial_message = issue
+        .and_then(|issue| issue.title.as_deref())
+        .unwrap_or_default();
+    let message: String = Text::new(&tr("prompt.message"))
+        .with_initial_value(initial_message)
+        .prompt()?;
+
+    if message.trim().is_empty() {
+        warn!("⚠️  Empty message provided. Exiting.");
+        return Ok(());
+    }
+
+    let formatted_message = interactive_message(
+        message.trim(),
+        commit_type,
+        commit_numbering(options.no_commit_number, config),
+        issue,
+        trailers,
+        &options.ticket,
+        config,
+    )?;
+
+    // Write the formatted message to the commit message file
+    write_commit_message(&config.commit_message_path()?, &formatted_message)?;
+
+    info!("\n✅ Commit message created!");
+    info!("📄 Message: {formatted_message}");
+    Ok(())
+}
+
+/// Builds the message of interactive mode: the `template` header with the typed subject,
+/// the issue footer and the trailers, with the context of the branch (see
+/// [`with_branch_context`]).
+///
+/// # Errors
+/// * If the branch or the commit number cannot be read
+/// * If the template cannot be processed
+fn interactive_message(
+    subject: &str,
+    commit_type: &str,
+    numbering: CommitNumbering,
+    issue: Option<&Issue>,
+    trailers: &[Trailer],
+    ticket: &TicketArgs,
+    config: &Config,
+) -> Result<String> {
+    let footer = issue
+        .map(|issue| format!("\n\n{}", issue.closing_footer()))
+        .unwrap_or_default();
+    let branch_name = format_branch_name(&COMMIT_TYPES, &head_name()?);
+    let commit_number = next_commit_number(numbering)?;
+
+    // Get template from config or use default based on the commit numbering
+    let default_template = if commit_number.is_none() {
+        "({commit_type} on {branch_name}) {message}"
+    } else {
+        "[{commit_number}] ({commit_type} on {branch_name}) {message}"
+    };
+
+    let template = config
+        .project_config
+        .template
+        .as_deref()
+        .unwrap_or(default_template);
+
+    // Validate template
+    let header = if let Err(e) = validate_template(template) {
+        warn!("⚠️  Template validation error: {e}");
+        info!("Using fallback format...");
+        if let Some(commit_number) = commit_number {
+            format!("[{commit_number}] ({commit_type} on {branch_name}) {subject}")
+        } else {
+            format!("({commit_type} on {branch_name}) {subject}")
+        }
+    } else {
+        // Create template variables
+        let variables = TemplateVariables::new(
+            commit_number,
+            commit_type.to_string(),
+            branch_name,
+            subject.to_string(),
+        )?
+        .with_breaking(trailers.iter().any(Trailer::is_breaking_change));
+
+        process_template(template, &variables)?
+    };
+
+    let message = append_trailers(&format!("{header}{footer}"), trailers);
+    let message = with_branch_context(message, ticket, config)?;
+    Ok(apply_emoji_policy(
+        &message,
+        commit_type,
+        emoji_policy(config),
+    ))
+}
+
+/// Handle editor mode for generate command
+///
+/// The configured editor may include arguments (e.g. `code --wait`), known GUI
+/// editors getting their wait flag otherwise.
+fn handle_editor_mode(config: &Config) -> Result<()> {
+    open_in_editor(&config.commit_message_path()?, config)
+}
+
+/// Opens a file in the configured editor and waits for it to be closed.
+fn open_in_editor(path: &Path, config: &Config) -> Result<()> {
+    let editor = config.get_editor()?;
+
+    build_editor_command(&editor, config.editor_args(), path)?
+        .spawn()?
+        .wait()?;
+    Ok(())
+}
+
+/// Handle the Initialize command which creates the initial configuration file,
+/// or scaffolds the project setup of a preset.
+///
+/// The configuration file location is prompted for unless `--global` or `--project`
+/// is given.
+///
+/// # Errors
+/// * If creating configuration file fails
+fn handle_initialize(args: &InitArgs, config: &Config) -> Result<()> {
+    let editor = args.editor.as_str();
+    let location = args.file.location();
+
+    let items = if let Some(preset) = args.preset {
+        Some(preset.items())
+    } else if args.scaffold {
+        Some(
+            MultiSelect::new(&tr("prompt.scaffold"), ScaffoldItem::ALL.to_vec())
+                .with_all_selected_by_default()
+                .prompt()?,
+        )
+    } else {
+        None
+    };
+
+    if let Some(items) = items {
+        let plan = scaffold_project(
+            &items,
+            editor,
+            config.project_config.commit_message_path.as_deref(),
+            config.dry_run,
+        )?;
+        return report_plan(&plan, config);
+    }
+
+    if config.dry_run {
+        let mut plan = Plan::new();
+        if let Some(location) = location {
+            plan.push(
+                ActionKind::CreateFile,
+                location.path()?.display().to_string(),
+                Vec::new(),
+            );
+        }
+        plan.push(ActionKind::SetConfig, "editor", vec![editor.to_string()]);
+        return report_plan(&plan, config);
+    }
+    config.create_config_file(editor, location, args.file.force)?;
+    Ok(())
+}
+
+/// Handle the Branch command which creates a branch named from an issue.
+///
+/// An issue number (e.g. `42` or `#42`) has its title fetched from GitHub/GitLab for
+/// the slug; anything else is used as the title.
+///
+/// # Errors
+/// * If the branch name is invalid or the branch already exists
+/// * If the branch cannot be created or checked out
+fn handle_branch(action: &BranchAction, config: &Config) -> Result<()> {
+    let args = match action {
+        BranchAction::FromIssue(args) => args,
+        BranchAction::Describe(args) => return handle_branch_describe(args, config),
+    };
+
+    let (issue, title) = match args.issue.trim_start_matches('#').parse::<u64>() {
+        Ok(number) => {
+            config.ensure_online("issue lookup")?;
+            let issue = fetch_issue(number, config.project_config.issue_token.as_deref());
+            if issue.title.is_none() {
+                warn!(
+                    "⚠️  Could not fetch the title of issue #{number}, naming the branch without it."
+                );
+            }
+            (Some(number), issue.title.unwrap_or_default())
+        }
+        Err(_) => (None, args.issue.clone()),
+    };
+
+    let template = config
+        .project_config
+        .branch_template
+        .as_deref()
+        .unwrap_or(DEFAULT_BRANCH_TEMPLATE);
+    let name = branch_name_from_template(template, &args.branch_type, issue, &slugify(&title));
+
+    let plan = create_branch(&name, !args.no_checkout, config.dry_run)?;
+
+    if !config.dry_run {
+        info!("✅ Created branch {name}");
+    }
+
+    report_plan(&plan, config)
+}
+
+/// Handle the `branch describe` command: the description of the branch is edited in the
+/// editor, unless given with `-m`, then saved in the git configuration.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If HEAD is detached and no branch is named, or the
+///   edited description is empty
+/// * If the description cannot be edited or saved
+fn handle_branch_describe(args: &DescribeArgs, config: &Config) -> Result<()> {
+    let branch = match &args.branch {
+        Some(branch) => branch.clone(),
+        None => match repository_state()? {
+            RepositoryState::Branch(branch) | RepositoryState::Unborn(branch) => branch,
+            RepositoryState::Detached => {
+                return Err(RonaError::InvalidInput(
+                    "HEAD is detached - name the branch to describe".to_string(),
+                ));
+            }
+        },
+    };
+
+    let mut plan = Plan::new();
+    let description = if let Some(message) = &args.message {
+        parse_branch_description(message)?
+    } else {
+        let path = branch_description_path()?;
+        plan.push(
+            ActionKind::WriteMessage,
+            path.display().to_string(),
+            Vec::new(),
+        );
+
+        if config.dry_run {
+            get_branch_description(&branch)?.unwrap_or_default()
+        } else {
+            write_branch_description(&path, &branch)?;
+            open_in_editor(&path, config)?;
+            read_branch_description(&path)?
+        }
+    };
+
+    plan.extend(set_branch_description(
+        &branch,
+        &description,
+        config.dry_run,
+    )?);
+
+    if !config.dry_run {
+        info!("✅ Described branch {branch}");
+    }
+
+    report_plan(&plan, config)
+}
+
+/// Handle the Checkout command which checks out a branch, picked in a fuzzy-filtered
+/// list of the local and remote branches unless one is named exactly.
+///
+/// Remote branches without local branch are checked out as a new local branch
+/// tracking them.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If there is no other branch, or no branch is named
+///   exactly outside a terminal
+/// * If the branches cannot be listed or the checkout fails
+fn handle_checkout(args: &CheckoutArgs, config: &Config) -> Result<()> {
+    let current = repository_state()?;
+    if let Some(branch) = current.branch()
+        && args.branch.as_deref() == Some(branch)
+    {
+        info!("Already on {branch}");
+        return Ok(());
+    }
+
+    let targets = list_checkout_targets()?;
+    if targets.is_empty() {
+        return Err(RonaError::InvalidInput(
+            "There is no other branch to check out".to_string(),
+        ));
+    }
+
+    let named = args
+        .branch
+        .as_deref()
+        .and_then(|name| targets.iter().find(|target| target.is_named(name)).cloned());
+    let target = match named {
+        Some(target) => target,
+        None if io::stdin().is_terminal() => Select::new(&tr("prompt.checkout"), targets)
+            .with_starting_filter_input(args.branch.as_deref().unwrap_or_default())
+            .prompt()?,
+        None => {
+            return Err(RonaError::InvalidInput(match &args.branch {
+                Some(name) => format!("There is no branch named {name}"),
+                None => "Name the branch to check out, or run in a terminal to pick it".to_string(),
+            }));
+        }
+    };
+
+    let plan = git_checkout(&target, config.dry_run)?;
+
+    if !config.dry_run {
+        info!("✅ Switched to {}", target.branch());
+    }
+
+    report_plan(&plan, config)
+}
+
+/// Handle the `CherryPick` command: each commit is applied without committing, then
+/// committed with its message adapted to the current branch (see `adapt_message`), in the
+/// editor unless `--no-edit`. The adapted message is written to the commit message file
+/// first, so a commit stopping on conflicts is committed by `rona commit` once they are
+/// resolved.
+///
+/// # Errors
+/// * If the commits cannot be read, or one is a merge commit
+/// * If changes are already staged, or a commit does not apply cleanly
+/// * If a message cannot be written, or a commit fails
+fn handle_cherry_pick(args: &CherryPickArgs, config: &Config) -> Result<()> {
+    let commits = list_picked_commits(&args.commits)?;
+    let branch = head_name()?;
+    let message_path = config.commit_message_path()?;
+    let signing = commit_signing(args.unsigned, config);
+    // The picked changes were already committed, so they are not scanned for secrets
+    let checks = CommitChecks {
+        validation: validation_rules(config),
+        scan_secrets: false,
+        no_verify: false,
+        ascii_subject: emoji_policy(config) == EmojiPolicy::Ascii,
+    };
+
+    let mut plan = Plan::new();
+    let mut number = next_commit_number(config.project_config.commit_number.unwrap_or_default())?;
+    for (index, commit) in commits.iter().enumerate() {
+        let original = read_commit_message(&commit.hash)?;
+        let message = adapt_message(&original, args.commit_type.as_deref(), number, &branch)
+            .unwrap_or_else(|| format!("{original}\n"));
+
+        plan.push(
+            ActionKind::WriteMessage,
+            message_path.display().to_string(),
+            Vec::new(),
+        );
+        if !config.dry_run {
+            write_commit_message(&message_path, &message)?;
+        }
+
+        let picked = cherry_pick_no_commit(commit, config.dry_run);
+        if picked.is_err() {
+            let remaining: Vec<&str> = commits[index + 1..]
+                .iter()
+                .map(|commit| commit.hash.get(..7).unwrap_or(&commit.hash))
+                .collect();
+            warn!(
+                "⚠️  {commit} was not applied - its adapted message is in the commit message file."
+            );
+            warn!("   Resolve the conflicts (rona conflicts) and commit it with rona commit,");
+            warn!("   or undo it with git reset --merge.");
+            if !remaining.is_empty() {
+                warn!(
+                    "   Then apply the next commits: rona cherry-pick {}",
+                    remaining.join(" ")
+                );
+            }
+        }
+        plan.extend(picked?);
+
+        if config.dry_run {
+            plan.extend(git_commit_checked(
+                &message,
+                &[],
+                checks,
+                &[],
+                &signing,
+                true,
+            )?);
+        } else if has_staged_changes()? {
+            if !args.no_edit {
+                handle_editor_mode(config)?;
+            }
+            plan.extend(git_commit(
+                &message_path,
+                &[],
+                checks,
+                &[],
+                &signing,
+                false,
+            )?);
+        } else {
+            info!("{commit} is already applied - skipped");
+            continue;
+        }
+        number = number.map(|number| number + 1);
+    }
+
+    report_plan(&plan, config)
+}
+
+/// Handle the Hooks command which writes a hook running rona into the hooks directory.
+///
+/// # Errors
+/// * If the hook already exists and `--force` is not given
+/// * If the hook cannot be written
+fn handle_hooks(action: &HooksAction, config: &Config) -> Result<()> {
+    let HooksAction::Generate { hook, force, .. } = action;
+
+    let plan = write_hook(
+        &find_project_root()?,
+        hook.name(),
+        hook.contents(),
+        *force,
+        config.dry_run,
+    )?;
+
+    if !config.dry_run {
+        info!("✅ Installed the {} hook", hook.name());
+    }
+
+    report_plan(&plan, config)
+}
+
+/// Trailer parameters shared by the `generateMessage` and `commit` methods of `rona serve`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct TrailerParams {
+    /// Add a `Signed-off-by` trailer
+    signoff: bool,
+    /// `Co-authored-by` trailers (`Name <email>`)
+    co_authors: Vec<String>,
+}
+
+/// Parameters of the `generateMessage` method of `rona serve`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateParams {
+    /// The commit type (e.g. `feat`)
+    #[serde(rename = "type")]
+    commit_type: String,
+    /// The subject completing the header
+    message: Option<String>,
+    /// Leave the commit number out of the header
+    #[serde(default)]
+    no_commit_number: bool,
+    /// Issue referenced by the commit
+    issue: Option<u64>,
+    /// Description of the breaking change the commit makes
+    breaking: Option<String>,
+    /// Discard the edits of a pending message
+    #[serde(default)]
+    force: bool,
+    #[serde(flatten)]
+    trailers: TrailerParams,
+}
+
+/// Checks skipped by the `commit` method of `rona serve`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct SkipParams {
+    /// Skip the secret scan
+    allow_secrets: bool,
+    /// Skip the `pre-commit` and `commit-msg` hooks
+    no_verify: bool,
+}
+
+/// Parameters of the `commit` method of `rona serve`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct CommitParams {
+    /// Additional arguments passed to `git commit`
+    args: Vec<String>,
+    /// Push after committing
+    push: bool,
+    /// Create an unsigned commit
+    unsigned: bool,
+    /// The message to commit instead of `commit_message.md`
+    message: Option<String>,
+    #[serde(flatten)]
+    trailers: TrailerParams,
+    #[serde(flatten)]
+    skip: SkipParams,
+}
+
+/// Parameters of the `push` method of `rona serve`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PushParams {
+    /// Additional arguments passed to `git push`
+    args: Vec<String>,
+}
+
+/// Methods answered by `rona serve`, besides `exit`.
+const SERVE_METHODS: [&str; 5] = ["initialize", "status", "generateMessage", "commit", "push"];
+
+/// Runs a method of `rona serve`, `None` for unknown methods.
+fn serve_method(
+    method: &str,
+    params: &serde_json::Value,
+    config: &Config,
+) -> Option<Result<serde_json::Value>> {
+    let result = match method {
+        "initialize" => Ok(json!({
+            "name": "rona",
+            "version": env!("CARGO_PKG_VERSION"),
+            "methods": SERVE_METHODS,
+        })),
+        "status" => StatusSnapshot::read().and_then(|snapshot| {
+            Ok(json!({
+                "branch": head_name()?,
+                "staged": snapshot.files_in(&[StatusSet::Staged]),
+                "unstaged": snapshot.files_in(&[StatusSet::Unstaged]),
+                "untracked": snapshot.files_in(&[StatusSet::Untracked]),
+                "deleted": snapshot.files_in(&[StatusSet::Deleted]),
+            }))
+        }),
+        "generateMessage" => parse_params(params).and_then(|params| serve_generate(params, config)),
+        "commit" => parse_params(params).and_then(|params: CommitParams| {
+            // The requests come on stdin, so a protected branch cannot be confirmed
+            confirm_protected_branch(true, config)?;
+            let trailers =
+                commit_trailers(params.trailers.signoff, &params.trailers.co_authors, config)?;
+            let skipped = SkippedChecks {
+                secrets: params.skip.allow_secrets,
+                hooks: params.skip.no_verify,
+                lock: false,
+            };
+            handle_commit(
+                &params.args,
+                params.push,
+                params.unsigned,
+                skipped,
+                &trailers,
+                params.message.as_deref(),
+                config,
+            )?;
+
+            let commit = get_last_commit()?;
+            Ok(json!({
+                "hash": commit.as_ref().map(|commit| &commit.hash),
+                "subject": commit.as_ref().map(|commit| &commit.subject),
+            }))
+        }),
+        "push" => parse_params(params).and_then(|params: PushParams| {
+            confirm_protected_branch(true, config)?;
+            handle_push(&params.args, false, false, config)?;
+            Ok(json!({ "branch": head_name()? }))
+        }),
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Runs the `generateMessage` method of `rona serve`, returning the written message.
+fn serve_generate(params: GenerateParams, config: &Config) -> Result<serde_json::Value> {
+    let options = GenerateOptions {
+        commit_type: Some(params.commit_type),
+        message: params.message,
+        no_edit: true,
+        no_commit_number: params.no_commit_number,
+        issue: params.issue.map(IssueSelection::Number),
+        ai: false,
+        breaking: params.breaking,
+        ticket: TicketArgs::default(),
+    };
+    let trailers = commit_trailers(params.trailers.signoff, &params.trailers.co_authors, config)?;
+    handle_generate(
+        false,
+        &options,
+        &DiffSourceArgs::default(),
+        &trailers,
+        params.force,
+        params.force,
+        config,
+    )?;
+
+    let path = config.commit_message_path()?;
+    Ok(json!({
+        "path": path,
+        "message": std::fs::read_to_string(&path)?,
+    }))
+}
+
+/// Handle the Serve command which answers JSON-RPC requests on stdin and stdout.
+///
+/// Stdout carries the responses only: informational messages are silenced, warnings
+/// and errors still go to stderr.
+///
+/// # Errors
+/// * If stdin cannot be read or stdout cannot be written
+fn handle_serve(config: &Config) -> Result<()> {
+    log::set_max_level(log::LevelFilter::Warn.min(log::max_level()));
+
+    serve(io::stdin().lock(), io::stdout().lock(), |method, params| {
+        serve_method(method, params, config)
+    })
+}
+
+/// Handle the Lint command which validates a commit message file.
+///
+/// The subject length limit is `max_subject_length`, whether or not
+/// `validate_commit_message` is enabled.
+///
+/// # Errors
+/// * If the message file cannot be read
+/// * If the message has issues
+fn handle_lint(file: Option<&Path>, config: &Config) -> Result<()> {
+    let path = match file {
+        Some(file) => file.to_path_buf(),
+        None => config.commit_message_path()?,
+    };
+
+    let message = strip_comments(&std::fs::read_to_string(&path)?);
+    lint_commit_message(&message, configured_validation_rules(config))?;
+
+    info!("✅ The commit message is valid");
+    Ok(())
+}
+
+/// Handle the Doctor command which checks the environment rona relies on, printing a
+/// checklist with fixes (as JSON with `--json`).
+///
+/// # Errors
+/// * If a check fails (warnings do not)
+/// * If the checks cannot be serialized
+fn handle_doctor(config: &Config) -> Result<()> {
+    let checks = run_checks(config);
+
+    if config.output_format == OutputFormat::Json {
+        let json = serde_json::to_string_pretty(&checks)
+            .map_err(|e| RonaError::InvalidInput(format!("Failed to serialize checks: {e}")))?;
+        println!("{json}");
+    } else {
+        println!(
+            "{}",
+            render_checks(
+                &checks,
+                decorations_enabled(Stream::Stdout),
+                colors_enabled(Stream::Stdout)
+            )
+        );
+    }
+
+    let failed = checks
+        .iter()
+        .filter(|check| check.status == CheckStatus::Error)
+        .count();
+    if failed > 0 {
+        return Err(RonaError::CommandFailed {
+            command: format!("rona doctor ({failed} check(s) failed)"),
+        });
+    }
+    Ok(())
+}
+
+/// Handle the History command which prints the last entries of the audit log: the
+/// invocations that moved HEAD, or every recorded operation with `--ops` (as JSON with
+/// `--json`).
+///
+/// # Errors
+/// * If not in a git repository
+/// * If the audit log cannot be read
+fn handle_history(args: &HistoryArgs, config: &Config) -> Result<()> {
+    let entries: Vec<_> = read_audit_log(&audit_log_path()?)?
+        .into_iter()
+        .filter(|entry| args.ops || entry.head.is_some())
+        .collect();
+    let entries = &entries[entries.len().saturating_sub(args.limit)..];
+
+    if config.output_format == OutputFormat::Json {
+        let json = serde_json::to_string_pretty(entries).map_err(|e| {
+            RonaError::InvalidInput(format!("Failed to serialize the audit log: {e}"))
+        })?;
+        println!("{json}");
+    } else if entries.is_empty() {
+        info!("No rona operations recorded yet");
+    } else if args.ops {
+        for entry in entries {
+            println!("{entry}");
+        }
+    } else {
+        for entry in entries {
+            println!(
+                "{}  {}  {}",
+                entry.time.format("%Y-%m-%d %H:%M:%S"),
+                entry.head.as_deref().unwrap_or_default(),
+                entry.command_line()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle the Info command which prints a short summary of the repository state.
+///
+/// # Errors
+/// * If not in a git repository
+/// * If a git query fails
+fn handle_info(config: &Config) -> Result<()> {
+    let now = Utc::now().timestamp();
+    let upstream = get_upstream()?;
+    let last_commit = get_last_commit()?;
+
+    let upstream_line = upstream.as_ref().map_or_else(
+        || "none".to_string(),
+        |upstream| {
+            format!(
+                "{} ({} ahead, {} behind)",
+                upstream.name, upstream.ahead, upstream.behind
+            )
+        },
+    );
+
+    let last_commit_line = last_commit.as_ref().map_or_else(
+        || "none".to_string(),
+        |commit| {
+            format!(
+                "{} {} ({}, {})",
+                commit.hash,
+                commit.subject,
+                commit.author,
+                format_age(now - commit.timestamp)
+            )
+        },
+    );
+
+    let last_push_line = match &upstream {
+        Some(upstream) => get_last_push_time(&upstream.name)?
+            .map_or_else(|| "unknown".to_string(), |pushed| format_age(now - pushed)),
+        None => "never".to_string(),
+    };
+
+    let edited = message_edited_at(&config.commit_message_path()?);
+    let message_line = match edited {
+        None => "none".to_string(),
+        Some(edited)
+            if last_commit
+                .as_ref()
+                .is_some_and(|commit| edited < commit.timestamp) =>
+        {
+            "committed".to_string()
+        }
+        Some(edited) => format!("edited {}, not committed yet", format_age(now - edited)),
+    };
+
+    let branch = if is_head_detached()? {
+        format!("HEAD detached at {}", head_name()?)
+    } else {
+        get_current_branch()?
+    };
+    let default_branch_line = match get_default_branch() {
+        Ok((default_branch, _)) if default_branch == branch => default_branch,
+        Ok((default_branch, reference)) => match count_ahead_behind(&reference) {
+            Ok((ahead, behind)) => format!("{default_branch} ({ahead} ahead, {behind} behind)"),
+            Err(_) => default_branch,
+        },
+        Err(_) => "unknown".to_string(),
+    };
+
+    let operation_line = match operation_in_progress()? {
+        Some(operation) => format!(
+            "git {operation} in progress ({} conflicted file(s))",
+            list_conflicted_files()?.len()
+        ),
+        None => "none".to_string(),
+    };
+    let rerere_line = if rerere_enabled()? {
+        "enabled"
+    } else {
+        "disabled"
+    };
+
+    let rows = [
+        ("Branch", branch),
+        ("Operation", operation_line),
+        ("Default branch", default_branch_line),
+        ("Upstream", upstream_line),
+        ("Last commit", last_commit_line),
+        ("Last push", last_push_line),
+        ("Stashes", get_stash_count()?.to_string()),
+        ("Commit message", message_line),
+        ("Rerere", rerere_line.to_string()),
+    ];
+
+    for (label, value) in rows {
+        println!("{:<16}{value}", format!("{label}:"));
+    }
+
+    Ok(())
+}
+
+/// Handle the `ListStatus` command
+///
+/// Without flags, every file of git status but the deleted ones is listed; the flags
+/// select the staged, unstaged, untracked and deleted files instead.
+///
+/// # Errors
+/// * If reading git status fails
+fn handle_list_status(args: &ListStatusArgs) -> Result<()> {
+    let files = StatusSnapshot::read()?.files_in(&args.sets());
+    let separator = if args.null { '\0' } else { '\n' };
+
+    // Print each file on its own line for fish shell completion
+    let mut stdout = io::stdout().lock();
+    for file in files {
+        write!(stdout, "{file}{separator}")?;
+    }
+    Ok(())
+}
+
+/// Handle the `Mv` command which moves files with `git mv`, then moves their entries in the
+/// pending commit message.
+///
+/// # Arguments
+/// * `paths` - The files or directories to move, then the destination
+/// * `force` - Whether to overwrite the destination
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If another rona process is using the commit message
+/// * If `git mv` fails
+/// * If the pending commit message cannot be rewritten
+fn handle_mv(paths: &[String], force: bool, config: &Config) -> Result<()> {
+    let [sources @ .., destination] = paths else {
+        return Err(RonaError::InvalidInput("Nothing to move".to_string()));
+    };
+
+    let _lock = lock_message(false, config)?;
+    let mut plan = git_mv(sources, destination, force, config.dry_run)?;
+
+    let moves: Vec<(String, String)> = plan
+        .actions()
+        .iter()
+        .filter(|action| action.kind == ActionKind::Move)
+        .filter_map(|action| Some((action.target.clone(), action.args.first()?.clone())))
+        .collect();
+    sync_pending_message(
+        &mut plan,
+        |message| move_message_entries(message, &moves),
+        config,
+    )?;
+
+    report_plan(&plan, config)
+}
+
+/// Rewrites the pending commit message after files were moved or removed, showing the
+/// changes (planning the write in dry-run mode). Nothing happens without a pending message.
+///
+/// # Errors
+/// * If the pending commit message cannot be read or written
+fn sync_pending_message(
+    plan: &mut Plan,
+    rewrite: impl FnOnce(&str) -> String,
+    config: &Config,
+) -> Result<()> {
+    let message_path = config.commit_message_path()?;
+    let Some(previous) = pending_message(&message_path)? else {
+        return Ok(());
+    };
+
+    let message = rewrite(&previous);
+    if message == previous {
+        return Ok(());
+    }
+
+    if config.dry_run {
+        plan.push(
+            ActionKind::WriteMessage,
+            message_path.display().to_string(),
+            Vec::new(),
+        );
+    } else {
+        write_commit_message(&message_path, &message)?;
+        show_regeneration(Some(&previous), &message);
+    }
+    Ok(())
+}
+
+/// Handle the Open command which opens a page of the remote repository in the browser.
+///
+/// # Arguments
+/// * `args` - The page to open (`--pr`, `--branch` or `--file`, relative to the current
+///   directory) and the remote whose repository is opened
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If the remote does not exist or is not hosted on a supported service
+/// * If the browser cannot be launched
+fn handle_open(args: &OpenArgs, config: &Config) -> Result<()> {
+    let repository = parse_remote_url(&get_remote_url(&args.remote)?)?;
+
+    let url = if args.pr || args.branch || args.file.is_some() {
+        let current_branch = get_current_branch()?;
+
+        if args.pr {
+            repository.pull_request_url(&current_branch)
+        } else if let Some(file) = args.file.as_deref() {
+            let root = find_project_root()?;
+            let relative = std::env::current_dir()?.join(file);
+            let relative = relative.strip_prefix(&root).unwrap_or(Path::new(file));
+            let path = to_git_path(&relative.to_string_lossy());
+
+            repository.file_url(&current_branch, &path)
+        } else {
+            repository.branch_url(&current_branch)
+        }
+    } else {
+        repository.web_url.clone()
+    };
+
+    let mut plan = Plan::new();
+    plan.push(ActionKind::OpenUrl, &url, Vec::new());
+
+    if !config.dry_run {
+        debug!("Opening {url}");
+        open_in_browser(&url)?;
+    }
+
+    report_plan(&plan, config)
+}
+
+/// Handle the Conflicts command which walks through the conflicted files of a stopped
+/// operation, then continues or aborts it.
+///
+/// With `--continue` or `--abort`, the operation is finished directly. Otherwise each
+/// conflicted file can be opened in the editor and marked as resolved (staged), until
+/// none is left and the operation can be continued.
+///
+/// # Errors
+/// * If no merge, rebase, cherry-pick or revert is in progress for `--continue`/`--abort`
+/// * If `--continue` is used while files are still conflicted
+/// * If the git commands or the editor fail
+/// * If the user cancels a prompt
+fn handle_conflicts(args: &ConflictsArgs, config: &Config) -> Result<()> {
+    let ConflictsArgs {
+        continue_operation,
+        abort,
+        ..
+    } = *args;
+    let operation = operation_in_progress()?;
+    let files = list_conflicted_files()?;
+
+    if continue_operation || abort {
+        let operation = operation.ok_or_else(|| {
+            RonaError::InvalidInput(
+                "No merge, rebase, cherry-pick or revert in progress".to_string(),
+            )
+        })?;
+
+        if continue_operation && !files.is_empty() {
+            return Err(RonaError::InvalidInput(format!(
+                "{} files are still conflicted: {}",
+                files.len(),
+                files.join(", ")
+            )));
+        }
+
+        let action = if abort {
+            FinishAction::Abort
+        } else {
+            FinishAction::Continue
+        };
+        let plan = finish_operation(operation, action, config.dry_run)?;
+        return report_plan(&plan, config);
+    }
+
+    if config.dry_run {
+        let mut plan = mark_resolved(&files, true)?;
+        if let Some(operation) = operation {
+            plan.extend(finish_operation(operation, FinishAction::Continue, true)?);
+        }
+        return report_plan(&plan, config);
+    }
+
+    if operation.is_none() && files.is_empty() {
+        info!("No merge or rebase in progress and no conflicted files.");
+        return Ok(());
+    }
+
+    let root = find_project_root()?;
+    loop {
+        let files = list_conflicted_files()?;
+
+        if files.is_empty() {
+            let Some(operation) = operation else {
+                info!("✅ All conflicts resolved.");
+                return Ok(());
+            };
+
+            let choice = Select::new(
+                &tr_args("prompt.all_resolved", &[("operation", &operation)]),
+                vec![tr("prompt.continue"), tr("prompt.abort"), tr("prompt.quit")],
+            )
+            .raw_prompt()?;
+
+            return match choice.index {
+                0 => finish_operation(operation, FinishAction::Continue, false).map(drop),
+                1 => finish_operation(operation, FinishAction::Abort, false).map(drop),
+                _ => Ok(()),
+            };
+        }
+
+        let abort_choice = operation
+            .map(|operation| tr_args("prompt.abort_operation", &[("operation", &operation)]));
+        let mut choices = files.clone();
+        choices.extend(abort_choice.clone());
+        choices.push(tr("prompt.quit"));
+
+        let title = tr_args("prompt.conflicted_files", &[("count", &files.len())]);
+        let choice = Select::new(&title, choices).prompt()?;
+
+        if let Some(operation) = operation
+            && abort_choice.as_ref() == Some(&choice)
+        {
+            return finish_operation(operation, FinishAction::Abort, false).map(drop);
+        }
+        if !files.contains(&choice) {
+            return Ok(());
+        }
+
+        resolve_conflicted_file(&choice, &root, config)?;
+    }
+}
+
+/// Lets the user open a conflicted file in the editor until it is marked as resolved
+/// or left for later.
+///
+/// # Errors
+/// * If the editor cannot be run
+/// * If staging the file fails
+/// * If the user cancels a prompt
+fn resolve_conflicted_file(file: &str, root: &Path, config: &Config) -> Result<()> {
+    loop {
+        let actions = vec![
+            tr("prompt.open_in_editor"),
+            tr("prompt.mark_resolved"),
+            tr("prompt.back"),
+        ];
+        let action = Select::new(file, actions).raw_prompt()?;
+
+        match action.index {
+            0 => {
+                open_in_editor(&root.join(file), config)?;
+            }
+            1 => {
+                if has_conflict_markers(&root.join(file)) {
+                    let confirmed =
+                        Confirm::new(&tr_args("prompt.still_conflicted", &[("file", &file)]))
+                            .with_default(false)
+                            .prompt()?;
+
+                    if !confirmed {
+                        continue;
+                    }
+                }
+
+                mark_resolved(&[file.to_string()], false)?;
+                info!("✅ {file} marked as resolved");
+                return Ok(());
+            }
+            _ => return Ok(()),
+        }
+    }
+}
+
+/// Handle the Diff command which shows the staged changes followed by their summary.
+///
+/// The diff is paged like `git diff` unless `--no-pager` is passed.
+///
+/// # Errors
+/// * If the git diff command fails (e.g. unknown `--against` ref)
+/// * If the pager cannot be run
+fn handle_diff(args: &DiffArgs, config: &Config) -> Result<()> {
+    let options = DiffOptions {
+        against: args.against.clone(),
+        word_diff: args.word_diff,
+        color: args.color,
+    };
+    let colored = match options.color {
+        DiffColor::Always => true,
+        DiffColor::Never => false,
+        DiffColor::Auto => colors_enabled(Stream::Stdout),
+    };
+
+    let (diff, stat) = staged_diff(&options, colored)?;
+
+    if stat.is_empty() {
+        match &options.against {
+            Some(reference) => info!("No staged changes against {reference}"),
+            None => info!("No staged changes"),
+        }
+        return Ok(());
+    }
+
+    print_paged(&format!("{}\n\n{stat}", diff.trim_end()), config.pager)?;
+    Ok(())
+}
+
+/// Handle the Fetch command which fetches from remotes and summarizes the changes.
+///
+/// # Arguments
+/// * `remote` - The remote to fetch from, if any
+/// * `options` - `--all`, `--prune` and `--tags` options
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If git fetch operation fails
+fn handle_fetch(remote: Option<&str>, options: FetchOptions, config: &Config) -> Result<()> {
+    if !config.dry_run {
+        config.ensure_online("git fetch")?;
+    }
+
+    let plan = git_fetch(remote, options, config.dry_run)?;
+    report_plan(&plan, config)
+}
+
+/// Handle the Push command which pushes changes to the remote repository.
+///
+/// # Arguments
+/// * `args` - Additional arguments to pass to git push (`--force` is pushed with lease)
+/// * `force_unsafe` - Whether to force the push without lease
+/// * `all_remotes` - Whether to push to every remote (see [`push_remotes`])
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If git push operation fails (on any of the remotes when pushing to several)
+/// * If the user declines to discard the remote commits of a force push
+fn handle_push(
+    args: &[String],
+    force_unsafe: bool,
+    all_remotes: bool,
+    config: &Config,
+) -> Result<()> {
+    if !config.dry_run {
+        config.ensure_online("git push")?;
+    }
+
+    let mut plan = Plan::new();
+    let args = force_push_args(args, force_unsafe);
+    warn_detached_head(Some(&args))?;
+    confirm_force_push(&args, config)?;
+    let remotes = push_remotes(&args, all_remotes, config)?;
+    if remotes.is_empty() {
+        let args = guard_push(&args, &mut plan, config)?;
+        plan.extend(git_push(&args, config.dry_run)?);
+    } else {
+        plan.extend(git_push_remotes(&remotes, &args, config.dry_run)?);
+    }
+    notify(NotificationEvent::Push, config)?;
+    report_plan(&plan, config)
+}
+
+/// The remotes `rona push` pushes to one after the other: every remote with
+/// `--all-remotes`, the configured `push_remotes` otherwise. None (a single `git push`)
+/// when nothing is configured or the arguments name the remote to push to.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If `--all-remotes` is passed along with a remote
+/// * If the remotes cannot be listed
+fn push_remotes(args: &[String], all_remotes: bool, config: &Config) -> Result<Vec<String>> {
+    let names_remote = args.iter().any(|arg| !arg.starts_with('-'));
+
+    if all_remotes {
+        if names_remote {
+            return Err(RonaError::InvalidInput(
+                "--all-remotes pushes to every remote - drop the remote and refspecs from the arguments"
+                    .to_string(),
+            ));
+        }
+        return list_remotes();
+    }
+
+    if names_remote {
+        return Ok(Vec::new());
+    }
+    Ok(config
+        .project_config
+        .push_remotes
+        .clone()
+        .unwrap_or_default())
+}
+
+/// Shows the upstream commits a force push would discard and, in a terminal, asks to
+/// confirm discarding them.
+///
+/// # Errors
+/// * If the discarded commits cannot be listed
+/// * If the user declines the force push
+fn confirm_force_push(args: &[String], config: &Config) -> Result<()> {
+    if !forces_push(args) {
+        return Ok(());
+    }
+
+    let discarded = discarded_remote_commits()?;
+    if discarded.is_empty() {
+        return Ok(());
+    }
+
+    warn!(
+        "⚠️  The force push would discard {} commit(s) from the remote:",
+        discarded.len()
+    );
+    for commit in &discarded {
+        warn!("   {commit}");
+    }
+
+    if config.dry_run || !io::stdin().is_terminal() {
+        return Ok(());
+    }
+
+    let confirmed = Confirm::new(&tr("prompt.force_push"))
+        .with_default(false)
+        .prompt()?;
+
+    if confirmed {
+        Ok(())
+    } else {
+        Err(RonaError::UserCancelled)
+    }
+}
+
+/// Asks to confirm committing or pushing when the current branch matches
+/// `protected_branches`. `--strict`, or input that is not a terminal, refuses instead.
+///
+/// # Errors
+/// * `GitError::ProtectedBranchRefused` - If the branch is protected and cannot be confirmed
+/// * `RonaError::UserCancelled` - If the user declines
+fn confirm_protected_branch(strict: bool, config: &Config) -> Result<()> {
+    let Some(patterns) = &config.project_config.protected_branches else {
+        return Ok(());
+    };
+    let (RepositoryState::Branch(branch) | RepositoryState::Unborn(branch)) = repository_state()?
+    else {
+        return Ok(());
+    };
+    if !is_protected_branch(&branch, patterns) {
+        return Ok(());
+    }
+
+    warn!("⚠️  {branch} is a protected branch (see protected_branches)");
+    if config.dry_run {
+        return Ok(());
+    }
+    if strict || !io::stdin().is_terminal() {
+        return Err(GitError::ProtectedBranchRefused { branch }.into());
+    }
+
+    let confirmed = Confirm::new(&tr_args("prompt.protected_branch", &[("branch", &branch)]))
+        .with_default(false)
+        .prompt()?;
+
+    if confirmed {
+        Ok(())
+    } else {
+        Err(RonaError::UserCancelled)
+    }
+}
+
+/// What to do when the branch is behind its upstream before pushing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BehindChoice {
+    PullRebase,
+    ForceWithLease,
+    PushAnyway,
+    Cancel,
+}
+
+impl std::fmt::Display for BehindChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&tr(match self {
+            Self::PullRebase => "prompt.pull_rebase",
+            Self::ForceWithLease => "prompt.force_with_lease",
+            Self::PushAnyway => "prompt.push_anyway",
+            Self::Cancel => "prompt.cancel",
+        }))
+    }
+}
+
+/// The remote and the branch of the first push of a branch without upstream, `None`
+/// when HEAD is detached, the branch has an upstream or no remote can be picked.
+///
+/// # Errors
+/// * If the upstream or the remotes cannot be read
+fn new_branch_push_target(state: &RepositoryState) -> Result<Option<(String, String)>> {
+    let Some(branch) = state.branch() else {
+        return Ok(None);
+    };
+    if !state.is_unborn() && get_upstream()?.is_some() {
+        return Ok(None);
+    }
+
+    Ok(default_push_remote()?.map(|remote| (remote, branch.to_string())))
+}
+
+/// The choices offered when the branch is behind its upstream: forcing the push with
+/// lease only when the branch is also ahead and the upstream tip is in its reflog, the
+/// upstream commits having been rewritten locally.
+///
+/// # Errors
+/// * If the reflog cannot be read
+fn behind_choices(
+    ahead: usize,
+    upstream_in_reflog: impl FnOnce() -> Result<bool>,
+) -> Result<Vec<BehindChoice>> {
+    let mut choices = vec![BehindChoice::PullRebase];
+    if ahead > 0 && upstream_in_reflog()? {
+        choices.push(BehindChoice::ForceWithLease);
+    }
+    choices.extend([BehindChoice::PushAnyway, BehindChoice::Cancel]);
+
+    Ok(choices)
+}
+
+/// Compares the current branch with its upstream before pushing. When it is behind,
+/// a warning is shown and, in a terminal, pulling with rebase first is offered, as well
+/// as forcing the push with lease when the upstream commits were rewritten locally.
+///
+/// # Returns
+/// * `Vec<String>` - The push arguments, with `--force-with-lease` when chosen
+///
+/// # Errors
+/// * If the upstream cannot be compared with the branch
+/// * If the pull fails or the push is cancelled
+fn guard_push(args: &[String], plan: &mut Plan, config: &Config) -> Result<Vec<String>> {
+    let mut args = args.to_vec();
+
+    if bypasses_upstream_check(&args) {
+        return Ok(args);
+    }
+
+    let state = repository_state()?;
+    if let RepositoryState::Unborn(branch) = &state
+        && !config.dry_run
+    {
+        return Err(RonaError::InvalidInput(format!(
+            "The branch {branch} has no commits yet, there is nothing to push"
+        )));
+    }
+    if let Some((remote, branch)) = new_branch_push_target(&state)? {
+        info!("🔗 Setting the upstream of {branch} to {remote}/{branch}");
+        args.extend(["--set-upstream".to_string(), remote, branch]);
+        return Ok(args);
+    }
+
+    let Some(upstream) = get_upstream()?.filter(|upstream| upstream.behind > 0) else {
+        return Ok(args);
+    };
+
+    let diverged = if upstream.ahead > 0 {
+        format!(" and {} ahead of it", upstream.ahead)
+    } else {
+        String::new()
+    };
+    warn!(
+        "⚠️  The branch is {} commit(s) behind {}{diverged}, the push would be rejected.",
+        upstream.behind, upstream.name
+    );
+
+    let choices = behind_choices(upstream.ahead, is_upstream_in_reflog)?;
+
+    if config.dry_run || !io::stdin().is_terminal() {
+        warn!("   Run `git pull --rebase` first.");
+        if choices.contains(&BehindChoice::ForceWithLease) {
+            warn!(
+                "   The upstream commits were rewritten locally: `--force-with-lease` would be safe."
+            );
+        }
+        return Ok(args);
+    }
+
+    match Select::new(&tr("prompt.push_behind"), choices).prompt()? {
+        BehindChoice::PullRebase => plan.extend(git_pull_rebase(false)?),
+        BehindChoice::ForceWithLease => args.push("--force-with-lease".to_string()),
+        BehindChoice::PushAnyway => {}
+        BehindChoice::Cancel => return Err(RonaError::UserCancelled),
+    }
+
+    Ok(args)
+}
+
+/// Handle the Tag command which creates a tag, then pushes it, pushes every tag or lists them.
+///
+/// Without a message, the tag message file is generated with the commits since the
+/// previous tag and opened in the editor; the tag is created from the edited file.
+///
+/// # Errors
+/// * If the editor cannot be launched or the edited message is empty
+/// * If the tag cannot be created (e.g. it already exists)
+/// * If the push fails
+fn handle_tag(args: &TagArgs, config: &Config) -> Result<()> {
+    if let Some(pattern) = &args.list {
+        return handle_tag_list(pattern);
+    }
+
+    if (args.push || args.name.is_none()) && !config.dry_run {
+        config.ensure_online("git push")?;
+    }
+
+    let Some(name) = args.name.as_deref() else {
+        let plan = push_tags(&args.remote, None, config.dry_run)?;
+        return report_plan(&plan, config);
+    };
+
+    let mut plan = Plan::new();
+    let message = if let Some(message) = &args.message {
+        message.clone()
+    } else {
+        let path = tag_message_path()?;
+        plan.push(
+            ActionKind::WriteMessage,
+            path.display().to_string(),
+            Vec::new(),
+        );
+
+        if config.dry_run {
+            name.to_string()
+        } else {
+            write_tag_message(&path, name)?;
+            open_in_editor(&path, config)?;
+            read_tag_message(&path)?
+        }
+    };
+
+    plan.extend(create_annotated_tag(
+        name,
+        &message,
+        args.sign,
+        config.dry_run,
+    )?);
+
+    if args.push {
+        plan.extend(push_tags(&args.remote, Some(name), config.dry_run)?);
+    }
+
+    if !config.dry_run {
+        info!("✅ Created tag {name}");
+    }
+
+    report_plan(&plan, config)
+}
+
+/// Handle the Tag command with `--list`, printing the tags with the subject of their message.
+///
+/// # Errors
+/// * If the tags cannot be listed
+fn handle_tag_list(pattern: &str) -> Result<()> {
+    let tags = list_tags(pattern)?;
+    let width = tags.iter().map(|tag| tag.name.len()).max().unwrap_or(0);
+
+    for tag in tags {
+        println!("{:width$}  {}", tag.name, tag.subject);
+    }
+
+    Ok(())
+}
+
+/// Handle the Submodule command which runs `git submodule` with the given arguments.
+///
+/// # Errors
+/// * If the git submodule command fails
+fn handle_submodule(args: &[String], config: &Config) -> Result<()> {
+    let plan = git_submodule(args, config.dry_run)?;
+    report_plan(&plan, config)
+}
+
+/// Handle the Release command which bumps the version, commits, tags and pushes.
+///
+/// # Arguments
+/// * `version` - The version to release
+/// * `sign` - Whether to sign the tag
+/// * `unsigned` - Whether to create an unsigned release commit
+/// * `no_push` - Whether to skip pushing the commit and tag
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If the version is invalid
+/// * If any step of the release flow fails
+fn handle_release(
+    version: &str,
+    sign: bool,
+    unsigned: bool,
+    no_push: bool,
+    config: &Config,
+) -> Result<()> {
+    if !no_push && !config.dry_run {
+        config.ensure_online("git push")?;
+    }
+
+    let options = ReleaseOptions {
+        sign_tag: sign,
+        signing: commit_signing(unsigned, config),
+        push: !no_push,
+    };
+    let version = normalize_version(version)?;
+    let header = build_release_header(&format!("v{version}"), config)?;
+
+    let files = config
+        .project_config
+        .release_files
+        .clone()
+        .unwrap_or_else(|| {
+            DEFAULT_RELEASE_FILES
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        });
+
+    let plan = git_release(&version, &header, &files, &options, config.dry_run)?;
+    report_plan(&plan, config)
+}
+
+/// Builds the release commit header using the project template.
+fn build_release_header(tag: &str, config: &Config) -> Result<String> {
+    let commit_number =
+        next_commit_number(config.project_config.commit_number.unwrap_or_default())?;
+    let default_template = if commit_number.is_none() {
+        "({commit_type} on {branch_name}) {message}"
+    } else {
+        "[{commit_number}] ({commit_type} on {branch_name}) {message}"
+    };
+
+    let template = config
+        .project_config
+        .template
+        .as_deref()
+        .unwrap_or(default_template);
+    validate_template(template)?;
+
+    let variables = TemplateVariables::new(
+        commit_number,
+        "chore".to_string(),
+        format_branch_name(&COMMIT_TYPES, &head_name()?),
+        format!("Release {tag}"),
+    )?;
+
+    Ok(apply_emoji_policy(
+        &process_template(template, &variables)?,
+        "chore",
+        emoji_policy(config),
+    ))
+}
+
+/// Handle the Set command which updates the editor in the configuration.
+///
+/// # Arguments
+/// * `editor` - The editor command to set
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If updating configuration file fails
+fn handle_set(editor: &str, config: &Config) -> Result<()> {
+    if config.dry_run {
+        let mut plan = Plan::new();
+        plan.push(ActionKind::SetConfig, "editor", vec![editor.to_string()]);
+        return report_plan(&plan, config);
+    }
+    config.set_editor(editor)?;
+    Ok(())
+}
+
+/// Handle the Rebase command which rebases the current branch onto a revision.
+///
+/// With `--interactive`, the action of each commit is chosen from prompts, then the
+/// commits can be reordered, before the todo list is handed to `git rebase -i`.
+///
+/// # Errors
+/// * If `--interactive` is used without a terminal, or a prompt is cancelled
+/// * If the commits cannot be listed or the first kept commit is melded
+/// * If the rebase fails or stops on conflicts
+fn handle_rebase(args: &RebaseArgs, config: &Config) -> Result<()> {
+    if !args.interactive {
+        let plan = git_rebase(&args.base, config.dry_run)?;
+        return report_plan(&plan, config);
+    }
+
+    if !io::stdin().is_terminal() {
+        return Err(RonaError::InvalidInput(
+            "rebase --interactive needs a terminal to choose the commit actions".to_string(),
+        ));
+    }
+
+    let commits = list_rebase_commits(&args.base)?;
+    if commits.is_empty() {
+        info!(
+            "Nothing to rebase: HEAD has no commits on top of {}",
+            args.base
+        );
+        return Ok(());
+    }
+
+    let mut entries = Vec::with_capacity(commits.len());
+    for commit in commits {
+        let action = Select::new(&commit.to_string(), RebaseAction::ALL.to_vec()).prompt()?;
+        entries.push((action, commit));
+    }
+
+    let reorder = Confirm::new(&tr("prompt.reorder"))
+        .with_default(false)
+        .prompt()?;
+
+    if reorder {
+        entries = reorder_entries(entries)?;
+    }
+
+    let todo = build_todo(&entries)?;
+    let plan = git_rebase_interactive(&args.base, &todo, config.dry_run)?;
+    report_plan(&plan, config)
+}
+
+/// Prompts for the commits in their new order, oldest first.
+///
+/// # Errors
+/// * If a prompt is cancelled
+fn reorder_entries(
+    mut remaining: Vec<(RebaseAction, RebaseCommit)>,
+) -> Result<Vec<(RebaseAction, RebaseCommit)>> {
+    let mut ordered = Vec::with_capacity(remaining.len());
+
+    while remaining.len() > 1 {
+        let options: Vec<String> = remaining
+            .iter()
+            .map(|(action, commit)| format!("{action} {commit}"))
+            .collect();
+        let position = ordered.len() + 1;
+
+        let choice = Select::new(
+            &tr_args("prompt.commit_position", &[("position", &position)]),
+            options,
+        )
+        .raw_prompt()?;
+        ordered.push(remaining.remove(choice.index));
+    }
+
+    ordered.append(&mut remaining);
+    Ok(ordered)
+}
+
+/// What the messages of `rona rewrite` are built with.
+struct RewriteContext<'a> {
+    /// The configured commit types
+    commit_types: Vec<&'a str>,
+    /// How the headers are numbered
+    numbering: CommitNumbering,
+    /// The branch of the headers
+    branch: String,
+    /// Whether each message is confirmed from prompts
+    can_prompt: bool,
+}
+
+/// Handle the Rewrite command: the commits of `BASE..HEAD` not in the rona format yet
+/// get a rona-format message (prompted for unless `--no-edit`), then are reworded by an
+/// interactive rebase.
+///
+/// # Errors
+/// * If the commits or their messages cannot be read
+/// * If a commit type is missing when no prompt can be shown, or a prompt is cancelled
+/// * If the rebase fails or stops on conflicts
+fn handle_rewrite(args: &RewriteArgs, config: &Config) -> Result<()> {
+    let commits = list_rebase_commits(&args.base)?;
+    if commits.is_empty() {
+        info!(
+            "Nothing to rewrite: HEAD has no commits on top of {}",
+            args.base
+        );
+        return Ok(());
+    }
+
+    let context = RewriteContext {
+        commit_types: config.project_config.commit_types.as_ref().map_or_else(
+            || COMMIT_TYPES.to_vec(),
+            |types| types.iter().map(String::as_str).collect(),
+        ),
+        numbering: config.project_config.commit_number.unwrap_or_default(),
+        branch: head_name()?,
+        can_prompt: !args.no_edit && io::stdin().is_terminal(),
+    };
+
+    let mut entries = Vec::with_capacity(commits.len());
+    for commit in commits {
+        let message = rewritten_message(&commit, args.commit_type.as_deref(), &context, config)?;
+        entries.push(RewriteEntry { commit, message });
+    }
+
+    let reworded = entries
+        .iter()
+        .filter(|entry| entry.message.is_some())
+        .count();
+    if reworded == 0 {
+        info!("Every commit keeps its message - nothing to rewrite");
+        return Ok(());
+    }
+
+    let plan = rewrite_commits(&args.base, &entries, config.dry_run)?;
+    if !config.dry_run {
+        info!("✅ Rewrote {reworded} commit(s)");
+    }
+    report_plan(&plan, config)
+}
+
+/// The rona-format message of a commit of `rona rewrite`, `None` when it keeps its
+/// message: it is already in the rona format, or the original is chosen at the prompt.
+///
+/// # Errors
+/// * If the message or the files of the commit cannot be read
+/// * If the commit type is unknown, or missing when no prompt can be shown
+/// * If a prompt is cancelled or the editor cannot be opened
+fn rewritten_message(
+    commit: &RebaseCommit,
+    given_type: Option<&str>,
+    context: &RewriteContext,
+    config: &Config,
+) -> Result<Option<String>> {
+    let original = read_commit_message(&commit.hash)?;
+    let subject = original.lines().next().unwrap_or_default();
+    if is_rona_header(subject) {
+        debug!("{commit} is already in the rona format");
+        return Ok(None);
+    }
+
+    info!("📝 {commit}");
+    for line in original.lines().skip(1) {
+        info!("   {line}");
+    }
+
+    // The type of a conventional commit is kept when it is a configured one
+    let conventional = conventional_subject(subject)
+        .map(|(commit_type, _)| commit_type)
+        .filter(|commit_type| context.commit_types.contains(commit_type));
+    let given = conventional.or(given_type);
+    let suggested = if given.is_none() && context.can_prompt {
+        let files = MessageFiles::from_range(&commit.hash)?;
+        suggested_commit_type(&files, &context.commit_types, config)
+    } else {
+        None
+    };
+    let commit_type = select_commit_type(
+        given,
+        context.commit_types.clone(),
+        suggested,
+        context.can_prompt,
+    )?;
+
+    let number = commit_number_at(&commit.hash, context.numbering)?;
+    let proposal = rewrite_message(&original, commit_type, number, &context.branch);
+    if !context.can_prompt {
+        return Ok(Some(proposal));
+    }
+
+    info!("   -> {}", proposal.lines().next().unwrap_or_default());
+    let options = vec![
+        tr("prompt.rewrite_use"),
+        tr("prompt.rewrite_edit"),
+        tr("prompt.rewrite_keep"),
+    ];
+    match Select::new(&tr("prompt.rewrite_choice"), options)
+        .raw_prompt()?
+        .index
+    {
+        0 => Ok(Some(proposal)),
+        1 => {
+            let path = rewrite_message_path(commit)?;
+            write_commit_message(&path, &proposal)?;
+            open_in_editor(&path, config)?;
+            let edited = std::fs::read_to_string(&path)?;
+            std::fs::remove_file(&path)?;
+            Ok(Some(edited).filter(|message| !message.trim().is_empty()))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Handle the Revert command which reverts a commit with a rona-format message.
+///
+/// # Errors
+/// * If the commit cannot be reverted (see `prepare_revert`)
+/// * If launching the editor fails
+/// * If the revert commit fails, in which case the command to undo the revert is printed
+fn handle_revert(args: &RevertArgs, config: &Config) -> Result<()> {
+    let commit = read_reverted_commit(&args.revision)?;
+    let RevertPreparation { mut plan, message } = prepare_revert(
+        &commit,
+        config.project_config.commit_number.unwrap_or_default(),
+        &commit_trailers(false, &[], config)?,
+        emoji_policy(config),
+        &config.commit_message_path()?,
+        config.dry_run,
+    )?;
+
+    let signing = commit_signing(args.unsigned, config);
+
+    if config.dry_run {
+        plan.extend(git_commit_with_message(&message, &[], &signing, true)?);
+        return report_plan(&plan, config);
+    }
+
+    let committed = (|| {
+        if !args.no_edit {
+            handle_editor_mode(config)?;
+        }
+        git_commit(
+            &config.commit_message_path()?,
+            &[],
+            // The reverted changes were already committed, so they are not scanned for secrets
+            CommitChecks {
+                validation: validation_rules(config),
+                scan_secrets: false,
+                no_verify: false,
+                ascii_subject: emoji_policy(config) == EmojiPolicy::Ascii,
+            },
+            &[],
+            &signing,
+            false,
+        )
+    })();
+
+    if committed.is_err() {
+        warn!("⚠️  The revert was not committed. To undo it, run:");
+        warn!("   git revert --abort");
+    }
+
+    plan.extend(committed?);
+    report_plan(&plan, config)
+}
+
+/// Handle the `Rm` command which removes files with `git rm`, then rewrites their entries in
+/// the pending commit message: `deleted` for the files of the last commit, dropped for the
+/// others.
+///
+/// # Arguments
+/// * `args` - The files to remove and the options of `git rm`
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If another rona process is using the commit message
+/// * If `git rm` fails
+/// * If the pending commit message cannot be rewritten
+fn handle_rm(args: &RmArgs, config: &Config) -> Result<()> {
+    let _lock = lock_message(false, config)?;
+    let mut plan = git_rm(&args.paths, args.options, config.dry_run)?;
+
+    let removed: Vec<String> = plan
+        .targets(ActionKind::Delete)
+        .into_iter()
+        .map(ToString::to_string)
+        .collect();
+    if !config.dry_run {
+        for file in &removed {
+            info!("rm '{file}'");
+        }
+    }
+
+    let committed = committed_files(&plan.targets(ActionKind::Delete))?;
+    sync_pending_message(
+        &mut plan,
+        |message| remove_message_entries(message, &removed, &committed),
+        config,
+    )?;
+
+    report_plan(&plan, config)
+}
+
+/// Handle the Squash command which squashes the last commits into one.
+///
+/// # Arguments
+/// * `count` - The number of commits to squash
+/// * `no_edit` - Whether to commit the merged message without opening the editor
+/// * `unsigned` - Whether to create an unsigned commit
+/// * `config` - Global configuration including verbosity and dry-run settings
+///
+/// # Errors
+/// * If the commits cannot be squashed (see `prepare_squash`)
+/// * If launching the editor fails
+/// * If the new commit fails, in which case the command to restore the commits is printed
+fn handle_squash(count: u32, no_edit: bool, unsigned: bool, config: &Config) -> Result<()> {
+    let SquashPreparation {
+        mut plan,
+        message,
+        previous_head,
+    } = prepare_squash(
+        count,
+        config.project_config.commit_number.unwrap_or_default(),
+        &config.commit_message_path()?,
+        config.dry_run,
+    )?;
+
+    let signing = commit_signing(unsigned, config);
+
+    if config.dry_run {
+        plan.extend(git_commit_with_message(&message, &[], &signing, true)?);
+        return report_plan(&plan, config);
+    }
+
+    let committed = (|| {
+        if !no_edit {
+            handle_editor_mode(config)?;
+        }
+        git_commit(
+            &config.commit_message_path()?,
+            &[],
+            // The squashed changes were already committed, so they are not scanned for secrets
+            CommitChecks {
+                validation: validation_rules(config),
+                scan_secrets: false,
+                no_verify: false,
+                ascii_subject: emoji_policy(config) == EmojiPolicy::Ascii,
+            },
+            &commit_trailers(false, &[], config)?,
+            &signing,
+            false,
+        )
+    })();
+
+    if committed.is_err() {
+        warn!("⚠️  The squashed commit was not created. To restore the original commits, run:");
+        warn!("   git reset --soft {previous_head}");
+    }
+
+    plan.extend(committed?);
+    report_plan(&plan, config)
+}
+
+/// Handle the Wip command which commits a checkpoint or pops the last one.
+///
+/// # Arguments
+/// * `pop` - Whether to soft-reset the last checkpoint instead of creating one
+/// * `unsigned` - Whether to create an unsigned commit
+/// * `config` - Global configuration including the `wip_exclude` patterns and dry-run settings
+///
+/// # Errors
+/// * If a `wip_exclude` pattern is invalid
+/// * If staging or committing fails, or there is nothing to commit
+/// * If popping and the last commit is not a checkpoint
+fn handle_wip(pop: bool, unsigned: bool, config: &Config) -> Result<()> {
+    let plan = if pop {
+        git_wip_pop(config.dry_run)?
+    } else {
+        let patterns = wip_exclude_patterns(config)?;
+        git_wip(&patterns, &commit_signing(unsigned, config), config.dry_run)?
+    };
+
+    report_plan(&plan, config)
+}
+
+/// Compiles the `wip_exclude` patterns of the configuration.
+///
+/// # Errors
+/// * If a pattern is invalid
+fn wip_exclude_patterns(config: &Config) -> Result<Vec<Pattern>> {
+    config
+        .project_config
+        .wip_exclude
+        .iter()
+        .flatten()
+        .map(|pattern| {
+            Pattern::new(&to_git_path(pattern)).map_err(|e| {
+                RonaError::InvalidInput(format!("Invalid wip_exclude pattern '{pattern}': {e}"))
+            })
+        })
+        .collect()
+}
+
+/// Handle the Watch command which shows what would be staged each time the working
+/// tree changes, recording checkpoints on a WIP branch with `--wip-branch`.
+///
+/// # Errors
+/// * If an exclusion pattern is invalid
+/// * If the WIP branch is checked out
+/// * If the working tree cannot be watched or the status cannot be read
+fn handle_watch(args: &WatchArgs, config: &Config) -> Result<()> {
+    let mut exclude_patterns = wip_exclude_patterns(config)?;
+    for pattern in &args.exclude {
+        exclude_patterns.push(
+            Pattern::new(&to_git_path(pattern)).map_err(|e| {
+                RonaError::InvalidInput(format!("Invalid pattern '{pattern}': {e}"))
+            })?,
+        );
+    }
+
+    let wip_branch = match args.wip_branch.as_deref() {
+        Some("") => Some(format!("wip/{}", get_current_branch()?)),
+        branch => branch.map(ToString::to_string),
+    };
+    if let Some(branch) = &wip_branch {
+        check_wip_branch(branch)?;
+    }
+
+    watch(&WatchOptions {
+        exclude_patterns,
+        wip_branch,
+        interval: Duration::from_secs(args.interval),
+    })
+}
+
+/// Handle the Flow command which chains `add-with-exclude`, `generate` (type selection,
+/// then editor or interactive message), `commit` and, with `--push`, `push`.
+///
+/// # Errors
+/// * If an exclusion pattern is invalid
+/// * If any stage fails, the later ones not being run
+fn handle_flow(args: &FlowArgs, config: &Config) -> Result<()> {
+    if config.dry_run {
+        return preview_flow(args, config);
+    }
+
+    if args.push {
+        config.ensure_online("git push")?;
+    }
+
+    let trailers = commit_trailers(args.commit.signoff, &args.commit.co_authors, config)?;
+
+    // Held for the whole flow, the generate and commit stages not locking again
+    let _lock = lock_message(args.commit.force, config)?;
+
+    if args.runs(FlowStage::Add) {
+        git_add_with_exclude_patterns(&flow_patterns(args)?, false)?;
+    }
+
+    if args.runs(FlowStage::Generate) {
+        handle_generate(
+            args.interactive,
+            &args.options,
+            &DiffSourceArgs::default(),
+            &trailers,
+            true,
+            args.commit.force,
+            config,
+        )?;
+    }
+
+    if args.runs(FlowStage::Commit) {
+        let skipped = SkippedChecks {
+            secrets: false,
+            hooks: false,
+            lock: true,
+        };
+        handle_commit(
+            &[],
+            args.push,
+            args.commit.unsigned,
+            skipped,
+            &trailers,
+            None,
+            config,
+        )
+    } else if args.push {
+        handle_push(&[], false, false, config)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the exclusion patterns of the Flow command.
+///
+/// # Errors
+/// * If a pattern is invalid
+fn flow_patterns(args: &FlowArgs) -> Result<Vec<Pattern>> {
+    args.exclude
+        .iter()
+        .map(|pattern| {
+            Pattern::new(&to_git_path(pattern))
+                .map_err(|e| RonaError::InvalidInput(format!("Invalid pattern '{pattern}': {e}")))
+        })
+        .collect()
+}
+
+/// Previews the Flow command as one plan: the files staged, the generated commit
+/// message with its signing status, then the push target.
+///
+/// Nothing is staged or written, the message listing the files that would be staged.
+///
+/// # Errors
+/// * If an exclusion pattern is invalid
+/// * If the commit type is unknown, or missing when no prompt can be shown
+/// * If the status, the commit message file, the commit number or the upstream cannot be read
+fn preview_flow(args: &FlowArgs, config: &Config) -> Result<()> {
+    let mut plan = if args.runs(FlowStage::Add) {
+        git_add_with_exclude_patterns(&flow_patterns(args)?, true)?
+    } else {
+        Plan::new()
+    };
+    let trailers = commit_trailers(args.commit.signoff, &args.commit.co_authors, config)?;
+    let message_path = config.commit_message_path()?;
+
+    let message = if args.runs(FlowStage::Generate) {
+        let options = &args.options;
+        let can_prompt = !options.no_edit && io::stdin().is_terminal();
+        let commit_types_vec = config.project_config.commit_types.as_ref().map_or_else(
+            || COMMIT_TYPES.to_vec(),
+            |v| v.iter().map(String::as_str).collect::<Vec<&str>>(),
+        );
+        let mut files = staged_message_files(config)?;
+        files.add_plan(&plan);
+
+        let commit_type = select_commit_type(
+            options.commit_type.as_deref(),
+            commit_types_vec.clone(),
+            suggested_commit_type(&files, &commit_types_vec, config),
+            can_prompt,
+        )?;
+        let draft = if options.ai {
+            draft_with_ai(config)?
+        } else {
+            None
+        };
+        let issue = resolve_issue(options.issue, can_prompt, config)?;
+        let message = render_commit_message(
+            &files,
+            commit_type,
+            options.message.as_deref(),
+            commit_numbering(options.no_commit_number, config),
+            issue.as_ref(),
+            draft.as_ref(),
+            &trailers,
+        )?;
+        let message = with_branch_context(message, &options.ticket, config)?;
+        apply_emoji_policy(&message, commit_type, emoji_policy(config))
+    } else {
+        std::fs::read_to_string(&message_path).map_err(|_| GitError::CommitMessageNotFound)?
+    };
+
+    if args.runs(FlowStage::Commit) {
+        plan.extend(git_commit_with_message(
+            &append_trailers(&message, &trailers),
+            &[],
+            &commit_signing(args.commit.unsigned, config),
+            true,
+        )?);
+    } else if args.runs(FlowStage::Generate) {
+        plan.push(
+            ActionKind::WriteMessage,
+            message_path.display().to_string(),
+            Vec::new(),
+        );
+    }
+
+    if args.push {
+        let target = match (
+            get_upstream()?,
+            new_branch_push_target(&repository_state()?)?,
+        ) {
+            (Some(upstream), _) => upstream.name,
+            (None, Some((remote, branch))) => format!("{remote}/{branch} (new upstream)"),
+            (None, None) => {
+                warn!("⚠️  The branch has no upstream, `git push` would need one to be set.");
+                String::new()
+            }
+        };
+        plan.push(ActionKind::Push, target, Vec::new());
+    }
+
+    report_plan(&plan, config)
+}
+
+/// Prints the plan of an operation when running in dry-run mode.
+///
+/// # Errors
+/// * If the plan cannot be rendered in the requested format
+fn report_plan(plan: &Plan, config: &Config) -> Result<()> {
+    if config.dry_run {
+        plan.print(config.output_format, config.pager)?;
+    }
+    Ok(())
+}
+
+/// Runs the program by parsing command line arguments and executing the appropriate command.
+///
+/// # Errors
+/// * If creating configuration fails
+/// * If command execution fails
+/// * If any operation fails based on command-specific errors
+///
+/// # Returns
+/// * `Result<()>` - Ok if all operations succeed, Err with error details otherwise
+pub fn run() -> Result<()> {
+    let cli = Cli::parse();
+
+    // The logger comes first, loading the configuration can warn
+    let verbosity = Verbosity::from_flags(cli.quiet, cli.verbose);
+    logging::init(verbosity);
+    exec::set_trace(cli.diagnostics.trace_git);
+
+    // Set the global flags in the config
+    let mut config = Config::new()?;
+    apply_profile(cli.profile.as_deref(), &mut config)?;
+    config.set_verbosity(verbosity);
+    config.set_output_format(if cli.output.json {
+        OutputFormat::Json
+    } else {
+        OutputFormat::Text
+    });
+
+    config.set_pager(!cli.output.no_pager);
+    config.set_dry_run(cli.command.dry_run());
+    if cli.offline {
+        config.set_offline(true);
+    }
+    if let Some(seconds) = config.project_config.network_timeout {
+        set_network_timeout(seconds);
+    }
+    i18n::set_language(Language::detect(config.project_config.language.as_deref()));
+
+    // Apply the configured symbols and colors to all inquire prompts
+    let theme = Theme::new(config.project_config.theme.as_ref(), cli.output.no_emoji);
+    inquire::set_global_render_config(theme.render_config(colors_enabled(Stream::Stderr)));
+    logging::set_emoji(theme.emoji);
+
+    if !cli.setup.no_wizard && !cli.output.json && cli.command.offers_wizard() && is_first_run() {
+        run_wizard(&mut config)?;
+    }
+    if let Some(strict) = cli.command.protected_branch_check() {
+        confirm_protected_branch(strict, &config)?;
+    }
+    if cli.command.numbers_commits() {
+        check_shallow_clone(&mut config)?;
+    }
+
+    let audit = if cli.command.audited() {
+        AuditRecord::start(
+            env::args_os()
+                .skip(1)
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect(),
+        )
+    } else {
+        None
+    };
+    let result = execute(cli.command, &mut config);
+    if let Some(audit) = audit {
+        audit.finish(&result);
+    }
+    result
+}
+
+/// Checks the commit numbering of a shallow clone (e.g. a CI checkout), whose commits are
+/// counted from the truncated history: as set by `shallow_clone`, rona warns, leaves the
+/// numbers out, or fetches the full history first.
+///
+/// # Errors
+/// * If the full history cannot be fetched, or rona is offline
+fn check_shallow_clone(config: &mut Config) -> Result<()> {
+    let numbering = config.project_config.commit_number.unwrap_or_default();
+    if numbering == CommitNumbering::None || !is_shallow_repository()? {
+        return Ok(());
+    }
+
+    match config.project_config.shallow_clone.unwrap_or_default() {
+        ShallowClone::Warn => {
+            warn!(
+                "⚠️  This is a shallow clone: commit numbers are counted from its truncated history."
+            );
+            warn!("   Fetch the full history (git fetch --unshallow), or set shallow_clone to");
+            warn!("   \"unshallow\" or \"omit\" in the configuration.");
+        }
+        ShallowClone::Omit => {
+            debug!("Shallow clone: the commit numbers are left out");
+            config.project_config.commit_number = Some(CommitNumbering::None);
+        }
+        ShallowClone::Unshallow if config.dry_run => {
+            info!("Would fetch the full history of the shallow clone (git fetch --unshallow)");
+        }
+        ShallowClone::Unshallow => {
+            config.ensure_online("git fetch --unshallow")?;
+            info!("Shallow clone: fetching the full history to number the commits...");
+            git_unshallow(false)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies the profile named by `--profile`, else the one whose `remote_patterns` match a
+/// remote of the repository: its settings override the configuration, and its
+/// `author_email` is given to every git invocation.
+///
+/// # Errors
+/// * If no profile has the given name
+fn apply_profile(name: Option<&str>, config: &mut Config) -> Result<()> {
+    let remote_urls: Vec<String> = if name.is_none() && config.project_config.profile.is_some() {
+        list_remotes()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|remote| get_remote_url(remote).ok())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let Some((name, profile)) = config.project_config.select_profile(name, &remote_urls)? else {
+        return Ok(());
+    };
+    debug!("Using the {name} profile");
+
+    let profile = profile.clone();
+    if let Some(email) = &profile.author_email {
+        exec::set_config_override("user.email", email);
+    }
+    config.project_config.apply_profile(&profile);
+
+    Ok(())
+}
+
+/// Executes a parsed command with the global configuration.
+///
+/// # Errors
+/// * If the command fails
+fn execute(command: CliCommand, config: &mut Config) -> Result<()> {
+    match command {
+        CliCommand::AddWithExclude {
+            to_exclude: exclude,
+            fuzzy,
+            ..
+        } => handle_add_with_exclude(&exclude, fuzzy, config),
+
+        CliCommand::Branch { action } => handle_branch(&action, config),
+
+        CliCommand::Checkout(args) => handle_checkout(&args, config),
+
+        CliCommand::CherryPick(args) => handle_cherry_pick(&args, config),
+
+        CliCommand::Commit {
+            args,
+            push,
+            unsigned,
+            signoff,
+            co_authors,
+            allow_secrets,
+            no_verify,
+            force,
+            author,
+            date,
+            message_file,
+            stdin,
+            ..
+        } => {
+            let args = [authorship_args(author.as_deref(), date.as_deref())?, args].concat();
+            let trailers = commit_trailers(signoff, &co_authors, config)?;
+            let skipped = SkippedChecks {
+                secrets: allow_secrets,
+                hooks: no_verify,
+                lock: force,
+            };
+            let message = external_message(message_file.as_deref(), stdin)?;
+            let message = message.as_deref();
+            handle_commit(&args, push, unsigned, skipped, &trailers, message, config)
+        }
+
+        CliCommand::Completion {
+            shell,
+            output,
+            list,
+        } => handle_completion(shell, output.as_deref(), list, config),
+
+        CliCommand::Conflicts(args) => handle_conflicts(&args, config),
+
+        CliCommand::Diff(args) => handle_diff(&args, config),
+
+        CliCommand::Fetch {
+            remote,
+            all,
+            prune,
+            tags,
+            ..
+        } => handle_fetch(remote.as_deref(), FetchOptions { all, prune, tags }, config),
+
+        CliCommand::Flow(args) => handle_flow(&args, config),
+
+        CliCommand::Generate {
+            interactive,
+            ref options,
+            signoff,
+            ref co_authors,
+            force,
+            update,
+            ref source,
+            ..
+        } if !update => {
+            let trailers = &commit_trailers(signoff, co_authors, config)?;
+            handle_generate(interactive, options, source, trailers, force, force, config)
+        }
+        CliCommand::Generate { options, force, .. } => handle_update(&options, force, config),
+
+        CliCommand::History(args) => handle_history(&args, config),
+
+        CliCommand::Hooks { action } => handle_hooks(&action, config),
+
+        CliCommand::Doctor => handle_doctor(config),
+
+        CliCommand::Info => handle_info(config),
+
+        CliCommand::Initialize(args) => handle_initialize(&args, config),
+
+        CliCommand::Lint { file } => handle_lint(file.as_deref(), config),
+
+        CliCommand::ListStatus(args) => handle_list_status(&args),
+
+        CliCommand::Mv { paths, force, .. } => handle_mv(&paths, force, config),
+
+        CliCommand::Open(args) => handle_open(&args, config),
+
+        CliCommand::Push {
+            args,
+            force_unsafe,
+            all_remotes,
+            ..
+        } => handle_push(&args, force_unsafe, all_remotes, config),
+
+        CliCommand::Rebase(args) => handle_rebase(&args, config),
+
+        CliCommand::Release {
+            version,
+            sign,
+            unsigned,
+            no_push,
+            ..
+        } => handle_release(&version, sign, unsigned, no_push, config),
+
+        CliCommand::Revert(args) => handle_revert(&args, config),
+
+        CliCommand::Rewrite(args) => handle_rewrite(&args, config),
+
+        CliCommand::Rm(args) => handle_rm(&args, config),
+
+        CliCommand::Serve { .. } => handle_serve(config),
+
+        CliCommand::Set { editor, .. } => handle_set(&editor, config),
+
+        CliCommand::Squash(args) => handle_squash(args.count, args.no_edit, args.unsigned, config),
+
+        CliCommand::Submodule { args, .. } => handle_submodule(&args, config),
+
+        CliCommand::Tag(args) => handle_tag(&args, config),
+
+        CliCommand::Unadd(args) => handle_unadd(&args, config),
+
+        CliCommand::Wip { pop, unsigned, .. } => handle_wip(pop, unsigned, config),
+
+        CliCommand::Watch(args) => handle_watch(&args, config),
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::*;
+    use crate::{config::ProjectConfig, git::exec::with_runner, testing::MockRunner};
+    use clap::Parser;
+    use std::sync::Arc;
+
+    // === ADD COMMAND TESTS ===
+
+    #[test]
+    fn test_add_basic() {
+        let args = vec!["rona", "-a"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::AddWithExclude {
+                to_exclude: exclude,
+                dry_run,
+                ..
+            } => {
+                assert!(exclude.is_empty());
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_add_single_pattern() {
+        let args = vec!["rona", "-a", "*.txt"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::AddWithExclude {
+                to_exclude: exclude,
+                dry_run,
+                ..
+            } => {
+                assert_eq!(exclude, vec!["*.txt"]);
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_add_multiple_patterns() {
+        let args = vec!["rona", "-a", "*.txt", "*.log", "target/*"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::AddWithExclude {
+                to_exclude: exclude,
+                dry_run,
+                ..
+            } => {
+                assert_eq!(exclude, vec!["*.txt", "*.log", "target/*"]);
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_add_with_long_name() {
+        let args = vec!["rona", "add-with-exclude", "*.txt"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::AddWithExclude {
+                to_exclude: exclude,
+                dry_run,
+                ..
+            } => {
+                assert_eq!(exclude, vec!["*.txt"]);
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_add_fuzzy() {
+        let args = vec!["rona", "-a", "src/tst", "--fuzzy", "--dry-run"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::AddWithExclude {
+                to_exclude: exclude,
+                dry_run,
+                fuzzy,
+            } => {
+                assert_eq!(exclude, vec!["src/tst"]);
+                assert!(dry_run);
+                assert!(fuzzy);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    // === BRANCH COMMAND TESTS ===
+
+    #[test]
+    fn test_checkout_command() {
+        let cli = Cli::try_parse_from(["rona", "checkout", "feat/lo", "--dry-run"]).unwrap();
+
+        match cli.command {
+            CliCommand::Checkout(CheckoutArgs { branch, dry_run }) => {
+                assert_eq!(branch.as_deref(), Some("feat/lo"));
+                assert!(dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+        assert!(matches!(
+            Cli::try_parse_from(["rona", "checkout"]).unwrap().command,
+            CliCommand::Checkout(CheckoutArgs { branch: None, .. })
+        ));
+    }
+
+    #[test]
+    fn test_branch_from_issue() {
+        let args = vec![
+            "rona",
+            "branch",
+            "from-issue",
+            "#42",
+            "-t",
+            "fix",
+            "--dry-run",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Branch {
+                action:
+                    BranchAction::FromIssue(FromIssueArgs {
+                        issue,
+                        branch_type,
+                        no_checkout,
+                        dry_run,
+                    }),
+            } => {
+                assert_eq!(issue, "#42");
+                assert_eq!(branch_type, "fix");
+                assert!(!no_checkout);
+                assert!(dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_branch_describe() {
+        let args = vec!["rona", "branch", "describe", "-m", "Rewrite of the parser"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Branch {
+                action:
+                    BranchAction::Describe(DescribeArgs {
+                        branch,
+                        message,
+                        dry_run,
+                    }),
+            } => {
+                assert_eq!(branch, None);
+                assert_eq!(message.as_deref(), Some("Rewrite of the parser"));
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    // === COMMIT COMMAND TESTS ===
+
+    #[test]
+    fn test_commit_basic() {
+        let args = vec!["rona", "-c"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                args,
+                push,
+                dry_run,
+                unsigned,
+                ..
+            } => {
+                assert!(!push);
+                assert!(args.is_empty());
+                assert!(!dry_run);
+                assert!(!unsigned);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_commit_no_verify() {
+        for flag in ["--no-verify", "-n"] {
+            let cli = Cli::try_parse_from(vec!["rona", "-c", flag]).unwrap();
+
+            match cli.command {
+                CliCommand::Commit {
+                    args, no_verify, ..
+                } => {
+                    assert!(no_verify);
+                    assert!(args.is_empty());
+                }
+                _ => panic!("Wrong command parsed"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_commit_message_source() {
+        let cli = Cli::try_parse_from(vec!["rona", "-c", "--message-file", "msg.txt"]).unwrap();
+        match cli.command {
+            CliCommand::Commit {
+                message_file,
+                stdin,
+                ..
+            } => {
+                assert_eq!(message_file, Some(PathBuf::from("msg.txt")));
+                assert!(!stdin);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+
+        assert!(Cli::try_parse_from(vec!["rona", "-c", "--stdin", "--message-file", "m"]).is_err());
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("message.txt");
+        std::fs::write(&path, "(fix on main) Guard the input\n").unwrap();
+        assert_eq!(
+            external_message(Some(&path), false).unwrap().as_deref(),
+            Some("(fix on main) Guard the input\n")
+        );
+        std::fs::write(&path, "\n").unwrap();
+        assert!(external_message(Some(&path), false).is_err());
+        assert!(external_message(Some(&dir.path().join("missing")), false).is_err());
+        assert_eq!(external_message(None, false).unwrap(), None);
+    }
+
+    #[test]
+    fn test_commit_author_and_date() {
+        let args = vec![
+            "rona",
+            "-c",
+            "--author",
+            "Jane Doe <jane@example.com>",
+            "--date",
+            "2024-05-01 10:30",
+            "--",
+            "--amend",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                args, author, date, ..
+            } => {
+                assert_eq!(author.as_deref(), Some("Jane Doe <jane@example.com>"));
+                assert_eq!(date.as_deref(), Some("2024-05-01 10:30"));
+                assert_eq!(args, vec!["--amend"]);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_commit_allow_secrets() {
+        let args = vec!["rona", "-c", "--allow-secrets"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                args,
+                allow_secrets,
+                ..
+            } => {
+                assert!(allow_secrets);
+                assert!(args.is_empty());
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_commit_with_push_flag() {
+        let args = vec!["rona", "-c", "--push"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                args,
+                push,
+                dry_run,
+                unsigned,
+                ..
+            } => {
+                assert!(push);
+                assert!(args.is_empty());
+                assert!(!dry_run);
+                assert!(!unsigned);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_commit_with_message() {
+        let args = vec!["rona", "-c", "Regular commit message"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                args,
+                push,
+                dry_run,
+                unsigned,
+                ..
+            } => {
+                assert!(!push);
+                assert_eq!(args, vec!["Regular commit message"]);
+                assert!(!dry_run);
+                assert!(!unsigned);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_commit_with_git_flag() {
+        let args = vec!["rona", "-c", "--amend"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                args,
+                push,
+                dry_run,
+                unsigned,
+                ..
+            } => {
+                assert!(!push);
+                assert_eq!(args, vec!["--amend"]);
+                assert!(!dry_run);
+                assert!(!unsigned);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_commit_with_multiple_git_flags() {
+        let args = vec!["rona", "-c", "--amend", "--no-edit"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                args,
+                push,
+                dry_run,
+                unsigned,
+                ..
+            } => {
+                assert!(!push);
+                assert_eq!(args, vec!["--amend", "--no-edit"]);
+                assert!(!dry_run);
+                assert!(!unsigned);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_commit_with_push_and_git_flags() {
+        let args = vec!["rona", "-c", "--push", "--amend", "--no-edit"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                args,
+                push,
+                dry_run,
+                unsigned,
+                ..
+            } => {
+                assert!(push);
+                assert_eq!(args, vec!["--amend", "--no-edit"]);
+                assert!(!dry_run);
+                assert!(!unsigned);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_commit_with_message_and_push() {
+        let args = vec!["rona", "-c", "--push", "Commit message"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                args,
+                push,
+                dry_run,
+                unsigned,
+                ..
+            } => {
+                assert!(push);
+                assert_eq!(args, vec!["Commit message"]);
+                assert!(!dry_run);
+                assert!(!unsigned);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_commit_and_generate_force() {
+        let cli = Cli::try_parse_from(vec!["rona", "commit", "--force", "--amend"]).unwrap();
+        match cli.command {
+            CliCommand::Commit { force, args, .. } => {
+                assert!(force);
+                assert_eq!(args, vec!["--amend"]);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+
+        let cli = Cli::try_parse_from(vec!["rona", "generate", "--force"]).unwrap();
+        match cli.command {
+            CliCommand::Generate { force, .. } => assert!(force),
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    // === SUBMODULE COMMAND TESTS ===
+
+    #[test]
+    fn test_submodule_passthrough() {
+        let args = vec![
+            "rona",
+            "submodule",
+            "--dry-run",
+            "update",
+            "--init",
+            "--recursive",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Submodule { args, dry_run } => {
+                assert_eq!(args, vec!["update", "--init", "--recursive"]);
+                assert!(dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    // === TAG COMMAND TESTS ===
+
+    #[test]
+    fn test_tag_create() {
+        let args = vec![
+            "rona", "tag", "v1.2.0", "-s", "--push", "--remote", "upstream",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Tag(args) => {
+                assert_eq!(args.name.as_deref(), Some("v1.2.0"));
+                assert!(args.sign);
+                assert!(args.push);
+                assert_eq!(args.remote, "upstream");
+                assert_eq!(args.list, None);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_tag_list() {
+        let cli = Cli::try_parse_from(["rona", "tag", "--list"]).unwrap();
+        match cli.command {
+            CliCommand::Tag(args) => assert_eq!(args.list.as_deref(), Some("*")),
+            _ => panic!("Wrong command parsed"),
+        }
+
+        let cli = Cli::try_parse_from(["rona", "tag", "-l", "v1.*"]).unwrap();
+        match cli.command {
+            CliCommand::Tag(args) => assert_eq!(args.list.as_deref(), Some("v1.*")),
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_tag_requires_name_list_or_push() {
+        assert!(Cli::try_parse_from(["rona", "tag"]).is_err());
+        assert!(Cli::try_parse_from(["rona", "tag", "--push"]).is_ok());
+        assert!(Cli::try_parse_from(["rona", "tag", "v1", "--list"]).is_err());
+    }
+
+    // === PUSH COMMAND TESTS ===
+
+    #[test]
+    fn test_push_basic() {
+        let args = vec!["rona", "-p"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Push { args, dry_run, .. } => {
+                assert!(args.is_empty());
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_push_with_force() {
+        let args = vec!["rona", "-p", "--force"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Push { args, dry_run, .. } => {
+                assert_eq!(args, vec!["--force"]);
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_push_force_unsafe() {
+        let args = vec!["rona", "-p", "--force-unsafe", "origin", "main"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Push {
+                args, force_unsafe, ..
+            } => {
+                assert_eq!(args, vec!["origin", "main"]);
+                assert!(force_unsafe);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_push_all_remotes() {
+        let cli = Cli::try_parse_from(["rona", "push", "--all-remotes", "--tags"]).unwrap();
+
+        match cli.command {
+            CliCommand::Push {
+                args, all_remotes, ..
+            } => {
+                assert_eq!(args, vec!["--tags"]);
+                assert!(all_remotes);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_push_with_multiple_args() {
+        let args = vec!["rona", "-p", "--force", "--set-upstream", "origin", "main"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Push { args, dry_run, .. } => {
+                assert_eq!(args, vec!["--force", "--set-upstream", "origin", "main"]);
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_push_with_remote_and_branch() {
+        let args = vec!["rona", "-p", "origin", "feature/branch"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Push { args, dry_run, .. } => {
+                assert_eq!(args, vec!["origin", "feature/branch"]);
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_push_with_upstream_tracking() {
+        let args = vec!["rona", "-p", "-u", "origin", "main"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Push { args, dry_run, .. } => {
+                assert_eq!(args, vec!["-u", "origin", "main"]);
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_behind_choices() {
+        let force_offered = |ahead, in_reflog| {
+            behind_choices(ahead, || Ok(in_reflog))
+                .unwrap()
+                .contains(&BehindChoice::ForceWithLease)
+        };
+
+        assert!(force_offered(2, true));
+        assert!(!force_offered(2, false));
+        assert!(!force_offered(0, true));
+        assert_eq!(
+            behind_choices(0, || panic!("the reflog is only read when ahead")).unwrap(),
+            [
+                BehindChoice::PullRebase,
+                BehindChoice::PushAnyway,
+                BehindChoice::Cancel
+            ]
+        );
+    }
+
+    // === CONFLICTS COMMAND TESTS ===
+
+    #[test]
+    fn test_conflicts_command() {
+        let cli = Cli::try_parse_from(vec!["rona", "conflicts"]).unwrap();
+
+        match cli.command {
+            CliCommand::Conflicts(ConflictsArgs {
+                continue_operation,
+                abort,
+                dry_run,
+            }) => {
+                assert!(!continue_operation);
+                assert!(!abort);
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_conflicts_continue_and_abort() {
+        let cli = Cli::try_parse_from(vec!["rona", "conflicts", "--continue"]).unwrap();
+
+        match cli.command {
+            CliCommand::Conflicts(ConflictsArgs {
+                continue_operation,
+                abort,
+                ..
+            }) => {
+                assert!(continue_operation);
+                assert!(!abort);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+
+        assert!(Cli::try_parse_from(vec!["rona", "conflicts", "--continue", "--abort"]).is_err());
+    }
+
+    // === DIFF COMMAND TESTS ===
+
+    #[test]
+    fn test_diff_command_defaults() {
+        let cli = Cli::try_parse_from(vec!["rona", "diff"]).unwrap();
+
+        match cli.command {
+            CliCommand::Diff(DiffArgs {
+                against,
+                word_diff,
+                color,
+            }) => {
+                assert_eq!(against, None);
+                assert!(!word_diff);
+                assert_eq!(color, DiffColor::Auto);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_diff_command_with_options() {
+        let cli = Cli::try_parse_from(vec![
+            "rona",
+            "diff",
+            "--against",
+            "main",
+            "-w",
+            "--color",
+            "never",
+        ])
+        .unwrap();
+
+        match cli.command {
+            CliCommand::Diff(DiffArgs {
+                against,
+                word_diff,
+                color,
+            }) => {
+                assert_eq!(against.as_deref(), Some("main"));
+                assert!(word_diff);
+                assert_eq!(color, DiffColor::Never);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    // === FETCH COMMAND TESTS ===
+
+    #[test]
+    fn test_fetch_basic() {
+        let cli = Cli::try_parse_from(vec!["rona", "fetch"]).unwrap();
+
+        match cli.command {
+            CliCommand::Fetch {
+                remote,
+                all,
+                prune,
+                tags,
+                dry_run,
+            } => {
+                assert_eq!(remote, None);
+                assert!(!all && !prune && !tags && !dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_fetch_with_options() {
+        let cli = Cli::try_parse_from(vec![
+            "rona",
+            "fetch",
+            "upstream",
+            "--prune",
+            "--tags",
+            "--dry-run",
+        ])
+        .unwrap();
+
+        match cli.command {
+            CliCommand::Fetch {
+                remote,
+                all,
+                prune,
+                tags,
+                dry_run,
+            } => {
+                assert_eq!(remote.as_deref(), Some("upstream"));
+                assert!(!all);
+                assert!(prune && tags && dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_fetch_all_conflicts_with_remote() {
+        assert!(Cli::try_parse_from(vec!["rona", "fetch", "--all", "--prune"]).is_ok());
+        assert!(Cli::try_parse_from(vec!["rona", "fetch", "origin", "--all"]).is_err());
+    }
+
+    // === FLOW COMMAND TESTS ===
+
+    #[test]
+    fn test_flow_with_options() {
+        let cli = Cli::try_parse_from(vec![
+            "rona",
+            "flow",
+            "--dry-run",
+            "*.log",
+            "-t",
+            "feat",
+            "-u",
+            "-p",
+        ])
+        .unwrap();
+
+        match cli.command {
+            CliCommand::Flow(FlowArgs {
+                exclude,
+                options,
+                commit,
+                push,
+                dry_run,
+                ..
+            }) => {
+                assert_eq!(exclude, vec!["*.log"]);
+                assert_eq!(options.commit_type.as_deref(), Some("feat"));
+                assert!(commit.unsigned && push && dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_flow_skip_stages() {
+        let cli = Cli::try_parse_from(vec![
+            "rona", "flow", "--skip", "add", "--skip", "generate", "-p",
+        ])
+        .unwrap();
+
+        match cli.command {
+            CliCommand::Flow(args) => {
+                assert!(!args.runs(FlowStage::Add) && !args.runs(FlowStage::Generate));
+                assert!(args.runs(FlowStage::Commit));
+                assert!(args.push && !args.dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+
+        assert!(Cli::try_parse_from(vec!["rona", "flow", "--skip", "push"]).is_err());
+    }
+
+    // === GENERATE COMMAND TESTS ===
+
+    #[test]
+    fn test_generate_command() {
+        let args = vec!["rona", "-g"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Generate {
+                dry_run,
+                interactive,
+                options:
+                    GenerateOptions {
+                        no_commit_number, ..
+                    },
+                ..
+            } => {
+                assert!(!dry_run);
+                assert!(!interactive);
+                assert!(!no_commit_number);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_generate_interactive_command() {
+        let args = vec!["rona", "-g", "-i"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Generate {
+                dry_run,
+                interactive,
+                options:
+                    GenerateOptions {
+                        no_commit_number, ..
+                    },
+                ..
+            } => {
+                assert!(!dry_run);
+                assert!(interactive);
+                assert!(!no_commit_number);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_generate_interactive_long_form() {
+        let args = vec!["rona", "-g", "--interactive"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Generate {
+                dry_run,
+                interactive,
+                options:
+                    GenerateOptions {
+                        no_commit_number, ..
+                    },
+                ..
+            } => {
+                assert!(!dry_run);
+                assert!(interactive);
+                assert!(!no_commit_number);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_generate_from_range() {
+        let args = vec!["rona", "-g", "--range", "main..HEAD"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Generate { source, .. } => {
+                assert_eq!(source.range.as_deref(), Some("main..HEAD"));
+                assert_eq!(source.from_diff, None);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+
+        let args = vec!["rona", "-g", "--range", "HEAD", "--from-diff", "fix.patch"];
+        assert!(Cli::try_parse_from(args).is_err());
+    }
+
+    #[test]
+    fn test_generate_no_commit_number() {
+        let args = vec!["rona", "-g", "-n"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Generate {
+                dry_run,
+                interactive,
+                options:
+                    GenerateOptions {
+                        no_commit_number, ..
+                    },
+                ..
+            } => {
+                assert!(!dry_run);
+                assert!(!interactive);
+                assert!(no_commit_number);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_generate_no_commit_number_long_form() {
+        let args = vec!["rona", "-g", "--no-commit-number"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Generate {
+                dry_run,
+                interactive,
+                options:
+                    GenerateOptions {
+                        no_commit_number, ..
+                    },
+                ..
+            } => {
+                assert!(!dry_run);
+                assert!(!interactive);
+                assert!(no_commit_number);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_generate_interactive_no_commit_number() {
+        let args = vec!["rona", "-g", "-i", "-n"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Generate {
+                dry_run,
+                interactive,
+                options:
+                    GenerateOptions {
+                        no_commit_number, ..
+                    },
+                ..
+            } => {
+                assert!(!dry_run);
+                assert!(interactive);
+                assert!(no_commit_number);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    #[test]
+    fn test_trailer_flags() {
+        let args = vec![
+            "rona",
+            "-c",
+            "-s",
+            "--co-author",
+            "Jane <jane@example.com>",
+            "--co-author",
+            "John <john@example.com>",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            CliCommand::Commit {
+                signoff,
+                co_authors,
+                args,
+                ..
+            } => {
+                assert!(signoff);
+                assert_eq!(
+                    co_authors,
+                    vec!["Jane <jane@example.com>", "John <john@example.com>"]
+                );
+                assert!(args.is_empty());
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "--signoff"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            CliCommand::Generate { signoff: true, .. }
+        ));
+    }
+
+    #[test]
+    fn test_interactive_message_ticket() {
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "-i", "--ticket", "PROJ-42"]).unwrap();
+        let CliCommand::Generate {
+            interactive: true,
+            options,
+            ..
+        } = cli.command
+        else {
+            panic!("Wrong command parsed");
+        };
 
-    // Validate template
-    if let Err(e) = validate_template(template) {
-        println!("⚠️  Template validation error: {e}");
-        println!("Using fallback format...");
-        let formatted_message = if no_commit_number {
-            format!("({} on {}) {}", commit_type, branch_name, message.trim())
-        } else {
-            format!(
-                "[{}] ({} on {}) {}",
-                commit_number.unwrap(),
-                commit_type,
-                branch_name,
-                message.trim()
-            )
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::with_root(dir.path());
+        config.project_config = ProjectConfig {
+            template: None,
+            ..ProjectConfig::default()
         };
-        fs::write(COMMIT_MESSAGE_FILE_PATH, &formatted_message)?;
-        println!("\n✅ Commit message created!");
-        println!("📄 Message: {formatted_message}");
-        return Ok(());
-    }
+        let mock = Arc::new(MockRunner::new());
+        mock.respond(
+            &["symbolic-ref", "--quiet", "--short", "HEAD"],
+            "feat/login\n",
+        )
+        .respond(
+            &["symbolic-ref", "--quiet", "HEAD"],
+            "refs/heads/feat/login\n",
+        );
 
-    // Create template variables
-    let variables = TemplateVariables::new(
-        commit_number,
-        commit_type.to_string(),
-        branch_name,
-        message.trim().to_string(),
-    )?;
+        let message = |ticket: &TicketArgs, config: &Config| {
+            with_runner(mock.clone(), || {
+                interactive_message(
+                    "Add the login form",
+                    "feat",
+                    CommitNumbering::None,
+                    None,
+                    &[],
+                    ticket,
+                    config,
+                )
+            })
+            .unwrap()
+        };
 
-    // Process template
-    let formatted_message = process_template(template, &variables)?;
+        assert_eq!(
+            message(&options.ticket, &config),
+            "(feat on login) PROJ-42 Add the login form"
+        );
 
-    // Write the formatted message to commit_message.md
-    fs::write(COMMIT_MESSAGE_FILE_PATH, &formatted_message)?;
+        // The ticket found in the branch name by `ticket_pattern`
+        mock.respond(
+            &["symbolic-ref", "--quiet", "--short", "HEAD"],
+            "feat/PROJ-7-login\n",
+        )
+        .respond(
+            &["symbolic-ref", "--quiet", "HEAD"],
+            "refs/heads/feat/PROJ-7-login\n",
+        );
+        config.project_config.ticket_pattern = Some("PROJ-[0-9]+".to_string());
+        assert_eq!(
+            message(&TicketArgs::default(), &config),
+            "(feat on PROJ-7-login) PROJ-7 Add the login form"
+        );
+    }
 
-    println!("\n✅ Commit message created!");
-    println!("📄 Message: {formatted_message}");
-    Ok(())
-}
+    // === COMPLETION COMMAND TESTS ===
 
-/// Handle editor mode for generate command
-fn handle_editor_mode(config: &Config) -> Result<()> {
-    let editor = config.get_editor()?;
+    #[test]
+    fn test_completion_shell() {
+        let cli = Cli::try_parse_from(vec!["rona", "completion", "zsh"]).unwrap();
 
-    Command::new(editor)
-        .arg(COMMIT_MESSAGE_FILE_PATH)
-        .spawn()
-        .expect("Failed to spawn editor")
-        .wait()
-        .expect("Failed to wait for editor");
-    Ok(())
-}
+        match cli.command {
+            CliCommand::Completion {
+                shell,
+                output,
+                list,
+            } => {
+                assert_eq!(shell, Some(Shell::Zsh));
+                assert_eq!(output, None);
+                assert_eq!(list, None);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+    }
 
-/// Handle the Initialize command which creates the initial configuration file.
-///
-/// # Arguments
-/// * `editor` - The editor command to configure
-/// * `config` - Global configuration including verbose and dry-run settings
-///
-/// # Errors
-/// * If creating configuration file fails
-fn handle_initialize(editor: &str, config: &Config) -> Result<()> {
-    if config.dry_run {
-        println!("Would create config file with editor: {editor}");
-        return Ok(());
+    #[test]
+    fn test_completion_list() {
+        let cli =
+            Cli::try_parse_from(vec!["rona", "completion", "--list", "commit-types"]).unwrap();
+
+        match cli.command {
+            CliCommand::Completion { shell, list, .. } => {
+                assert_eq!(shell, None);
+                assert_eq!(list, Some(CompletionKind::CommitTypes));
+            }
+            _ => panic!("Wrong command parsed"),
+        }
     }
-    config.create_config_file(editor)?;
-    Ok(())
-}
 
-/// Handle the `ListStatus` command
-fn handle_list_status() -> Result<()> {
-    let files = get_status_files()?;
-    // Print each file on a new line for fish shell completion
-    for file in files {
-        println!("{file}");
+    #[test]
+    fn test_completion_output() {
+        let output = |args: &[&str]| match Cli::try_parse_from(args).unwrap().command {
+            CliCommand::Completion { output, .. } => output,
+            _ => panic!("Wrong command parsed"),
+        };
+
+        assert_eq!(
+            output(&["rona", "completion", "fish", "--output"]),
+            Some(PathBuf::new())
+        );
+        assert_eq!(
+            output(&["rona", "completion", "-o", "rona.fish"]),
+            Some(PathBuf::from("rona.fish"))
+        );
+        assert!(
+            Cli::try_parse_from(["rona", "completion", "--list", "files", "--output"]).is_err()
+        );
     }
-    Ok(())
-}
 
-/// Handle the Push command which pushes changes to the remote repository.
-///
-/// # Arguments
-/// * `args` - Additional arguments to pass to git push
-/// * `config` - Global configuration including verbose and dry-run settings
-///
-/// # Errors
-/// * If git push operation fails
-fn handle_push(args: &[String], config: &Config) -> Result<()> {
-    git_push(args, config.verbose, config.dry_run)?;
-    Ok(())
-}
+    #[test]
+    fn test_default_completion_path() {
+        let home = Path::new("/home/me");
+        let no_env = |_: &str| None;
+        let xdg = |name: &str| (name == "XDG_CONFIG_HOME").then(|| "/xdg".to_string());
+
+        assert_eq!(
+            default_completion_path(Shell::Fish, home, no_env),
+            Some(PathBuf::from("/home/me/.config/fish/completions/rona.fish"))
+        );
+        assert_eq!(
+            default_completion_path(Shell::Fish, home, xdg),
+            Some(PathBuf::from("/xdg/fish/completions/rona.fish"))
+        );
+        assert_eq!(
+            default_completion_path(Shell::Bash, home, no_env),
+            Some(PathBuf::from(
+                "/home/me/.local/share/bash-completion/completions/rona"
+            ))
+        );
+        assert_eq!(
+            default_completion_path(Shell::Zsh, home, no_env),
+            Some(PathBuf::from("/home/me/.zfunc/_rona"))
+        );
+        assert_eq!(
+            default_completion_path(Shell::PowerShell, home, no_env),
+            None
+        );
+    }
 
-/// Handle the Set command which updates the editor in the configuration.
-///
-/// # Arguments
-/// * `editor` - The editor command to set
-/// * `config` - Global configuration including verbose and dry-run settings
-///
-/// # Errors
-/// * If updating configuration file fails
-fn handle_set(editor: &str, config: &Config) -> Result<()> {
-    if config.dry_run {
-        println!("Would set editor to: {editor}");
-        return Ok(());
+    #[test]
+    fn test_completion_shell_is_optional() {
+        match Cli::try_parse_from(vec!["rona", "completion"])
+            .unwrap()
+            .command
+        {
+            CliCommand::Completion { shell, list, .. } => {
+                assert_eq!(shell, None);
+                assert_eq!(list, None);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+        assert!(
+            Cli::try_parse_from(vec!["rona", "completion", "bash", "--list", "files"]).is_err()
+        );
     }
-    config.set_editor(editor)?;
-    Ok(())
-}
 
-/// Runs the program by parsing command line arguments and executing the appropriate command.
-///
-/// # Errors
-/// * If creating configuration fails
-/// * If command execution fails
-/// * If any operation fails based on command-specific errors
-///
-/// # Returns
-/// * `Result<()>` - Ok if all operations succeed, Err with error details otherwise
-pub fn run() -> Result<()> {
-    // Apply global colors/styles for all inquire prompts
-    inquire::set_global_render_config(get_render_config());
+    // === SERVE COMMAND TESTS ===
 
-    let cli = Cli::parse();
-    let mut config = Config::new()?;
+    #[test]
+    fn test_serve_requires_stdio() {
+        let cli = Cli::try_parse_from(vec!["rona", "serve", "--stdio"]).unwrap();
+        assert!(matches!(cli.command, CliCommand::Serve { stdio: true }));
 
-    // Set the global flags in the config
-    config.set_verbose(cli.verbose);
+        assert!(Cli::try_parse_from(vec!["rona", "serve"]).is_err());
+    }
 
-    match cli.command {
-        CliCommand::AddWithExclude {
-            to_exclude: exclude,
-            dry_run,
-        } => {
-            config.set_dry_run(dry_run);
-            handle_add_with_exclude(&exclude, &config)
-        }
+    // === HOOKS COMMAND TESTS ===
 
-        CliCommand::Commit {
-            args,
-            push,
-            dry_run,
-            unsigned,
-        } => {
-            config.set_dry_run(dry_run);
-            handle_commit(&args, push, unsigned, &config)
-        }
+    #[test]
+    fn test_hooks_generate_commit_msg() {
+        let cli = Cli::try_parse_from(vec!["rona", "hooks", "generate", "commit-msg", "--force"])
+            .unwrap();
 
-        CliCommand::Completion { shell } => {
-            handle_completion(shell);
-            Ok(())
+        match cli.command {
+            CliCommand::Hooks {
+                action:
+                    HooksAction::Generate {
+                        hook,
+                        force,
+                        dry_run,
+                    },
+            } => {
+                assert_eq!(hook, HookKind::CommitMsg);
+                assert!(force);
+                assert!(!dry_run);
+            }
+            _ => panic!("Wrong command parsed"),
         }
 
-        CliCommand::Generate {
-            dry_run,
-            interactive,
-            no_commit_number,
-        } => {
-            config.set_dry_run(dry_run);
-            handle_generate(interactive, no_commit_number, &config)
-        }
+        assert!(Cli::try_parse_from(vec!["rona", "hooks", "generate", "pre-push"]).is_err());
+    }
 
-        CliCommand::Initialize { editor, dry_run } => {
-            config.set_dry_run(dry_run);
-            handle_initialize(&editor, &config)
-        }
+    // === DOCTOR COMMAND TESTS ===
 
-        CliCommand::ListStatus => handle_list_status(),
+    #[test]
+    fn test_doctor_command() {
+        let cli = Cli::try_parse_from(vec!["rona", "doctor", "--json"]).unwrap();
 
-        CliCommand::Push { args, dry_run } => {
-            config.set_dry_run(dry_run);
-            handle_push(&args, &config)
-        }
+        assert!(matches!(cli.command, CliCommand::Doctor));
+        assert!(cli.output.json);
+        assert!(!cli.command.dry_run());
+    }
 
-        CliCommand::Set { editor, dry_run } => {
-            config.set_dry_run(dry_run);
-            handle_set(&editor, &config)
+    // === HISTORY COMMAND TESTS ===
+
+    #[test]
+    fn test_history_command() {
+        let cli = Cli::try_parse_from(vec!["rona", "history", "--ops", "-n", "5"]).unwrap();
+
+        match &cli.command {
+            CliCommand::History(args) => {
+                assert!(args.ops);
+                assert_eq!(args.limit, 5);
+                assert!(!cli.command.audited());
+            }
+            _ => panic!("Wrong command parsed"),
         }
     }
-}
 
-#[cfg(test)]
-mod cli_tests {
-    use super::*;
-    use clap::Parser;
+    #[test]
+    fn test_audited_commands() {
+        let audited = |args: &[&str]| {
+            let args = [&["rona"], args].concat();
+            Cli::try_parse_from(args).unwrap().command.audited()
+        };
 
-    // === ADD COMMAND TESTS ===
+        assert!(audited(&["-a", "*.lock"]));
+        assert!(audited(&["commit", "-p"]));
+        assert!(audited(&["push"]));
+        assert!(audited(&["tag", "v1.0.0", "-m", "First"]));
+        assert!(!audited(&["commit", "--dry-run"]));
+        assert!(!audited(&["tag", "--list"]));
+        assert!(!audited(&["generate"]));
+        assert!(!audited(&["info"]));
+    }
+
+    // === INFO COMMAND TESTS ===
 
     #[test]
-    fn test_add_basic() {
-        let args = vec!["rona", "-a"];
-        let cli = Cli::try_parse_from(args).unwrap();
+    fn test_info_command() {
+        let cli = Cli::try_parse_from(vec!["rona", "info"]).unwrap();
 
         match cli.command {
-            CliCommand::AddWithExclude {
-                to_exclude: exclude,
-                dry_run,
-            } => {
-                assert!(exclude.is_empty());
-                assert!(!dry_run);
+            CliCommand::Info => assert!(!cli.command.dry_run()),
+            _ => panic!("Wrong command parsed"),
+        }
+    }
+
+    // === LINT COMMAND TESTS ===
+
+    #[test]
+    fn test_lint_file() {
+        let cli =
+            Cli::try_parse_from(vec!["rona", "lint", "--file", ".git/COMMIT_EDITMSG"]).unwrap();
+
+        match cli.command {
+            CliCommand::Lint { file } => {
+                assert_eq!(file, Some(PathBuf::from(".git/COMMIT_EDITMSG")));
             }
             _ => panic!("Wrong command parsed"),
         }
     }
 
+    // === LIST STATUS COMMAND TESTS ===
+
     #[test]
-    fn test_add_single_pattern() {
-        let args = vec!["rona", "-a", "*.txt"];
+    fn test_list_status_command() {
+        let args = vec!["rona", "-l"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::AddWithExclude {
-                to_exclude: exclude,
-                dry_run,
-            } => {
-                assert_eq!(exclude, vec!["*.txt"]);
-                assert!(!dry_run);
-            }
+            CliCommand::ListStatus(args) => assert!(args.sets().is_empty()),
             _ => panic!("Wrong command parsed"),
         }
     }
 
     #[test]
-    fn test_add_multiple_patterns() {
-        let args = vec!["rona", "-a", "*.txt", "*.log", "target/*"];
+    fn test_list_status_filters() {
+        let args = vec!["rona", "-l", "--staged", "--deleted", "-z"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::AddWithExclude {
-                to_exclude: exclude,
-                dry_run,
-            } => {
-                assert_eq!(exclude, vec!["*.txt", "*.log", "target/*"]);
-                assert!(!dry_run);
+            CliCommand::ListStatus(args) => {
+                assert_eq!(args.sets(), [StatusSet::Staged, StatusSet::Deleted]);
+                assert!(args.null);
             }
             _ => panic!("Wrong command parsed"),
         }
     }
 
     #[test]
-    fn test_add_with_long_name() {
-        let args = vec!["rona", "add-with-exclude", "*.txt"];
-        let cli = Cli::try_parse_from(args).unwrap();
+    fn test_generate_non_interactive() {
+        let cli = Cli::try_parse_from(vec![
+            "rona",
+            "-g",
+            "--type",
+            "feat",
+            "--message",
+            "Add the tag command",
+            "--no-edit",
+        ])
+        .unwrap();
+        match cli.command {
+            CliCommand::Generate { options, .. } => {
+                assert_eq!(options.commit_type.as_deref(), Some("feat"));
+                assert_eq!(options.message.as_deref(), Some("Add the tag command"));
+                assert!(options.no_edit);
+            }
+            _ => panic!("Wrong command parsed"),
+        }
 
+        assert!(Cli::try_parse_from(vec!["rona", "-g", "-i", "--no-edit"]).is_err());
+        assert!(Cli::try_parse_from(vec!["rona", "-g", "-i", "-m", "Subject"]).is_err());
+    }
+
+    #[test]
+    fn test_generate_ticket() {
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "--ticket", "PROJ-7"]).unwrap();
         match cli.command {
-            CliCommand::AddWithExclude {
-                to_exclude: exclude,
-                dry_run,
+            CliCommand::Generate {
+                options: GenerateOptions { ticket, .. },
+                ..
+            } => assert_eq!(ticket.ticket.as_deref(), Some("PROJ-7")),
+            _ => panic!("Wrong command parsed"),
+        }
+
+        let args = vec!["rona", "-g", "--ticket", "PROJ-7", "--no-ticket"];
+        assert!(Cli::try_parse_from(args).is_err());
+        assert!(Cli::try_parse_from(vec!["rona", "-g", "--update", "--no-ticket"]).is_err());
+    }
+
+    #[test]
+    fn test_generate_breaking_change() {
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "--breaking", "Drop --legacy"]).unwrap();
+        match cli.command {
+            CliCommand::Generate {
+                options: GenerateOptions { breaking, .. },
+                ..
+            } => assert_eq!(breaking.as_deref(), Some("Drop --legacy")),
+            _ => panic!("Wrong command parsed"),
+        }
+
+        assert!(Cli::try_parse_from(vec!["rona", "-g", "--update", "--breaking", "x"]).is_err());
+        assert!(breaking_change(Some(" "), false).is_err());
+        assert_eq!(breaking_change(None, false).unwrap(), None);
+        assert_eq!(
+            with_breaking_change(
+                &[Trailer::new("Signed-off-by", "Jane <j@e.com>")],
+                Some("x")
+            )
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+            ["BREAKING CHANGE: x", "Signed-off-by: Jane <j@e.com>"]
+        );
+    }
+
+    #[test]
+    fn test_generate_with_issue() {
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "--issue", "42"]).unwrap();
+        match cli.command {
+            CliCommand::Generate {
+                options: GenerateOptions { issue, .. },
+                ..
             } => {
-                assert_eq!(exclude, vec!["*.txt"]);
-                assert!(!dry_run);
+                assert_eq!(issue, Some(IssueSelection::Number(42)));
+            }
+            _ => panic!("Wrong command parsed"),
+        }
+
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "--issue"]).unwrap();
+        match cli.command {
+            CliCommand::Generate {
+                options: GenerateOptions { issue, .. },
+                ..
+            } => assert_eq!(issue, Some(IssueSelection::Select)),
+            _ => panic!("Wrong command parsed"),
+        }
+
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "--issue", "#7"]).unwrap();
+        match cli.command {
+            CliCommand::Generate {
+                options: GenerateOptions { issue, .. },
+                ..
+            } => {
+                assert_eq!(issue, Some(IssueSelection::Number(7)));
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        let cli = Cli::try_parse_from(vec!["rona", "-g"]).unwrap();
+        match cli.command {
+            CliCommand::Generate {
+                options: GenerateOptions { issue, .. },
+                ..
+            } => assert_eq!(issue, None),
+            _ => panic!("Wrong command parsed"),
+        }
+
+        assert!(Cli::try_parse_from(vec!["rona", "-g", "--issue", "abc"]).is_err());
     }
 
-    // === COMMIT COMMAND TESTS ===
+    #[test]
+    fn test_generate_with_ai() {
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "--ai"]).unwrap();
+        match cli.command {
+            CliCommand::Generate {
+                options: GenerateOptions { ai, .. },
+                ..
+            } => assert!(ai),
+            _ => panic!("Wrong command parsed"),
+        }
+
+        assert!(Cli::try_parse_from(vec!["rona", "-g", "--ai", "-i"]).is_err());
+    }
+
+    #[test]
+    fn test_generate_update() {
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "--update", "--no-edit"]).unwrap();
+        match cli.command {
+            CliCommand::Generate {
+                update, options, ..
+            } => assert!(update && options.no_edit),
+            _ => panic!("Wrong command parsed"),
+        }
+
+        for conflicting in ["-i", "--type=feat", "--ai", "--signoff"] {
+            assert!(Cli::try_parse_from(vec!["rona", "-g", "--update", conflicting]).is_err());
+        }
+    }
+
+    // === INITIALIZE COMMAND TESTS ===
 
     #[test]
-    fn test_commit_basic() {
-        let args = vec!["rona", "-c"];
+    fn test_init_default_editor() {
+        let args = vec!["rona", "-i"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Commit {
-                args,
-                push,
+            CliCommand::Initialize(InitArgs {
+                editor,
                 dry_run,
-                unsigned,
-            } => {
-                assert!(!push);
-                assert!(args.is_empty());
+                preset,
+                scaffold,
+                file:
+                    ConfigFileArgs {
+                        global,
+                        project,
+                        force,
+                    },
+            }) => {
+                assert_eq!(editor, "nano");
                 assert!(!dry_run);
-                assert!(!unsigned);
+                assert_eq!(preset, None);
+                assert!(!scaffold);
+                assert!(!global && !project && !force);
             }
             _ => panic!("Wrong command parsed"),
         }
     }
 
     #[test]
-    fn test_commit_with_push_flag() {
-        let args = vec!["rona", "-c", "--push"];
+    fn test_init_custom_editor() {
+        let args = vec!["rona", "-i", "zed"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Commit {
-                args,
-                push,
+            CliCommand::Initialize(InitArgs {
+                editor,
                 dry_run,
-                unsigned,
-            } => {
-                assert!(push);
-                assert!(args.is_empty());
+                preset,
+                scaffold,
+                file:
+                    ConfigFileArgs {
+                        global,
+                        project,
+                        force,
+                    },
+            }) => {
+                assert_eq!(editor, "zed");
                 assert!(!dry_run);
-                assert!(!unsigned);
+                assert_eq!(preset, None);
+                assert!(!scaffold);
+                assert!(!global && !project && !force);
             }
             _ => panic!("Wrong command parsed"),
         }
     }
 
     #[test]
-    fn test_commit_with_message() {
-        let args = vec!["rona", "-c", "Regular commit message"];
-        let cli = Cli::try_parse_from(args).unwrap();
+    fn test_init_preset() {
+        let cli = Cli::try_parse_from(vec!["rona", "init", "vim", "--preset", "full"]).unwrap();
 
         match cli.command {
-            CliCommand::Commit {
-                args,
-                push,
-                dry_run,
-                unsigned,
-            } => {
-                assert!(!push);
-                assert_eq!(args, vec!["Regular commit message"]);
-                assert!(!dry_run);
-                assert!(!unsigned);
+            CliCommand::Initialize(InitArgs {
+                editor,
+                preset,
+                scaffold,
+                ..
+            }) => {
+                assert_eq!(editor, "vim");
+                assert_eq!(preset, Some(Preset::Full));
+                assert!(!scaffold);
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        assert!(Cli::try_parse_from(vec!["rona", "init", "--preset", "huge"]).is_err());
+        assert!(
+            Cli::try_parse_from(vec!["rona", "init", "--preset", "full", "--scaffold"]).is_err()
+        );
     }
 
     #[test]
-    fn test_commit_with_git_flag() {
-        let args = vec!["rona", "-c", "--amend"];
+    fn test_init_location() {
+        let args = vec!["rona", "init", "vim", "--global", "--force"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Commit {
-                args,
-                push,
-                dry_run,
-                unsigned,
-            } => {
-                assert!(!push);
-                assert_eq!(args, vec!["--amend"]);
-                assert!(!dry_run);
-                assert!(!unsigned);
+            CliCommand::Initialize(InitArgs { file, .. }) => {
+                assert_eq!(file.location(), Some(ConfigLocation::Global));
+                assert!(file.force);
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        assert!(Cli::try_parse_from(vec!["rona", "init", "--global", "--project"]).is_err());
+        assert!(
+            Cli::try_parse_from(vec!["rona", "init", "--project", "--preset", "full"]).is_err()
+        );
     }
 
+    // === REBASE COMMAND TESTS ===
+
     #[test]
-    fn test_commit_with_multiple_git_flags() {
-        let args = vec!["rona", "-c", "--amend", "--no-edit"];
+    fn test_rebase_interactive() {
+        let args = vec!["rona", "rebase", "-i", "main", "--dry-run"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Commit {
-                args,
-                push,
+            CliCommand::Rebase(RebaseArgs {
+                base,
+                interactive,
                 dry_run,
-                unsigned,
-            } => {
-                assert!(!push);
-                assert_eq!(args, vec!["--amend", "--no-edit"]);
-                assert!(!dry_run);
-                assert!(!unsigned);
+            }) => {
+                assert_eq!(base, "main");
+                assert!(interactive);
+                assert!(dry_run);
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        assert!(Cli::try_parse_from(vec!["rona", "rebase", "-i"]).is_err());
     }
 
+    // === RELEASE COMMAND TESTS ===
+
     #[test]
-    fn test_commit_with_push_and_git_flags() {
-        let args = vec!["rona", "-c", "--push", "--amend", "--no-edit"];
+    fn test_release_basic() {
+        let args = vec!["rona", "release", "1.2.0"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Commit {
-                args,
-                push,
+            CliCommand::Release {
+                version,
                 dry_run,
+                sign,
                 unsigned,
+                no_push,
             } => {
-                assert!(push);
-                assert_eq!(args, vec!["--amend", "--no-edit"]);
+                assert_eq!(version, "1.2.0");
                 assert!(!dry_run);
+                assert!(!sign);
                 assert!(!unsigned);
+                assert!(!no_push);
             }
             _ => panic!("Wrong command parsed"),
         }
     }
 
     #[test]
-    fn test_commit_with_message_and_push() {
-        let args = vec!["rona", "-c", "--push", "Commit message"];
+    fn test_release_with_flags() {
+        let args = vec![
+            "rona",
+            "release",
+            "v2.0.0",
+            "-s",
+            "-u",
+            "--no-push",
+            "--dry-run",
+        ];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Commit {
-                args,
-                push,
+            CliCommand::Release {
+                version,
                 dry_run,
+                sign,
                 unsigned,
+                no_push,
             } => {
-                assert!(push);
-                assert_eq!(args, vec!["Commit message"]);
-                assert!(!dry_run);
-                assert!(!unsigned);
+                assert_eq!(version, "v2.0.0");
+                assert!(dry_run);
+                assert!(sign);
+                assert!(unsigned);
+                assert!(no_push);
             }
             _ => panic!("Wrong command parsed"),
         }
     }
 
-    // === PUSH COMMAND TESTS ===
+    #[test]
+    fn test_release_missing_version() {
+        let args = vec!["rona", "release"];
+        assert!(Cli::try_parse_from(args).is_err());
+    }
+
+    // === OPEN COMMAND TESTS ===
 
     #[test]
-    fn test_push_basic() {
-        let args = vec!["rona", "-p"];
-        let cli = Cli::try_parse_from(args).unwrap();
+    fn test_open_default() {
+        let cli = Cli::try_parse_from(vec!["rona", "open"]).unwrap();
 
         match cli.command {
-            CliCommand::Push { args, dry_run } => {
-                assert!(args.is_empty());
+            CliCommand::Open(OpenArgs {
+                pr,
+                branch,
+                file,
+                remote,
+                dry_run,
+            }) => {
+                assert!(!pr);
+                assert!(!branch);
+                assert!(file.is_none());
+                assert_eq!(remote, "origin");
                 assert!(!dry_run);
             }
             _ => panic!("Wrong command parsed"),
@@ -783,218 +6704,317 @@ mod cli_tests {
     }
 
     #[test]
-    fn test_push_with_force() {
-        let args = vec!["rona", "-p", "--force"];
+    fn test_open_with_target() {
+        let args = vec![
+            "rona",
+            "open",
+            "--file",
+            "src/main.rs",
+            "--remote",
+            "upstream",
+        ];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Push { args, dry_run } => {
-                assert_eq!(args, vec!["--force"]);
-                assert!(!dry_run);
+            CliCommand::Open(OpenArgs { file, remote, .. }) => {
+                assert_eq!(file.as_deref(), Some("src/main.rs"));
+                assert_eq!(remote, "upstream");
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        let cli = Cli::try_parse_from(vec!["rona", "open", "--pr"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            CliCommand::Open(OpenArgs { pr: true, .. })
+        ));
     }
 
     #[test]
-    fn test_push_with_multiple_args() {
-        let args = vec!["rona", "-p", "--force", "--set-upstream", "origin", "main"];
-        let cli = Cli::try_parse_from(args).unwrap();
-
-        match cli.command {
-            CliCommand::Push { args, dry_run } => {
-                assert_eq!(args, vec!["--force", "--set-upstream", "origin", "main"]);
-                assert!(!dry_run);
-            }
-            _ => panic!("Wrong command parsed"),
-        }
+    fn test_open_conflicting_targets() {
+        assert!(Cli::try_parse_from(vec!["rona", "open", "--pr", "--branch"]).is_err());
+        assert!(Cli::try_parse_from(vec!["rona", "open", "--branch", "--file", "a"]).is_err());
     }
 
+    // === SQUASH COMMAND TESTS ===
+
     #[test]
-    fn test_push_with_remote_and_branch() {
-        let args = vec!["rona", "-p", "origin", "feature/branch"];
+    fn test_squash() {
+        let args = vec!["rona", "squash", "3", "--no-edit", "-u"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Push { args, dry_run } => {
-                assert_eq!(args, vec!["origin", "feature/branch"]);
+            CliCommand::Squash(SquashArgs {
+                count,
+                dry_run,
+                no_edit,
+                unsigned,
+            }) => {
+                assert_eq!(count, 3);
                 assert!(!dry_run);
+                assert!(no_edit);
+                assert!(unsigned);
             }
             _ => panic!("Wrong command parsed"),
         }
     }
 
     #[test]
-    fn test_push_with_upstream_tracking() {
-        let args = vec!["rona", "-p", "-u", "origin", "main"];
+    fn test_squash_invalid_count() {
+        assert!(Cli::try_parse_from(vec!["rona", "squash"]).is_err());
+        assert!(Cli::try_parse_from(vec!["rona", "squash", "1"]).is_err());
+        assert!(Cli::try_parse_from(vec!["rona", "squash", "two"]).is_err());
+    }
+
+    #[test]
+    fn test_cherry_pick() {
+        let args = vec![
+            "rona",
+            "cherry-pick",
+            "a1b2c3d",
+            "main..fix/parser",
+            "-t",
+            "fix",
+        ];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Push { args, dry_run } => {
-                assert_eq!(args, vec!["-u", "origin", "main"]);
-                assert!(!dry_run);
+            CliCommand::CherryPick(CherryPickArgs {
+                commits,
+                commit_type,
+                no_edit,
+                ..
+            }) => {
+                assert_eq!(commits, ["a1b2c3d", "main..fix/parser"]);
+                assert_eq!(commit_type.as_deref(), Some("fix"));
+                assert!(!no_edit);
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        assert!(Cli::try_parse_from(vec!["rona", "cherry-pick"]).is_err());
     }
 
-    // === GENERATE COMMAND TESTS ===
+    // === REVERT COMMAND TESTS ===
 
     #[test]
-    fn test_generate_command() {
-        let args = vec!["rona", "-g"];
+    fn test_revert() {
+        let args = vec!["rona", "revert", "HEAD~2", "--no-edit", "--dry-run"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Generate {
+            CliCommand::Revert(RevertArgs {
+                revision,
                 dry_run,
-                interactive,
-                no_commit_number,
-            } => {
-                assert!(!dry_run);
-                assert!(!interactive);
-                assert!(!no_commit_number);
+                no_edit,
+                unsigned,
+            }) => {
+                assert_eq!(revision, "HEAD~2");
+                assert!(dry_run);
+                assert!(no_edit);
+                assert!(!unsigned);
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        assert!(Cli::try_parse_from(vec!["rona", "revert"]).is_err());
     }
 
     #[test]
-    fn test_generate_interactive_command() {
-        let args = vec!["rona", "-g", "-i"];
+    fn test_rewrite() {
+        let args = vec!["rona", "rewrite", "main", "-t", "chore", "--no-edit"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Generate {
+            CliCommand::Rewrite(RewriteArgs {
+                base,
+                commit_type,
+                no_edit,
                 dry_run,
-                interactive,
-                no_commit_number,
-            } => {
+            }) => {
+                assert_eq!(base, "main");
+                assert_eq!(commit_type.as_deref(), Some("chore"));
+                assert!(no_edit);
                 assert!(!dry_run);
-                assert!(interactive);
-                assert!(!no_commit_number);
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        assert!(Cli::try_parse_from(vec!["rona", "rewrite"]).is_err());
     }
 
+    // === MV AND RM COMMAND TESTS ===
+
     #[test]
-    fn test_generate_interactive_long_form() {
-        let args = vec!["rona", "-g", "--interactive"];
-        let cli = Cli::try_parse_from(args).unwrap();
+    fn test_mv_and_rm_commands() {
+        let cli = Cli::try_parse_from(vec!["rona", "mv", "a.rs", "b.rs", "src/", "-f"]).unwrap();
+        match cli.command {
+            CliCommand::Mv { paths, force, .. } => {
+                assert_eq!(paths, vec!["a.rs", "b.rs", "src/"]);
+                assert!(force);
+            }
+            _ => panic!("Expected Mv command"),
+        }
+        assert!(Cli::try_parse_from(vec!["rona", "mv", "a.rs"]).is_err());
 
+        let cli = Cli::try_parse_from(vec!["rona", "rm", "-r", "--cached", "build", "--dry-run"])
+            .unwrap();
+        assert!(cli.command.dry_run());
         match cli.command {
-            CliCommand::Generate {
-                dry_run,
-                interactive,
-                no_commit_number,
-            } => {
-                assert!(!dry_run);
-                assert!(interactive);
-                assert!(!no_commit_number);
+            CliCommand::Rm(RmArgs { paths, options, .. }) => {
+                assert_eq!(paths, vec!["build"]);
+                assert!(options.recursive && options.cached && !options.force);
             }
-            _ => panic!("Wrong command parsed"),
+            _ => panic!("Expected Rm command"),
         }
+        assert!(Cli::try_parse_from(vec!["rona", "rm"]).is_err());
     }
 
-    #[test]
-    fn test_generate_no_commit_number() {
-        let args = vec!["rona", "-g", "-n"];
-        let cli = Cli::try_parse_from(args).unwrap();
+    // === UNADD COMMAND TESTS ===
 
+    #[test]
+    fn test_unadd_command() {
+        let cli =
+            Cli::try_parse_from(vec!["rona", "unadd", "*.lock", "dist/*", "--dry-run"]).unwrap();
+        assert!(cli.command.dry_run());
         match cli.command {
-            CliCommand::Generate {
-                dry_run,
+            CliCommand::Unadd(UnaddArgs {
+                patterns,
                 interactive,
-                no_commit_number,
-            } => {
-                assert!(!dry_run);
+                ..
+            }) => {
+                assert_eq!(patterns, vec!["*.lock", "dist/*"]);
                 assert!(!interactive);
-                assert!(no_commit_number);
             }
-            _ => panic!("Wrong command parsed"),
+            _ => panic!("Expected Unadd command"),
         }
+
+        assert!(matches!(
+            Cli::try_parse_from(vec!["rona", "unstage", "-i"])
+                .unwrap()
+                .command,
+            CliCommand::Unadd(UnaddArgs {
+                interactive: true,
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(vec!["rona", "unadd", "-i", "*.lock"]).is_err());
     }
 
+    // === WIP COMMAND TESTS ===
+
     #[test]
-    fn test_generate_no_commit_number_long_form() {
-        let args = vec!["rona", "-g", "--no-commit-number"];
-        let cli = Cli::try_parse_from(args).unwrap();
+    fn test_wip() {
+        let cli = Cli::try_parse_from(vec!["rona", "wip", "-u", "--dry-run"]).unwrap();
 
         match cli.command {
-            CliCommand::Generate {
+            CliCommand::Wip {
+                pop,
                 dry_run,
-                interactive,
-                no_commit_number,
+                unsigned,
             } => {
-                assert!(!dry_run);
-                assert!(!interactive);
-                assert!(no_commit_number);
+                assert!(!pop);
+                assert!(dry_run);
+                assert!(unsigned);
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        let cli = Cli::try_parse_from(vec!["rona", "wip", "--pop"]).unwrap();
+        assert!(matches!(cli.command, CliCommand::Wip { pop: true, .. }));
+
+        assert!(Cli::try_parse_from(vec!["rona", "wip", "--pop", "-u"]).is_err());
     }
 
+    // === JSON OUTPUT TESTS ===
+
     #[test]
-    fn test_generate_interactive_no_commit_number() {
-        let args = vec!["rona", "-g", "-i", "-n"];
+    fn test_json_flag() {
+        let args = vec!["rona", "-c", "--dry-run", "--json"];
         let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.output.json);
 
         match cli.command {
-            CliCommand::Generate {
-                dry_run,
-                interactive,
-                no_commit_number,
-            } => {
-                assert!(!dry_run);
-                assert!(interactive);
-                assert!(no_commit_number);
-            }
+            CliCommand::Commit { dry_run, .. } => assert!(dry_run),
             _ => panic!("Wrong command parsed"),
         }
+
+        let cli = Cli::try_parse_from(vec!["rona", "--json", "-p", "--dry-run"]).unwrap();
+        assert!(cli.output.json);
+
+        let cli = Cli::try_parse_from(vec!["rona", "-p"]).unwrap();
+        assert!(!cli.output.json);
     }
 
-    // === LIST STATUS COMMAND TESTS ===
+    #[test]
+    fn test_no_pager_flag() {
+        let cli = Cli::try_parse_from(vec!["rona", "-a", "--dry-run", "--no-pager"]).unwrap();
+        assert!(cli.output.no_pager);
+
+        let cli = Cli::try_parse_from(vec!["rona", "-a", "--dry-run"]).unwrap();
+        assert!(!cli.output.no_pager);
+    }
 
     #[test]
-    fn test_list_status_command() {
-        let args = vec!["rona", "-l"];
-        let cli = Cli::try_parse_from(args).unwrap();
+    fn test_trace_git_flag() {
+        let cli = Cli::try_parse_from(vec!["rona", "info", "--trace-git"]).unwrap();
+        assert!(cli.diagnostics.trace_git);
 
-        match cli.command {
-            CliCommand::ListStatus => (),
-            _ => panic!("Wrong command parsed"),
-        }
+        let cli = Cli::try_parse_from(vec!["rona", "--trace-git", "-a"]).unwrap();
+        assert!(cli.diagnostics.trace_git);
     }
 
-    // === INITIALIZE COMMAND TESTS ===
+    #[test]
+    fn test_no_wizard_flag() {
+        let cli = Cli::try_parse_from(vec!["rona", "-g", "--no-wizard"]).unwrap();
+        assert!(cli.setup.no_wizard);
+        assert!(cli.command.offers_wizard());
+
+        let cli = Cli::try_parse_from(vec!["rona", "-c", "--dry-run"]).unwrap();
+        assert!(!cli.setup.no_wizard);
+        assert!(!cli.command.offers_wizard());
+        assert!(
+            !Cli::try_parse_from(vec!["rona", "info"])
+                .unwrap()
+                .command
+                .offers_wizard()
+        );
+    }
 
     #[test]
-    fn test_init_default_editor() {
-        let args = vec!["rona", "-i"];
-        let cli = Cli::try_parse_from(args).unwrap();
+    fn test_offline_flag() {
+        let cli = Cli::try_parse_from(vec!["rona", "push", "--offline"]).unwrap();
+        assert!(cli.offline);
 
-        match cli.command {
-            CliCommand::Initialize { editor, dry_run } => {
-                assert_eq!(editor, "nano");
-                assert!(!dry_run);
-            }
-            _ => panic!("Wrong command parsed"),
-        }
+        let cli = Cli::try_parse_from(vec!["rona", "--offline", "fetch"]).unwrap();
+        assert!(cli.offline);
+        assert!(matches!(cli.command, CliCommand::Fetch { .. }));
     }
 
+    // === WATCH COMMAND TESTS ===
+
     #[test]
-    fn test_init_custom_editor() {
-        let args = vec!["rona", "-i", "zed"];
+    fn test_watch_wip_branch() {
+        let args = vec!["rona", "watch", "*.log", "--wip-branch", "--interval", "60"];
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command {
-            CliCommand::Initialize { editor, dry_run } => {
-                assert_eq!(editor, "zed");
-                assert!(!dry_run);
+            CliCommand::Watch(WatchArgs {
+                exclude,
+                wip_branch,
+                interval,
+            }) => {
+                assert_eq!(exclude, vec!["*.log"]);
+                assert_eq!(wip_branch.as_deref(), Some(""));
+                assert_eq!(interval, 60);
             }
             _ => panic!("Wrong command parsed"),
         }
+
+        assert!(Cli::try_parse_from(vec!["rona", "watch", "--interval", "60"]).is_err());
+        assert!(
+            Cli::try_parse_from(vec!["rona", "watch", "--wip-branch", "--interval", "0"]).is_err()
+        );
     }
 
     // === SET EDITOR COMMAND TESTS ===
@@ -1064,6 +7084,62 @@ mod cli_tests {
         assert!(cli.verbose);
     }
 
+    #[test]
+    fn test_quiet_flag() {
+        let cli = Cli::try_parse_from(vec!["rona", "-q", "-c"]).unwrap();
+        assert!(cli.quiet);
+        assert!(!cli.verbose);
+
+        let cli = Cli::try_parse_from(vec!["rona", "--quiet", "-v", "-p"]).unwrap();
+        assert!(cli.quiet);
+        assert!(cli.verbose);
+    }
+
+    // === PROTECTED BRANCH TESTS ===
+
+    #[test]
+    fn test_protected_branch_check() {
+        let check = |args: &[&str]| {
+            Cli::try_parse_from(args)
+                .unwrap()
+                .command
+                .protected_branch_check()
+        };
+
+        assert_eq!(check(&["rona", "commit"]), Some(false));
+        assert_eq!(check(&["rona", "commit", "--strict", "--push"]), Some(true));
+        assert_eq!(check(&["rona", "push", "--strict"]), Some(true));
+        assert_eq!(check(&["rona", "flow", "-t", "feat"]), Some(false));
+        assert_eq!(check(&["rona", "wip"]), Some(false));
+        assert_eq!(check(&["rona", "generate"]), None);
+    }
+
+    // === SHALLOW CLONE TESTS ===
+
+    #[test]
+    fn test_check_shallow_clone() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::with_root(dir.path());
+        config.project_config.commit_number = Some(CommitNumbering::Repo);
+        let mock = Arc::new(MockRunner::new());
+        mock.respond(&["rev-parse", "--is-shallow-repository"], "true\n");
+
+        config.project_config.shallow_clone = Some(ShallowClone::Omit);
+        with_runner(mock.clone(), || check_shallow_clone(&mut config)).unwrap();
+        assert_eq!(
+            config.project_config.commit_number,
+            Some(CommitNumbering::None)
+        );
+
+        config.project_config.commit_number = Some(CommitNumbering::Repo);
+        config.project_config.shallow_clone = Some(ShallowClone::Unshallow);
+        config.set_dry_run(true);
+        with_runner(mock.clone(), || check_shallow_clone(&mut config)).unwrap();
+        // Only the two shallow checks ran, no fetch
+        assert_eq!(mock.calls().len(), 2);
+        assert!(mock.calls().iter().all(|call| call[0] != "fetch"));
+    }
+
     // === EDGE CASES AND ERROR TESTS ===
 
     #[test]
@@ -1077,6 +7153,7 @@ mod cli_tests {
                 push,
                 dry_run,
                 unsigned,
+                ..
             } => {
                 assert!(!push); // --push should be treated as git arg
                 assert_eq!(args, vec!["--amend", "--push"]);
@@ -1098,6 +7175,7 @@ mod cli_tests {
                 push,
                 dry_run,
                 unsigned,
+                ..
             } => {
                 assert!(!push);
                 assert_eq!(args, vec!["--push-to-upstream"]);
@@ -1132,6 +7210,7 @@ mod cli_tests {
                 push,
                 dry_run,
                 unsigned,
+                ..
             } => {
                 assert!(push);
                 assert_eq!(args, vec!["--amend", "--no-edit"]);
@@ -1153,6 +7232,7 @@ mod cli_tests {
                 push,
                 dry_run,
                 unsigned,
+                ..
             } => {
                 assert!(!push);
                 assert!(args.is_empty());
@@ -1174,6 +7254,7 @@ mod cli_tests {
                 push,
                 dry_run,
                 unsigned,
+                ..
             } => {
                 assert!(!push);
                 assert!(args.is_empty());
@@ -1195,6 +7276,7 @@ mod cli_tests {
                 push,
                 dry_run,
                 unsigned,
+                ..
             } => {
                 assert!(push);
                 assert_eq!(args, vec!["--amend"]);
@@ -1233,6 +7315,7 @@ mod cli_tests {
             time: "14:30:00".to_string(),
             author: "Test User".to_string(),
             email: "test@example.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(default_template, &variables).unwrap();
@@ -1269,6 +7352,7 @@ mod cli_tests {
             time: "14:30:00".to_string(),
             author: "Test User".to_string(),
             email: "test@example.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(default_template, &variables).unwrap();
@@ -1299,6 +7383,7 @@ mod cli_tests {
             time: "14:30:00".to_string(),
             author: "Test User".to_string(),
             email: "test@example.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(wrong_template, &variables).unwrap();
@@ -22,17 +22,47 @@
 //! - Home directory not found
 
 use config as config_crate;
+use glob::Pattern;
 use inquire::Select;
+use log::{info, warn};
 use serde::{Deserialize, Serialize};
-use std::{env, io::Write, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    env,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+};
 
 use crate::{
-    errors::{ConfigError, GitError, Result},
+    errors::{ConfigError, GitError, Result, RonaError},
+    extends::config_chain,
+    git::{
+        CommitNumbering, EmojiPolicy, FileGrouping, FileSize, ShallowClone, TicketPosition,
+        TrailerValues, TypeRule, commit_message_path,
+    },
+    i18n::{tr, tr_args},
+    logging::Verbosity,
+    notification::NotificationConfig,
+    plan::OutputFormat,
+    theme::ThemeConfig,
     utils::{find_project_root, print_error},
 };
 
+/// Prefix of the environment variables overriding configuration keys (e.g. `RONA_EDITOR`).
+pub const ENV_PREFIX: &str = "RONA";
+
+/// Keys holding lists, given as comma-separated values in the environment.
+const ENV_LIST_KEYS: [&str; 6] = [
+    "commit_types",
+    "editor_args",
+    "protected_branches",
+    "push_remotes",
+    "release_files",
+    "wip_exclude",
+];
+
 // Define your default commit types
-const DEFAULT_COMMIT_TYPES: &[&str] = &["feat", "fix", "docs", "test", "chore"];
+pub(crate) const DEFAULT_COMMIT_TYPES: &[&str] = &["feat", "fix", "docs", "test", "chore"];
 
 /// Project-specific configuration that can be defined in rona.toml
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -40,18 +70,181 @@ pub struct ProjectConfig {
     /// Editor command to use for commit messages
     pub editor: Option<String>,
 
+    /// Arguments passed to the editor before the file (e.g. `["--new-window"]`)
+    pub editor_args: Option<Vec<String>>,
+
     /// Custom commit types for this project
     pub commit_types: Option<Vec<String>>,
 
     /// Template for interactive commit message generation
-    /// Available variables: {`commit_number`}, {`commit_type`}, {`branch_name`}, {`message`}, {`date`}, {`time`}, {`author`}, {`email`}
+    /// Available variables: {`commit_number`}, {`commit_type`}, {`branch_name`}, {`message`}, {`date`}, {`time`}, {`author`}, {`email`}, {`breaking`}
     pub template: Option<String>,
+
+    /// Files whose version is bumped by `rona release` (defaults to `Cargo.toml` and `package.json`)
+    pub release_files: Option<Vec<String>>,
+
+    /// Validate the commit message (subject length, empty bullets, typos) before committing
+    pub validate_commit_message: Option<bool>,
+
+    /// Maximum subject length allowed by commit message validation (defaults to 72)
+    pub max_subject_length: Option<usize>,
+
+    /// Block commits whose staged changes contain possible secrets (AWS keys, private keys, `.env` files)
+    pub scan_secrets: Option<bool>,
+
+    /// Warn about binary and large files when staging and generating (defaults to true)
+    pub check_large_files: Option<bool>,
+
+    /// Size from which files are flagged as large (e.g. `"10MB"`, defaults to 5 MB)
+    pub large_file_threshold: Option<FileSize>,
+
+    /// API token used to look up issues when the `gh`/`glab` CLI is not available
+    pub issue_token: Option<String>,
+
+    /// How the `[N]` commit number is computed: `"repo"` (default), `"branch"` or `"none"`
+    pub commit_number: Option<CommitNumbering>,
+
+    /// Commit numbers in a shallow clone, counted from its truncated history: `"warn"`
+    /// (default), `"omit"` or `"unshallow"` (fetch the full history first)
+    pub shallow_clone: Option<ShallowClone>,
+
+    /// Emoji in commit subjects: `"allow"` (default), `"gitmoji"` (emoji of the commit type
+    /// in generated headers) or `"ascii"` (non-ASCII characters stripped from the subject)
+    pub emoji: Option<EmojiPolicy>,
+
+    /// How `rona generate` groups the file entries: `"none"` (default) or `"directory"`
+    /// (under a `### <directory>` heading per directory)
+    pub group_by: Option<FileGrouping>,
+
+    /// Key used to sign commits (`git commit -S<keyid>`), validated against the GPG secret keyring
+    pub signing_key: Option<String>,
+
+    /// Whether commits are signed when GPG signing is available (defaults to true); `false`
+    /// never signs them, as `--unsigned` does
+    pub sign_commits: Option<bool>,
+
+    /// OpenAI-compatible API base URL used by `generate --ai` (defaults to `https://api.openai.com/v1`)
+    pub ai_endpoint: Option<String>,
+
+    /// Model used by `generate --ai` (defaults to `gpt-4o-mini`)
+    pub ai_model: Option<String>,
+
+    /// API key used by `generate --ai`, `RONA_AI_API_KEY` or `OPENAI_API_KEY` being used otherwise
+    pub ai_api_key: Option<String>,
+
+    /// Remotes `rona push` pushes to one after the other (e.g. `["origin", "mirror"]`)
+    pub push_remotes: Option<Vec<String>>,
+
+    /// Glob patterns of files never staged by `rona wip` (nor recorded by `rona watch`)
+    pub wip_exclude: Option<Vec<String>>,
+
+    /// Trailers always closing the commit messages (`Key = "value"` or `Key = ["value", ...]`)
+    pub trailers: Option<BTreeMap<String, TrailerValues>>,
+
+    /// Symbols and colors of the interactive prompts, and whether emoji are used
+    pub theme: Option<ThemeConfig>,
+
+    /// Desktop and webhook notifications sent after a successful commit or push
+    pub notifications: Option<NotificationConfig>,
+
+    /// Language of the prompts and dry-run summaries (`en` or `fr`), the locale
+    /// (`LC_ALL`, `LC_MESSAGES`, `LANG`) being used otherwise
+    pub language: Option<String>,
+
+    /// Path of the commit message file, relative to the project root
+    /// (defaults to `.git/rona/commit_message.md`)
+    pub commit_message_path: Option<String>,
+
+    /// Rules suggesting the commit type of the staged files, tried before the built-in ones
+    pub type_rules: Option<Vec<TypeRule>>,
+
+    /// Template of the branches created by `rona branch from-issue`
+    /// (defaults to `{type}/{issue}-{slug}`)
+    pub branch_template: Option<String>,
+
+    /// Whether generated messages start their body with the description of the current
+    /// branch (`rona branch describe`), defaults to false
+    pub branch_description: Option<bool>,
+
+    /// Branches (glob patterns, e.g. `release/*`) that `rona commit` and `rona push` ask
+    /// to confirm committing or pushing on
+    pub protected_branches: Option<Vec<String>>,
+
+    /// Regular expression finding the ticket (e.g. `PROJ-1234`) in the branch name, added
+    /// to generated messages: its first capture group, or the whole match
+    pub ticket_pattern: Option<String>,
+
+    /// Where the ticket goes in generated messages: `header` (default) or `footer`
+    pub ticket_position: Option<TicketPosition>,
+
+    /// Fail fast on the features needing network access (push, fetch, issue lookup, AI drafting)
+    pub offline: Option<bool>,
+
+    /// Seconds a push, pull or fetch may take before it is stopped (defaults to 120, 0 for
+    /// no limit)
+    pub network_timeout: Option<u64>,
+
+    /// Base configuration this file extends: a URL (fetched and cached) or a path
+    /// relative to the file
+    pub extends: Option<String>,
+
+    /// Named profiles (`[profile.work]`), selected with `--profile` or by the URLs of the
+    /// remotes
+    pub profile: Option<BTreeMap<String, ProfileConfig>>,
+}
+
+/// A named set of settings (`[profile.<name>]`), for the identities used on one machine
+/// (e.g. work and open source). Its settings override the configured ones.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct ProfileConfig {
+    /// Editor command of the commit messages
+    pub editor: Option<String>,
+
+    /// GPG key ID signing the commits
+    pub signing_key: Option<String>,
+
+    /// Email of the commits, overriding `user.email` of the git configuration
+    pub author_email: Option<String>,
+
+    /// Remotes `rona push` pushes to
+    pub push_remotes: Option<Vec<String>>,
+
+    /// Glob patterns of remote URLs (e.g. `*github.com[:/]acme/*`) selecting the profile
+    /// when no `--profile` is given
+    pub remote_patterns: Option<Vec<String>>,
+}
+
+impl ProfileConfig {
+    /// Whether one of the `remote_patterns` matches a remote URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::config::ProfileConfig;
+    ///
+    /// let profile = ProfileConfig {
+    ///     remote_patterns: Some(vec!["*github.com[:/]acme/*".to_string()]),
+    ///     ..ProfileConfig::default()
+    /// };
+    ///
+    /// assert!(profile.matches_remote("git@github.com:acme/api.git"));
+    /// assert!(profile.matches_remote("https://github.com/acme/api"));
+    /// assert!(!profile.matches_remote("git@github.com:jane/dotfiles.git"));
+    /// ```
+    #[must_use]
+    pub fn matches_remote(&self, url: &str) -> bool {
+        self.remote_patterns
+            .iter()
+            .flatten()
+            .any(|pattern| Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(url)))
+    }
 }
 
 impl Default for ProjectConfig {
     fn default() -> Self {
         Self {
             editor: Some("nano".to_string()),
+            editor_args: None,
             commit_types: Some(
                 DEFAULT_COMMIT_TYPES
                     .iter()
@@ -61,6 +254,39 @@ impl Default for ProjectConfig {
             template: Some(
                 "[{commit_number}] ({commit_type} on {branch_name}) {message}".to_string(),
             ),
+            release_files: None,
+            validate_commit_message: None,
+            max_subject_length: None,
+            scan_secrets: None,
+            check_large_files: None,
+            large_file_threshold: None,
+            issue_token: None,
+            commit_number: None,
+            shallow_clone: None,
+            emoji: None,
+            group_by: None,
+            signing_key: None,
+            sign_commits: None,
+            ai_endpoint: None,
+            ai_model: None,
+            ai_api_key: None,
+            push_remotes: None,
+            wip_exclude: None,
+            trailers: None,
+            theme: None,
+            notifications: None,
+            language: None,
+            commit_message_path: None,
+            type_rules: None,
+            branch_template: None,
+            branch_description: None,
+            protected_branches: None,
+            ticket_pattern: None,
+            ticket_position: None,
+            offline: None,
+            network_timeout: None,
+            extends: None,
+            profile: None,
         }
     }
 }
@@ -68,9 +294,14 @@ impl Default for ProjectConfig {
 impl ProjectConfig {
     /// Loads the project configuration, merging global and project config files.
     ///
+    /// Later sources override earlier ones: the global config files, the project
+    /// `.rona.toml`, then the `RONA_*` environment variables (see [`ENV_PREFIX`]). A file
+    /// with an `extends` key comes right after its base (see [`crate::extends`]).
+    ///
     /// # Errors
     /// Returns `ConfigError::ConfigNotFound` if the config files cannot be found or read.
     /// Returns `ConfigError::InvalidConfig` if deserialization fails.
+    /// Returns `ConfigError::ExtendsFailed` if a base configuration cannot be loaded.
     ///
     /// # Panics
     /// Panics if the current working directory cannot be determined (i.e., if `std::env::current_dir()` fails).
@@ -82,35 +313,238 @@ impl ProjectConfig {
 
         let mut builder = config_crate::Config::builder();
 
-        // Support both old and new global config paths
-        let home = dirs::home_dir().ok_or(ConfigError::ConfigNotFound)?;
-        let old_global = home.join(".config/rona/config.toml");
-        let new_global = home.join(".config/rona.toml");
-
-        if old_global.exists() {
-            builder = builder.add_source(config_crate::File::from(old_global).required(false));
-        }
-        if new_global.exists() {
-            builder = builder.add_source(config_crate::File::from(new_global).required(false));
+        // Each file comes after the bases it extends
+        for path in config_file_paths()? {
+            if path.exists() {
+                for file in config_chain(&path)? {
+                    builder = builder.add_source(config_crate::File::from(file).required(false));
+                }
+            }
         }
 
-        // Add project config if it exists
-        let project_config_path = env::current_dir()?.join(".rona.toml");
-        if project_config_path.exists() {
-            builder =
-                builder.add_source(config_crate::File::from(project_config_path).required(false));
-        }
+        // Environment variables override every file
+        builder = builder.add_source(environment_source());
 
         // Build the config
         let settings = builder.build().map_err(|_| ConfigError::ConfigNotFound)?;
         match settings.try_deserialize() {
             Ok(config) => Ok(config),
             Err(e) => {
-                eprintln!("Failed to deserialize config: {e}");
+                warn!("Failed to deserialize config: {e}");
                 Err(ConfigError::InvalidConfig.into())
             }
         }
     }
+
+    /// The profile to use: the one named by `--profile`, else the first one (by name)
+    /// whose `remote_patterns` match one of `remote_urls`.
+    ///
+    /// # Errors
+    /// * `RonaError::InvalidInput` - If no profile has the given name
+    pub fn select_profile(
+        &self,
+        name: Option<&str>,
+        remote_urls: &[String],
+    ) -> Result<Option<(&str, &ProfileConfig)>> {
+        let mut profiles = self.profile.iter().flatten();
+
+        let Some(name) = name else {
+            return Ok(profiles
+                .find(|(_, profile)| remote_urls.iter().any(|url| profile.matches_remote(url)))
+                .map(|(name, profile)| (name.as_str(), profile)));
+        };
+
+        if let Some((name, profile)) = profiles.find(|(profile_name, _)| *profile_name == name) {
+            return Ok(Some((name.as_str(), profile)));
+        }
+
+        let names: Vec<&str> = self
+            .profile
+            .iter()
+            .flatten()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        Err(RonaError::InvalidInput(if names.is_empty() {
+            format!("Unknown profile '{name}' - no [profile.<name>] section is configured")
+        } else {
+            format!(
+                "Unknown profile '{name}' - the configured profiles are: {}",
+                names.join(", ")
+            )
+        }))
+    }
+
+    /// Overrides the settings with the ones of a profile. Its `author_email` is not part of
+    /// the configuration, being given to git instead.
+    pub fn apply_profile(&mut self, profile: &ProfileConfig) {
+        if let Some(editor) = &profile.editor {
+            self.editor = Some(editor.clone());
+        }
+        if let Some(signing_key) = &profile.signing_key {
+            self.signing_key = Some(signing_key.clone());
+        }
+        if let Some(push_remotes) = &profile.push_remotes {
+            self.push_remotes = Some(push_remotes.clone());
+        }
+    }
+
+    /// Changes keys of a configuration file and writes it back.
+    ///
+    /// Only the keys of that file are kept, read with [`ProjectConfig::read_file`], so the
+    /// values coming from the environment (e.g. `RONA_AI_API_KEY`), the profiles or the
+    /// bases it extends are never written to it.
+    ///
+    /// # Arguments
+    /// * `location` - Where to write the file, prompted for when `None`
+    /// * `force` - Whether to overwrite an existing configuration file
+    /// * `change` - The change made to the keys of the file
+    ///
+    /// # Returns
+    /// * `PathBuf` - The path of the written file
+    ///
+    /// # Errors
+    /// * If creating the configuration directory fails
+    /// * If the configuration file cannot be read or written
+    /// * If the configuration file already exists and `force` is not set
+    /// * If no location is given and no prompt can be shown
+    pub fn save(
+        location: Option<ConfigLocation>,
+        force: bool,
+        change: impl FnOnce(&mut Self),
+    ) -> Result<PathBuf> {
+        let location = match location {
+            Some(location) => location,
+            None if !io::stdin().is_terminal() => {
+                return Err(RonaError::InvalidInput(
+                    "No configuration location given - pass --project or --global when no prompt can be shown"
+                        .to_string(),
+                ));
+            }
+            None => {
+                let options = vec![
+                    tr_args("prompt.project_config", &[("path", &".rona.toml")]),
+                    tr("prompt.global_config"),
+                ];
+                let selection = Select::new(&tr("prompt.config_location"), options)
+                    .with_starting_cursor(0)
+                    .raw_prompt()?;
+
+                if selection.index == 0 {
+                    ConfigLocation::Project
+                } else {
+                    ConfigLocation::Global
+                }
+            }
+        };
+        let config_path = location.path()?;
+
+        let config_folder = config_path.parent().ok_or(ConfigError::ConfigNotFound)?;
+        if !config_folder.exists() {
+            std::fs::create_dir_all(config_folder)?;
+        }
+
+        if config_path.exists() && !force {
+            print_error(
+                "Configuration file already exists.",
+                &format!(
+                    "A configuration file already exists at {}",
+                    config_path.display()
+                ),
+                "Use `rona --set-editor <editor>` (or `rona -s <editor>`) to change it, or `rona init --force` to overwrite it.",
+            );
+            return Err(ConfigError::ConfigAlreadyExists.into());
+        }
+
+        let mut config = Self::read_file(&config_path)?;
+        change(&mut config);
+        config.write_file(&config_path)?;
+
+        Ok(config_path)
+    }
+
+    /// Reads a single configuration file, without the environment variables, the
+    /// profiles or the bases it extends: the default configuration when it does not exist.
+    ///
+    /// # Errors
+    /// * If the file cannot be read or is not a valid configuration
+    pub fn read_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        toml::from_str(&std::fs::read_to_string(path)?).map_err(|e| {
+            warn!("Failed to deserialize {}: {e}", path.display());
+            ConfigError::InvalidConfig.into()
+        })
+    }
+
+    /// Writes the configuration to a file.
+    ///
+    /// # Errors
+    /// * If the configuration cannot be serialized or the file cannot be written
+    pub fn write_file(&self, path: &Path) -> Result<()> {
+        let toml_str = toml::to_string_pretty(self).map_err(|_| ConfigError::InvalidConfig)?;
+        std::fs::write(path, toml_str)?;
+        Ok(())
+    }
+}
+
+/// The configuration files read by [`ProjectConfig::load`], by priority: the old and the
+/// new global files (`~/.config/rona/config.toml`, `~/.config/rona.toml`), then the
+/// project `.rona.toml`. They may not exist.
+///
+/// # Errors
+/// * If the home or the current directory cannot be determined
+pub fn config_file_paths() -> Result<[PathBuf; 3]> {
+    let home = dirs::home_dir().ok_or(ConfigError::ConfigNotFound)?;
+
+    Ok([
+        home.join(".config/rona/config.toml"),
+        home.join(".config/rona.toml"),
+        env::current_dir()?.join(".rona.toml"),
+    ])
+}
+
+/// The source of the `RONA_*` environment variables overriding configuration keys.
+///
+/// The key is the variable name without the prefix, lowercased (`RONA_COMMIT_TYPES` sets
+/// `commit_types`), `__` separating the keys of a section (`RONA_THEME__EMOJI` sets
+/// `emoji` in `[theme]`). Lists are comma-separated, booleans and numbers are parsed.
+fn environment_source() -> config_crate::Environment {
+    ENV_LIST_KEYS.iter().fold(
+        config_crate::Environment::with_prefix(ENV_PREFIX)
+            .prefix_separator("_")
+            .separator("__")
+            .list_separator(",")
+            .try_parsing(true)
+            .ignore_empty(true),
+        |environment, key| environment.with_list_parse_key(key),
+    )
+}
+
+/// Where `rona init` creates the configuration file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigLocation {
+    /// `.rona.toml` in the current directory
+    Project,
+    /// `~/.config/rona.toml`, shared by every project
+    Global,
+}
+
+impl ConfigLocation {
+    /// The path of the configuration file at this location.
+    ///
+    /// # Errors
+    /// * If the current or home directory cannot be determined
+    pub fn path(self) -> Result<PathBuf> {
+        match self {
+            Self::Project => Ok(env::current_dir()?.join(".rona.toml")),
+            Self::Global => {
+                let home = dirs::home_dir().ok_or(ConfigError::ConfigNotFound)?;
+                Ok(home.join(".config/rona.toml"))
+            }
+        }
+    }
 }
 
 /// Main configuration struct that handles all config operations.
@@ -119,12 +553,17 @@ impl ProjectConfig {
 ///
 /// # Fields
 /// * `root` - The root path for configuration files
-/// * `verbose` - Whether to show detailed output
+/// * `verbosity` - How much output is logged (`--quiet`, default or `--verbose`)
 /// * `dry_run` - Whether to simulate operations without making changes
+/// * `output_format` - How dry-run plans are rendered
+/// * `pager` - Whether long output may be paged (disabled with `--no-pager`)
 pub struct Config {
     root: PathBuf,
-    pub(crate) verbose: bool,
+    pub(crate) verbosity: Verbosity,
     pub(crate) dry_run: bool,
+    pub(crate) output_format: OutputFormat,
+    pub(crate) pager: bool,
+    pub(crate) offline: bool,
     pub project_config: ProjectConfig,
 }
 
@@ -133,17 +572,25 @@ impl Config {
     ///
     /// # Errors
     /// * If the home directory cannot be determined
-    /// * If the project configuration cannot be loaded
+    /// * If a base configuration (`extends`) cannot be loaded
     ///
     /// # Returns
     /// * `Result<Config>` - A new Config instance with default settings
     pub fn new() -> Result<Self> {
         let root = Config::get_config_root()?;
-        let project_config = ProjectConfig::load().unwrap_or_default();
+        // A base configuration that cannot be loaded is reported rather than silently
+        // replaced by the defaults, the team settings being missing otherwise
+        let project_config = match ProjectConfig::load() {
+            Err(error @ RonaError::Config(ConfigError::ExtendsFailed { .. })) => return Err(error),
+            loaded => loaded.unwrap_or_default(),
+        };
         let config = Config {
             root,
-            verbose: false,
+            verbosity: Verbosity::Normal,
             dry_run: false,
+            output_format: OutputFormat::Text,
+            pager: true,
+            offline: project_config.offline.unwrap_or(false),
             project_config,
         };
         Ok(config)
@@ -163,18 +610,42 @@ impl Config {
 
         Config {
             root,
-            verbose: false,
+            verbosity: Verbosity::Normal,
             dry_run: false,
+            output_format: OutputFormat::Text,
+            pager: true,
+            offline: project_config.offline.unwrap_or(false),
             project_config,
         }
     }
 
-    /// Sets the verbose flag which controls detailed output logging.
+    /// Sets the verbosity which controls how much output is logged.
+    ///
+    /// # Arguments
+    /// * `verbosity` - The verbosity derived from `--quiet` and `--verbose`
+    pub fn set_verbosity(&mut self, verbosity: Verbosity) {
+        self.verbosity = verbosity;
+    }
+
+    /// Forbids the features needing network access (see [`Config::ensure_online`]).
     ///
     /// # Arguments
-    /// * `verbose` - Whether to enable verbose output
-    pub fn set_verbose(&mut self, verbose: bool) {
-        self.verbose = verbose;
+    /// * `offline` - Whether to work offline
+    pub fn set_offline(&mut self, offline: bool) {
+        self.offline = offline;
+    }
+
+    /// Checks that a feature needing network access may run.
+    ///
+    /// # Errors
+    /// * `RonaError::OfflineMode` - In offline mode (`--offline` or `offline = true`)
+    pub fn ensure_online(&self, feature: &str) -> Result<()> {
+        if self.offline {
+            return Err(RonaError::OfflineMode {
+                feature: feature.to_string(),
+            });
+        }
+        Ok(())
     }
 
     /// Sets the `dry_run` flag which controls whether operations are simulated.
@@ -186,6 +657,39 @@ impl Config {
         self.dry_run = dry_run;
     }
 
+    /// Sets the format used to render dry-run plans.
+    ///
+    /// # Arguments
+    /// * `output_format` - Text for humans, JSON for scripts
+    pub fn set_output_format(&mut self, output_format: OutputFormat) {
+        self.output_format = output_format;
+    }
+
+    /// Sets whether long output may be piped through the pager.
+    ///
+    /// # Arguments
+    /// * `pager` - Whether paging is allowed
+    pub fn set_pager(&mut self, pager: bool) {
+        self.pager = pager;
+    }
+
+    /// Resolves the path of the commit message file from the `commit_message_path` setting.
+    ///
+    /// # Errors
+    /// * If not in a git repository
+    pub fn commit_message_path(&self) -> Result<PathBuf> {
+        commit_message_path(self.project_config.commit_message_path.as_deref())
+    }
+
+    /// The arguments passed to the editor before the file (`editor_args`).
+    #[must_use]
+    pub fn editor_args(&self) -> &[String] {
+        self.project_config
+            .editor_args
+            .as_deref()
+            .unwrap_or_default()
+    }
+
     /// Retrieves the editor from the configuration file.
     ///
     /// # Errors
@@ -246,33 +750,30 @@ impl Config {
             return Ok(());
         }
 
-        let options = vec!["Project (./.rona.toml)", "Global (~/.config/rona.toml)"];
+        let options = vec![
+            tr_args("prompt.project_config", &[("path", &"./.rona.toml")]),
+            tr("prompt.global_config"),
+        ];
 
-        let selection = Select::new("Where do you want to set the editor?", options)
+        let selection = Select::new(&tr("prompt.editor_location"), options)
             .with_starting_cursor(0)
-            .prompt()
-            .map_err(|_| ConfigError::InvalidConfig)?;
+            .raw_prompt()?;
 
-        let config_path = match selection {
-            "Project (./.rona.toml)" => find_project_root()
+        let config_path = if selection.index == 0 {
+            find_project_root()
                 .map(|root| root.join(".rona.toml"))
-                .map_err(|_| ConfigError::ConfigNotFound)?,
-            "Global (~/.config/rona.toml)" => {
-                let home = dirs::home_dir().ok_or(ConfigError::ConfigNotFound)?;
-                home.join(".config/rona.toml")
-            }
-            _ => unreachable!(),
+                .map_err(|_| ConfigError::ConfigNotFound)?
+        } else {
+            let home = dirs::home_dir().ok_or(ConfigError::ConfigNotFound)?;
+            home.join(".config/rona.toml")
         };
 
-        let mut config = self.project_config.clone();
+        // Only the keys of the file are written back, not the merged configuration
+        let mut config = ProjectConfig::read_file(&config_path)?;
         config.editor = Some(editor.to_string());
+        config.write_file(&config_path)?;
 
-        let toml_str = toml::to_string_pretty(&config).map_err(|_| ConfigError::InvalidConfig)?;
-        let mut file = std::fs::File::create(&config_path)?;
-
-        file.write_all(toml_str.as_bytes())?;
-
-        println!("Editor set in: {}", config_path.display());
+        info!("Editor set in: {}", config_path.display());
 
         Ok(())
     }
@@ -281,12 +782,20 @@ impl Config {
     ///
     /// # Arguments
     /// * `editor` - The editor command to configure
+    /// * `location` - Where to create the file, prompted for when `None`
+    /// * `force` - Whether to overwrite an existing configuration file
     ///
     /// # Errors
     /// * If creating the configuration directory fails
     /// * If writing the configuration file fails
-    /// * If the configuration file already exists
-    pub fn create_config_file(&self, editor: &str) -> Result<()> {
+    /// * If the configuration file already exists and `force` is not set
+    /// * If no location is given and no prompt can be shown
+    pub fn create_config_file(
+        &self,
+        editor: &str,
+        location: Option<ConfigLocation>,
+        force: bool,
+    ) -> Result<()> {
         // During tests, use the old behavior for compatibility
         if cfg!(test) {
             let config_folder = self.get_config_folder_path()?;
@@ -298,7 +807,7 @@ impl Config {
             let config_file = self.get_config_file_path()?;
             let config_content = format!("editor = \"{editor}\"");
 
-            if config_file.exists() {
+            if config_file.exists() && !force {
                 return Err(ConfigError::ConfigAlreadyExists.into());
             }
 
@@ -307,45 +816,9 @@ impl Config {
             return Ok(());
         }
 
-        let options = vec!["Project (.rona.toml)", "Global (~/.config/rona.toml)"];
-        let selection = Select::new("Where do you want to initialize the config?", options)
-            .with_starting_cursor(0)
-            .prompt()
-            .map_err(|_| ConfigError::InvalidConfig)?;
-
-        let config_path = match selection {
-            "Project (.rona.toml)" => env::current_dir()?.join(".rona.toml"),
-            "Global (~/.config/rona.toml)" => {
-                let home = dirs::home_dir().ok_or(ConfigError::ConfigNotFound)?;
-                home.join(".config/rona.toml")
-            }
-            _ => unreachable!(),
-        };
-
-        let config_folder = config_path.parent().ok_or(ConfigError::ConfigNotFound)?;
-        if !config_folder.exists() {
-            std::fs::create_dir_all(config_folder)?;
-        }
-
-        if config_path.exists() {
-            if !cfg!(test) {
-                print_error(
-                    "Configuration file already exists.",
-                    &format!(
-                        "A configuration file already exists at {}",
-                        config_path.display()
-                    ),
-                    "Use `rona --set-editor <editor>` (or `rona -s <editor>`) to change it.",
-                );
-            }
-            return Err(ConfigError::ConfigAlreadyExists.into());
-        }
-
-        let mut config = self.project_config.clone();
-        config.editor = Some(editor.to_string());
-
-        let toml_str = toml::to_string_pretty(&config).map_err(|_| ConfigError::InvalidConfig)?;
-        std::fs::write(&config_path, toml_str)?;
+        ProjectConfig::save(location, force, |config| {
+            config.editor = Some(editor.to_string());
+        })?;
 
         Ok(())
     }
@@ -403,11 +876,89 @@ pub const CONFIG_FOLDER_NAME: &str = "rona-test-config";
 
 #[cfg(test)]
 mod tests {
-    use crate::errors::RonaError;
-
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_environment_overrides() {
+        let variables = [
+            ("RONA_EDITOR", "vim"),
+            ("RONA_COMMIT_TYPES", "feat,fix"),
+            ("RONA_MAX_SUBJECT_LENGTH", "50"),
+            ("RONA_SCAN_SECRETS", "true"),
+            ("RONA_THEME__EMOJI", "false"),
+            ("RONA_SIGNING_KEY", ""),
+            ("OTHER_EDITOR", "nano"),
+        ];
+        let source = variables
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect();
+
+        let config: ProjectConfig = config_crate::Config::builder()
+            .add_source(config_crate::File::from_str(
+                "editor = \"zed\"\nsigning_key = \"ABC\"",
+                config_crate::FileFormat::Toml,
+            ))
+            .add_source(environment_source().source(Some(source)))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        assert_eq!(config.editor.as_deref(), Some("vim"));
+        assert_eq!(
+            config.commit_types,
+            Some(vec!["feat".to_string(), "fix".to_string()])
+        );
+        assert_eq!(config.max_subject_length, Some(50));
+        assert_eq!(config.scan_secrets, Some(true));
+        assert_eq!(config.theme.and_then(|theme| theme.emoji), Some(false));
+        assert_eq!(config.signing_key.as_deref(), Some("ABC"));
+    }
+
+    #[test]
+    fn test_select_profile() {
+        let config: ProjectConfig = toml::from_str(
+            r#"
+            [profile.oss]
+            editor = "hx"
+            remote_patterns = ["*github.com[:/]jane/*"]
+
+            [profile.work]
+            signing_key = "ABC"
+            author_email = "jane@acme.com"
+            push_remotes = ["origin", "mirror"]
+            remote_patterns = ["*gitlab.acme.com*"]
+            "#,
+        )
+        .unwrap();
+        let urls = ["git@gitlab.acme.com:api/server.git".to_string()];
+
+        let (name, profile) = config.select_profile(None, &urls).unwrap().unwrap();
+        assert_eq!(name, "work");
+        assert_eq!(profile.author_email.as_deref(), Some("jane@acme.com"));
+        assert_eq!(
+            config
+                .select_profile(Some("oss"), &urls)
+                .unwrap()
+                .unwrap()
+                .0,
+            "oss"
+        );
+        assert!(config.select_profile(None, &[]).unwrap().is_none());
+        assert!(config.select_profile(Some("home"), &urls).is_err());
+
+        let mut applied = ProjectConfig::default();
+        applied.apply_profile(profile);
+        assert_eq!(applied.editor.as_deref(), Some("nano"));
+        assert_eq!(applied.signing_key.as_deref(), Some("ABC"));
+        assert_eq!(
+            applied.push_remotes,
+            Some(vec!["origin".to_string(), "mirror".to_string()])
+        );
+    }
+
     #[test]
     fn test_create_config_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -415,7 +966,7 @@ mod tests {
         let editor = "test_editor";
 
         // Create a new config file with the temp directory as root
-        assert!(config.create_config_file(editor).is_ok());
+        assert!(config.create_config_file(editor, None, false).is_ok());
 
         // Check the file exists and has the correct content
         let config_file = config.get_config_file_path().unwrap();
@@ -425,7 +976,34 @@ mod tests {
         assert_eq!(content, format!("editor = \"{editor}\""));
 
         // Test error when a file already exists
-        assert!(config.create_config_file(editor).is_err());
+        assert!(config.create_config_file(editor, None, false).is_err());
+
+        // Unless it is overwritten
+        config.create_config_file("vim", None, true).unwrap();
+        let content = std::fs::read_to_string(&config_file).unwrap();
+        assert_eq!(content, "editor = \"vim\"");
+    }
+
+    #[test]
+    fn test_read_file_keeps_only_its_keys() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(".rona.toml");
+        std::fs::write(&path, "editor = \"vim\"\n").unwrap();
+
+        let mut config = ProjectConfig::read_file(&path).unwrap();
+        assert_eq!(config.ai_api_key, None);
+        assert_eq!(config.template, None);
+
+        config.editor = Some("zed".to_string());
+        config.write_file(&path).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "editor = \"zed\"\n"
+        );
+
+        // A missing file starts from the default configuration
+        let missing = ProjectConfig::read_file(&temp_dir.path().join("missing.toml")).unwrap();
+        assert_eq!(missing.editor, ProjectConfig::default().editor);
     }
 
     #[test]
@@ -435,7 +1013,7 @@ mod tests {
         let editor = "nano";
 
         // Create a config file
-        config.create_config_file(editor).unwrap();
+        config.create_config_file(editor, None, false).unwrap();
 
         // Test getting the editor
         let result = config.get_editor();
@@ -450,7 +1028,9 @@ mod tests {
         let initial_editor = "vim";
 
         // Create a config file
-        config.create_config_file(initial_editor).unwrap();
+        config
+            .create_config_file(initial_editor, None, false)
+            .unwrap();
 
         // Test setting a new editor
         let new_editor = "emacs";
@@ -505,4 +1085,82 @@ mod tests {
             Err(RonaError::Config(ConfigError::InvalidConfig))
         ));
     }
+
+    #[test]
+    fn test_commit_number_option() {
+        let config: ProjectConfig = toml::from_str(r#"commit_number = "branch""#).unwrap();
+        assert_eq!(config.commit_number, Some(CommitNumbering::Branch));
+
+        let config: ProjectConfig = toml::from_str(r#"commit_number = "none""#).unwrap();
+        assert_eq!(config.commit_number, Some(CommitNumbering::None));
+
+        let config: ProjectConfig = toml::from_str(r#"shallow_clone = "omit""#).unwrap();
+        assert_eq!(config.shallow_clone, Some(ShallowClone::Omit));
+
+        assert!(toml::from_str::<ProjectConfig>(r#"commit_number = "tag""#).is_err());
+    }
+
+    #[test]
+    fn test_group_by_option() {
+        let config: ProjectConfig = toml::from_str(r#"group_by = "directory""#).unwrap();
+        assert_eq!(config.group_by, Some(FileGrouping::Directory));
+
+        assert!(toml::from_str::<ProjectConfig>(r#"group_by = "extension""#).is_err());
+    }
+
+    #[test]
+    fn test_large_file_threshold_option() {
+        let config: ProjectConfig = toml::from_str(r#"large_file_threshold = "10MB""#).unwrap();
+        assert_eq!(
+            config.large_file_threshold,
+            Some(FileSize(10 * 1024 * 1024))
+        );
+
+        let config: ProjectConfig = toml::from_str("large_file_threshold = 2048").unwrap();
+        assert_eq!(config.large_file_threshold, Some(FileSize(2048)));
+
+        assert!(toml::from_str::<ProjectConfig>(r#"large_file_threshold = "big""#).is_err());
+    }
+
+    #[test]
+    fn test_emoji_option() {
+        let config: ProjectConfig = toml::from_str(r#"emoji = "gitmoji""#).unwrap();
+        assert_eq!(config.emoji, Some(EmojiPolicy::Gitmoji));
+
+        assert!(toml::from_str::<ProjectConfig>(r#"emoji = "none""#).is_err());
+    }
+
+    #[test]
+    fn test_extended_config_is_overridden() {
+        let dir = TempDir::new().unwrap();
+        let project = dir.path().join(".rona.toml");
+        std::fs::write(
+            dir.path().join("base.toml"),
+            "commit_types = [\"feat\", \"fix\", \"perf\"]\neditor = \"vim\"\n",
+        )
+        .unwrap();
+        std::fs::write(&project, "extends = \"base.toml\"\neditor = \"zed\"\n").unwrap();
+
+        let config: ProjectConfig = config_chain(&project)
+            .unwrap()
+            .into_iter()
+            .fold(config_crate::Config::builder(), |builder, file| {
+                builder.add_source(config_crate::File::from(file))
+            })
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        assert_eq!(config.editor.as_deref(), Some("zed"));
+        assert_eq!(
+            config.commit_types,
+            Some(vec![
+                "feat".to_string(),
+                "fix".to_string(),
+                "perf".to_string()
+            ])
+        );
+        assert_eq!(config.extends.as_deref(), Some("base.toml"));
+    }
 }
@@ -0,0 +1,591 @@
+//! Environment Diagnostic
+//!
+//! `rona doctor` checks what rona relies on: the git version, the repository, the git
+//! identity, commit signing (GPG, SSH or X.509 keys), the editor, the configuration
+//! files, the commit hooks and the remotes. Each check reports what it found and, when
+//! something is wrong, how to fix it.
+//!
+//! Warnings point at what degrades rona (unsigned commits, messages not validated by a
+//! hook); errors at what makes commands fail.
+
+use std::{
+    fmt::Write,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use console::Style;
+use serde::Serialize;
+
+use crate::{
+    config::{Config, ProjectConfig, config_file_paths},
+    extends::config_chain,
+    git::{
+        Repository,
+        commit::{has_secret_key, is_gpg_signing_available, signing_format},
+        exec::GitCommand,
+        hooks::{HookStatus, commit_hooks, hooks_dir},
+        remote::{check_remote_reachable, get_remote_url, list_remotes},
+    },
+    utils::{find_program, split_command_line},
+};
+
+/// Oldest git version supported (`git switch` appeared in 2.23).
+pub const MIN_GIT_VERSION: (u32, u32) = (2, 23);
+
+/// How long a remote has to answer.
+const REMOTE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The outcome of a check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    /// Everything is fine
+    Ok,
+    /// rona works, in a degraded way
+    Warning,
+    /// rona commands will fail
+    Error,
+    /// The check could not run (e.g. remotes in offline mode)
+    Skipped,
+}
+
+impl CheckStatus {
+    /// The marker of the status in the checklist, with or without emoji.
+    #[must_use]
+    pub const fn marker(self, emoji: bool) -> &'static str {
+        match (self, emoji) {
+            (Self::Ok, true) => "✅",
+            (Self::Warning, true) => "⚠️ ",
+            (Self::Error, true) => "❌",
+            (Self::Skipped, true) => "⏭️ ",
+            (Self::Ok, false) => "[ok]  ",
+            (Self::Warning, false) => "[warn]",
+            (Self::Error, false) => "[fail]",
+            (Self::Skipped, false) => "[skip]",
+        }
+    }
+}
+
+/// The result of one check of `rona doctor`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Check {
+    /// What is checked (e.g. `git`, `editor`)
+    pub name: &'static str,
+    /// The outcome
+    pub status: CheckStatus,
+    /// What was found
+    pub detail: String,
+    /// How to fix it, for warnings and errors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Ok,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warning(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warning,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn error(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Error,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn skipped(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Skipped,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+}
+
+/// Runs every check, in the order of the checklist.
+///
+/// The remotes are not contacted in offline mode.
+#[must_use]
+pub fn run_checks(config: &Config) -> Vec<Check> {
+    let repository = Repository::discover().ok();
+
+    let mut checks = vec![check_git(), check_repository(repository.as_ref())];
+    if repository.is_some() {
+        checks.push(check_identity());
+    }
+    checks.push(check_signing(&config.project_config));
+    checks.push(check_editor(&config.project_config));
+    checks.extend(check_config_files());
+    if let Some(repository) = &repository {
+        checks.push(check_hooks(repository));
+        checks.extend(check_remotes(config.offline));
+    }
+
+    checks
+}
+
+/// Parses the version printed by `git --version`.
+///
+/// # Examples
+///
+/// ```
+/// use rona::doctor::parse_git_version;
+///
+/// assert_eq!(parse_git_version("git version 2.43.0\n"), Some((2, 43, 0)));
+/// assert_eq!(parse_git_version("git version 2.39.3 (Apple Git-146)"), Some((2, 39, 3)));
+/// assert_eq!(parse_git_version("git version 2.45.1.windows.1"), Some((2, 45, 1)));
+/// assert_eq!(parse_git_version("command not found"), None);
+/// ```
+#[must_use]
+pub fn parse_git_version(output: &str) -> Option<(u32, u32, u32)> {
+    let version = output.trim().strip_prefix("git version ")?;
+    let mut numbers = version
+        .split(|c: char| !c.is_ascii_digit())
+        .map(str::parse::<u32>);
+
+    let major = numbers.next()?.ok()?;
+    let minor = numbers.next()?.ok()?;
+    let patch = numbers.next().and_then(Result::ok).unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+/// Checks that git is installed and recent enough.
+fn check_git() -> Check {
+    let Ok(output) = GitCommand::new().arg("--version").output() else {
+        return Check::error(
+            "git",
+            "git is not installed",
+            "install git: https://git-scm.com",
+        );
+    };
+    let printed = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    match parse_git_version(&printed) {
+        Some((major, minor, _)) if (major, minor) >= MIN_GIT_VERSION => Check::ok("git", printed),
+        Some(_) => Check::error(
+            "git",
+            format!(
+                "{printed} is older than {}.{}",
+                MIN_GIT_VERSION.0, MIN_GIT_VERSION.1
+            ),
+            "upgrade git: https://git-scm.com",
+        ),
+        None => Check::warning(
+            "git",
+            format!("unknown git version: {printed}"),
+            "check that `git --version` runs git",
+        ),
+    }
+}
+
+/// Checks that the current directory is in a repository, telling linked worktrees and
+/// bare repositories apart.
+fn check_repository(repository: Option<&Repository>) -> Check {
+    let Some(repository) = repository else {
+        return Check::error(
+            "repository",
+            "not in a git repository",
+            "run rona inside a repository, or create one with `git init`",
+        );
+    };
+
+    match repository.work_tree() {
+        None => Check::warning(
+            "repository",
+            format!("{} is a bare repository", repository.git_dir().display()),
+            "run rona in a working tree of the repository (`git worktree add`)",
+        ),
+        Some(work_tree) if is_linked_worktree(repository.git_dir()) => Check::ok(
+            "repository",
+            format!("{} (linked worktree)", work_tree.display()),
+        ),
+        Some(work_tree) => Check::ok("repository", work_tree.display().to_string()),
+    }
+}
+
+/// Whether a git directory is the one of a linked worktree (`.git/worktrees/<name>`).
+fn is_linked_worktree(git_dir: &Path) -> bool {
+    git_dir
+        .parent()
+        .and_then(Path::file_name)
+        .is_some_and(|name| name == "worktrees")
+}
+
+/// Reads a git configuration value, `None` when unset.
+fn git_config(key: &str) -> Option<String> {
+    let output = GitCommand::new()
+        .args(["config", "--get", key])
+        .output()
+        .ok()?;
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    (output.status.success() && !value.is_empty()).then_some(value)
+}
+
+/// Checks that commits have an author.
+fn check_identity() -> Check {
+    match (git_config("user.name"), git_config("user.email")) {
+        (Some(name), Some(email)) => Check::ok("identity", format!("{name} <{email}>")),
+        (name, _) => {
+            let key = if name.is_none() {
+                "user.name"
+            } else {
+                "user.email"
+            };
+            Check::error(
+                "identity",
+                format!("`{key}` is not set: git refuses to commit"),
+                format!("git config --global {key} <value>"),
+            )
+        }
+    }
+}
+
+/// Checks that commits can be signed with the configured key, or the default GPG one.
+fn check_signing(config: &ProjectConfig) -> Check {
+    let key = config
+        .signing_key
+        .clone()
+        .filter(|key| !key.trim().is_empty())
+        .or_else(|| git_config("user.signingkey"));
+    let unsigned_fix = "or pass --unsigned (-u) to commit without signing";
+
+    match (signing_format().as_str(), key) {
+        ("ssh", None) => Check::warning(
+            "signing",
+            "`gpg.format` is ssh but no signing key is set: commits are unsigned",
+            format!("git config --global user.signingkey ~/.ssh/id_ed25519.pub, {unsigned_fix}"),
+        ),
+        ("ssh", Some(key)) => {
+            let inline = key.starts_with("ssh-") || key.starts_with("key::");
+            if find_program("ssh-keygen").is_none() {
+                Check::error(
+                    "signing",
+                    "ssh-keygen is not installed: SSH commits cannot be signed",
+                    format!("install OpenSSH, {unsigned_fix}"),
+                )
+            } else if !inline && !expand_home(&key).is_file() {
+                Check::error(
+                    "signing",
+                    format!("the SSH signing key {key} does not exist"),
+                    "git config --global user.signingkey <path to a public key>",
+                )
+            } else {
+                Check::ok("signing", format!("SSH key {key}"))
+            }
+        }
+        ("x509", _) => {
+            let program = git_config("gpg.x509.program").unwrap_or_else(|| "gpgsm".to_string());
+            if find_program(&program).is_some() {
+                Check::ok("signing", format!("X.509 with {program}"))
+            } else {
+                Check::error(
+                    "signing",
+                    format!("{program} is not installed: X.509 commits cannot be signed"),
+                    format!("install {program}, {unsigned_fix}"),
+                )
+            }
+        }
+        (_, Some(key)) if has_secret_key(&key) => Check::ok("signing", format!("GPG key {key}")),
+        (_, Some(key)) => Check::error(
+            "signing",
+            format!("the GPG key {key} is not in the secret keyring"),
+            format!("list the keys with `gpg --list-secret-keys` and set one, {unsigned_fix}"),
+        ),
+        (_, None) if is_gpg_signing_available() => Check::ok("signing", "GPG with the default key"),
+        (_, None) => Check::warning(
+            "signing",
+            "GPG is not available: commits are unsigned",
+            format!(
+                "install GnuPG and run `git config --global user.signingkey <KEY>`, {unsigned_fix}"
+            ),
+        ),
+    }
+}
+
+/// Expands a leading `~/` to the home directory.
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Checks that the configured editor can be launched.
+fn check_editor(config: &ProjectConfig) -> Check {
+    let Some(editor) = config
+        .editor
+        .as_deref()
+        .filter(|editor| !editor.trim().is_empty())
+    else {
+        return Check::error(
+            "editor",
+            "no editor configured: commit messages cannot be edited",
+            "rona set-editor <editor> (e.g. `rona set-editor \"code --wait\"`)",
+        );
+    };
+
+    let program = split_command_line(editor)
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    match find_program(&program) {
+        Some(path) => Check::ok("editor", format!("{editor} ({})", path.display())),
+        None => Check::error(
+            "editor",
+            format!("{program} is not found in PATH"),
+            "install it, or choose another editor with `rona set-editor <editor>`",
+        ),
+    }
+}
+
+/// Checks that the configuration files and the bases they extend are valid.
+fn check_config_files() -> Vec<Check> {
+    let Ok(paths) = config_file_paths() else {
+        return vec![Check::error(
+            "config",
+            "the home directory cannot be determined",
+            "set the HOME environment variable",
+        )];
+    };
+
+    let checks: Vec<Check> = paths
+        .iter()
+        .filter(|path| path.exists())
+        .map(|path| check_config_file(path))
+        .collect();
+
+    if checks.is_empty() {
+        return vec![Check::warning(
+            "config",
+            "no configuration file: the defaults are used",
+            "rona init",
+        )];
+    }
+    checks
+}
+
+/// Checks one configuration file and the bases it extends.
+fn check_config_file(path: &Path) -> Check {
+    let chain = match config_chain(path) {
+        Ok(chain) => chain,
+        Err(e) => {
+            return Check::error(
+                "config",
+                format!("{}: {e}", path.display()),
+                "fix the `extends` key of the file",
+            );
+        }
+    };
+
+    for file in &chain {
+        let parsed = read_to_string(file)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| {
+                toml::from_str::<ProjectConfig>(&contents).map_err(|e| e.to_string())
+            });
+
+        if let Err(e) = parsed {
+            return Check::error(
+                "config",
+                format!("{} is invalid: {}", file.display(), e.trim()),
+                "fix the file, or recreate it with `rona init`",
+            );
+        }
+    }
+
+    Check::ok("config", path.display().to_string())
+}
+
+/// Checks that the commit hooks run, and whether the `commit-msg` hook of rona is installed.
+fn check_hooks(repository: &Repository) -> Check {
+    let root = repository
+        .work_tree()
+        .unwrap_or_else(|| repository.git_dir());
+    let Ok(dir) = hooks_dir(root) else {
+        return Check::warning(
+            "hooks",
+            "the hooks directory cannot be resolved",
+            "check `git config core.hooksPath`",
+        );
+    };
+
+    let hooks = commit_hooks(&dir, false);
+    if let Some((name, _)) = hooks
+        .iter()
+        .find(|(_, status)| *status == HookStatus::NotExecutable)
+    {
+        return Check::warning(
+            "hooks",
+            format!("the {name} hook is ignored by git: it is not executable"),
+            format!("chmod +x {}", dir.join(name).display()),
+        );
+    }
+
+    let validates =
+        read_to_string(dir.join("commit-msg")).is_ok_and(|script| script.contains("rona lint"));
+    if !validates {
+        return Check::warning(
+            "hooks",
+            "no commit-msg hook validates the messages committed with git",
+            "rona hooks generate commit-msg",
+        );
+    }
+
+    let names: Vec<&str> = hooks.iter().map(|(name, _)| *name).collect();
+    Check::ok(
+        "hooks",
+        format!("{} in {}", names.join(", "), dir.display()),
+    )
+}
+
+/// Checks that each remote can be read with the current credentials.
+fn check_remotes(offline: bool) -> Vec<Check> {
+    let remotes = match list_remotes() {
+        Ok(remotes) if remotes.is_empty() => {
+            return vec![Check::warning(
+                "remote",
+                "no remote configured: nothing to push to",
+                "git remote add origin <url>",
+            )];
+        }
+        Ok(remotes) => remotes,
+        Err(e) => {
+            return vec![Check::error(
+                "remote",
+                e.to_string(),
+                "check `git remote -v`",
+            )];
+        }
+    };
+
+    remotes
+        .iter()
+        .map(|remote| {
+            let url = get_remote_url(remote).unwrap_or_default();
+            if offline {
+                return Check::skipped("remote", format!("{remote} ({url}): offline mode"));
+            }
+
+            match check_remote_reachable(remote, REMOTE_TIMEOUT) {
+                Ok(()) => Check::ok("remote", format!("{remote} ({url})")),
+                Err(e) => Check::error(
+                    "remote",
+                    format!("{remote} ({url}) cannot be reached"),
+                    e.to_string().trim().to_string(),
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Renders the checklist, one check per line followed by its fix.
+///
+/// # Examples
+///
+/// ```
+/// use rona::doctor::{Check, CheckStatus, render_checks};
+///
+/// let checks = [Check {
+///     name: "editor",
+///     status: CheckStatus::Error,
+///     detail: "no editor configured".to_string(),
+///     fix: Some("rona set-editor <editor>".to_string()),
+/// }];
+///
+/// assert_eq!(
+///     render_checks(&checks, false, false),
+///     "[fail] editor      no editor configured\n                   -> rona set-editor <editor>"
+/// );
+/// ```
+#[must_use]
+pub fn render_checks(checks: &[Check], emoji: bool, colored: bool) -> String {
+    let mut rendered = String::new();
+
+    for check in checks {
+        let style = match check.status {
+            CheckStatus::Ok => Style::new().green(),
+            CheckStatus::Warning => Style::new().yellow(),
+            CheckStatus::Error => Style::new().red(),
+            CheckStatus::Skipped => Style::new().dim(),
+        }
+        .force_styling(colored);
+
+        let _ = writeln!(
+            rendered,
+            "{} {:<11} {}",
+            check.status.marker(emoji),
+            style.apply_to(check.name),
+            check.detail
+        );
+        if let Some(fix) = &check.fix {
+            let _ = writeln!(rendered, "{:<18} -> {fix}", "");
+        }
+    }
+
+    rendered.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linked_worktree() {
+        assert!(is_linked_worktree(Path::new(
+            "/repo/.git/worktrees/feature"
+        )));
+        assert!(!is_linked_worktree(Path::new("/repo/.git")));
+    }
+
+    #[test]
+    fn test_check_config_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let valid = dir.path().join("valid.toml");
+        let invalid = dir.path().join("invalid.toml");
+        std::fs::write(&valid, "editor = \"vim\"\n").unwrap();
+        std::fs::write(&invalid, "commit_types = \"feat\"\n").unwrap();
+
+        assert_eq!(check_config_file(&valid).status, CheckStatus::Ok);
+
+        let check = check_config_file(&invalid);
+        assert_eq!(check.status, CheckStatus::Error);
+        assert!(check.detail.contains("invalid.toml is invalid"));
+    }
+
+    #[test]
+    fn test_render_checks() {
+        let checks = [
+            Check::ok("git", "git version 2.43.0"),
+            Check::warning(
+                "hooks",
+                "no commit-msg hook",
+                "rona hooks generate commit-msg",
+            ),
+        ];
+
+        assert_eq!(
+            render_checks(&checks, true, false),
+            "✅ git         git version 2.43.0\n⚠️  hooks       no commit-msg hook\n                   -> rona hooks generate commit-msg"
+        );
+        assert!(render_checks(&checks, false, true).contains("\u{1b}[32mgit"));
+    }
+}
@@ -1,7 +1,10 @@
+use inquire::InquireError;
+use log::error;
 use thiserror::Error;
 
 /// Main error type for the Rona application
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum RonaError {
     #[error("Configuration error: {0}")]
     Config(#[from] ConfigError),
@@ -20,10 +23,92 @@ pub enum RonaError {
 
     #[error("Command execution failed: {command}")]
     CommandFailed { command: String },
+
+    #[error(
+        "{feature} needs network access, which offline mode forbids - drop --offline or set offline = false"
+    )]
+    OfflineMode { feature: String },
+}
+
+impl RonaError {
+    /// Exit status for the error, so wrapper scripts can branch on the failure type.
+    ///
+    /// | Code | Meaning |
+    /// |------|---------|
+    /// | 1    | Unexpected I/O failure |
+    /// | 2    | Configuration error (also used by clap for invalid arguments) |
+    /// | 3    | Not in a git repository |
+    /// | 4    | A git (or external) command failed |
+    /// | 5    | The repository state prevents the operation (e.g. nothing staged, dirty tree) |
+    /// | 6    | Invalid input |
+    /// | 7    | Network access needed in offline mode |
+    /// | 130  | Cancelled by the user |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::errors::{GitError, RonaError};
+    ///
+    /// assert_eq!(RonaError::Git(GitError::RepositoryNotFound).exit_code(), 3);
+    /// assert_eq!(RonaError::UserCancelled.exit_code(), 130);
+    /// ```
+    #[must_use]
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Io(error) if wraps_repository_not_found(error) => 3,
+            Self::Io(_) => 1,
+            Self::Config(_) => 2,
+            Self::Git(GitError::RepositoryNotFound) => 3,
+            Self::Git(
+                GitError::CommandFailed { .. }
+                | GitError::IoError(_)
+                | GitError::AuthenticationFailed { .. }
+                | GitError::SshAuthenticationFailed
+                | GitError::CredentialHelperMissing { .. }
+                | GitError::ProxyError { .. }
+                | GitError::NonFastForward { .. }
+                | GitError::ProtectedBranch { .. }
+                | GitError::RemoteGone { .. }
+                | GitError::PushFailedOnRemotes { .. }
+                | GitError::Timeout { .. },
+            )
+            | Self::CommandFailed { .. } => 4,
+            Self::Git(_) => 5,
+            Self::InvalidInput(_) => 6,
+            Self::OfflineMode { .. } => 7,
+            Self::UserCancelled => 130,
+        }
+    }
+}
+
+/// Prompt errors: Esc and Ctrl-C cancel the operation (exiting with 130, see
+/// [`RonaError::exit_code`]), a missing terminal is reported as invalid input.
+impl From<InquireError> for RonaError {
+    fn from(error: InquireError) -> Self {
+        match error {
+            InquireError::OperationCanceled | InquireError::OperationInterrupted => {
+                Self::UserCancelled
+            }
+            InquireError::IO(error) => Self::Io(error),
+            InquireError::NotTTY => {
+                Self::InvalidInput("This prompt requires an interactive terminal".to_string())
+            }
+            other => Self::InvalidInput(other.to_string()),
+        }
+    }
+}
+
+/// Whether an I/O error was raised for a missing repository (see `utils::discover_repository`).
+fn wraps_repository_not_found(error: &std::io::Error) -> bool {
+    error
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<GitError>())
+        .is_some_and(|inner| matches!(inner, GitError::RepositoryNotFound))
 }
 
 /// Configuration-related errors
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum ConfigError {
     #[error("IO error while accessing config: {0}")]
     IoError(#[from] std::io::Error),
@@ -45,10 +130,14 @@ pub enum ConfigError {
 
     #[error("Unsupported editor: {editor}. Supported editors: vim, zed, nano")]
     UnsupportedEditor { editor: String },
+
+    #[error("Could not load the base configuration {base}: {reason}")]
+    ExtendsFailed { base: String, reason: String },
 }
 
 /// Git-related errors
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum GitError {
     #[error("IO error during git operation: {0}")]
     IoError(#[from] std::io::Error),
@@ -62,9 +151,14 @@ pub enum GitError {
     #[error("Invalid git status output format: {output}")]
     InvalidStatus { output: String },
 
-    #[error("Commit message file 'commit_message.md' not found - run 'rona generate' first")]
+    #[error("Commit message file not found - run 'rona generate' first")]
     CommitMessageNotFound,
 
+    #[error(
+        "Another rona process (pid {pid}) is using the commit message - wait for it to finish or pass --force"
+    )]
+    CommitMessageLocked { pid: String },
+
     #[error("Failed to process .gitignore file: {reason}")]
     GitignoreError { reason: String },
 
@@ -79,6 +173,64 @@ pub enum GitError {
 
     #[error("Remote repository not configured - add a remote with 'git remote add origin <url>'")]
     NoRemoteConfigured,
+
+    #[error(
+        "Authentication failed for {url} - check your credentials (a token instead of a password for HTTPS) and configure a credential helper to store them: git config --global credential.helper <helper>"
+    )]
+    AuthenticationFailed { url: String },
+
+    #[error(
+        "SSH authentication failed - check that your key is loaded in the SSH agent (ssh-add -l) and registered on the remote host"
+    )]
+    SshAuthenticationFailed,
+
+    #[error(
+        "Credential helper '{helper}' is not installed - install it or change the helper: git config --global credential.helper <helper>"
+    )]
+    CredentialHelperMissing { helper: String },
+
+    #[error(
+        "The push was rejected: the remote branch {branch} has commits that your branch does not have - integrate them with 'git pull --rebase', then push again"
+    )]
+    NonFastForward { branch: String },
+
+    #[error(
+        "The push was rejected: {branch} is protected on the remote - push your commits to a new branch (git switch -c <branch>) and open a merge request from it: rona open --pr"
+    )]
+    ProtectedBranch { branch: String },
+
+    #[error(
+        "{branch} is a protected branch (protected_branches) - commit on another branch (git switch -c <branch>), or confirm from a terminal without --strict"
+    )]
+    ProtectedBranchRefused { branch: String },
+
+    #[error(
+        "A git {operation} is in progress - git writes the message of its commits: resolve the conflicts with rona conflicts, then finish it with rona conflicts --continue, or cancel it with rona conflicts --abort"
+    )]
+    OperationInProgress { operation: String },
+
+    #[error(
+        "The remote repository {url} was not found - it may have been deleted, renamed or made private: check the remote URL (git remote -v) and update it with git remote set-url <remote> <url>"
+    )]
+    RemoteGone { url: String },
+
+    #[error("The push failed on {remotes} - see the errors above")]
+    PushFailedOnRemotes { remotes: String },
+
+    #[error(
+        "Could not reach the remote through the proxy: {reason} - check http.proxy (git config --get http.proxy) and the https_proxy environment variable"
+    )]
+    ProxyError { reason: String },
+
+    #[error(
+        "The staged changes contain {count} possible secret(s) - unstage them or commit with --allow-secrets"
+    )]
+    SecretsDetected { count: usize },
+
+    #[error(
+        "{command} did not finish within {seconds} seconds and was stopped - check your network connection and retry, raise network_timeout, or work with --offline"
+    )]
+    Timeout { command: String, seconds: u64 },
 }
 
 /// Type alias for Result using `RonaError`
@@ -97,19 +249,94 @@ pub type Result<T> = std::result::Result<T, RonaError>;
 /// # Arguments
 ///
 /// * `error_message` - A borrowed string containing the error message to format
-/// ```
 pub fn pretty_print_error(error_message: &str) {
-    println!("-------------------");
+    error!("-------------------");
 
     if error_message.lines().all(|line| line.trim().is_empty()) {
-        println!("No additional information provided.");
+        error!("No additional information provided.");
     } else {
         for line in error_message.lines() {
             if !line.trim().is_empty() {
-                println!("{}", line.trim());
+                error!("{}", line.trim());
             }
         }
     }
 
-    println!("-------------------");
+    error!("-------------------");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(RonaError::Io(std::io::Error::other("boom")).exit_code(), 1);
+        assert_eq!(RonaError::Config(ConfigError::InvalidConfig).exit_code(), 2);
+        assert_eq!(RonaError::Git(GitError::RepositoryNotFound).exit_code(), 3);
+        assert_eq!(
+            RonaError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                GitError::RepositoryNotFound
+            ))
+            .exit_code(),
+            3
+        );
+        assert_eq!(
+            RonaError::Git(GitError::CommandFailed {
+                command: "git push".to_string(),
+                output: String::new(),
+            })
+            .exit_code(),
+            4
+        );
+        assert_eq!(
+            RonaError::CommandFailed {
+                command: "git commit".to_string()
+            }
+            .exit_code(),
+            4
+        );
+        assert_eq!(
+            RonaError::Git(GitError::SshAuthenticationFailed).exit_code(),
+            4
+        );
+        assert_eq!(
+            RonaError::Git(GitError::NonFastForward {
+                branch: "main".to_string()
+            })
+            .exit_code(),
+            4
+        );
+        assert_eq!(
+            RonaError::Git(GitError::PushFailedOnRemotes {
+                remotes: "mirror".to_string()
+            })
+            .exit_code(),
+            4
+        );
+        assert_eq!(RonaError::Git(GitError::NoStagedChanges).exit_code(), 5);
+        assert_eq!(RonaError::InvalidInput("bad".to_string()).exit_code(), 6);
+        assert_eq!(
+            RonaError::OfflineMode {
+                feature: "git push".to_string()
+            }
+            .exit_code(),
+            7
+        );
+        assert_eq!(RonaError::UserCancelled.exit_code(), 130);
+    }
+
+    #[test]
+    fn test_prompt_cancellation() {
+        for error in [
+            InquireError::OperationCanceled,
+            InquireError::OperationInterrupted,
+        ] {
+            let error = RonaError::from(error);
+            assert!(matches!(error, RonaError::UserCancelled));
+            assert_eq!(error.exit_code(), 130);
+        }
+        assert_eq!(RonaError::from(InquireError::NotTTY).exit_code(), 6);
+    }
 }
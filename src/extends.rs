@@ -0,0 +1,236 @@
+//! Shared Base Configuration
+//!
+//! A configuration file can start from a base managed centrally by a team (commit types,
+//! templates, checks) with `extends`, either a URL or a path relative to the extending
+//! file:
+//!
+//! ```toml
+//! extends = "https://example.com/team/rona-base.toml"
+//! # or
+//! extends = "config/rona-base.toml"
+//! ```
+//!
+//! The keys of the extending file override those of its base, which can itself extend
+//! another one. Remote bases are fetched with `curl` and cached under `rona/extends` in
+//! the user cache directory (`~/.cache` on Linux): the cached copy is used for a day, and
+//! past that whenever it cannot be fetched again (e.g. offline).
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime},
+};
+
+use log::{debug, warn};
+
+use crate::errors::{ConfigError, Result};
+
+/// Maximum number of bases a configuration file can be extended from.
+pub const MAX_EXTENDS_DEPTH: usize = 8;
+
+/// How long a fetched base is used before being fetched again.
+const CACHE_TTL: Duration = Duration::from_hours(24);
+
+/// Builds the error of a base that cannot be loaded.
+fn extends_failed(base: &str, reason: impl Into<String>) -> ConfigError {
+    ConfigError::ExtendsFailed {
+        base: base.to_string(),
+        reason: reason.into(),
+    }
+}
+
+/// The `extends` key of a configuration file, if any.
+///
+/// # Errors
+/// * If the file cannot be read or is not valid TOML
+fn read_extends(path: &Path) -> Result<Option<String>> {
+    let display = path.display().to_string();
+    let content = fs::read_to_string(path).map_err(|e| extends_failed(&display, e.to_string()))?;
+    let table: toml::Table = content
+        .parse()
+        .map_err(|e: toml::de::Error| extends_failed(&display, e.message()))?;
+
+    Ok(table
+        .get("extends")
+        .and_then(toml::Value::as_str)
+        .map(ToString::to_string))
+}
+
+/// Whether a base is a URL rather than a path.
+fn is_url(base: &str) -> bool {
+    base.starts_with("https://") || base.starts_with("http://")
+}
+
+/// The name of the cached copy of a remote base, readable and stable across releases.
+///
+/// # Examples
+///
+/// ```
+/// use rona::extends::cache_file_name;
+///
+/// assert_eq!(
+///     cache_file_name("https://example.com/team/rona-base.toml"),
+///     "https___example.com_team_rona-base.toml"
+/// );
+/// ```
+#[must_use]
+pub fn cache_file_name(url: &str) -> String {
+    url.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Whether a cached copy was written less than [`CACHE_TTL`] ago.
+fn is_fresh(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < CACHE_TTL)
+}
+
+/// Fetches a remote base with curl, checking that it is valid TOML.
+fn fetch(url: &str) -> std::result::Result<String, String> {
+    debug!("Fetching the base configuration {url}");
+
+    let output = Command::new("curl")
+        .args(["-sS", "--fail", "--location", "--max-time", "10", url])
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let content = String::from_utf8_lossy(&output.stdout).into_owned();
+    content
+        .parse::<toml::Table>()
+        .map_err(|e| format!("invalid TOML: {}", e.message()))?;
+    Ok(content)
+}
+
+/// The path of the cached copy of a remote base, fetching it when it is missing or
+/// older than a day.
+///
+/// # Errors
+/// * If the base cannot be fetched and was never cached
+fn cached_base(url: &str) -> Result<PathBuf> {
+    let cache_dir = dirs::cache_dir()
+        .ok_or(ConfigError::HomeDirNotFound)?
+        .join("rona/extends");
+    let path = cache_dir.join(cache_file_name(url));
+
+    if is_fresh(&path) {
+        return Ok(path);
+    }
+
+    match fetch(url) {
+        Ok(content) => {
+            fs::create_dir_all(&cache_dir).map_err(ConfigError::IoError)?;
+            let temporary = path.with_extension("tmp");
+            fs::write(&temporary, content)
+                .and_then(|()| fs::rename(&temporary, &path))
+                .map_err(ConfigError::IoError)?;
+            Ok(path)
+        }
+        Err(reason) if path.exists() => {
+            warn!("⚠️  Could not fetch {url} ({reason}), using the cached copy");
+            Ok(path)
+        }
+        Err(reason) => Err(extends_failed(url, reason).into()),
+    }
+}
+
+/// The configuration files to merge for `path`: its bases, the farthest first, then
+/// `path` itself.
+///
+/// # Errors
+/// * If a file of the chain cannot be read or is not valid TOML
+/// * If a remote base cannot be fetched and was never cached
+/// * If the chain loops or is deeper than [`MAX_EXTENDS_DEPTH`]
+pub fn config_chain(path: &Path) -> Result<Vec<PathBuf>> {
+    let mut chain = vec![path.to_path_buf()];
+
+    while let Some(base) = read_extends(&chain[chain.len() - 1])? {
+        if chain.len() > MAX_EXTENDS_DEPTH {
+            return Err(extends_failed(
+                &base,
+                format!("more than {MAX_EXTENDS_DEPTH} bases are extended"),
+            )
+            .into());
+        }
+
+        let base_path = if is_url(&base) {
+            cached_base(&base)?
+        } else {
+            let extending = &chain[chain.len() - 1];
+            extending
+                .parent()
+                .map_or_else(|| PathBuf::from(&base), |dir| dir.join(&base))
+        };
+
+        if chain.contains(&base_path) {
+            return Err(extends_failed(&base, "it extends itself").into());
+        }
+        chain.push(base_path);
+    }
+
+    chain.reverse();
+    Ok(chain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs::{create_dir_all, write};
+
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_config_chain() {
+        let dir = TempDir::new().unwrap();
+        let project = dir.path().join(".rona.toml");
+        let team = dir.path().join("config/team.toml");
+        let company = dir.path().join("config/company.toml");
+
+        create_dir_all(dir.path().join("config")).unwrap();
+        write(
+            &project,
+            "extends = \"config/team.toml\"\neditor = \"vim\"\n",
+        )
+        .unwrap();
+        write(&team, "extends = \"company.toml\"\n").unwrap();
+        write(&company, "commit_types = [\"feat\", \"fix\"]\n").unwrap();
+
+        let chain = config_chain(&project).unwrap();
+        assert_eq!(chain.len(), 3);
+        assert!(chain[0].ends_with("config/company.toml"));
+        assert!(chain[1].ends_with("config/team.toml"));
+        assert_eq!(chain[2], project);
+    }
+
+    #[test]
+    fn test_config_chain_errors() {
+        let dir = TempDir::new().unwrap();
+        let looping = dir.path().join("looping.toml");
+        let missing = dir.path().join("missing.toml");
+
+        write(&looping, "extends = \"looping.toml\"\n").unwrap();
+        write(&missing, "extends = \"nowhere.toml\"\n").unwrap();
+
+        assert!(matches!(
+            config_chain(&looping),
+            Err(crate::errors::RonaError::Config(
+                ConfigError::ExtendsFailed { .. }
+            ))
+        ));
+        assert!(config_chain(&missing).is_err());
+    }
+}
@@ -0,0 +1,253 @@
+//! Audit Log
+//!
+//! Record of the rona invocations changing the repository (`add`, `commit`, `push`, `tag`,
+//! ...), appended to `audit.log` in the `rona` directory of the git directory and shown by
+//! `rona history`. Each line is a JSON object holding the time, the arguments, the exit code
+//! and error of an invocation, and the commit HEAD moved to, so "what did rona actually
+//! run?" can be answered from the log. Dry runs and read-only commands are not recorded.
+
+use std::{
+    fmt,
+    fs::{OpenOptions, create_dir_all, read_to_string},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, FixedOffset, Local, SubsecRound};
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Result, RonaError};
+
+use super::{
+    commit::COMMIT_MESSAGE_DIR, exec::GitCommand, exec::quote_arg, repository::find_git_root,
+};
+
+/// Name of the audit log, in the `rona` directory of the git directory.
+pub const AUDIT_LOG_FILE_NAME: &str = "audit.log";
+
+/// A recorded rona invocation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// When the invocation finished
+    pub time: DateTime<FixedOffset>,
+    /// The arguments rona was run with, without the program name
+    pub args: Vec<String>,
+    /// The exit status of the invocation, 0 when it succeeded
+    pub exit_code: i32,
+    /// The error the invocation failed with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The abbreviated hash of the commit HEAD moved to, if it moved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+}
+
+impl AuditEntry {
+    /// The command line of the invocation, the arguments being quoted when needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Local;
+    /// use rona::git::audit::AuditEntry;
+    ///
+    /// let entry = AuditEntry {
+    ///     time: Local::now().fixed_offset(),
+    ///     args: vec!["commit".into(), "-m".into(), "it's done".into()],
+    ///     exit_code: 0,
+    ///     error: None,
+    ///     head: None,
+    /// };
+    /// assert_eq!(entry.command_line(), r"rona commit -m 'it'\''s done'");
+    /// ```
+    #[must_use]
+    pub fn command_line(&self) -> String {
+        std::iter::once("rona".into())
+            .chain(self.args.iter().map(|arg| quote_arg(arg)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl fmt::Display for AuditEntry {
+    /// The time, the result and the command line of the invocation, then the commit HEAD
+    /// moved to or the error.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.exit_code == 0 {
+            "ok".to_string()
+        } else {
+            format!("exit {}", self.exit_code)
+        };
+        write!(
+            f,
+            "{}  {status:<7}  {}",
+            self.time.format("%Y-%m-%d %H:%M:%S"),
+            self.command_line()
+        )?;
+
+        if let Some(head) = &self.head {
+            write!(f, "  -> {head}")?;
+        }
+        if let Some(error) = &self.error {
+            write!(f, "  ({})", error.lines().next().unwrap_or_default().trim())?;
+        }
+        Ok(())
+    }
+}
+
+/// Path of the audit log of the current repository, `.git/rona/audit.log`.
+///
+/// # Errors
+/// * If not in a git repository
+pub fn audit_log_path() -> Result<PathBuf> {
+    Ok(find_git_root()?
+        .join(COMMIT_MESSAGE_DIR)
+        .join(AUDIT_LOG_FILE_NAME))
+}
+
+/// Appends an entry to the audit log, creating it if needed.
+///
+/// # Errors
+/// * If the log cannot be written
+pub fn append_audit_entry(path: &Path, entry: &AuditEntry) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir)?;
+    }
+
+    let line = serde_json::to_string(entry)
+        .map_err(|e| RonaError::InvalidInput(format!("Failed to serialize audit entry: {e}")))?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+
+    Ok(())
+}
+
+/// Reads the entries of the audit log, oldest first: none when there is no log, and the
+/// lines that are not entries (e.g. cut by a crash) are skipped.
+///
+/// # Errors
+/// * If the log exists but cannot be read
+pub fn read_audit_log(path: &Path) -> Result<Vec<AuditEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    Ok(read_to_string(path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            serde_json::from_str(line)
+                .inspect_err(|e| debug!("Skipping audit log line '{line}': {e}"))
+                .ok()
+        })
+        .collect())
+}
+
+/// The abbreviated hash of HEAD, `None` when there are no commits yet.
+fn head_commit() -> Option<String> {
+    let output = GitCommand::new()
+        .args(["rev-parse", "--quiet", "--verify", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// An invocation being recorded: started before the command runs, finished with its result.
+#[derive(Debug)]
+pub struct AuditRecord {
+    path: PathBuf,
+    args: Vec<String>,
+    head_before: Option<String>,
+}
+
+impl AuditRecord {
+    /// Starts recording an invocation with the given arguments, `None` outside a git
+    /// repository.
+    #[must_use]
+    pub fn start(args: Vec<String>) -> Option<Self> {
+        let path = audit_log_path().ok()?;
+
+        Some(Self {
+            path,
+            args,
+            head_before: head_commit(),
+        })
+    }
+
+    /// Appends the invocation with its result to the audit log. A log that cannot be
+    /// written is only reported in verbose mode, never failing the command.
+    pub fn finish<T>(self, result: &Result<T>) {
+        let head = head_commit().filter(|head| Some(head) != self.head_before.as_ref());
+        let entry = AuditEntry {
+            time: Local::now().fixed_offset().trunc_subsecs(0),
+            args: self.args,
+            exit_code: result.as_ref().map_or_else(RonaError::exit_code, |_| 0),
+            error: result.as_ref().err().map(ToString::to_string),
+            head,
+        };
+
+        if let Err(e) = append_audit_entry(&self.path, &entry) {
+            debug!("Could not write the audit log {}: {e}", self.path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    fn entry(args: &[&str], exit_code: i32) -> AuditEntry {
+        AuditEntry {
+            time: FixedOffset::east_opt(3600)
+                .unwrap()
+                .with_ymd_and_hms(2026, 10, 16, 14, 3, 12)
+                .unwrap(),
+            args: args.iter().map(ToString::to_string).collect(),
+            exit_code,
+            error: None,
+            head: None,
+        }
+    }
+
+    #[test]
+    fn test_audit_log_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("rona").join(AUDIT_LOG_FILE_NAME);
+        assert!(read_audit_log(&path).unwrap().is_empty());
+
+        let commit = AuditEntry {
+            head: Some("1a2b3c4".to_string()),
+            ..entry(&["commit", "-p"], 0)
+        };
+        let push = AuditEntry {
+            error: Some("Git push failed\nhint: pull first".to_string()),
+            ..entry(&["push"], 4)
+        };
+        append_audit_entry(&path, &commit).unwrap();
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"time\": \"cut by a cra\n")
+            .unwrap();
+        append_audit_entry(&path, &push).unwrap();
+
+        let entries = read_audit_log(&path).unwrap();
+        assert_eq!(entries, [commit, push]);
+        assert_eq!(
+            entries[0].to_string(),
+            "2026-10-16 14:03:12  ok       rona commit -p  -> 1a2b3c4"
+        );
+        assert_eq!(
+            entries[1].to_string(),
+            "2026-10-16 14:03:12  exit 4   rona push  (Git push failed)"
+        );
+    }
+}
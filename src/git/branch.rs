@@ -1,55 +1,62 @@
 //! Branch Operations
 //!
-//! Git branch-related functionality including branch information retrieval
-//! and branch name formatting utilities.
+//! Git branch-related functionality including branch information retrieval,
+//! branch name formatting utilities, checking out local or remote branches, the
+//! branch descriptions (`branch.<name>.description`) edited by `rona branch describe` and
+//! the tickets (e.g. `PROJ-1234`) found in branch names by `ticket_pattern`.
 
-use std::process::Command;
+use std::{
+    fmt::{self, Write as _},
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+use glob::Pattern;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     errors::{GitError, Result, RonaError},
-    git::commit::get_current_commit_nb,
+    git::{
+        commit::COMMIT_MESSAGE_DIR,
+        remote::list_remotes,
+        repository::{RepositoryState, find_git_root, repository_state},
+        trailers::{Trailer, append_trailers},
+    },
+    plan::{ActionKind, Plan},
 };
 
-/// Attempts to get the default branch name from git config.
-///
-/// This helper function tries to retrieve the default branch name using
-/// `git config --get init.defaultBranch`. If successful, it returns the
-/// branch name. If the config command fails, it returns an error with
-/// the specified fallback command name for context.
-///
-/// # Arguments
-///
-/// * `fallback_command` - The command name to use in error messages when
-///   the git config command fails
-///
-/// # Returns
-///
-/// * `Ok(String)` - The default branch name if successfully retrieved
-/// * `Err(RonaError)` - Error with the fallback command context if config fails
-fn try_get_default_branch(fallback_command: &str) -> Result<String> {
-    let config_output = Command::new("git")
-        .args(["config", "--get", "init.defaultBranch"])
-        .output()?;
+use super::exec::GitCommand;
 
-    if config_output.status.success() {
-        let default_branch = String::from_utf8_lossy(&config_output.stdout)
-            .trim()
-            .to_string();
-        Ok(default_branch)
-    } else {
-        // Return error with the provided fallback command context
-        let error_message = String::from_utf8_lossy(&config_output.stderr);
-        Err(RonaError::Git(GitError::CommandFailed {
-            command: fallback_command.to_string(),
-            output: error_message.to_string(),
-        }))
-    }
+/// Template of the branches created by `rona branch from-issue`.
+pub const DEFAULT_BRANCH_TEMPLATE: &str = "{type}/{issue}-{slug}";
+
+/// Maximum length of the slug of a branch name.
+const MAX_SLUG_LENGTH: usize = 50;
+
+/// Name of the file branch descriptions are edited in, in the `rona` directory of the
+/// git directory.
+pub const BRANCH_DESCRIPTION_FILE_NAME: &str = "branch_description.md";
+
+/// Key of the trailer referencing the ticket in the footer.
+const TICKET_TRAILER: &str = "Refs";
+
+/// Where the ticket of the branch goes in generated messages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TicketPosition {
+    /// Before the subject of the header (`[4] (fix on PROJ-1234-fix-login) PROJ-1234 ...`)
+    #[default]
+    Header,
+    /// In a `Refs: PROJ-1234` trailer
+    Footer,
 }
 
 /// Gets the current branch name.
 ///
-/// This function returns the name of the currently checked out branch.
-/// For detached HEAD states, it returns the commit hash.
+/// This function returns the name of the currently checked out branch, including
+/// a branch without commits yet (fresh repository or orphan branch).
+/// For detached HEAD states, it returns `HEAD` (see [`head_name`] for headers).
 ///
 /// # Errors
 ///
@@ -77,36 +84,777 @@ fn try_get_default_branch(fallback_command: &str) -> Result<String> {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn get_current_branch() -> Result<String> {
-    let output = Command::new("git")
-        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+    Ok(match repository_state()? {
+        RepositoryState::Branch(branch) | RepositoryState::Unborn(branch) => branch,
+        RepositoryState::Detached => "HEAD".to_string(),
+    })
+}
+
+/// Whether HEAD is detached, i.e. checks out a commit instead of a branch.
+///
+/// # Errors
+/// * If the git command cannot be run, or not in a git repository
+pub fn is_head_detached() -> Result<bool> {
+    let output = GitCommand::new()
+        .args(["symbolic-ref", "--quiet", "HEAD"])
+        .output()?;
+
+    // `git symbolic-ref --quiet` exits with 1 when HEAD is not a symbolic ref
+    match output.status.code() {
+        Some(0) => Ok(false),
+        Some(1) => Ok(true),
+        _ => Err(RonaError::Git(GitError::CommandFailed {
+            command: "git symbolic-ref --quiet HEAD".to_string(),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        })),
+    }
+}
+
+/// The name of HEAD in commit headers: the current branch or, when HEAD is detached,
+/// the nearest tag (`v1.2.0`, or `v1.2.0-3-g1a2b3c4` past it) or the short hash.
+///
+/// # Errors
+/// * If the current branch or the commit cannot be described
+pub fn head_name() -> Result<String> {
+    if !is_head_detached()? {
+        return get_current_branch();
+    }
+
+    let output = GitCommand::new()
+        .args(["describe", "--tags", "--always", "HEAD"])
         .output()?;
 
     if output.status.success() {
-        let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        Ok(branch)
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     } else {
-        // Check if it's a freshly initialized repository (no commits yet)
-        // If get_current_commit_nb() fails, it's likely a fresh repo with no HEAD
-        match get_current_commit_nb() {
-            Ok(0) => {
-                // Fresh repository with no commits
-                // Try to get the default branch name
-                try_get_default_branch("git config --get init.defaultBranch")
-            }
-            Ok(_) => {
-                // Repository has commits, so the original error wasn't due to fresh repo
-                let error_message = String::from_utf8_lossy(&output.stderr);
-
-                Err(RonaError::Git(GitError::CommandFailed {
-                    command: "git rev-parse --abbrev-ref HEAD".to_string(),
-                    output: error_message.to_string(),
-                }))
-            }
-            Err(_) => {
-                // Can't determine commit count, likely a fresh repo with no HEAD
-                // Try to get the default branch name
-                try_get_default_branch("git rev-parse --abbrev-ref HEAD")
+        Err(RonaError::Git(GitError::CommandFailed {
+            command: "git describe --tags --always HEAD".to_string(),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }))
+    }
+}
+
+/// Checks whether a ref exists in the repository.
+fn ref_exists(reference: &str) -> Result<bool> {
+    let status = GitCommand::new()
+        .args(["rev-parse", "--verify", "--quiet", reference])
+        .output()?
+        .status;
+
+    Ok(status.success())
+}
+
+/// Branch names tried when `origin/HEAD` is not set, in order.
+const COMMON_DEFAULT_BRANCHES: [&str; 2] = ["main", "master"];
+
+/// Reads `init.defaultBranch`, if set.
+fn configured_default_branch() -> Result<Option<String>> {
+    let output = GitCommand::new()
+        .args(["config", "--get", "init.defaultBranch"])
+        .output()?;
+
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok((output.status.success() && !branch.is_empty()).then_some(branch))
+}
+
+/// Resolves the default branch and the ref to compare against.
+///
+/// The branch `origin_head` points to (e.g. `origin/main`) is used first, then the
+/// [`COMMON_DEFAULT_BRANCHES`] existing locally or on `origin`, then the `configured`
+/// `init.defaultBranch`. The local branch is preferred as reference when it exists,
+/// otherwise its `origin/` counterpart is used.
+fn resolve_default_branch<F>(
+    origin_head: Option<&str>,
+    configured: Option<&str>,
+    ref_exists: F,
+) -> Option<(String, String)>
+where
+    F: Fn(&str) -> bool,
+{
+    let local = |branch: &str| ref_exists(&format!("refs/heads/{branch}"));
+    let remote = |branch: &str| ref_exists(&format!("refs/remotes/origin/{branch}"));
+
+    let branch = origin_head
+        .map(|head| head.strip_prefix("origin/").unwrap_or(head))
+        .or_else(|| {
+            COMMON_DEFAULT_BRANCHES
+                .into_iter()
+                .find(|branch| local(branch) || remote(branch))
+        })
+        .or(configured)?;
+
+    let reference = if local(branch) || !remote(branch) {
+        branch.to_string()
+    } else {
+        format!("origin/{branch}")
+    };
+
+    Some((branch.to_string(), reference))
+}
+
+/// Gets the default branch of the repository and the ref to compare against.
+///
+/// The branch `origin/HEAD` points to is used first, then `main` or `master` (local
+/// or on `origin`), then `init.defaultBranch`. The local branch is preferred as
+/// reference when it exists, otherwise its `origin/` counterpart is used.
+///
+/// # Errors
+///
+/// Returns an error if no default branch can be determined.
+///
+/// # Returns
+///
+/// A tuple `(branch_name, reference)`, e.g. `("main", "main")` or `("main", "origin/main")`
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::branch::get_default_branch;
+///
+/// let (branch, reference) = get_default_branch()?;
+/// println!("Default branch: {branch} (compared against {reference})");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn get_default_branch() -> Result<(String, String)> {
+    let origin_head = GitCommand::new()
+        .args([
+            "symbolic-ref",
+            "--quiet",
+            "--short",
+            "refs/remotes/origin/HEAD",
+        ])
+        .output()?;
+    let origin_head = origin_head.status.success().then(|| {
+        String::from_utf8_lossy(&origin_head.stdout)
+            .trim()
+            .to_string()
+    });
+
+    let configured = configured_default_branch()?;
+
+    resolve_default_branch(origin_head.as_deref(), configured.as_deref(), |reference| {
+        ref_exists(reference).unwrap_or(false)
+    })
+    .ok_or_else(|| {
+        RonaError::Git(GitError::CommandFailed {
+            command: "git symbolic-ref refs/remotes/origin/HEAD".to_string(),
+            output: "No default branch found - run `git remote set-head origin --auto` or set init.defaultBranch".to_string(),
+        })
+    })
+}
+
+/// Lists the local branches.
+///
+/// # Errors
+///
+/// Returns an error if the git command fails.
+///
+/// # Returns
+///
+/// The short names of the local branches
+pub fn list_branches() -> Result<Vec<String>> {
+    let output = GitCommand::new()
+        .args(["for-each-ref", "--format=%(refname:short)", "refs/heads"])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: "git for-each-ref refs/heads".to_string(),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(ToString::to_string)
+        .collect())
+}
+
+/// A branch `rona checkout` can check out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckoutTarget {
+    /// A local branch
+    Local(String),
+    /// A remote-tracking branch without local branch of the same name, checked out as
+    /// a new local branch tracking it
+    Remote {
+        /// The remote (e.g. `origin`)
+        remote: String,
+        /// The branch on the remote (e.g. `feature/login`)
+        branch: String,
+    },
+}
+
+impl CheckoutTarget {
+    /// The name of the local branch checked out.
+    #[must_use]
+    pub fn branch(&self) -> &str {
+        match self {
+            Self::Local(branch) | Self::Remote { branch, .. } => branch,
+        }
+    }
+
+    /// Whether the target is named `name`: its local name, or `<remote>/<branch>` for
+    /// remote-only branches.
+    #[must_use]
+    pub fn is_named(&self, name: &str) -> bool {
+        self.branch() == name || self.to_string() == name
+    }
+}
+
+impl fmt::Display for CheckoutTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local(branch) => f.write_str(branch),
+            Self::Remote { remote, branch } => write!(f, "{remote}/{branch}"),
+        }
+    }
+}
+
+/// Parses `git for-each-ref --format=%(refname) refs/heads refs/remotes` output into
+/// the branches to check out: the local branches but `current`, then the remote-tracking
+/// branches that have no local branch of the same name (remote `HEAD`s skipped).
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::branch::{CheckoutTarget, checkout_targets};
+///
+/// let refs = "refs/heads/main\nrefs/heads/fix\nrefs/remotes/origin/HEAD\n\
+///             refs/remotes/origin/main\nrefs/remotes/origin/feature/login\n";
+/// let remotes = vec!["origin".to_string()];
+///
+/// assert_eq!(
+///     checkout_targets(refs, &remotes, Some("main")),
+///     vec![
+///         CheckoutTarget::Local("fix".to_string()),
+///         CheckoutTarget::Remote {
+///             remote: "origin".to_string(),
+///             branch: "feature/login".to_string(),
+///         },
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn checkout_targets(
+    refs: &str,
+    remotes: &[String],
+    current: Option<&str>,
+) -> Vec<CheckoutTarget> {
+    let local: Vec<&str> = refs
+        .lines()
+        .filter_map(|line| line.strip_prefix("refs/heads/"))
+        .collect();
+
+    let mut targets: Vec<CheckoutTarget> = local
+        .iter()
+        .filter(|branch| Some(**branch) != current)
+        .map(|branch| CheckoutTarget::Local((*branch).to_string()))
+        .collect();
+
+    for name in refs
+        .lines()
+        .filter_map(|line| line.strip_prefix("refs/remotes/"))
+    {
+        // The longest remote prefix, remote names may contain `/`
+        let Some((remote, branch)) = remotes
+            .iter()
+            .filter_map(|remote| {
+                let branch = name.strip_prefix(remote.as_str())?.strip_prefix('/')?;
+                Some((remote, branch))
+            })
+            .max_by_key(|(remote, _)| remote.len())
+        else {
+            continue;
+        };
+
+        let known = local.contains(&branch)
+            || targets.iter().any(|target| {
+                matches!(target, CheckoutTarget::Remote { branch: other, .. } if other == branch)
+            });
+        if branch != "HEAD" && !known {
+            targets.push(CheckoutTarget::Remote {
+                remote: remote.clone(),
+                branch: branch.to_string(),
+            });
+        }
+    }
+
+    targets
+}
+
+/// Lists the branches to check out (see [`checkout_targets`]), the current one aside.
+///
+/// # Errors
+/// * If the branches or the remotes cannot be listed
+pub fn list_checkout_targets() -> Result<Vec<CheckoutTarget>> {
+    let output = GitCommand::new()
+        .args([
+            "for-each-ref",
+            "--format=%(refname)",
+            "refs/heads",
+            "refs/remotes",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: "git for-each-ref refs/heads refs/remotes".to_string(),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    Ok(checkout_targets(
+        &String::from_utf8_lossy(&output.stdout),
+        &list_remotes()?,
+        repository_state()?.branch(),
+    ))
+}
+
+/// Checks out a branch with `git switch`, creating a local branch tracking the remote
+/// branch for remote-only targets (`git switch --track <remote>/<branch>`).
+///
+/// # Errors
+/// * If the switch fails (e.g. local changes would be overwritten)
+pub fn git_checkout(target: &CheckoutTarget, dry_run: bool) -> Result<Plan> {
+    let mut plan = Plan::new();
+    if let CheckoutTarget::Remote { .. } = target {
+        plan.push(
+            ActionKind::CreateBranch,
+            target.branch(),
+            vec![target.to_string()],
+        );
+    }
+    plan.push(ActionKind::Checkout, target.branch(), Vec::new());
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    let args = match target {
+        CheckoutTarget::Local(branch) => vec!["switch".to_string(), branch.clone()],
+        CheckoutTarget::Remote { .. } => {
+            vec![
+                "switch".to_string(),
+                "--track".to_string(),
+                target.to_string(),
+            ]
+        }
+    };
+    let output = GitCommand::new().args(&args).output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git {}", args.join(" ")),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    Ok(plan)
+}
+
+/// Turns a title into a branch name slug: lowercase words joined by `-`, without
+/// punctuation, cut at a word boundary to 50 characters.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::branch::slugify;
+///
+/// assert_eq!(slugify("Fix: crash when the config is empty!"), "fix-crash-when-the-config-is-empty");
+/// assert_eq!(slugify("  Support `--dry-run` in CI  "), "support-dry-run-in-ci");
+/// ```
+#[must_use]
+pub fn slugify(title: &str) -> String {
+    let words = title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase);
+
+    let mut slug = String::new();
+    for word in words {
+        let separator = usize::from(!slug.is_empty());
+        if !slug.is_empty()
+            && slug.chars().count() + separator + word.chars().count() > MAX_SLUG_LENGTH
+        {
+            break;
+        }
+        if separator == 1 {
+            slug.push('-');
+        }
+        slug.push_str(&word);
+    }
+
+    slug
+}
+
+/// Formats a branch name from a template with `{type}`, `{issue}` and `{slug}`
+/// placeholders.
+///
+/// Separators left around an empty value are dropped, so that a branch without
+/// issue number is `feat/add-login` rather than `feat/-add-login`.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::branch::{DEFAULT_BRANCH_TEMPLATE, branch_name_from_template};
+///
+/// assert_eq!(
+///     branch_name_from_template(DEFAULT_BRANCH_TEMPLATE, "fix", Some(42), "login-crash"),
+///     "fix/42-login-crash"
+/// );
+/// assert_eq!(
+///     branch_name_from_template(DEFAULT_BRANCH_TEMPLATE, "feat", None, "add-login"),
+///     "feat/add-login"
+/// );
+/// ```
+#[must_use]
+pub fn branch_name_from_template(
+    template: &str,
+    branch_type: &str,
+    issue: Option<u64>,
+    slug: &str,
+) -> String {
+    let issue = issue.map(|number| number.to_string()).unwrap_or_default();
+    let name = template
+        .replace("{type}", branch_type)
+        .replace("{issue}", &issue)
+        .replace("{slug}", slug);
+
+    name.split('/')
+        .map(|part| part.trim_matches(['-', '_', '.']))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Creates a branch from `HEAD`, checking it out unless `checkout` is false.
+///
+/// # Arguments
+/// * `name` - The name of the new branch
+/// * `checkout` - Whether to check out the new branch
+/// * `dry_run` - If true, only plan the branch creation
+///
+/// # Errors
+/// * If the name is not a valid branch name
+/// * If the branch already exists or cannot be created
+pub fn create_branch(name: &str, checkout: bool, dry_run: bool) -> Result<Plan> {
+    let valid = GitCommand::new()
+        .args(["check-ref-format", "--branch", name])
+        .output()?
+        .status
+        .success();
+    if !valid {
+        return Err(RonaError::InvalidInput(format!(
+            "'{name}' is not a valid branch name"
+        )));
+    }
+
+    if ref_exists(&format!("refs/heads/{name}"))? {
+        return Err(RonaError::InvalidInput(format!(
+            "The branch '{name}' already exists"
+        )));
+    }
+
+    let mut plan = Plan::new();
+    plan.push(ActionKind::CreateBranch, name, Vec::new());
+    if checkout {
+        plan.push(ActionKind::Checkout, name, Vec::new());
+    }
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    let args = if checkout {
+        ["switch", "-c", name]
+    } else {
+        ["branch", "--", name]
+    };
+    let output = GitCommand::new().args(args).output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git {}", args.join(" ")),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    Ok(plan)
+}
+
+/// The description of a branch (`branch.<name>.description`), `None` when it has none.
+///
+/// # Errors
+/// * If the git configuration cannot be read
+pub fn get_branch_description(branch: &str) -> Result<Option<String>> {
+    let key = format!("branch.{branch}.description");
+    let output = GitCommand::new().args(["config", "--get", &key]).output()?;
+
+    // `git config --get` exits with 1 when the key is not set
+    match output.status.code() {
+        Some(0) => {
+            let description = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            Ok(Some(description).filter(|description| !description.is_empty()))
+        }
+        Some(1) => Ok(None),
+        _ => Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git config --get {key}"),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        })),
+    }
+}
+
+/// Checks that a branch can be described: it is a local branch or the current one (which
+/// may have no commits yet).
+fn ensure_local_branch(branch: &str) -> Result<()> {
+    let current = matches!(
+        repository_state()?,
+        RepositoryState::Branch(name) | RepositoryState::Unborn(name) if name == branch
+    );
+    if !current && !ref_exists(&format!("refs/heads/{branch}"))? {
+        return Err(RonaError::InvalidInput(format!(
+            "There is no local branch named '{branch}'"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Sets the description of a branch (`git config branch.<name>.description`).
+///
+/// # Arguments
+/// * `branch` - The branch described, a local branch or the current one
+/// * `description` - The description
+/// * `dry_run` - If true, only plan the change
+///
+/// # Errors
+/// * If there is no such local branch
+/// * If the git configuration cannot be written
+pub fn set_branch_description(branch: &str, description: &str, dry_run: bool) -> Result<Plan> {
+    ensure_local_branch(branch)?;
+
+    let key = format!("branch.{branch}.description");
+    let mut plan = Plan::new();
+    plan.push(ActionKind::SetConfig, &key, vec![description.to_string()]);
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    let output = GitCommand::new()
+        .args(["config", &key, description])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git config {key}"),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    Ok(plan)
+}
+
+/// Path of the branch description file, `.git/rona/branch_description.md`.
+///
+/// # Errors
+/// * If not in a git repository
+pub fn branch_description_path() -> Result<PathBuf> {
+    Ok(find_git_root()?
+        .join(COMMIT_MESSAGE_DIR)
+        .join(BRANCH_DESCRIPTION_FILE_NAME))
+}
+
+/// Builds the file a branch description is edited in: the current description, followed
+/// by editing instructions.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::branch::{build_branch_description, parse_branch_description};
+///
+/// let content = build_branch_description("feat/parser", Some("Rewrite of the parser"));
+/// assert!(content.starts_with("Rewrite of the parser\n\n# Describe branch feat/parser"));
+/// assert_eq!(parse_branch_description(&content)?, "Rewrite of the parser");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+pub fn build_branch_description(branch: &str, current: Option<&str>) -> String {
+    let mut content = current
+        .map(|description| format!("{description}\n"))
+        .unwrap_or_default();
+
+    let _ = write!(
+        content,
+        "\n# Describe branch {branch}: what it is for, the context its commits share.\n\
+         # Lines starting with '#' are ignored, an empty description leaves it unchanged.\n"
+    );
+
+    content
+}
+
+/// Removes the comment lines of an edited branch description.
+///
+/// # Errors
+/// * If the description is empty once comments are removed
+pub fn parse_branch_description(content: &str) -> Result<String> {
+    let description = content
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let description = description.trim();
+
+    if description.is_empty() {
+        return Err(RonaError::InvalidInput(
+            "Empty branch description - description left unchanged".to_string(),
+        ));
+    }
+
+    Ok(description.to_string())
+}
+
+/// Writes the current description of `branch` to `path`, to be edited.
+///
+/// # Errors
+/// * If there is no such local branch
+/// * If the description cannot be read
+/// * If the file cannot be written
+pub fn write_branch_description(path: &Path, branch: &str) -> Result<()> {
+    ensure_local_branch(branch)?;
+    let current = get_branch_description(branch)?;
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(path, build_branch_description(branch, current.as_deref()))?;
+
+    Ok(())
+}
+
+/// Reads the edited branch description file.
+///
+/// # Errors
+/// * If the file cannot be read
+/// * If the description is empty once comments are removed
+pub fn read_branch_description(path: &Path) -> Result<String> {
+    parse_branch_description(&read_to_string(path)?)
+}
+
+/// Adds a branch description under the header of a generated commit message, as the
+/// first paragraph of its body.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::branch::with_branch_description;
+///
+/// let message = "[4] (feat on parser) Add the lexer\n\n\n- `src/lexer.rs`:\n";
+/// assert_eq!(
+///     with_branch_description(message, "Rewrite of the parser"),
+///     "[4] (feat on parser) Add the lexer\n\nRewrite of the parser\n\n- `src/lexer.rs`:\n"
+/// );
+/// ```
+#[must_use]
+pub fn with_branch_description(message: &str, description: &str) -> String {
+    let (header, body) = message.split_once('\n').unwrap_or((message, ""));
+    let body = body.trim_start_matches('\n');
+
+    if body.is_empty() {
+        format!("{header}\n\n{description}\n")
+    } else {
+        format!("{header}\n\n{description}\n\n{body}")
+    }
+}
+
+/// Whether a branch matches one of the `protected_branches` glob patterns (e.g. `main`
+/// or `release/*`).
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::branch::is_protected_branch;
+///
+/// let patterns = ["main".to_string(), "release/*".to_string()];
+/// assert!(is_protected_branch("release/2.0", &patterns));
+/// assert!(!is_protected_branch("feat/login", &patterns));
+/// ```
+#[must_use]
+pub fn is_protected_branch(branch: &str, patterns: &[String]) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(branch)))
+}
+
+/// The ticket found in a branch name by `ticket_pattern`: its first capture group, or the
+/// whole match when it has none.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the pattern is not a valid regular expression
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::branch::branch_ticket;
+///
+/// let pattern = "[A-Z][A-Z0-9]+-[0-9]+";
+/// assert_eq!(branch_ticket("feat/PROJ-1234-fix-login", pattern)?.as_deref(), Some("PROJ-1234"));
+/// assert_eq!(branch_ticket("main", pattern)?, None);
+/// assert_eq!(branch_ticket("fix/gh-42", r"gh-(\d+)")?.as_deref(), Some("42"));
+/// # Ok::<(), rona::errors::RonaError>(())
+/// ```
+pub fn branch_ticket(branch: &str, pattern: &str) -> Result<Option<String>> {
+    let regex = Regex::new(pattern)
+        .map_err(|e| RonaError::InvalidInput(format!("Invalid ticket_pattern '{pattern}': {e}")))?;
+
+    Ok(regex.captures(branch).and_then(|captures| {
+        captures
+            .get(1)
+            .or_else(|| captures.get(0))
+            .map(|ticket| ticket.as_str().to_string())
+    }))
+}
+
+/// Adds a ticket to a generated commit message: before the subject completing the header,
+/// or in a `Refs` trailer. A subject already mentioning the ticket is left as it is.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::branch::{TicketPosition, with_ticket};
+///
+/// let message = "[4] (fix on fix-login) Fix the login\n\n- `src/auth.rs`:\n";
+/// assert_eq!(
+///     with_ticket(message, "PROJ-1234", TicketPosition::Header),
+///     "[4] (fix on fix-login) PROJ-1234 Fix the login\n\n- `src/auth.rs`:\n"
+/// );
+/// assert_eq!(
+///     with_ticket(message, "PROJ-1234", TicketPosition::Footer),
+///     "[4] (fix on fix-login) Fix the login\n\n- `src/auth.rs`:\n\nRefs: PROJ-1234\n"
+/// );
+/// ```
+#[must_use]
+pub fn with_ticket(message: &str, ticket: &str, position: TicketPosition) -> String {
+    match position {
+        TicketPosition::Footer => append_trailers(message, &[Trailer::new(TICKET_TRAILER, ticket)]),
+        TicketPosition::Header => {
+            let (header, rest) = message
+                .find('\n')
+                .map_or((message, ""), |index| message.split_at(index));
+            // The subject comes after the `(type on branch)` group of the header
+            let (group, subject) = header.split_once(')').unwrap_or(("", header));
+            if subject.contains(ticket) {
+                return message.to_string();
             }
+
+            let group = if group.is_empty() {
+                String::new()
+            } else {
+                format!("{group}) ")
+            };
+            let header = format!("{group}{ticket} {}", subject.trim_start());
+            format!("{}{rest}", header.trim_end())
         }
     }
 }
@@ -149,10 +897,10 @@ pub fn get_current_branch() -> Result<String> {
 ///     "main"
 /// );
 ///
-/// // Multiple prefixes are handled
+/// // Every matching prefix is removed
 /// assert_eq!(
 ///     format_branch_name(&commit_types, "feat/fix/complex-branch"),
-///     "fix/complex-branch"  // Only first matching prefix is removed
+///     "complex-branch"
 /// );
 /// ```
 ///
@@ -175,3 +923,241 @@ pub fn format_branch_name(commit_types: &[&str; 4], branch: &str) -> String {
 
     formatted_branch
 }
+
+/// The upstream of the current branch, with how far the branch diverged from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Upstream {
+    /// Short name of the upstream branch (e.g. `origin/main`)
+    pub name: String,
+    /// Commits of the branch not in the upstream
+    pub ahead: usize,
+    /// Commits of the upstream not in the branch
+    pub behind: usize,
+}
+
+/// Parses the `<ahead>\t<behind>` output of `git rev-list --left-right --count`.
+fn parse_ahead_behind(output: &str) -> Option<(usize, usize)> {
+    let mut counts = output.split_whitespace().map(str::parse);
+    match (counts.next(), counts.next()) {
+        (Some(Ok(ahead)), Some(Ok(behind))) => Some((ahead, behind)),
+        _ => None,
+    }
+}
+
+/// Counts the commits of `HEAD` not in `reference` (ahead) and of `reference` not in
+/// `HEAD` (behind).
+///
+/// # Errors
+///
+/// Returns an error if the reference does not exist or the commits cannot be counted.
+pub fn count_ahead_behind(reference: &str) -> Result<(usize, usize)> {
+    let range = format!("HEAD...{reference}");
+    let output = GitCommand::new()
+        .args(["rev-list", "--left-right", "--count", &range])
+        .output()?;
+
+    output
+        .status
+        .success()
+        .then(|| parse_ahead_behind(&String::from_utf8_lossy(&output.stdout)))
+        .flatten()
+        .ok_or_else(|| {
+            RonaError::Git(GitError::CommandFailed {
+                command: format!("git rev-list --left-right --count {range}"),
+                output: String::from_utf8_lossy(&output.stderr).to_string(),
+            })
+        })
+}
+
+/// Gets the upstream of the current branch and the ahead/behind counts against it.
+///
+/// # Errors
+///
+/// Returns an error if the commits cannot be counted.
+///
+/// # Returns
+///
+/// The upstream, or `None` if the current branch does not track one
+pub fn get_upstream() -> Result<Option<Upstream>> {
+    let output = GitCommand::new()
+        .args([
+            "rev-parse",
+            "--abbrev-ref",
+            "--symbolic-full-name",
+            "@{upstream}",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let (ahead, behind) = count_ahead_behind("@{upstream}")?;
+
+    Ok(Some(Upstream {
+        name,
+        ahead,
+        behind,
+    }))
+}
+
+/// Whether the current branch pointed at the tip of its upstream before.
+///
+/// The upstream commits were then rewritten locally (rebase, amend) rather than
+/// pushed from elsewhere, so forcing the push with `--force-with-lease` is safe.
+///
+/// # Errors
+///
+/// Returns an error if the current branch cannot be determined.
+pub fn is_upstream_in_reflog() -> Result<bool> {
+    let upstream = GitCommand::new()
+        .args(["rev-parse", "--verify", "--quiet", "@{upstream}"])
+        .output()?;
+
+    if !upstream.status.success() {
+        return Ok(false);
+    }
+
+    let upstream = String::from_utf8_lossy(&upstream.stdout).trim().to_string();
+    let reflog = GitCommand::new()
+        .args([
+            "reflog",
+            "show",
+            "--format=%H",
+            &format!("refs/heads/{}", get_current_branch()?),
+            "--",
+        ])
+        .output()?;
+
+    Ok(String::from_utf8_lossy(&reflog.stdout)
+        .lines()
+        .any(|hash| hash == upstream))
+}
+
+/// Finds the time of the latest push in the `<timestamp>\t<subject>` reflog lines of
+/// a remote-tracking branch, newest first.
+fn find_last_push(reflog: &str) -> Option<i64> {
+    reflog.lines().find_map(|line| {
+        let (timestamp, subject) = line.split_once('\t')?;
+        subject
+            .starts_with("update by push")
+            .then(|| timestamp.parse().ok())
+            .flatten()
+    })
+}
+
+/// Gets when the current branch was last pushed to its upstream.
+///
+/// The time is read from the reflog of the remote-tracking branch, so pushes made
+/// from another clone are not known.
+///
+/// # Arguments
+///
+/// * `upstream` - Short name of the upstream branch (e.g. `origin/main`)
+///
+/// # Errors
+///
+/// Returns an error if the git command cannot be run.
+///
+/// # Returns
+///
+/// The Unix timestamp of the last push, or `None` if no push is recorded
+pub fn get_last_push_time(upstream: &str) -> Result<Option<i64>> {
+    let output = GitCommand::new()
+        .args([
+            "reflog",
+            "show",
+            "--format=%ct%x09%gs",
+            &format!("refs/remotes/{upstream}"),
+            "--",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    Ok(find_last_push(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkout_targets() {
+        let refs = "refs/heads/main\nrefs/remotes/origin/main\nrefs/remotes/origin/fix\n\
+                    refs/remotes/team/gitea/fix\nrefs/remotes/team/gitea/docs\n";
+        let remotes = vec!["origin".to_string(), "team/gitea".to_string()];
+
+        let targets = checkout_targets(refs, &remotes, None);
+        assert_eq!(
+            targets.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["main", "origin/fix", "team/gitea/docs"]
+        );
+        assert!(targets[1].is_named("fix") && targets[1].is_named("origin/fix"));
+        assert_eq!(targets[2].branch(), "docs");
+    }
+
+    #[test]
+    fn test_with_branch_description() {
+        assert_eq!(
+            with_branch_description("(feat on parser)\n\n\n", "Rewrite of the parser\n\nSee #12"),
+            "(feat on parser)\n\nRewrite of the parser\n\nSee #12\n"
+        );
+        assert!(parse_branch_description("\n# Describe branch main\n").is_err());
+    }
+
+    #[test]
+    fn test_with_ticket() {
+        let message = "[2] (fix on PROJ-9-login)\n\n\n";
+        assert_eq!(
+            with_ticket(message, "PROJ-9", TicketPosition::Header),
+            "[2] (fix on PROJ-9-login) PROJ-9\n\n\n"
+        );
+        let message = "(fix on main) PROJ-9: Fix the login\n";
+        assert_eq!(
+            with_ticket(message, "PROJ-9", TicketPosition::Header),
+            message
+        );
+        assert!(branch_ticket("PROJ-9", "[A-Z+").is_err());
+    }
+
+    #[test]
+    fn test_parse_ahead_behind() {
+        assert_eq!(parse_ahead_behind("2\t5\n"), Some((2, 5)));
+        assert_eq!(parse_ahead_behind(""), None);
+    }
+
+    #[test]
+    fn test_resolve_default_branch() {
+        let refs = ["refs/heads/feature", "refs/remotes/origin/master"];
+        let exists = |reference: &str| refs.contains(&reference);
+
+        assert_eq!(
+            resolve_default_branch(Some("origin/develop"), Some("trunk"), exists),
+            Some(("develop".to_string(), "develop".to_string()))
+        );
+        assert_eq!(
+            resolve_default_branch(None, Some("trunk"), exists),
+            Some(("master".to_string(), "origin/master".to_string()))
+        );
+        assert_eq!(
+            resolve_default_branch(None, Some("trunk"), |_| false),
+            Some(("trunk".to_string(), "trunk".to_string()))
+        );
+        assert_eq!(resolve_default_branch(None, None, |_| false), None);
+    }
+
+    #[test]
+    fn test_find_last_push() {
+        let reflog = "1760600000\tfetch: fast-forward\n\
+                      1760500000\tupdate by push\n\
+                      1760400000\tupdate by push\n";
+
+        assert_eq!(find_last_push(reflog), Some(1_760_500_000));
+        assert_eq!(find_last_push("1760600000\tfetch: fast-forward\n"), None);
+    }
+}
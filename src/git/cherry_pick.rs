@@ -0,0 +1,182 @@
+//! Cherry-Pick Operations
+//!
+//! Applying commits of another branch with `git cherry-pick --no-commit`, each one then
+//! committed with its message adapted to the current branch: the stale `[N] (type on
+//! other-branch)` header becomes the one the commit gets here, with the next commit number
+//! and the current branch, and the rest of the message is kept.
+
+use log::debug;
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::{
+    exec::GitCommand,
+    rebase::RebaseCommit,
+    repository::has_staged_changes,
+    rewrite::{conventional_subject, parse_rona_header, rewrite_message},
+};
+
+/// Parses the `%H%x09%P%x09%s` lines of `git log` into the commits to pick.
+///
+/// # Errors
+/// * If a commit is a merge commit (its mainline would be ambiguous)
+fn parse_picked_commits(output: &str) -> Result<Vec<RebaseCommit>> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            Some((fields.next()?, fields.next()?, fields.next().unwrap_or_default()))
+        })
+        .map(|(hash, parents, subject)| {
+            if parents.split_whitespace().count() > 1 {
+                return Err(RonaError::InvalidInput(format!(
+                    "Cannot cherry-pick merge commit {hash} - use `git cherry-pick -m <parent>` instead"
+                )));
+            }
+            Ok(RebaseCommit {
+                hash: hash.to_string(),
+                subject: subject.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The commits named by the revisions, oldest first within a range: a range (`A..B`)
+/// lists its commits but the merges, any other revision names a single commit.
+///
+/// # Errors
+/// * If a revision does not name a commit, or names a merge commit
+/// * `RonaError::InvalidInput` - If the revisions name no commit (e.g. an empty range)
+pub fn list_picked_commits(revisions: &[String]) -> Result<Vec<RebaseCommit>> {
+    let mut commits = Vec::new();
+
+    for revision in revisions {
+        let mut command = GitCommand::new();
+        command.arg("log").arg("--format=%H%x09%P%x09%s");
+        if revision.contains("..") {
+            command.args(["--reverse", "--no-merges", revision.as_str()]);
+        } else {
+            command.arg("-1").arg(format!("{revision}^{{commit}}"));
+        }
+        commits.extend(parse_picked_commits(&command.arg("--").stdout()?)?);
+    }
+
+    if commits.is_empty() {
+        return Err(RonaError::InvalidInput(format!(
+            "No commit to cherry-pick in {}",
+            revisions.join(" ")
+        )));
+    }
+
+    Ok(commits)
+}
+
+/// The message of a picked commit adapted to the current branch, `None` when it is kept
+/// as is: the type of a rona header or of a conventional commit subject is kept, the
+/// other messages getting `fallback_type`, if any.
+///
+/// # Arguments
+/// * `original` - The message of the picked commit
+/// * `fallback_type` - The commit type of the messages without one
+/// * `commit_number` - The number of the new commit, if commits are numbered
+/// * `branch` - The current branch
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::cherry_pick::adapt_message;
+///
+/// let original = "[31] (fix on release/2.1) Guard the parser input\n\n- `src/parser.rs`:\n";
+/// assert_eq!(
+///     adapt_message(original, None, Some(58), "main").as_deref(),
+///     Some("[58] (fix on main) Guard the parser input\n\n- `src/parser.rs`:\n")
+/// );
+/// assert_eq!(adapt_message("Guard the parser input", None, Some(58), "main"), None);
+/// ```
+#[must_use]
+pub fn adapt_message(
+    original: &str,
+    fallback_type: Option<&str>,
+    commit_number: Option<u64>,
+    branch: &str,
+) -> Option<String> {
+    let (subject, body) = original.split_once('\n').unwrap_or((original, ""));
+
+    if let Some((commit_type, title)) = parse_rona_header(subject) {
+        let original = format!("{title}\n{body}");
+        return Some(rewrite_message(
+            &original,
+            commit_type,
+            commit_number,
+            branch,
+        ));
+    }
+
+    let commit_type = conventional_subject(subject)
+        .map(|(commit_type, _)| commit_type)
+        .or(fallback_type)?;
+    Some(rewrite_message(
+        original,
+        commit_type,
+        commit_number,
+        branch,
+    ))
+}
+
+/// Applies a commit to the index and the working tree without committing it.
+///
+/// # Arguments
+/// * `commit` - The commit to apply
+/// * `dry_run` - If true, only plan the cherry-pick
+///
+/// # Errors
+/// * If changes are already staged (they would end up in the commit)
+/// * If `git cherry-pick` fails, e.g. on conflicts
+pub fn cherry_pick_no_commit(commit: &RebaseCommit, dry_run: bool) -> Result<Plan> {
+    if has_staged_changes()? {
+        return Err(RonaError::Git(GitError::DirtyWorkingDirectory));
+    }
+
+    let mut plan = Plan::new();
+    plan.push(
+        ActionKind::CherryPick,
+        commit.to_string(),
+        vec!["--no-commit".to_string()],
+    );
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    debug!("Cherry-picking {}...", commit.hash);
+
+    GitCommand::new()
+        .args(["cherry-pick", "--no-commit", &commit.hash])
+        .stdout()?;
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adapt_message() {
+        assert_eq!(
+            adapt_message("feat(cli): add the flag", None, None, "feat/cli").as_deref(),
+            Some("(feat on cli) add the flag\n")
+        );
+        assert_eq!(
+            adapt_message("Add the flag\n\nBody", Some("chore"), Some(3), "main").as_deref(),
+            Some("[3] (chore on main) Add the flag\n\nBody\n")
+        );
+
+        let commits = parse_picked_commits("a1\tp1\tAdd parser\nb2\tp2\t\n").unwrap();
+        assert_eq!(commits[1].hash, "b2");
+        assert!(parse_picked_commits("m3\tp1 p2\tMerge branch 'x'\n").is_err());
+    }
+}
@@ -4,26 +4,78 @@
 //! and commit execution operations.
 
 use std::{
-    fs::{File, OpenOptions, read_to_string, write},
-    io::Write,
-    path::Path,
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    fs::read_to_string,
+    path::{Path, PathBuf},
     process::Command,
 };
 
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+
 use crate::{
     errors::{GitError, Result, RonaError},
-    git::branch::{format_branch_name, get_current_branch},
+    git::branch::{format_branch_name, get_current_branch, get_default_branch, head_name},
+    performance::join,
+    plan::{ActionKind, Plan},
     utils::find_project_root,
 };
 
 use super::{
-    files::get_ignore_patterns,
-    status::{process_deleted_files_for_commit_message, process_git_status, read_git_status},
+    emoji::{non_ascii_characters, strip_non_ascii_subject},
+    exec::GitCommand,
+    files::{IgnorePattern, get_ignore_patterns, is_ignored, write_commit_message},
+    hooks::{report_commit_hooks, skips_hooks},
+    issue::Issue,
+    message_diff::update_message_entries,
+    repository::{find_git_common_dir, find_git_root},
+    secrets::check_staged_secrets,
+    status::{StatusSnapshot, unquote_path},
+    trailers::{Trailer, append_trailers, is_identity},
+    validation::{ValidationRules, confirm_commit_message},
 };
 
-pub const COMMIT_MESSAGE_FILE_PATH: &str = "commit_message.md";
+/// Name of the commit message file.
+pub const COMMIT_MESSAGE_FILE_NAME: &str = "commit_message.md";
+/// Directory of the git directory holding the commit message file by default.
+pub const COMMIT_MESSAGE_DIR: &str = "rona";
 pub const COMMIT_TYPES: [&str; 4] = ["chore", "feat", "fix", "test"];
 
+/// Resolves the path of the commit message file.
+///
+/// A configured `commit_message_path` is relative to the project root (absolute paths
+/// are kept as-is). By default the file is kept in the git directory, at
+/// `.git/rona/commit_message.md`, out of the working tree and of editor file pickers.
+///
+/// # Arguments
+/// * `configured` - The `commit_message_path` of the configuration, if any
+///
+/// # Errors
+/// * If not in a git repository
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::commit::commit_message_path;
+///
+/// // `.git/rona/commit_message.md`
+/// let default_path = commit_message_path(None)?;
+///
+/// // `commit_message.md` at the project root
+/// let root_path = commit_message_path(Some("commit_message.md"))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn commit_message_path(configured: Option<&str>) -> Result<PathBuf> {
+    match configured.map(str::trim).filter(|path| !path.is_empty()) {
+        Some(path) => Ok(find_project_root()?.join(path)),
+        None => Ok(find_git_root()?
+            .join(COMMIT_MESSAGE_DIR)
+            .join(COMMIT_MESSAGE_FILE_NAME)),
+    }
+}
+
 /// Gets the total number of commits in the current branch.
 ///
 /// This function counts all commits reachable from the current HEAD,
@@ -39,7 +91,7 @@ pub const COMMIT_TYPES: [&str; 4] = ["chore", "feat", "fix", "test"];
 ///
 /// # Returns
 ///
-/// The total number of commits as a `u32`
+/// The total number of commits as a `u64`
 ///
 /// # Examples
 ///
@@ -54,47 +106,186 @@ pub const COMMIT_TYPES: [&str; 4] = ["chore", "feat", "fix", "test"];
 /// println!("Next commit will be #{}", next_commit_number);
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-pub fn get_current_commit_nb() -> Result<u32> {
-    let output = Command::new("git")
-        .args(["rev-list", "--count", "HEAD"])
+pub fn get_current_commit_nb() -> Result<u64> {
+    let unborn = find_git_root()
+        .ok()
+        .zip(find_git_common_dir().ok())
+        .and_then(|(git_dir, common_dir)| is_head_unborn(&git_dir, &common_dir));
+
+    // HEAD has no commit yet (fresh repository or orphan branch): count the other branches
+    if unborn == Some(true) {
+        return count_commits("--all");
+    }
+
+    count_commits("HEAD").or_else(|error| match unborn {
+        Some(_) => Err(error),
+        None => count_commits("--all").map_err(|_| error),
+    })
+}
+
+/// Whether HEAD points to a branch without commits, told from the refs of the git
+/// directory without running git.
+///
+/// # Returns
+/// * `None` - If it cannot be told from the files (e.g. reftable repositories)
+fn is_head_unborn(git_dir: &Path, common_dir: &Path) -> Option<bool> {
+    let head = read_to_string(git_dir.join("HEAD")).ok()?;
+    let Some(reference) = head.trim().strip_prefix("ref: ") else {
+        // A detached HEAD always points to a commit
+        return Some(false);
+    };
+
+    if common_dir.join(reference).is_file() {
+        return Some(false);
+    }
+    if common_dir.join("reftable").exists() {
+        return None;
+    }
+
+    let packed_refs = read_to_string(common_dir.join("packed-refs")).unwrap_or_default();
+    let packed = packed_refs.lines().any(|line| {
+        line.split_once(' ')
+            .is_some_and(|(_, name)| name == reference)
+    });
+
+    Some(!packed)
+}
+
+/// Counts the commits reachable from a revision or range (`git rev-list --count`).
+///
+/// # Errors
+/// * If the git command fails or its output is not a number
+fn count_commits(revision: &str) -> Result<u64> {
+    let output = GitCommand::new()
+        .args(["rev-list", "--count", revision])
         .output()?;
 
-    if output.status.success() {
-        let commit_count_output = String::from_utf8_lossy(&output.stdout);
-        let commit_count_str = commit_count_output.trim();
-        let commit_count = commit_count_str.parse::<u32>().map_err(|_| {
-            RonaError::Git(GitError::InvalidStatus {
-                output: format!("Invalid commit count: {commit_count_str}"),
-            })
-        })?;
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git rev-list --count {revision}"),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
 
-        Ok(commit_count)
-    } else {
-        // HEAD might not exist in a freshly initialized repository
-        // Try counting all commits across all branches
-        let fallback_output = Command::new("git")
-            .args(["rev-list", "--count", "--all"])
-            .output()?;
-
-        if fallback_output.status.success() {
-            let commit_count_output = String::from_utf8_lossy(&fallback_output.stdout);
-            let commit_count_str = commit_count_output.trim();
-            let commit_count = commit_count_str.parse::<u32>().map_err(|_| {
-                RonaError::Git(GitError::InvalidStatus {
-                    output: format!("Invalid commit count: {commit_count_str}"),
-                })
-            })?;
-
-            Ok(commit_count)
-        } else {
-            // Return original error from the HEAD command
-            let error_message = String::from_utf8_lossy(&output.stderr);
+    parse_commit_count(&String::from_utf8_lossy(&output.stdout))
+}
 
-            Err(RonaError::Git(GitError::CommandFailed {
-                command: "git rev-list --count HEAD".to_string(),
-                output: error_message.to_string(),
-            }))
+/// Parses the output of `git rev-list --count`.
+///
+/// # Errors
+/// * If the output is not a number
+fn parse_commit_count(output: &str) -> Result<u64> {
+    let count = output.trim();
+    count.parse().map_err(|_| {
+        RonaError::Git(GitError::InvalidStatus {
+            output: format!("Invalid commit count: {count}"),
+        })
+    })
+}
+
+/// How the `[N]` commit number of generated headers is computed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommitNumbering {
+    /// Count every commit reachable from HEAD
+    #[default]
+    Repo,
+    /// Count the commits since the branch diverged from the default branch
+    Branch,
+    /// Do not number commits
+    None,
+}
+
+/// What happens to the commit numbers in a shallow clone (e.g. a CI checkout), where the
+/// commits are counted from the truncated history.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShallowClone {
+    /// Number the commits anyway, with a warning
+    #[default]
+    Warn,
+    /// Leave the numbers out, as with `commit_number = "none"`
+    Omit,
+    /// Fetch the full history first (`git fetch --unshallow`)
+    Unshallow,
+}
+
+/// Gets the number of commits on the current branch since it diverged from the
+/// default branch (`git rev-list --count <default>..HEAD`).
+///
+/// On the default branch itself, the total commit count is used instead.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The default branch cannot be determined
+/// - The git command fails to execute
+/// - The commit count cannot be parsed as a number
+pub fn get_branch_commit_nb() -> Result<u64> {
+    let (default_branch, reference) = get_default_branch()?;
+
+    if get_current_branch()? == default_branch {
+        return get_current_commit_nb();
+    }
+
+    count_commits(&format!("{reference}..HEAD"))
+}
+
+/// Gets the number of commits already counted by the given numbering.
+///
+/// # Errors
+/// * If counting the commits fails
+///
+/// # Returns
+/// * `Option<u64>` - The commit count, or `None` when commits are not numbered
+pub fn get_numbered_commit_count(numbering: CommitNumbering) -> Result<Option<u64>> {
+    match numbering {
+        CommitNumbering::Repo => get_current_commit_nb().map(Some),
+        CommitNumbering::Branch => get_branch_commit_nb().map(Some),
+        CommitNumbering::None => Ok(None),
+    }
+}
+
+/// Gets the number of the next commit with the given numbering.
+///
+/// # Errors
+/// * If counting the commits fails
+///
+/// # Returns
+/// * `Option<u64>` - The next commit number, or `None` when commits are not numbered
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::commit::{CommitNumbering, next_commit_number};
+///
+/// if let Some(number) = next_commit_number(CommitNumbering::Branch)? {
+///     println!("Next commit will be #{number} on this branch");
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn next_commit_number(numbering: CommitNumbering) -> Result<Option<u64>> {
+    Ok(get_numbered_commit_count(numbering)?.map(|count| count + 1))
+}
+
+/// Gets the number an existing commit has with the given numbering, as if it had been
+/// generated when it was committed on the current branch.
+///
+/// # Errors
+/// * If the revision does not name a commit
+/// * If counting the commits fails
+pub fn commit_number_at(revision: &str, numbering: CommitNumbering) -> Result<Option<u64>> {
+    match numbering {
+        CommitNumbering::Repo => count_commits(revision).map(Some),
+        CommitNumbering::Branch => {
+            let (default_branch, reference) = get_default_branch()?;
+            if get_current_branch()? == default_branch {
+                count_commits(revision).map(Some)
+            } else {
+                count_commits(&format!("{reference}..{revision}")).map(Some)
+            }
         }
+        CommitNumbering::None => Ok(None),
     }
 }
 
@@ -126,7 +317,7 @@ pub fn get_current_commit_nb() -> Result<u32> {
 #[must_use]
 pub fn is_gpg_signing_available() -> bool {
     // Check if git has a signing key configured
-    let git_signing_key = Command::new("git")
+    let git_signing_key = GitCommand::new()
         .args(["config", "--get", "user.signingkey"])
         .output();
 
@@ -139,17 +330,11 @@ pub fn is_gpg_signing_available() -> bool {
         let signing_key = String::from_utf8_lossy(&output.stdout).trim().to_string();
 
         // Check if GPG is available and the key exists
-        let gpg_check = Command::new("gpg")
-            .args(["--list-secret-keys", &signing_key])
-            .output();
-
-        if let Ok(gpg_output) = gpg_check {
-            return gpg_output.status.success();
-        }
+        return has_secret_key(&signing_key);
     }
 
     // As a fallback, check if gpg.program is configured and accessible
-    let git_gpg_program = Command::new("git")
+    let git_gpg_program = GitCommand::new()
         .args(["config", "--get", "gpg.program"])
         .output();
 
@@ -172,266 +357,974 @@ pub fn is_gpg_signing_available() -> bool {
     }
 }
 
-/// Handles dry run output for commit operations.
+/// How a commit is signed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum CommitSigning {
+    /// Sign with the default key when GPG signing is available
+    #[default]
+    Auto,
+    /// Sign with a specific key (`-S<keyid>`), failing if the key is not available
+    Key(String),
+    /// Create an unsigned commit
+    Disabled,
+}
+
+impl CommitSigning {
+    /// Builds the signing mode from the `--unsigned` flag and the configured `signing_key`.
+    ///
+    /// `--unsigned` wins over the configured key.
+    #[must_use]
+    pub fn from_options(unsigned: bool, signing_key: Option<&str>) -> Self {
+        match (unsigned, signing_key) {
+            (true, _) => Self::Disabled,
+            (false, Some(key)) if !key.trim().is_empty() => Self::Key(key.trim().to_string()),
+            (false, _) => Self::Auto,
+        }
+    }
+}
+
+/// Lists the GPG secret keys as `<keyid> <user id>` lines.
 ///
-/// # Arguments
-/// * `file_content` - The commit message content
-/// * `unsigned` - Whether the commit should be unsigned
-/// * `filtered_args` - Additional git arguments
-fn handle_dry_run_output(file_content: &str, unsigned: bool, filtered_args: &[String]) {
-    println!("Would commit with message:");
-    println!("---");
-    println!("{}", file_content.trim());
-    println!("---");
-
-    let gpg_available = is_gpg_signing_available();
-    let would_sign = !unsigned && gpg_available;
-
-    if unsigned {
-        println!("Would create unsigned commit");
-    } else if would_sign {
-        println!("Would sign commit with -S flag");
-    } else {
-        println!("Would create unsigned commit (GPG signing not available)");
-        if !gpg_available {
-            println!("⚠️  Warning: GPG signing not available or not configured.");
-            println!("   To suppress this warning, use the --unsigned (-u) flag.");
+/// # Returns
+/// * `Vec<String>` - The secret keys, empty if GPG is not available
+#[must_use]
+pub fn list_secret_keys() -> Vec<String> {
+    let Ok(output) = Command::new("gpg")
+        .args(["--list-secret-keys", "--with-colons"])
+        .output()
+    else {
+        return Vec::new();
+    };
+
+    parse_secret_keys(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parses the `--with-colons` output of `gpg --list-secret-keys`.
+fn parse_secret_keys(output: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    let mut current_key: Option<String> = None;
+
+    for line in output.lines() {
+        let fields: Vec<&str> = line.split(':').collect();
+
+        match fields.first() {
+            Some(&"sec") => {
+                if let Some(key) = current_key.take() {
+                    keys.push(key);
+                }
+                current_key = fields.get(4).map(ToString::to_string);
+            }
+            Some(&"uid") => {
+                if let Some(key) = current_key.take() {
+                    let user_id = fields.get(9).copied().unwrap_or_default();
+                    keys.push(format!("{key} {user_id}"));
+                }
+            }
+            _ => {}
         }
     }
 
-    if !filtered_args.is_empty() {
-        println!("With additional args: {filtered_args:?}");
+    keys.extend(current_key);
+    keys
+}
+
+/// The format of the signing keys (`gpg.format`): `openpgp` (the default), `ssh` or `x509`.
+#[must_use]
+pub fn signing_format() -> String {
+    GitCommand::new()
+        .args(["config", "--get", "gpg.format"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .ok()
+        .filter(|format| !format.is_empty())
+        .unwrap_or_else(|| "openpgp".to_string())
+}
+
+/// Whether a GPG key is in the secret keyring.
+#[must_use]
+pub fn has_secret_key(key: &str) -> bool {
+    Command::new("gpg")
+        .args(["--list-secret-keys", key])
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Checks that a signing key can be used, GPG keys being looked up in the secret keyring.
+///
+/// SSH and X.509 keys (`gpg.format`) are passed to git as-is.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the GPG key is not in the secret keyring, listing the available keys
+fn validate_signing_key(key: &str) -> Result<()> {
+    if signing_format() != "openpgp" {
+        return Ok(());
+    }
+
+    if has_secret_key(key) {
+        return Ok(());
     }
+
+    let available = list_secret_keys();
+    let available = if available.is_empty() {
+        "No GPG secret key found".to_string()
+    } else {
+        format!("Available keys:\n  {}", available.join("\n  "))
+    };
+
+    Err(RonaError::InvalidInput(format!(
+        "Signing key `{key}` (from `signing_key`) is not in the GPG secret keyring.\n{available}"
+    )))
 }
 
-/// Configures signing for git commit and displays appropriate warnings.
+/// Decides how the commit will be signed and displays appropriate warnings.
+///
+/// Warnings are written to stderr so they never mix with machine-readable output.
 ///
 /// # Arguments
-/// * `command` - The git command to configure
-/// * `unsigned` - Whether signing should be disabled
-/// * `verbose` - Whether to show verbose output
+/// * `signing` - The requested signing mode
+///
+/// # Errors
+/// * If the configured signing key is not available
 ///
 /// # Returns
-/// * `bool` - Whether the commit will be signed
-fn configure_commit_signing(command: &mut Command, unsigned: bool, verbose: bool) -> bool {
-    let gpg_available = is_gpg_signing_available();
-    let should_sign = !unsigned && gpg_available;
-
-    if should_sign {
-        command.arg("-S");
-    } else if !unsigned && !gpg_available {
-        println!(
-            "⚠️  Warning: GPG signing not available or not configured. Creating unsigned commit."
-        );
-        println!("   To suppress this warning, use the --unsigned (-u) flag.");
-    } else if verbose && !unsigned {
-        println!("GPG signing not available, creating unsigned commit");
+/// * `Option<String>` - The `-S` (or `-S<keyid>`) argument, `None` for an unsigned commit
+fn resolve_commit_signing(signing: &CommitSigning) -> Result<Option<String>> {
+    match signing {
+        CommitSigning::Disabled => Ok(None),
+        CommitSigning::Key(key) => {
+            validate_signing_key(key)?;
+            Ok(Some(format!("-S{key}")))
+        }
+        CommitSigning::Auto => {
+            if is_gpg_signing_available() {
+                Ok(Some("-S".to_string()))
+            } else {
+                warn!(
+                    "⚠️  Warning: GPG signing not available or not configured. Creating unsigned commit.\n   To suppress this warning, use the --unsigned (-u) flag."
+                );
+                debug!("GPG signing not available, creating unsigned commit");
+                Ok(None)
+            }
+        }
     }
+}
+
+/// Date formats accepted by `--date` without a timezone, read in the local timezone.
+const LOCAL_DATE_FORMATS: [&str; 3] = ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"];
 
-    should_sign
+/// Parses the date of a commit: RFC 3339 (`2024-05-01T10:30:00+02:00`), a date and time
+/// in the local timezone (`2024-05-01 10:30[:00]`) or a day (`2024-05-01`, at midnight).
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the date is in none of these formats, or does not
+///   exist in the local timezone
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::commit::parse_commit_date;
+///
+/// let date = parse_commit_date("2024-05-01T10:30:00+02:00")?;
+/// assert_eq!(date.to_rfc3339(), "2024-05-01T10:30:00+02:00");
+/// assert!(parse_commit_date("2024-05-01 10:30").is_ok());
+/// assert!(parse_commit_date("yesterday").is_err());
+/// # Ok::<(), rona::errors::RonaError>(())
+/// ```
+pub fn parse_commit_date(value: &str) -> Result<DateTime<FixedOffset>> {
+    let value = value.trim();
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Ok(date);
+    }
+
+    let naive = LOCAL_DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|day| day.and_hms_opt(0, 0, 0))
+        })
+        .ok_or_else(|| {
+            RonaError::InvalidInput(format!(
+                "Invalid date '{value}': expected 'YYYY-MM-DD[ HH:MM[:SS]]' or an RFC 3339 date"
+            ))
+        })?;
+
+    Local
+        .from_local_datetime(&naive)
+        .single()
+        .map(|date| date.fixed_offset())
+        .ok_or_else(|| {
+            RonaError::InvalidInput(format!(
+                "Invalid date '{value}': it does not exist or is ambiguous in the local timezone"
+            ))
+        })
+}
+
+/// Builds the `--author` and `--date` arguments of `git commit`, validated beforehand so
+/// git is not run with an identity or a date it would reject or misread.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the author is not in the `Name <email>` format or
+///   the date cannot be parsed (see [`parse_commit_date`])
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::commit::authorship_args;
+///
+/// let args = authorship_args(Some("Jane Doe <jane@example.com>"), Some("2024-05-01T10:30:00Z"))?;
+/// assert_eq!(args, ["--author=Jane Doe <jane@example.com>", "--date=2024-05-01T10:30:00+00:00"]);
+/// assert!(authorship_args(Some("jane@example.com"), None).is_err());
+/// # Ok::<(), rona::errors::RonaError>(())
+/// ```
+pub fn authorship_args(author: Option<&str>, date: Option<&str>) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+
+    if let Some(author) = author {
+        if !is_identity(author.trim()) {
+            return Err(RonaError::InvalidInput(format!(
+                "Invalid author '{author}': expected 'Name <email>'"
+            )));
+        }
+        args.push(format!("--author={}", author.trim()));
+    }
+
+    if let Some(date) = date {
+        args.push(format!("--date={}", parse_commit_date(date)?.to_rfc3339()));
+    }
+
+    Ok(args)
+}
+
+/// Checks run before committing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CommitChecks {
+    /// Rules to validate the message with, or `None` to skip validation
+    pub validation: Option<ValidationRules>,
+    /// Whether to block the commit when the staged changes contain possible secrets
+    pub scan_secrets: bool,
+    /// Whether to bypass the `pre-commit` and `commit-msg` hooks (`--no-verify`)
+    pub no_verify: bool,
+    /// Whether to strip non-ASCII characters from the subject line (the `ascii` emoji policy)
+    pub ascii_subject: bool,
 }
 
 /// Commits files to the git repository.
 ///
-/// This function reads the commit message from `commit_message.md` and creates
+/// This function reads the commit message from the commit message file and creates
 /// a git commit with that message. Additional git arguments can be passed through.
-/// By default, commits are signed with `-S` if GPG signing is available, unless the unsigned flag is set.
+/// By default, commits are signed with `-S` if GPG signing is available; a specific key
+/// can be required with [`CommitSigning::Key`] and signing disabled with [`CommitSigning::Disabled`].
+/// When validation rules are given, the message is checked first and the user is asked
+/// whether to commit anyway if issues are found. When secret scanning is enabled, the
+/// commit is blocked if the staged changes contain possible secrets. Trailers missing
+/// from the message are appended to it, and non-ASCII characters are stripped from its
+/// subject line when [`CommitChecks::ascii_subject`] is set.
 ///
 /// # Arguments
+/// * `message_path` - Path of the commit message file (see [`commit_message_path`])
 /// * `args` - Additional arguments to pass to the git commit command
-/// * `unsigned` - If true, creates an unsigned commit (skips -S flag)
-/// * `verbose` - Whether to print verbose output during the operation
-/// * `dry_run` - If true, only show what would be committed without actually committing
+/// * `checks` - Message validation and secret scanning run before committing
+/// * `trailers` - Trailers (e.g. `Signed-off-by`) closing the message
+/// * `signing` - How the commit is signed
+/// * `dry_run` - If true, only plan the commit without actually committing
+///
+/// # Returns
+/// * `Plan` - The commit action performed (or that would be, in dry-run mode)
 ///
 /// # Errors
 /// * If the commit message file doesn't exist
 /// * If reading the commit message file fails
+/// * If the message fails validation and the user declines to commit
+/// * If the staged changes contain possible secrets
+/// * If the configured signing key is not available
 /// * If the git commit command fails
 /// * If not in a git repository
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use rona::git::commit::git_commit;
+/// use rona::git::{
+///     commit::{CommitChecks, CommitSigning, commit_message_path, git_commit},
+///     trailers::Trailer,
+///     validation::ValidationRules,
+/// };
+///
+/// let message = commit_message_path(None)?;
 ///
 /// // Commit with automatic GPG detection (default)
-/// git_commit(&[], false, false, false)?;
+/// git_commit(&message, &[], CommitChecks::default(), &[], &CommitSigning::Auto, false)?;
 ///
 /// // Unsigned commit
-/// git_commit(&[], true, false, false)?;
+/// git_commit(&message, &[], CommitChecks::default(), &[], &CommitSigning::Disabled, false)?;
+///
+/// // Commit signed with a specific key
+/// git_commit(&message, &[], CommitChecks::default(), &[], &CommitSigning::Key("3AA5C34371567BD2".to_string()), false)?;
 ///
 /// // Commit with additional git arguments
-/// git_commit(&["--amend".to_string()], false, true, false)?;
+/// git_commit(&message, &["--amend".to_string()], CommitChecks::default(), &[], &CommitSigning::Auto, false)?;
+///
+/// // Validate the message and scan the staged changes for secrets before committing
+/// let checks = CommitChecks {
+///     validation: Some(ValidationRules::default()),
+///     scan_secrets: true,
+///     no_verify: false,
+///     ascii_subject: false,
+/// };
+/// git_commit(&message, &[], checks, &[], &CommitSigning::Auto, false)?;
+///
+/// // Add a co-author trailer
+/// let co_author = Trailer::co_author("Jane Doe <jane@example.com>")?;
+/// git_commit(&message, &[], CommitChecks::default(), &[co_author], &CommitSigning::Auto, false)?;
+///
+/// // Bypass the pre-commit and commit-msg hooks
+/// let checks = CommitChecks { no_verify: true, ..CommitChecks::default() };
+/// git_commit(&message, &[], checks, &[], &CommitSigning::Auto, false)?;
 ///
 /// // Dry run to preview the commit
-/// git_commit(&[], false, false, true)?;
+/// git_commit(&message, &[], CommitChecks::default(), &[], &CommitSigning::Auto, true)?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-pub fn git_commit(args: &[String], unsigned: bool, verbose: bool, dry_run: bool) -> Result<()> {
-    if verbose {
-        println!("Committing files...");
+pub fn git_commit(
+    message_path: &Path,
+    args: &[String],
+    checks: CommitChecks,
+    trailers: &[Trailer],
+    signing: &CommitSigning,
+    dry_run: bool,
+) -> Result<Plan> {
+    git_commit_in(
+        &find_project_root()?,
+        message_path,
+        args,
+        checks,
+        trailers,
+        signing,
+        dry_run,
+    )
+}
+
+/// Commits files to the git repository rooted at `project_root`.
+///
+/// Same as [`git_commit`], without relying on the current working directory:
+/// git is run from `project_root`.
+///
+/// # Errors
+/// * Same as [`git_commit`]
+pub fn git_commit_in(
+    project_root: &Path,
+    message_path: &Path,
+    args: &[String],
+    checks: CommitChecks,
+    trailers: &[Trailer],
+    signing: &CommitSigning,
+    dry_run: bool,
+) -> Result<Plan> {
+    debug!("Committing files...");
+
+    if !message_path.exists() {
+        return Err(RonaError::Git(GitError::CommitMessageNotFound));
     }
 
-    let project_root = find_project_root()?;
-    let commit_file_path = project_root.join(COMMIT_MESSAGE_FILE_PATH);
+    commit_checked_in(
+        project_root,
+        &read_to_string(message_path)?,
+        args,
+        checks,
+        trailers,
+        signing,
+        dry_run,
+    )
+}
 
-    if !commit_file_path.exists() {
-        return Err(RonaError::Io(std::io::Error::other(
-            "Commit message file not found",
-        )));
+/// Commits the staged changes with the given message, after the same checks as
+/// [`git_commit`] (e.g. a message read from standard input rather than from the commit
+/// message file).
+///
+/// # Errors
+/// * Same as [`git_commit`], but for the commit message file
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::commit::{CommitChecks, CommitSigning, git_commit_checked};
+///
+/// let message = "(fix on main) Guard the parser input\n";
+/// git_commit_checked(message, &[], CommitChecks::default(), &[], &CommitSigning::Auto, false)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn git_commit_checked(
+    message: &str,
+    args: &[String],
+    checks: CommitChecks,
+    trailers: &[Trailer],
+    signing: &CommitSigning,
+    dry_run: bool,
+) -> Result<Plan> {
+    commit_checked_in(
+        &find_project_root()?,
+        message,
+        args,
+        checks,
+        trailers,
+        signing,
+        dry_run,
+    )
+}
+
+/// Runs the checks of [`git_commit`] on a message, then commits with it.
+fn commit_checked_in(
+    project_root: &Path,
+    message: &str,
+    args: &[String],
+    checks: CommitChecks,
+    trailers: &[Trailer],
+    signing: &CommitSigning,
+    dry_run: bool,
+) -> Result<Plan> {
+    let mut file_content = append_trailers(message, trailers);
+
+    if checks.ascii_subject {
+        let subject = file_content.lines().next().unwrap_or_default();
+        let characters = non_ascii_characters(subject);
+        if !characters.is_empty() {
+            warn!("⚠️  Removing non-ASCII characters from the subject line: {characters}");
+            file_content = strip_non_ascii_subject(&file_content);
+        }
     }
 
-    let file_content = read_to_string(commit_file_path)?;
+    if let Some(rules) = checks.validation {
+        confirm_commit_message(&file_content, rules, dry_run)?;
+    }
+
+    if checks.scan_secrets {
+        check_staged_secrets(project_root, dry_run)?;
+    }
+
+    let mut args = args.to_vec();
+    if checks.no_verify && !skips_hooks(&args) {
+        args.push("--no-verify".to_string());
+    }
+    report_commit_hooks(project_root, skips_hooks(&args))?;
+
+    commit_with_message_in(project_root, &file_content, &args, signing, dry_run)
+}
+
+/// Commits the staged changes with the given message.
+///
+/// This is the shared implementation behind [`git_commit`], usable when the message
+/// does not come from the commit message file (e.g. release commits).
+/// Signing follows the same rules as [`git_commit`].
+///
+/// # Arguments
+/// * `message` - The full commit message
+/// * `args` - Additional arguments to pass to the git commit command
+/// * `signing` - How the commit is signed
+/// * `dry_run` - If true, only plan the commit without actually committing
+///
+/// # Returns
+/// * `Plan` - The commit action performed (or that would be, in dry-run mode)
+///
+/// # Errors
+/// * If the configured signing key is not available
+/// * If the git commit command fails
+pub fn git_commit_with_message(
+    message: &str,
+    args: &[String],
+    signing: &CommitSigning,
+    dry_run: bool,
+) -> Result<Plan> {
+    commit_with_message_in(&find_project_root()?, message, args, signing, dry_run)
+}
+
+/// Commits the staged changes of the repository rooted at `project_root` with the given message.
+fn commit_with_message_in(
+    project_root: &Path,
+    message: &str,
+    args: &[String],
+    signing: &CommitSigning,
+    dry_run: bool,
+) -> Result<Plan> {
+    let mut commit_args = Vec::new();
+
+    if let Some(sign_arg) = resolve_commit_signing(signing)? {
+        commit_args.push(sign_arg);
+    }
 
     // Filter out conflicting flags
-    let filtered_args: Vec<String> = args
-        .iter()
-        .filter(|arg| !arg.starts_with("-c") && !arg.starts_with("--commit"))
-        .cloned()
-        .collect();
+    commit_args.extend(
+        args.iter()
+            .filter(|arg| !arg.starts_with("-c") && !arg.starts_with("--commit"))
+            .cloned(),
+    );
+
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Commit, message, commit_args.clone());
 
     if dry_run {
-        handle_dry_run_output(&file_content, unsigned, &filtered_args);
-        return Ok(());
+        return Ok(plan);
     }
 
-    let mut command = Command::new("git");
-    command.arg("commit");
+    let output = GitCommand::new()
+        .current_dir(project_root)
+        .arg("commit")
+        .arg("-m")
+        .arg(message)
+        .args(&commit_args)
+        .output()?;
 
-    // Configure signing and get signing status
-    configure_commit_signing(&mut command, unsigned, verbose);
+    handle_output("commit", &output)?;
 
-    command.arg("-m").arg(file_content).args(&filtered_args);
+    Ok(plan)
+}
 
-    let output = command.output()?;
-    handle_output("commit", &output, verbose)
+/// Suggested content pre-filling the generated commit message.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct CommitDraft {
+    /// Subject completing the commit header
+    pub subject: Option<String>,
+    /// Description of the changes of each file, keyed by path
+    #[serde(default)]
+    pub files: HashMap<String, String>,
 }
 
-/// Prepares the commit message.
-/// It creates the commit message file and empties it if it already exists.
-/// It also adds the modified / added files to the commit message file.
+/// How the file entries of a generated commit message are grouped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileGrouping {
+    /// A single list of entries
+    #[default]
+    None,
+    /// The entries under a `### <directory>` heading per directory (`### .` at the root)
+    Directory,
+}
+
+/// Files listed in the body of a generated commit message.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageFiles {
+    /// Files with staged changes, submodules included
+    pub changed: Vec<String>,
+    /// Submodules checking out a new commit
+    pub submodules: Vec<String>,
+    /// Files whose deletion is staged
+    pub deleted: Vec<String>,
+    /// How the entries of the files are grouped
+    pub grouping: FileGrouping,
+}
+
+impl MessageFiles {
+    /// The files staged in the status snapshot.
+    #[must_use]
+    pub fn staged(status: &StatusSnapshot) -> Self {
+        Self {
+            changed: status.staged_files(),
+            submodules: status.staged_submodules(),
+            deleted: status.staged_deletions(),
+            grouping: FileGrouping::None,
+        }
+    }
+
+    /// The files changed by a patch (`git diff`, `git show` or `git format-patch` output),
+    /// to write the message of changes that are not staged (e.g. a commit reworded during
+    /// an interactive rebase). Renamed files are listed with their new path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::git::MessageFiles;
+    ///
+    /// let patch = "diff --git a/src/lib.rs b/src/lib.rs\n\
+    ///              --- a/src/lib.rs\n\
+    ///              +++ b/src/lib.rs\n\
+    ///              @@ -1 +1 @@\n\
+    ///              -pub mod parse;\n\
+    ///              +pub mod parser;\n\
+    ///              diff --git a/src/parse.rs b/src/parser.rs\n\
+    ///              similarity index 100%\n\
+    ///              rename from src/parse.rs\n\
+    ///              rename to src/parser.rs\n\
+    ///              diff --git a/old.txt b/old.txt\n\
+    ///              deleted file mode 100644\n";
+    ///
+    /// let files = MessageFiles::from_patch(patch);
+    /// assert_eq!(files.changed, ["src/lib.rs", "src/parser.rs"]);
+    /// assert_eq!(files.deleted, ["old.txt"]);
+    /// ```
+    #[must_use]
+    pub fn from_patch(patch: &str) -> Self {
+        let mut files = Self::default();
+        // Path of the file being read and whether it is deleted
+        let mut current: Option<(String, bool)> = None;
+        let mut in_hunk = false;
+
+        for line in patch.lines() {
+            if let Some(header) = line.strip_prefix("diff --git ") {
+                files.push_patch_file(current.take());
+                current = Some((patch_header_path(header), false));
+                in_hunk = false;
+                continue;
+            }
+            let Some((file, deleted)) = current.as_mut().filter(|_| !in_hunk) else {
+                continue;
+            };
+
+            if line.starts_with("@@") {
+                in_hunk = true;
+            } else if line.starts_with("deleted file mode") {
+                *deleted = true;
+            } else if let Some(renamed) = line.strip_prefix("rename to ") {
+                *file = unquote_path(renamed);
+            } else if let Some(new_path) = line.strip_prefix("+++ ") {
+                // git appends a TAB to the paths containing spaces
+                let new_path = unquote_path(new_path.trim_end_matches('\t'));
+                if let Some(new_path) = new_path.strip_prefix("b/") {
+                    *file = new_path.to_string();
+                }
+            }
+        }
+        files.push_patch_file(current);
+
+        files
+    }
+
+    /// Adds a file read from a patch.
+    fn push_patch_file(&mut self, file: Option<(String, bool)>) {
+        let Some((path, deleted)) = file else {
+            return;
+        };
+        let list = if deleted {
+            &mut self.deleted
+        } else {
+            &mut self.changed
+        };
+        if !list.contains(&path) {
+            list.push(path);
+        }
+    }
+
+    /// The files changed in a commit range (`A..B`, `A...B`), or by a single commit.
+    ///
+    /// # Errors
+    /// * If the range does not name commits
+    pub fn from_range(range: &str) -> Result<Self> {
+        // A single commit stands for the changes it made
+        let range = if range.contains("..") {
+            range.to_string()
+        } else {
+            format!("{range}^!")
+        };
+
+        let mut command = GitCommand::new();
+        command.args(["diff", "--name-status", "-z", "-M", &range, "--"]);
+        let output = command.output()?;
+        if !output.status.success() {
+            return Err(RonaError::Git(GitError::CommandFailed {
+                command: command.to_string(),
+                output: String::from_utf8_lossy(&output.stderr).to_string(),
+            }));
+        }
+
+        Ok(parse_name_status(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// The files with their entries grouped by `grouping`.
+    #[must_use]
+    pub fn grouped(self, grouping: FileGrouping) -> Self {
+        Self { grouping, ..self }
+    }
+
+    /// Adds the files staged by a staging plan (e.g. a dry run of
+    /// [`git_add_with_exclude_patterns`](super::git_add_with_exclude_patterns)), to preview
+    /// the message of changes not staged yet.
+    pub fn add_plan(&mut self, plan: &Plan) {
+        fn add(files: &mut Vec<String>, file: &str) {
+            if !files.iter().any(|known| known == file) {
+                files.push(file.to_string());
+            }
+        }
+
+        for file in plan.targets(ActionKind::Stage) {
+            add(&mut self.changed, file);
+        }
+        for file in plan.targets(ActionKind::StageSubmodule) {
+            add(&mut self.changed, file);
+            add(&mut self.submodules, file);
+        }
+        for file in plan.targets(ActionKind::Delete) {
+            add(&mut self.deleted, file);
+        }
+    }
+}
+
+/// The path of the `b/` side of a `diff --git a/<path> b/<path>` header.
+fn patch_header_path(header: &str) -> String {
+    let path = if header.ends_with('"') {
+        header
+            .rfind(" \"b/")
+            .map_or(header, |start| &header[start + 1..])
+    } else {
+        header
+            .rfind(" b/")
+            .map_or(header, |start| &header[start + 1..])
+    };
+
+    let path = unquote_path(path);
+    path.strip_prefix("b/")
+        .map_or_else(|| path.clone(), ToString::to_string)
+}
+
+/// Parses the `git diff --name-status -z` output of a range into the files it changed,
+/// renamed and copied files being listed with their new path.
+fn parse_name_status(output: &str) -> MessageFiles {
+    let mut files = MessageFiles::default();
+    let mut fields = output.split('\0').filter(|field| !field.is_empty());
+
+    while let Some(status) = fields.next() {
+        let Some(mut path) = fields.next() else {
+            break;
+        };
+
+        match status.chars().next() {
+            Some('D') => files.deleted.push(path.to_string()),
+            Some('R' | 'C') => {
+                if let Some(new_path) = fields.next() {
+                    path = new_path;
+                }
+                files.changed.push(path.to_string());
+            }
+            _ => files.changed.push(path.to_string()),
+        }
+    }
+
+    files
+}
+
+/// Renders a generated commit message: the header, an entry per file and the footers.
 ///
 /// # Errors
-/// * If we cannot write to the commit message file
-/// * If we cannot read the git status
-/// * If we cannot process either git status or deleted files from the git status
-/// * If we cannot read the commitignore file
+/// * If the current branch, the commit number or the ignore patterns cannot be read
 ///
 /// # Arguments
-/// * `commit_type` - `&str` - The commit type
-/// * `verbose` - `bool` - Verbose the operation
-/// * `no_commit_number` - `bool` - Whether to include the commit number in the header
-pub fn generate_commit_message(
+/// * `files` - The files listed in the body
+/// * `commit_type` - The commit type
+/// * `subject` - Subject completing the header, over the issue title and the draft subject
+/// * `numbering` - How the commit number of the header is computed
+/// * `issue` - Issue referenced by the commit: its title completes the header and a
+///   `Closes #N` footer is added
+/// * `draft` - Suggested subject (used when the issue has no title) and file descriptions
+/// * `trailers` - Trailers (e.g. `Signed-off-by`) closing the message
+pub fn render_commit_message(
+    files: &MessageFiles,
     commit_type: &str,
-    verbose: bool,
-    no_commit_number: bool,
-) -> Result<()> {
-    let commit_message_path = Path::new(COMMIT_MESSAGE_FILE_PATH);
+    subject: Option<&str>,
+    numbering: CommitNumbering,
+    issue: Option<&Issue>,
+    draft: Option<&CommitDraft>,
+    trailers: &[Trailer],
+) -> Result<String> {
+    let project_root = find_project_root()?;
+    let mut message = String::new();
+
+    // The commit count, the branch and the ignore files are read concurrently
+    let (commit_number, (head, ignore_patterns)) = join(
+        || next_commit_number(numbering),
+        || join(head_name, || get_ignore_patterns(&project_root)),
+    );
+
+    // Write header
+    let title = subject
+        .or_else(|| issue.and_then(|issue| issue.title.as_deref()))
+        .or_else(|| draft.and_then(|draft| draft.subject.as_deref()));
+    message.push_str(&commit_header(commit_type, commit_number?, &head?, title));
 
-    // Empty the file if it exists
-    if commit_message_path.exists() {
-        write(commit_message_path, "")?;
+    // Get files to ignore
+    let ignore_patterns = ignore_patterns?;
+
+    let entries = file_entries(files, draft, &ignore_patterns);
+    match files.grouping {
+        FileGrouping::None => {
+            for (_, entry) in entries {
+                message.push_str(&entry);
+            }
+        }
+        FileGrouping::Directory => message.push_str(&group_by_directory(&entries)),
     }
 
-    // Get git status info
-    let git_status = read_git_status()?;
-    let modified_files = process_git_status(&git_status)?;
-    let deleted_files = process_deleted_files_for_commit_message(&git_status)?;
+    if let Some(issue) = issue {
+        let _ = writeln!(message, "{}", issue.closing_footer());
+    }
 
-    // Open the commit file for writing
-    let mut commit_file = OpenOptions::new()
-        .append(true)
-        .create(true)
-        .open(commit_message_path)?;
+    if !trailers.is_empty() {
+        // The closing footer is its own paragraph, separated from the trailers
+        if issue.is_some() {
+            message.push('\n');
+        }
+        let trailer_lines: Vec<String> = trailers.iter().map(ToString::to_string).collect();
+        let _ = writeln!(message, "{}", trailer_lines.join("\n"));
+    }
 
-    // Write header
-    write_commit_header(&mut commit_file, commit_type, no_commit_number)?;
+    Ok(message)
+}
 
-    // Get files to ignore
-    let ignore_patterns = get_ignore_patterns()?;
+/// The entry of each file listed in the body of a generated commit message, in order:
+/// the changed files not ignored (`.commitignore`, `.gitignore`), then the deleted ones.
+fn file_entries(
+    files: &MessageFiles,
+    draft: Option<&CommitDraft>,
+    ignore_patterns: &[IgnorePattern],
+) -> Vec<(String, String)> {
+    let mut entries = Vec::new();
 
     // Process modified files
-    for file in modified_files {
-        if !should_ignore_file(&file, &ignore_patterns)? {
-            writeln!(commit_file, "- `{file}`:\n\n\t\n")?;
+    for file in &files.changed {
+        if files.submodules.contains(file) {
+            entries.push((file.clone(), format!("- `{file}`: submodule updated\n\n")));
+        } else if !is_ignored(file, ignore_patterns) {
+            let description = draft
+                .and_then(|draft| draft.files.get(file))
+                .map(String::as_str)
+                .unwrap_or_default();
+            entries.push((file.clone(), format!("- `{file}`:\n\n\t{description}\n\n")));
         }
     }
 
     // Process deleted files
-    for file in deleted_files {
-        writeln!(commit_file, "- `{file}`: deleted\n")?;
+    for file in &files.deleted {
+        entries.push((file.clone(), format!("- `{file}`: deleted\n\n")));
     }
 
-    // Close the file
-    commit_file.flush()?;
+    entries
+}
 
-    if verbose {
-        println!("{COMMIT_MESSAGE_FILE_PATH} created ✅ ");
+/// Lists file entries under a `### <directory>` heading per directory, the directories
+/// sorted and the entries of each kept in order.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::commit::group_by_directory;
+///
+/// let entries = vec![
+///     ("src/cli/args.rs".to_string(), "- `src/cli/args.rs`:\n\n\t\n\n".to_string()),
+///     ("README.md".to_string(), "- `README.md`:\n\n\t\n\n".to_string()),
+///     ("src/cli/mod.rs".to_string(), "- `src/cli/mod.rs`: deleted\n\n".to_string()),
+/// ];
+///
+/// assert_eq!(
+///     group_by_directory(&entries),
+///     concat!(
+///         "### .\n\n- `README.md`:\n\n\t\n\n",
+///         "### src/cli\n\n- `src/cli/args.rs`:\n\n\t\n\n- `src/cli/mod.rs`: deleted\n\n",
+///     )
+/// );
+/// ```
+#[must_use]
+pub fn group_by_directory(entries: &[(String, String)]) -> String {
+    let mut groups: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (file, entry) in entries {
+        let directory = file
+            .rsplit_once('/')
+            .map_or(".", |(directory, _)| directory);
+        groups.entry(directory).or_default().push(entry);
     }
 
-    Ok(())
+    let mut grouped = String::new();
+    for (directory, entries) in groups {
+        let _ = write!(grouped, "### {directory}\n\n");
+        for entry in entries {
+            grouped.push_str(entry);
+        }
+    }
+    grouped
 }
 
-/// Writes the commit header to the commit file.
+/// Updates the file entries of a pending commit message to the files listed now, keeping
+/// what was written in it (see [`update_message_entries`]).
+///
+/// # Errors
+/// * If the ignore patterns cannot be read
 ///
 /// # Arguments
-/// * `commit_file` - The file to write to
-/// * `commit_type` - The type of commit
-/// * `no_commit_number` - Whether to include the commit number in the header
+/// * `previous` - The pending commit message
+/// * `files` - The files to list
+pub fn update_commit_message(previous: &str, files: &MessageFiles) -> Result<String> {
+    let ignore_patterns = get_ignore_patterns(&find_project_root()?)?;
+    let entries = file_entries(files, None, &ignore_patterns);
+
+    Ok(update_message_entries(previous, &entries))
+}
+
+/// Prepares the commit message.
+/// It creates the commit message file and empties it if it already exists.
+/// It also adds the modified / added files to the commit message file.
 ///
 /// # Errors
-/// * If writing to the file fails
-fn write_commit_header(
-    commit_file: &mut File,
+/// * If we cannot write to the commit message file
+/// * If we cannot read the git status
+/// * If we cannot process either git status or deleted files from the git status
+/// * If we cannot read the commitignore file
+///
+/// # Arguments
+/// * `message_path` - `&Path` - Path of the commit message file (see [`commit_message_path`])
+/// * `commit_type` - `&str` - The commit type
+/// * `subject` - `Option<&str>` - Subject completing the header (e.g. `--message`), over
+///   the issue title and the draft subject
+/// * `numbering` - `CommitNumbering` - How the commit number of the header is computed
+/// * `issue` - `Option<&Issue>` - Issue referenced by the commit: its title completes the
+///   header and a `Closes #N` footer is added
+/// * `draft` - `Option<&CommitDraft>` - Suggested subject (used when the issue has no title)
+///   and file descriptions pre-filling the message
+/// * `trailers` - `&[Trailer]` - Trailers (e.g. `Signed-off-by`) closing the message
+pub fn generate_commit_message(
+    message_path: &Path,
     commit_type: &str,
-    no_commit_number: bool,
+    subject: Option<&str>,
+    numbering: CommitNumbering,
+    issue: Option<&Issue>,
+    draft: Option<&CommitDraft>,
+    trailers: &[Trailer],
 ) -> Result<()> {
-    let branch_name = format_branch_name(&COMMIT_TYPES, &get_current_branch()?);
+    let files = MessageFiles::staged(&StatusSnapshot::read()?);
+    let message = render_commit_message(
+        &files,
+        commit_type,
+        subject,
+        numbering,
+        issue,
+        draft,
+        trailers,
+    )?;
 
-    if no_commit_number {
-        writeln!(commit_file, "({commit_type} on {branch_name})\n\n")?;
-    } else {
-        let commit_number = get_current_commit_nb()? + 1;
-        writeln!(
-            commit_file,
-            "[{commit_number}] ({commit_type} on {branch_name})\n\n"
-        )?;
-    }
+    write_commit_message(message_path, &message)?;
+
+    debug!("{} created ✅ ", message_path.display());
 
     Ok(())
 }
 
-/// Checks if a file should be ignored based on ignored patterns.
+/// Builds the header of the commit message.
 ///
 /// # Arguments
-/// * `file` - The file to check
-/// * `ignore_patterns` - Patterns to check against
-///
-/// # Errors
-/// * If checking file paths fails
-///
-/// # Returns
-/// * `true` if the file should be ignored, `false` otherwise
-fn should_ignore_file(file: &str, ignore_patterns: &[String]) -> Result<bool> {
-    use crate::utils::check_for_file_in_folder;
-
-    // Check if the file is directly in the ignore list
-    if ignore_patterns.contains(&file.to_string()) {
-        return Ok(true);
-    }
-
-    // Check if the file is in a folder that's in the ignore list
-    let file_path = Path::new(file);
-
-    for item in ignore_patterns {
-        let item_path = Path::new(item);
-
-        if check_for_file_in_folder(file_path, item_path)? {
-            return Ok(true);
+/// * `commit_type` - The type of commit
+/// * `commit_number` - The commit number of the header, if commits are numbered
+/// * `head` - The current branch (see [`head_name`])
+/// * `title` - Optional text completing the header (e.g. the referenced issue title)
+pub(super) fn commit_header(
+    commit_type: &str,
+    commit_number: Option<u64>,
+    head: &str,
+    title: Option<&str>,
+) -> String {
+    let branch_name = format_branch_name(&COMMIT_TYPES, head);
+    let title = title.map(|title| format!(" {title}")).unwrap_or_default();
+
+    match commit_number {
+        Some(commit_number) => {
+            format!("[{commit_number}] ({commit_type} on {branch_name}){title}\n\n\n")
         }
+        None => format!("({commit_type} on {branch_name}){title}\n\n\n"),
     }
-
-    Ok(false)
 }
 
 // Use the shared handle_output function from the parent module
@@ -440,6 +1333,83 @@ use super::handle_output;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_message_files_add_plan() {
+        let mut files = MessageFiles {
+            changed: vec!["src/main.rs".to_string()],
+            ..MessageFiles::default()
+        };
+
+        let mut plan = Plan::new();
+        plan.push(ActionKind::Stage, "src/main.rs", Vec::new());
+        plan.push(ActionKind::Stage, "README.md", Vec::new());
+        plan.push(ActionKind::StageSubmodule, "vendor/lib", Vec::new());
+        plan.push(ActionKind::Delete, "old.rs", Vec::new());
+        plan.push(ActionKind::Exclude, "debug.log", Vec::new());
+        files.add_plan(&plan);
+
+        assert_eq!(files.changed, ["src/main.rs", "README.md", "vendor/lib"]);
+        assert_eq!(files.submodules, ["vendor/lib"]);
+        assert_eq!(files.deleted, ["old.rs"]);
+    }
+
+    #[test]
+    fn test_message_files_from_diffs() {
+        let patch = "From 1a2b Mon Sep 17 00:00:00 2001\n\
+                     Subject: [PATCH] Add docs\n\
+                     ---\n\
+                     diff --git a/my notes.md b/my notes.md\n\
+                     new file mode 100644\n\
+                     --- /dev/null\n\
+                     +++ b/my notes.md\t\n\
+                     @@ -0,0 +1 @@\n\
+                     ++++ b/not-a-file\n\
+                     diff --git \"a/\\303\\251t\\303\\251.md\" \"b/\\303\\251t\\303\\251.md\"\n\
+                     --- \"a/\\303\\251t\\303\\251.md\"\n\
+                     +++ \"b/\\303\\251t\\303\\251.md\"\n\
+                     @@ -1 +1 @@\n\
+                     diff --git a/logo.png b/logo.png\n\
+                     Binary files a/logo.png and b/logo.png differ\n";
+
+        let files = MessageFiles::from_patch(patch);
+        assert_eq!(files.changed, ["my notes.md", "été.md", "logo.png"]);
+        assert!(files.deleted.is_empty());
+
+        let files = parse_name_status("M\0src/lib.rs\0D\0old.rs\0R087\0before.rs\0after.rs\0");
+        assert_eq!(files.changed, ["src/lib.rs", "after.rs"]);
+        assert_eq!(files.deleted, ["old.rs"]);
+    }
+
+    #[test]
+    fn test_parse_commit_count() {
+        assert_eq!(parse_commit_count("70000\n").unwrap(), 70_000);
+        assert_eq!(parse_commit_count("5000000000").unwrap(), 5_000_000_000);
+        assert!(parse_commit_count("fatal").is_err());
+    }
+
+    #[test]
+    fn test_is_head_unborn() {
+        let dir = TempDir::new().unwrap();
+        let git_dir = dir.path();
+        std::fs::write(git_dir.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        assert_eq!(is_head_unborn(git_dir, git_dir), Some(true));
+
+        std::fs::write(
+            git_dir.join("packed-refs"),
+            "# pack-refs with: peeled fully-peeled sorted\n0123abcd refs/heads/main\n",
+        )
+        .unwrap();
+        assert_eq!(is_head_unborn(git_dir, git_dir), Some(false));
+
+        std::fs::write(git_dir.join("HEAD"), "0123abcd\n").unwrap();
+        assert_eq!(is_head_unborn(git_dir, git_dir), Some(false));
+
+        std::fs::write(git_dir.join("HEAD"), "ref: refs/heads/orphan\n").unwrap();
+        std::fs::create_dir(git_dir.join("reftable")).unwrap();
+        assert_eq!(is_head_unborn(git_dir, git_dir), None);
+    }
 
     #[test]
     fn test_gpg_signing_available() {
@@ -450,6 +1420,46 @@ mod tests {
         // but we verify the function executes without errors
     }
 
+    #[test]
+    fn test_commit_signing_from_options() {
+        assert_eq!(
+            CommitSigning::from_options(false, None),
+            CommitSigning::Auto
+        );
+        assert_eq!(
+            CommitSigning::from_options(false, Some(" ABCD1234 ")),
+            CommitSigning::Key("ABCD1234".to_string())
+        );
+        assert_eq!(
+            CommitSigning::from_options(false, Some("")),
+            CommitSigning::Auto
+        );
+        assert_eq!(
+            CommitSigning::from_options(true, Some("ABCD1234")),
+            CommitSigning::Disabled
+        );
+    }
+
+    #[test]
+    fn test_parse_secret_keys() {
+        let output = "\
+sec:u:255:22:3AA5C34371567BD2:1700000000:::u:::scESC:::+:::ed25519:::0:
+fpr:::::::::0123456789ABCDEF0123456789ABCDEF3AA5C343:
+uid:u::::1700000000::HASH::Jane Doe <jane@example.com>::::::::::0:
+ssb:u:255:18:42B317FD4BA89E7A:1700000000::::::e:::+:::cv25519::
+sec:u:4096:1:1111222233334444:1600000000:::u:::scESC:::+::::::0:
+";
+
+        assert_eq!(
+            parse_secret_keys(output),
+            vec![
+                "3AA5C34371567BD2 Jane Doe <jane@example.com>".to_string(),
+                "1111222233334444".to_string(),
+            ]
+        );
+        assert!(parse_secret_keys("").is_empty());
+    }
+
     #[test]
     fn test_git_commit_dry_run_with_unsigned() {
         use tempfile::TempDir;
@@ -458,7 +1468,7 @@ mod tests {
         let temp_path = temp_dir.path();
 
         // Initialize git repository
-        Command::new("git")
+        GitCommand::new()
             .current_dir(temp_path)
             .arg("init")
             .output()
@@ -466,17 +1476,19 @@ mod tests {
 
         // Create commit message file
         let commit_msg = "[1] (test on main)\n\n- `test.txt`:\n\n\t\n";
-        write(temp_path.join("commit_message.md"), commit_msg).unwrap();
-
-        // Change to temp directory
-        let original_dir = std::env::current_dir().unwrap();
-        std::env::set_current_dir(temp_path).unwrap();
+        let message_path = temp_path.join(COMMIT_MESSAGE_FILE_NAME);
+        std::fs::write(&message_path, commit_msg).unwrap();
 
         // Test dry run with unsigned flag - should not show warning
-        let result = git_commit(&[], true, false, true);
-
-        // Restore original directory
-        std::env::set_current_dir(original_dir).unwrap();
+        let result = git_commit_in(
+            temp_path,
+            &message_path,
+            &[],
+            CommitChecks::default(),
+            &[],
+            &CommitSigning::Disabled,
+            true,
+        );
 
         // Should succeed without errors
         assert!(result.is_ok());
@@ -0,0 +1,193 @@
+//! Conflict Resolution
+//!
+//! Listing of the files left conflicted by a stopped merge, rebase, cherry-pick or revert,
+//! staging of the resolved ones and continuing or aborting the operation.
+
+use std::{fs::read_to_string, path::Path};
+
+use log::debug;
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::{
+    exec::GitCommand,
+    handle_output,
+    repository::{OperationInProgress, get_top_level_path},
+    staging::literal_pathspec,
+    status::unquote_path,
+};
+
+/// Conflict markers opening and closing the conflicting hunks of a file.
+const CONFLICT_MARKERS: [&str; 2] = ["<<<<<<< ", ">>>>>>> "];
+
+/// How a stopped operation is finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinishAction {
+    /// Resume the operation (`--continue`)
+    Continue,
+    /// Cancel the operation and restore the previous state (`--abort`)
+    Abort,
+}
+
+impl FinishAction {
+    /// The git flag of the action.
+    #[must_use]
+    pub const fn flag(self) -> &'static str {
+        match self {
+            Self::Continue => "--continue",
+            Self::Abort => "--abort",
+        }
+    }
+}
+
+/// Lists the conflicted files, relative to the repository root.
+///
+/// # Errors
+/// * If the git diff command fails
+pub fn list_conflicted_files() -> Result<Vec<String>> {
+    let output = GitCommand::new()
+        .args(["diff", "--name-only", "--diff-filter=U"])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: "git diff --name-only --diff-filter=U".to_string(),
+            output: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }));
+    }
+
+    let mut files: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(unquote_path)
+        .collect();
+    // Files conflicted on several stages are listed once per stage
+    files.dedup();
+
+    Ok(files)
+}
+
+/// Whether a file still contains conflict markers.
+///
+/// Unreadable files (e.g. deleted on one side) are reported as resolved.
+#[must_use]
+pub fn has_conflict_markers(path: &Path) -> bool {
+    read_to_string(path).is_ok_and(|content| {
+        content.lines().any(|line| {
+            CONFLICT_MARKERS
+                .iter()
+                .any(|marker| line.starts_with(marker))
+        })
+    })
+}
+
+/// Marks conflicted files as resolved by staging them.
+///
+/// # Arguments
+/// * `files` - The resolved files, relative to the repository root
+/// * `dry_run` - If true, only plan the staging
+///
+/// # Errors
+/// * If the git add command fails
+pub fn mark_resolved(files: &[String], dry_run: bool) -> Result<Plan> {
+    let mut plan = Plan::new();
+    for file in files {
+        plan.push(ActionKind::Stage, file, Vec::new());
+    }
+
+    if dry_run || files.is_empty() {
+        return Ok(plan);
+    }
+
+    let output = GitCommand::new()
+        .current_dir(get_top_level_path()?)
+        .args(["add", "--"])
+        .args(files.iter().map(|file| literal_pathspec(file)))
+        .output()?;
+
+    handle_output("add", &output)?;
+
+    Ok(plan)
+}
+
+/// Continues or aborts the operation in progress.
+///
+/// The command inherits the terminal, so git can open the editor for the message
+/// of the commit concluding a merge or a rebase step.
+///
+/// # Arguments
+/// * `operation` - The operation in progress
+/// * `action` - Whether to continue or abort it
+/// * `dry_run` - If true, only plan the action
+///
+/// # Errors
+/// * If the git command fails (e.g. conflicts are left, or the next rebase step conflicts)
+pub fn finish_operation(
+    operation: OperationInProgress,
+    action: FinishAction,
+    dry_run: bool,
+) -> Result<Plan> {
+    let kind = match action {
+        FinishAction::Continue => ActionKind::Continue,
+        FinishAction::Abort => ActionKind::Abort,
+    };
+
+    let mut plan = Plan::new();
+    plan.push(kind, operation.command(), vec![action.flag().to_string()]);
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    debug!("Running git {operation} {}...", action.flag());
+
+    let status = GitCommand::new()
+        .args([operation.command(), action.flag()])
+        .status()?;
+
+    if !status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git {operation} {}", action.flag()),
+            output: format!("exited with {status}"),
+        }));
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::write;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_has_conflict_markers() {
+        let dir = TempDir::new().unwrap();
+        let conflicted = dir.path().join("conflicted.rs");
+        let resolved = dir.path().join("resolved.rs");
+
+        write(
+            &conflicted,
+            "fn main() {\n<<<<<<< HEAD\n    ours();\n=======\n    theirs();\n>>>>>>> feature\n}\n",
+        )
+        .unwrap();
+        write(&resolved, "fn main() {\n    both();\n}\n").unwrap();
+
+        assert!(has_conflict_markers(&conflicted));
+        assert!(!has_conflict_markers(&resolved));
+        assert!(!has_conflict_markers(&dir.path().join("missing.rs")));
+    }
+
+    #[test]
+    fn test_finish_operation_dry_run() {
+        let plan = finish_operation(OperationInProgress::Merge, FinishAction::Abort, true).unwrap();
+
+        assert_eq!(plan.actions()[0].kind, ActionKind::Abort);
+        assert_eq!(plan.actions()[0].target, "merge");
+        assert!(plan.render_text().contains("Would abort the merge"));
+    }
+}
@@ -0,0 +1,239 @@
+//! Diff Operations
+//!
+//! Staged changes viewer wrapping `git diff --cached`, with word-diff and color
+//! options and a summary of the changed files, insertions and deletions.
+
+use std::fmt;
+
+use clap::ValueEnum;
+
+use crate::errors::{GitError, Result, RonaError};
+
+use super::exec::GitCommand;
+
+/// When to color the diff.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum DiffColor {
+    /// Color when writing to a terminal
+    #[default]
+    Auto,
+    /// Always color
+    Always,
+    /// Never color
+    Never,
+}
+
+/// Options of the staged changes viewer.
+#[derive(Debug, Clone, Default)]
+pub struct DiffOptions {
+    /// Compare the staged changes against this ref instead of `HEAD`
+    pub against: Option<String>,
+    /// Highlight changed words instead of whole lines
+    pub word_diff: bool,
+    /// When to color the diff
+    pub color: DiffColor,
+}
+
+impl DiffOptions {
+    /// Builds the `git diff` arguments, `colored` being the resolved color choice.
+    #[must_use]
+    pub fn to_args(&self, colored: bool) -> Vec<String> {
+        let mut args = vec![
+            "diff".to_string(),
+            "--cached".to_string(),
+            format!("--color={}", if colored { "always" } else { "never" }),
+        ];
+
+        if self.word_diff {
+            args.push(format!(
+                "--word-diff={}",
+                if colored { "color" } else { "plain" }
+            ));
+        }
+
+        args.extend(self.against.clone());
+        args
+    }
+}
+
+/// Number of files, insertions and deletions of a diff.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffStat {
+    /// Files changed
+    pub files: usize,
+    /// Lines inserted
+    pub insertions: usize,
+    /// Lines deleted
+    pub deletions: usize,
+}
+
+impl DiffStat {
+    /// Parses the `git diff --shortstat` line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::git::diff::DiffStat;
+    ///
+    /// let stat = DiffStat::parse(" 3 files changed, 10 insertions(+), 2 deletions(-)");
+    /// assert_eq!((stat.files, stat.insertions, stat.deletions), (3, 10, 2));
+    /// ```
+    #[must_use]
+    pub fn parse(shortstat: &str) -> Self {
+        let mut stat = Self::default();
+
+        for part in shortstat.trim().split(", ") {
+            let Some((count, label)) = part.split_once(' ') else {
+                continue;
+            };
+            let Ok(count) = count.parse() else {
+                continue;
+            };
+
+            if label.starts_with("file") {
+                stat.files = count;
+            } else if label.starts_with("insertion") {
+                stat.insertions = count;
+            } else if label.starts_with("deletion") {
+                stat.deletions = count;
+            }
+        }
+
+        stat
+    }
+
+    /// Whether the diff is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.files == 0
+    }
+}
+
+impl fmt::Display for DiffStat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+
+        write!(
+            f,
+            "{} file{} changed, {} insertion{}(+), {} deletion{}(-)",
+            self.files,
+            plural(self.files),
+            self.insertions,
+            plural(self.insertions),
+            self.deletions,
+            plural(self.deletions)
+        )
+    }
+}
+
+/// Runs a `git diff` and returns its stdout.
+fn run_diff(args: &[String]) -> Result<String> {
+    let output = GitCommand::new().args(args).output()?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git {}", args.join(" ")),
+            output: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }))
+    }
+}
+
+/// Returns the staged changes and their summary.
+///
+/// # Arguments
+/// * `options` - The ref to compare against, word-diff and color options
+/// * `colored` - Whether the diff is colored (the resolved `options.color`)
+///
+/// # Returns
+/// * `(String, DiffStat)` - The diff and its files, insertions and deletions
+///
+/// # Errors
+/// * If the git diff command fails (e.g. unknown ref)
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::diff::{DiffOptions, staged_diff};
+///
+/// let options = DiffOptions { against: Some("main".to_string()), ..Default::default() };
+/// let (diff, stat) = staged_diff(&options, false)?;
+/// println!("{diff}\n{stat}");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn staged_diff(options: &DiffOptions, colored: bool) -> Result<(String, DiffStat)> {
+    let diff = run_diff(&options.to_args(colored))?;
+
+    let mut stat_args = vec![
+        "diff".to_string(),
+        "--cached".to_string(),
+        "--shortstat".to_string(),
+    ];
+    stat_args.extend(options.against.clone());
+    let stat = DiffStat::parse(&run_diff(&stat_args)?);
+
+    Ok((diff, stat))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_stat_parse() {
+        let stat = DiffStat::parse(" 1 file changed, 1 insertion(+)\n");
+        assert_eq!(
+            stat,
+            DiffStat {
+                files: 1,
+                insertions: 1,
+                deletions: 0
+            }
+        );
+
+        let stat = DiffStat::parse(" 2 files changed, 5 deletions(-)");
+        assert_eq!(stat.deletions, 5);
+        assert_eq!(stat.insertions, 0);
+
+        assert!(DiffStat::parse("").is_empty());
+    }
+
+    #[test]
+    fn test_diff_stat_display() {
+        let stat = DiffStat {
+            files: 1,
+            insertions: 10,
+            deletions: 1,
+        };
+
+        assert_eq!(
+            stat.to_string(),
+            "1 file changed, 10 insertions(+), 1 deletion(-)"
+        );
+    }
+
+    #[test]
+    fn test_diff_options_to_args() {
+        let options = DiffOptions {
+            against: Some("main".to_string()),
+            word_diff: true,
+            color: DiffColor::Auto,
+        };
+
+        assert_eq!(
+            options.to_args(false),
+            vec![
+                "diff",
+                "--cached",
+                "--color=never",
+                "--word-diff=plain",
+                "main"
+            ]
+        );
+        assert_eq!(
+            DiffOptions::default().to_args(true),
+            vec!["diff", "--cached", "--color=always"]
+        );
+    }
+}
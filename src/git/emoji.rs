@@ -0,0 +1,172 @@
+//! Commit Subject Emoji Policy
+//!
+//! Teams either require emoji in commit subjects ([gitmoji](https://gitmoji.dev)) or
+//! forbid anything outside ASCII. The `emoji` configuration option picks the policy:
+//! `gitmoji` adds the emoji of the commit type to generated headers, `ascii` strips
+//! non-ASCII characters (emoji from issue titles, accents...) from the subject line.
+
+use serde::{Deserialize, Serialize};
+
+/// How emoji and other non-ASCII characters are handled in commit subjects.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmojiPolicy {
+    /// Leave the subject as written
+    #[default]
+    Allow,
+    /// Add the gitmoji of the commit type to generated headers
+    Gitmoji,
+    /// Keep the subject line strictly ASCII
+    Ascii,
+}
+
+/// The gitmoji of a commit type, if it has one.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::emoji::gitmoji;
+///
+/// assert_eq!(gitmoji("feat"), Some("✨"));
+/// assert_eq!(gitmoji("wibble"), None);
+/// ```
+#[must_use]
+pub fn gitmoji(commit_type: &str) -> Option<&'static str> {
+    Some(match commit_type {
+        "feat" => "✨",
+        "fix" => "🐛",
+        "docs" => "📝",
+        "style" => "🎨",
+        "refactor" => "♻️",
+        "perf" => "⚡️",
+        "test" => "✅",
+        "build" => "📦️",
+        "ci" => "👷",
+        "chore" => "🔧",
+        "revert" => "⏪️",
+        "security" => "🔒️",
+        "deps" => "⬆️",
+        "wip" => "🚧",
+        _ => return None,
+    })
+}
+
+/// The non-ASCII characters of a text, in order.
+#[must_use]
+pub fn non_ascii_characters(text: &str) -> String {
+    text.chars().filter(|c| !c.is_ascii()).collect()
+}
+
+/// Removes the non-ASCII characters of a text, collapsing the spaces left around them.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::emoji::strip_non_ascii;
+///
+/// assert_eq!(strip_non_ascii("✨ Add the café menu"), "Add the caf menu");
+/// ```
+#[must_use]
+pub fn strip_non_ascii(text: &str) -> String {
+    text.chars()
+        .filter(char::is_ascii)
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Applies a function to the subject line of a message, keeping the rest as is.
+fn map_subject(message: &str, map: impl FnOnce(&str) -> String) -> String {
+    match message.split_once('\n') {
+        Some((subject, rest)) => format!("{}\n{rest}", map(subject)),
+        None => map(message),
+    }
+}
+
+/// Inserts the emoji after the `(type on branch)` group of a header, or before the
+/// header when it has no such group (custom templates).
+fn insert_gitmoji(subject: &str, commit_type: &str, emoji: &str) -> String {
+    if subject.contains(emoji) {
+        return subject.to_string();
+    }
+
+    let group = format!("({commit_type} on ");
+    let group_end = subject
+        .find(&group)
+        .and_then(|start| subject[start..].find(')').map(|end| start + end + 1));
+
+    match group_end {
+        Some(end) => format!("{} {emoji}{}", &subject[..end], &subject[end..]),
+        None => format!("{emoji} {subject}"),
+    }
+}
+
+/// Applies the emoji policy to the header of a generated commit message.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::emoji::{EmojiPolicy, apply_emoji_policy};
+///
+/// let message = "[4] (feat on main) Add parser\n\n- `src/parser.rs`:\n";
+/// assert_eq!(
+///     apply_emoji_policy(message, "feat", EmojiPolicy::Gitmoji),
+///     "[4] (feat on main) ✨ Add parser\n\n- `src/parser.rs`:\n"
+/// );
+/// ```
+#[must_use]
+pub fn apply_emoji_policy(message: &str, commit_type: &str, policy: EmojiPolicy) -> String {
+    match policy {
+        EmojiPolicy::Allow => message.to_string(),
+        EmojiPolicy::Gitmoji => match gitmoji(commit_type) {
+            Some(emoji) => map_subject(message, |subject| {
+                insert_gitmoji(subject, commit_type, emoji)
+            }),
+            None => message.to_string(),
+        },
+        EmojiPolicy::Ascii => strip_non_ascii_subject(message),
+    }
+}
+
+/// Removes the non-ASCII characters of the subject line of a message.
+#[must_use]
+pub fn strip_non_ascii_subject(message: &str) -> String {
+    map_subject(message, strip_non_ascii)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gitmoji_headers() {
+        let apply = |message| apply_emoji_policy(message, "fix", EmojiPolicy::Gitmoji);
+
+        assert_eq!(apply("(fix on main)\n\n\n"), "(fix on main) 🐛\n\n\n");
+        assert_eq!(
+            apply("[2] (fix on feat/login) 🐛 Fix login"),
+            "[2] (fix on feat/login) 🐛 Fix login"
+        );
+        assert_eq!(apply("Fix login - main"), "🐛 Fix login - main");
+        assert_eq!(
+            apply_emoji_policy("(wibble on main)", "wibble", EmojiPolicy::Gitmoji),
+            "(wibble on main)"
+        );
+    }
+
+    #[test]
+    fn test_ascii_subject() {
+        let message = "[3] (feat on main) 🚀 Ship the résumé page\n\n- `café.md`: done\n";
+
+        assert_eq!(
+            apply_emoji_policy(message, "feat", EmojiPolicy::Ascii),
+            "[3] (feat on main) Ship the rsum page\n\n- `café.md`: done\n"
+        );
+        assert_eq!(non_ascii_characters("🚀 résumé"), "🚀éé");
+        assert_eq!(
+            apply_emoji_policy(message, "feat", EmojiPolicy::Allow),
+            message
+        );
+    }
+}
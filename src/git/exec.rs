@@ -0,0 +1,482 @@
+//! Running Git
+//!
+//! Every `git` subprocess of rona is described by a [`GitCommand`] (arguments, working
+//! directory, environment, timeout) and run by a [`GitRunner`]: the [`SystemRunner`]
+//! spawning git, unless another runner is installed for the current thread with
+//! [`with_runner`] (e.g. the `MockRunner` of the `testing` feature, answering commands
+//! with canned outputs).
+//!
+//! Configuration values set with [`set_config_override`] (e.g. the `author_email` of the
+//! active profile) are given to every invocation, as `git -c key=value`.
+//!
+//! With tracing enabled (`--trace-git` or `RONA_TRACE_GIT`), each invocation is printed on
+//! stderr before it runs, then its exit code and duration, so bug reports show exactly what
+//! rona ran and which command hung.
+
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    env,
+    ffi::{OsStr, OsString},
+    fmt::{self, Write},
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus, Output, Stdio},
+    sync::{
+        Arc, Mutex, PoisonError,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::errors::{self, GitError, RonaError};
+
+/// Environment variable enabling tracing, like `--trace-git`.
+pub const TRACE_GIT_VARIABLE: &str = "RONA_TRACE_GIT";
+
+static TRACE: AtomicBool = AtomicBool::new(false);
+
+/// Configuration values every git invocation runs with.
+static CONFIG_OVERRIDES: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// The runner installed for the current thread by [`with_runner`].
+    static RUNNER: RefCell<Option<Arc<dyn GitRunner>>> = const { RefCell::new(None) };
+}
+
+/// Enables or disables the tracing of git invocations.
+pub fn set_trace(enabled: bool) {
+    TRACE.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether git invocations are traced: `--trace-git`, or `RONA_TRACE_GIT` set to anything
+/// but `0` or `false`.
+#[must_use]
+pub fn trace_enabled() -> bool {
+    TRACE.load(Ordering::Relaxed)
+        || env::var(TRACE_GIT_VARIABLE)
+            .is_ok_and(|value| !matches!(value.trim(), "" | "0" | "false"))
+}
+
+/// Runs every git invocation with a configuration value (`git -c key=value`), replacing
+/// the previous value of the key. Git passes it on to the git processes it starts (e.g. the
+/// commands of a rebase).
+pub fn set_config_override(key: &str, value: &str) {
+    let mut overrides = CONFIG_OVERRIDES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    overrides.retain(|(overridden, _)| overridden != key);
+    overrides.push((key.to_string(), value.to_string()));
+}
+
+/// Quotes an argument for display in a command line when the shell would need it: when it
+/// is empty or has characters other than letters, digits and `-_./:=,@%+`.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::exec::quote_arg;
+///
+/// assert_eq!(quote_arg("--porcelain=v2"), "--porcelain=v2");
+/// assert_eq!(quote_arg("*.lock"), "'*.lock'");
+/// assert_eq!(quote_arg("it's done"), r"'it'\''s done'");
+/// assert_eq!(quote_arg(""), "''");
+/// ```
+#[must_use]
+pub fn quote_arg(arg: &str) -> Cow<'_, str> {
+    if arg.is_empty() || arg.contains(|c: char| !c.is_alphanumeric() && !"-_./:=,@%+".contains(c)) {
+        Cow::Owned(format!("'{}'", arg.replace('\'', r"'\''")))
+    } else {
+        Cow::Borrowed(arg)
+    }
+}
+
+/// A git command to run: its arguments, working directory, environment and timeout.
+///
+/// The builder methods mirror [`Command`]. [`output`](Self::output) and
+/// [`status`](Self::status) run the command with the runner of the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::exec::GitCommand;
+/// use std::time::Duration;
+///
+/// let mut command = GitCommand::new();
+/// command
+///     .args(["commit", "-m", "Fix the parser"])
+///     .current_dir("/tmp/repo")
+///     .timeout(Duration::from_secs(30));
+///
+/// assert_eq!(command.to_string(), "git commit -m 'Fix the parser' (in /tmp/repo)");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GitCommand {
+    args: Vec<OsString>,
+    dir: Option<PathBuf>,
+    envs: Vec<(OsString, OsString)>,
+    timeout: Option<Duration>,
+}
+
+impl GitCommand {
+    /// A `git` command without arguments.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an argument.
+    pub fn arg<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut Self {
+        self.args.push(arg.as_ref().to_os_string());
+        self
+    }
+
+    /// Adds arguments.
+    pub fn args<I, S>(&mut self, args: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.as_ref().to_os_string()));
+        self
+    }
+
+    /// Runs git in a directory instead of the current one.
+    pub fn current_dir<P: AsRef<Path>>(&mut self, dir: P) -> &mut Self {
+        self.dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets an environment variable of git.
+    pub fn env<K: AsRef<OsStr>, V: AsRef<OsStr>>(&mut self, key: K, value: V) -> &mut Self {
+        self.envs
+            .push((key.as_ref().to_os_string(), value.as_ref().to_os_string()));
+        self
+    }
+
+    /// Kills git when it has not finished within `timeout`, [`output`](Self::output)
+    /// failing with [`io::ErrorKind::TimedOut`]. Git reads nothing from the terminal then.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The arguments of the command.
+    #[must_use]
+    pub fn get_args(&self) -> &[OsString] {
+        &self.args
+    }
+
+    /// The directory git runs in, if not the current one.
+    #[must_use]
+    pub fn get_current_dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// The environment variables set for git.
+    #[must_use]
+    pub fn get_envs(&self) -> &[(OsString, OsString)] {
+        &self.envs
+    }
+
+    /// The timeout of the command, if any.
+    #[must_use]
+    pub const fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// The [`Command`] spawning git, with the values of [`set_config_override`].
+    #[must_use]
+    pub fn to_command(&self) -> Command {
+        let mut command = Command::new("git");
+        for (key, value) in CONFIG_OVERRIDES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+        {
+            command.arg("-c").arg(format!("{key}={value}"));
+        }
+        command.args(&self.args);
+        command.envs(self.envs.iter().map(|(key, value)| (key, value)));
+        if let Some(dir) = &self.dir {
+            command.current_dir(dir);
+        }
+        command
+    }
+
+    /// Runs the command capturing its output.
+    ///
+    /// # Errors
+    /// * If git cannot be run
+    /// * [`io::ErrorKind::TimedOut`] - If git did not finish within the timeout
+    pub fn output(&self) -> io::Result<Output> {
+        let started = trace_start(self);
+        let output = current_runner().output(self);
+        trace_end(
+            started,
+            &outcome(output.as_ref().map(|output| output.status)),
+        );
+        output
+    }
+
+    /// Runs the command capturing its output, and returns its stdout.
+    ///
+    /// # Errors
+    /// * If git cannot be run
+    /// * `GitError::CommandFailed` - If git exits with an error, with its stderr
+    pub fn stdout(&self) -> errors::Result<String> {
+        let output = self.output()?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            Err(RonaError::Git(GitError::CommandFailed {
+                command: self.to_string(),
+                output: String::from_utf8_lossy(&output.stderr).to_string(),
+            }))
+        }
+    }
+
+    /// Runs the command inheriting the terminal (e.g. an interactive rebase), returning
+    /// its exit status.
+    ///
+    /// # Errors
+    /// * If git cannot be run
+    pub fn status(&self) -> io::Result<ExitStatus> {
+        let started = trace_start(self);
+        let status = current_runner().status(self);
+        trace_end(started, &outcome(status.as_ref().copied()));
+        status
+    }
+}
+
+impl fmt::Display for GitCommand {
+    /// The command line as it would be typed, the arguments being quoted when needed (see
+    /// [`quote_arg`]), and the working directory when it is not the current one.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut line = String::from("git");
+        for arg in &self.args {
+            line.push(' ');
+            line.push_str(&quote_arg(&arg.to_string_lossy()));
+        }
+        if let Some(dir) = &self.dir {
+            let _ = write!(line, " (in {})", dir.display());
+        }
+        f.write_str(&line)
+    }
+}
+
+/// Runs git commands.
+pub trait GitRunner: Send + Sync {
+    /// Runs a command capturing its output.
+    ///
+    /// # Errors
+    /// * If git cannot be run
+    /// * [`io::ErrorKind::TimedOut`] - If git did not finish within the timeout of the command
+    fn output(&self, command: &GitCommand) -> io::Result<Output>;
+
+    /// Runs a command inheriting the terminal, returning its exit status.
+    ///
+    /// # Errors
+    /// * If git cannot be run
+    fn status(&self, command: &GitCommand) -> io::Result<ExitStatus>;
+}
+
+/// The runner spawning `git`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemRunner;
+
+impl GitRunner for SystemRunner {
+    fn output(&self, command: &GitCommand) -> io::Result<Output> {
+        let mut process = command.to_command();
+        let Some(timeout) = command.timeout else {
+            return process.output();
+        };
+
+        let started = Instant::now();
+        let mut child = process
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // The pipes are drained while waiting, git blocking once their buffer is full
+        let stdout = child.stdout.take().map(read_in_background);
+        let stderr = child.stderr.take().map(read_in_background);
+
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if started.elapsed() >= timeout {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("no answer within {} seconds", timeout.as_secs()),
+                ));
+            }
+            thread::sleep(Duration::from_millis(50));
+        };
+
+        Ok(Output {
+            status,
+            stdout: join_reader(stdout)?,
+            stderr: join_reader(stderr)?,
+        })
+    }
+
+    fn status(&self, command: &GitCommand) -> io::Result<ExitStatus> {
+        command.to_command().status()
+    }
+}
+
+/// Reads a pipe of git to its end on another thread.
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<io::Result<Vec<u8>>> {
+    thread::spawn(move || {
+        let mut content = Vec::new();
+        pipe.read_to_end(&mut content)?;
+        Ok(content)
+    })
+}
+
+/// The content read by [`read_in_background`], empty for a pipe that was not captured.
+fn join_reader(reader: Option<JoinHandle<io::Result<Vec<u8>>>>) -> io::Result<Vec<u8>> {
+    reader.map_or_else(
+        || Ok(Vec::new()),
+        |reader| {
+            reader
+                .join()
+                .map_err(|_| io::Error::other("reading the output of git panicked"))?
+        },
+    )
+}
+
+/// The runner of the current thread: the one installed with [`with_runner`], else the
+/// [`SystemRunner`].
+#[must_use]
+pub fn current_runner() -> Arc<dyn GitRunner> {
+    RUNNER
+        .with_borrow(Clone::clone)
+        .unwrap_or_else(|| Arc::new(SystemRunner))
+}
+
+/// Runs `f` with the git commands of the current thread run by `runner`, restoring the
+/// previous runner afterwards (even if `f` panics). Threads started by
+/// [`performance::join`](crate::performance::join) inherit it.
+pub fn with_runner<T>(runner: Arc<dyn GitRunner>, f: impl FnOnce() -> T) -> T {
+    /// Puts the previous runner back when dropped.
+    struct Restore(Option<Arc<dyn GitRunner>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            RUNNER.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(RUNNER.replace(Some(runner)));
+    f()
+}
+
+/// Prints a command about to run when tracing, returning when it started.
+fn trace_start(command: &GitCommand) -> Instant {
+    if trace_enabled() {
+        eprintln!("[git] $ {command}");
+    }
+    Instant::now()
+}
+
+/// Prints the outcome of a traced command.
+fn trace_end(started: Instant, outcome: &str) {
+    if trace_enabled() {
+        eprintln!(
+            "[git]   {outcome} after {} ms",
+            started.elapsed().as_millis()
+        );
+    }
+}
+
+/// The outcome of a command for the trace: its exit code, or the signal or error ending it.
+fn outcome(status: Result<ExitStatus, &io::Error>) -> String {
+    match status {
+        Ok(status) => status.code().map_or_else(
+            || "killed by a signal".to_string(),
+            |code| format!("exit {code}"),
+        ),
+        Err(e) => format!("failed ({e})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_timeout() {
+        let output = GitCommand::new()
+            .arg("--version")
+            .timeout(Duration::from_secs(30))
+            .output()
+            .unwrap();
+
+        assert!(String::from_utf8_lossy(&output.stdout).starts_with("git version"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_timeout_kills_hung_git() {
+        let started = Instant::now();
+        let error = GitCommand::new()
+            .args(["-c", "alias.hang=!sleep 10", "hang"])
+            .timeout(Duration::from_millis(100))
+            .output()
+            .unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::TimedOut);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_timeout_reads_large_output() {
+        // More than the 64 KiB of a pipe buffer, on both pipes
+        let started = Instant::now();
+        let output = GitCommand::new()
+            .args([
+                "-c",
+                "alias.large=!head -c 200000 /dev/zero; head -c 100000 /dev/zero >&2",
+                "large",
+            ])
+            .timeout(Duration::from_secs(10))
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(output.stdout.len(), 200_000);
+        assert_eq!(output.stderr.len(), 100_000);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_stdout() {
+        let version = GitCommand::new().arg("--version").stdout().unwrap();
+        assert!(version.starts_with("git version"));
+
+        let error = GitCommand::new()
+            .args([
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                "refs/heads/rona-no-such-branch",
+            ])
+            .stdout()
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            RonaError::Git(GitError::CommandFailed { command, .. })
+                if command.starts_with("git rev-parse")
+        ));
+    }
+}
@@ -0,0 +1,398 @@
+//! Moving and Removing Files
+//!
+//! `rona mv` and `rona rm` run `git mv` and `git rm`, then rewrite the entries of the
+//! files in the pending commit message so it stays in sync with the index: the entry of
+//! a moved file follows it to its new path with its description, the entry of a removed
+//! file becomes a `deleted` entry, or is dropped when the file is not in the last commit.
+
+use std::{fmt::Write, path::Path};
+
+use clap::Args;
+use log::debug;
+
+use crate::{
+    errors::{GitError, Result},
+    plan::{ActionKind, Plan},
+};
+
+use super::{exec::GitCommand, handle_output, message_diff::parse_bullet};
+
+/// Options of `git rm`.
+#[derive(Args, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RemoveOptions {
+    /// Remove directories recursively
+    #[arg(short = 'r', default_value_t = false)]
+    pub recursive: bool,
+    /// Only remove the files from the index, keeping them in the working tree
+    #[arg(long, default_value_t = false)]
+    pub cached: bool,
+    /// Remove files with changes
+    #[arg(short = 'f', long, default_value_t = false)]
+    pub force: bool,
+}
+
+impl RemoveOptions {
+    /// The `git rm` arguments of the options.
+    #[must_use]
+    pub fn to_args(self) -> Vec<String> {
+        [
+            (self.recursive, "-r"),
+            (self.cached, "--cached"),
+            (self.force, "--force"),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, arg)| arg.to_string())
+        .collect()
+    }
+}
+
+/// The path of the current directory relative to the top of the working tree, with a
+/// trailing `/` (empty at the top).
+///
+/// # Errors
+/// * If not in a git working tree
+pub fn working_tree_prefix() -> Result<String> {
+    let output = GitCommand::new()
+        .args(["rev-parse", "--show-prefix"])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(GitError::RepositoryNotFound.into());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Joins a path typed in a directory of the working tree to the `prefix` of that
+/// directory (see [`working_tree_prefix`]), resolving `.` and `..` without touching
+/// the file system.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::file_ops::join_prefix;
+///
+/// assert_eq!(join_prefix("src/git/", "../cli.rs"), "src/cli.rs");
+/// assert_eq!(join_prefix("", "./docs/"), "docs");
+/// ```
+#[must_use]
+pub fn join_prefix(prefix: &str, path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+
+    for part in prefix.split('/').chain(path.split('/')) {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+
+    parts.join("/")
+}
+
+/// The moves `git mv <sources> <destination>` performs, as paths relative to the top of
+/// the working tree: into the destination when it is a directory, to it otherwise.
+///
+/// # Arguments
+/// * `prefix` - The [`working_tree_prefix`] of the directory the paths are relative to
+/// * `sources` - The files or directories to move
+/// * `destination` - Where to move them
+/// * `into_directory` - Whether the destination is an existing directory
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::file_ops::planned_moves;
+///
+/// let sources = vec!["a.rs".to_string(), "b.rs".to_string()];
+/// assert_eq!(
+///     planned_moves("src/", &sources, "../lib", true),
+///     vec![
+///         ("src/a.rs".to_string(), "lib/a.rs".to_string()),
+///         ("src/b.rs".to_string(), "lib/b.rs".to_string()),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn planned_moves(
+    prefix: &str,
+    sources: &[String],
+    destination: &str,
+    into_directory: bool,
+) -> Vec<(String, String)> {
+    let destination = join_prefix(prefix, destination);
+
+    sources
+        .iter()
+        .map(|source| {
+            let source = join_prefix(prefix, source);
+            let target = if into_directory {
+                let name = source.rsplit('/').next().unwrap_or(&source);
+                join_prefix(&destination, name)
+            } else {
+                destination.clone()
+            };
+            (source, target)
+        })
+        .collect()
+}
+
+/// Moves files or directories with `git mv`.
+///
+/// The plan holds a [`ActionKind::Move`] action per source, with paths relative to the
+/// top of the working tree.
+///
+/// # Errors
+/// * If not in a git working tree
+/// * If `git mv` fails (e.g. the source is not tracked or the destination exists)
+///
+/// # Arguments
+/// * `sources` - The files or directories to move, relative to the current directory
+/// * `destination` - The new path, or the directory to move the sources into
+/// * `force` - Whether to overwrite an existing destination
+/// * `dry_run` - Whether to only plan the moves
+pub fn git_mv(sources: &[String], destination: &str, force: bool, dry_run: bool) -> Result<Plan> {
+    let into_directory = sources.len() > 1 || Path::new(destination).is_dir();
+    let moves = planned_moves(
+        &working_tree_prefix()?,
+        sources,
+        destination,
+        into_directory,
+    );
+
+    let mut plan = Plan::new();
+    for (source, target) in moves {
+        plan.push(ActionKind::Move, source, vec![target]);
+    }
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    let output = GitCommand::new()
+        .arg("mv")
+        .args(force.then_some("--force"))
+        .arg("--")
+        .args(sources)
+        .arg(destination)
+        .output()?;
+    handle_output("mv", &output)?;
+
+    Ok(plan)
+}
+
+/// Removes files with `git rm` (or lists the files it would remove in dry-run mode).
+///
+/// The plan holds a [`ActionKind::Delete`] action per removed file, with its path
+/// relative to the top of the working tree.
+///
+/// # Errors
+/// * If `git rm` fails (e.g. a path matches no file or a file has changes)
+///
+/// # Arguments
+/// * `paths` - The files or pathspecs to remove, relative to the current directory
+/// * `options` - The options of `git rm`
+/// * `dry_run` - Whether to only list the files (`git rm --dry-run`)
+pub fn git_rm(paths: &[String], options: RemoveOptions, dry_run: bool) -> Result<Plan> {
+    let output = GitCommand::new()
+        .arg("rm")
+        .args(options.to_args())
+        .args(dry_run.then_some("--dry-run"))
+        .arg("--")
+        .args(paths)
+        .output()?;
+
+    if !output.status.success() {
+        handle_output("rm", &output)?;
+    }
+
+    // `rm 'path'` lines, the paths being relative to the top of the working tree
+    let mut plan = Plan::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        if let Some(path) = line
+            .strip_prefix("rm '")
+            .and_then(|rest| rest.strip_suffix('\''))
+        {
+            plan.push(ActionKind::Delete, path, Vec::new());
+        }
+    }
+
+    Ok(plan)
+}
+
+/// The files among `paths` (relative to the top of the working tree) that are in the
+/// last commit, none when there are no commits yet.
+///
+/// # Errors
+/// * If the command cannot be run
+pub fn committed_files(paths: &[&str]) -> Result<Vec<String>> {
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let output = GitCommand::new()
+        .args([
+            "ls-tree",
+            "-r",
+            "-z",
+            "--name-only",
+            "--full-tree",
+            "HEAD",
+            "--",
+        ])
+        .args(paths)
+        .output()?;
+
+    if !output.status.success() {
+        debug!("No last commit to look the removed files up in");
+        return Ok(Vec::new());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(ToString::to_string)
+        .collect())
+}
+
+/// The path a file ends up at after the moves, if one of them moves it (or a directory
+/// containing it).
+fn moved_path(file: &str, moves: &[(String, String)]) -> Option<String> {
+    moves.iter().find_map(|(source, target)| {
+        if file == source {
+            Some(target.clone())
+        } else {
+            file.strip_prefix(source.as_str())
+                .and_then(|rest| rest.strip_prefix('/'))
+                .map(|rest| format!("{target}/{rest}"))
+        }
+    })
+}
+
+/// Rewrites the entries of moved files in a commit message, keeping their descriptions.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::file_ops::move_message_entries;
+///
+/// let message = "[3] (feat on main)\n\n- `src/old/a.rs`:\n\n\tParse tokens\n";
+/// let moves = vec![("src/old".to_string(), "src/new".to_string())];
+///
+/// assert_eq!(
+///     move_message_entries(message, &moves),
+///     "[3] (feat on main)\n\n- `src/new/a.rs`:\n\n\tParse tokens\n"
+/// );
+/// ```
+#[must_use]
+pub fn move_message_entries(message: &str, moves: &[(String, String)]) -> String {
+    message
+        .split_inclusive('\n')
+        .map(|line| {
+            parse_bullet(line)
+                .and_then(|(file, _)| {
+                    let target = moved_path(file, moves)?;
+                    Some(line.replacen(&format!("`{file}`"), &format!("`{target}`"), 1))
+                })
+                .unwrap_or_else(|| line.to_string())
+        })
+        .collect()
+}
+
+/// Rewrites the entries of removed files in a commit message: the files of the last
+/// commit (`committed`) get a `deleted` entry, the entries of the others are dropped
+/// with their descriptions.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::file_ops::remove_message_entries;
+///
+/// let message = "(fix on main)\n\n- `a.rs`:\n\n\tGuard the input\n\n- `b.rs`:\n\n\tNew\n\n- `c.rs`:\n\n\t\n";
+/// let removed = vec!["a.rs".to_string(), "b.rs".to_string()];
+///
+/// assert_eq!(
+///     remove_message_entries(message, &removed, &["a.rs".to_string()]),
+///     "(fix on main)\n\n- `a.rs`: deleted\n\n- `c.rs`:\n\n\t\n"
+/// );
+/// ```
+#[must_use]
+pub fn remove_message_entries(message: &str, removed: &[String], committed: &[String]) -> String {
+    let mut rewritten = String::new();
+    let mut lines = message.split_inclusive('\n').peekable();
+
+    while let Some(line) = lines.next() {
+        let Some((file, _)) =
+            parse_bullet(line).filter(|(file, _)| removed.iter().any(|r| r == file))
+        else {
+            rewritten.push_str(line);
+            continue;
+        };
+
+        // The description: the blank and indented lines following the bullet
+        while lines
+            .next_if(|next| next.trim().is_empty() || next.starts_with([' ', '\t']))
+            .is_some()
+        {}
+
+        if committed.iter().any(|c| c == file) {
+            let _ = writeln!(rewritten, "- `{file}`: deleted");
+            if lines.peek().is_some() {
+                rewritten.push('\n');
+            }
+        }
+    }
+
+    rewritten
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_moves() {
+        let sources = vec!["parser.rs".to_string()];
+
+        assert_eq!(
+            planned_moves("src/", &sources, "lexer.rs", false),
+            vec![("src/parser.rs".to_string(), "src/lexer.rs".to_string())]
+        );
+        assert_eq!(
+            planned_moves("", &["src/".to_string()], "lib", true),
+            vec![("src".to_string(), "lib/src".to_string())]
+        );
+        assert_eq!(join_prefix("a/", "../../b"), "b");
+    }
+
+    #[test]
+    fn test_move_message_entries() {
+        let message =
+            "[3] (feat on main) Move `a.rs`\n\n- `a.rs`:\n\n\tAbout `a.rs`\n\n- `ab.rs`: deleted\n";
+        let moves = vec![("a.rs".to_string(), "b.rs".to_string())];
+
+        assert_eq!(
+            move_message_entries(message, &moves),
+            "[3] (feat on main) Move `a.rs`\n\n- `b.rs`:\n\n\tAbout `a.rs`\n\n- `ab.rs`: deleted\n"
+        );
+    }
+
+    #[test]
+    fn test_remove_message_entries() {
+        let message =
+            "[2] (fix on main)\n\n- `a.rs`:\n\n\tGuard the input\n\tand the output\n\nCloses #4\n";
+        let removed = vec!["a.rs".to_string()];
+
+        assert_eq!(
+            remove_message_entries(message, &removed, &[]),
+            "[2] (fix on main)\n\nCloses #4\n"
+        );
+        assert_eq!(
+            remove_message_entries(message, &removed, &removed),
+            "[2] (fix on main)\n\n- `a.rs`: deleted\n\nCloses #4\n"
+        );
+    }
+}
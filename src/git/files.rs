@@ -1,24 +1,30 @@
 //! File and Exclusion Handling
 //!
-//! Git file operations including exclusion patterns, ignore file processing,
-//! and file management utilities.
+//! Git file operations including exclusion patterns, `.commitignore` and `.gitignore`
+//! parsing with the gitignore glob and directory semantics, and file management utilities.
 
 use std::{
     collections::HashSet,
-    fs::{File, OpenOptions, read_to_string},
+    fs::{File, OpenOptions, copy, create_dir_all, read_to_string, remove_file, rename},
     io::Write,
-    path::Path,
+    path::{Path, PathBuf},
+    process,
 };
 
+use glob::{MatchOptions, Pattern};
+
 use crate::{
-    errors::Result,
-    git::{COMMIT_MESSAGE_FILE_PATH, find_git_root},
+    errors::{GitError, Result, RonaError},
+    git::find_git_common_dir,
     utils::{find_project_root, print_error},
 };
 
-const COMMITIGNORE_FILE_PATH: &str = ".commitignore";
+pub const COMMITIGNORE_FILE_PATH: &str = ".commitignore";
 const GITIGNORE_FILE_PATH: &str = ".gitignore";
 
+/// Suffix of the backup of the previous commit message.
+const BACKUP_SUFFIX: &str = ".bak";
+
 /// Add paths to the `.git/info/exclude` file.
 ///
 /// # Arguments
@@ -30,7 +36,7 @@ const GITIGNORE_FILE_PATH: &str = ".gitignore";
 /// # Returns
 /// * `Result<(), std::io::Error>` - Result of the operation.
 pub fn add_to_git_exclude(paths: &[&str]) -> Result<()> {
-    let git_root_path = find_git_root()?;
+    let git_root_path = find_git_common_dir()?;
 
     let exclude_file = git_root_path.join("info").join("exclude");
 
@@ -41,7 +47,7 @@ pub fn add_to_git_exclude(paths: &[&str]) -> Result<()> {
             "Please ensure you have a valid Git repository or submodule.",
         );
 
-        std::process::exit(1);
+        return Err(RonaError::Git(GitError::RepositoryNotFound));
     }
 
     // Read existing content to avoid duplicates
@@ -90,71 +96,323 @@ pub fn add_to_git_exclude(paths: &[&str]) -> Result<()> {
     Ok(())
 }
 
-/// Creates the necessary files in the project root.
+/// Path of the backup of a commit message file (e.g. `commit_message.md.bak`).
+#[must_use]
+pub fn backup_path(message_path: &Path) -> PathBuf {
+    let mut path = message_path.as_os_str().to_owned();
+    path.push(BACKUP_SUFFIX);
+    PathBuf::from(path)
+}
+
+/// Writes a commit message file atomically, so that an interrupted write never
+/// loses the previous message.
+///
+/// The message is written to a temporary file in the same directory, then renamed
+/// over the message file. A previous, different message is kept in its
+/// [`backup_path`] for recovery.
+///
+/// # Errors
+/// * If the directory, the backup or the temporary file cannot be written
+/// * If the temporary file cannot be renamed over the message file
+pub fn write_commit_message(message_path: &Path, message: &str) -> Result<()> {
+    if let Some(parent) = message_path.parent() {
+        create_dir_all(parent)?;
+    }
+
+    let previous = read_to_string(message_path).unwrap_or_default();
+    if !previous.trim().is_empty() && previous != message {
+        copy(message_path, backup_path(message_path))?;
+    }
+
+    let file_name = message_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let temporary = message_path.with_file_name(format!(".{file_name}.{}.tmp", process::id()));
+
+    let written = File::create(&temporary)
+        .and_then(|mut file| {
+            file.write_all(message.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|()| rename(&temporary, message_path));
+
+    if written.is_err() {
+        let _ = remove_file(&temporary);
+    }
+
+    Ok(written?)
+}
+
+/// Creates the commit message file and the `.commitignore` file.
+///
+/// Both are added to `.git/info/exclude`, the commit message file only when it is
+/// kept in the working tree.
+///
+/// # Arguments
+/// * `message_path` - Path of the commit message file
 ///
 /// # Errors
 /// * If the files cannot be created.
-/// * If the git add command fails.
-pub fn create_needed_files() -> Result<()> {
+/// * If the exclude file cannot be updated.
+pub fn create_needed_files(message_path: &Path) -> Result<()> {
     let project_root = find_project_root()?;
 
-    let commit_file_path = Path::new(&project_root).join(COMMIT_MESSAGE_FILE_PATH);
-    let commitignore_file_path = Path::new(&project_root).join(COMMITIGNORE_FILE_PATH);
+    let commitignore_file_path = project_root.join(COMMITIGNORE_FILE_PATH);
 
-    if !commit_file_path.exists() {
-        File::create(commit_file_path)?;
+    if !message_path.exists() {
+        if let Some(parent) = message_path.parent() {
+            create_dir_all(parent)?;
+        }
+        File::create(message_path)?;
     }
 
     if !commitignore_file_path.exists() {
         File::create(commitignore_file_path)?;
     }
 
-    add_to_git_exclude(&[COMMIT_MESSAGE_FILE_PATH, COMMITIGNORE_FILE_PATH])?;
+    let mut excluded = vec![COMMITIGNORE_FILE_PATH.to_string()];
+    if let Some(relative) = worktree_relative_path(&project_root, message_path) {
+        excluded.push(format!("{relative}{BACKUP_SUFFIX}"));
+        excluded.push(relative);
+    }
+    let excluded: Vec<&str> = excluded.iter().map(String::as_str).collect();
+    add_to_git_exclude(&excluded)?;
 
     Ok(())
 }
 
-/// Gets all patterns from commitignore and gitignore files.
+/// Path of a file relative to the project root, with `/` separators, or `None`
+/// when the file is outside of the working tree or inside the `.git` directory.
+#[must_use]
+pub fn worktree_relative_path(project_root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(project_root).ok()?;
+
+    if relative.starts_with(".git") {
+        return None;
+    }
+
+    Some(
+        relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+    )
+}
+
+/// A pattern of `.commitignore` or `.gitignore`, with the gitignore semantics.
 ///
-/// # Errors
-/// * If reading the ignored files fails
+/// - A pattern without `/` (other than a trailing one) matches a name at any depth
+/// - A pattern with a leading or middle `/` is relative to the project root
+/// - A trailing `/` only matches directories
+/// - `*`, `?`, `[...]` and `**` globs are supported, `*` not crossing `/`
 ///
-/// # Returns
-/// * A vector of patterns to ignore
-pub fn get_ignore_patterns() -> Result<Vec<String>> {
-    let commitignore_path = Path::new(COMMITIGNORE_FILE_PATH);
+/// Matching a directory ignores every file under it.
+#[derive(Debug, Clone)]
+pub struct IgnorePattern {
+    /// The glob, without its leading and trailing `/`
+    glob: Pattern,
+    /// Whether the pattern is relative to the project root rather than matching names
+    anchored: bool,
+    /// Whether the pattern only matches directories (trailing `/`)
+    directory_only: bool,
+}
+
+impl IgnorePattern {
+    /// Parses a line of an ignore file.
+    ///
+    /// # Returns
+    /// * `None` for blank lines, comments, negations (not supported) and invalid globs
+    #[must_use]
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
 
-    if !commitignore_path.exists() {
-        return Ok(Vec::new());
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            return None;
+        }
+
+        // `\#` and `\!` escape a leading `#` or `!`
+        let line = line.strip_prefix('\\').unwrap_or(line);
+
+        let directory_only = line.ends_with('/');
+        let line = line.trim_end_matches('/');
+        let anchored = line.contains('/');
+        let line = line.trim_start_matches('/');
+
+        if line.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            glob: Pattern::new(line).ok()?,
+            anchored,
+            directory_only,
+        })
     }
 
-    let mut patterns = process_gitignore_file()?;
-    patterns.append(&mut process_gitignore_file()?);
+    /// Whether a path relative to the project root (with `/` separators) is ignored
+    /// by this pattern, itself or through one of its parent directories.
+    #[must_use]
+    pub fn matches(&self, path: &str) -> bool {
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        let path = path.trim_end_matches('/');
 
-    Ok(patterns)
+        // Each parent directory, then the path itself
+        let ends = path
+            .match_indices('/')
+            .map(|(index, _)| (index, true))
+            .chain(std::iter::once((path.len(), false)));
+
+        ends.into_iter().any(|(end, is_directory)| {
+            if self.directory_only && !is_directory {
+                return false;
+            }
+
+            let candidate = &path[..end];
+            if self.anchored {
+                self.glob.matches_with(candidate, options)
+            } else {
+                let name = candidate.rsplit('/').next().unwrap_or(candidate);
+                self.glob.matches_with(name, options)
+            }
+        })
+    }
 }
 
-/// Processes the gitignore file.
+/// Parses the patterns of an ignore file.
+#[must_use]
+pub fn parse_ignore_patterns(contents: &str) -> Vec<IgnorePattern> {
+    contents.lines().filter_map(IgnorePattern::parse).collect()
+}
+
+/// Whether a path relative to the project root is ignored by any of the patterns.
+#[must_use]
+pub fn is_ignored(path: &str, patterns: &[IgnorePattern]) -> bool {
+    patterns.iter().any(|pattern| pattern.matches(path))
+}
+
+/// Gets all patterns from the `.commitignore` and `.gitignore` files.
+///
+/// # Arguments
+/// * `project_root` - The repository root holding the ignore files
 ///
 /// # Errors
-/// * If the gitignore file is not found
-/// * If the gitignore file cannot be read
-/// * If the gitignore file contains invalid patterns
+/// * If reading the ignore files fails
 ///
 /// # Returns
-/// * `Result<Vec<String>, Error>` - The files and folders to ignore or an error message
-pub fn process_gitignore_file() -> Result<Vec<String>> {
-    // look for the gitignore file
-    let gitignore_file_path = Path::new(GITIGNORE_FILE_PATH);
-    //
-    if !gitignore_file_path.exists() {
-        return Ok(Vec::new());
-    }
+/// * The patterns of both files, missing files being skipped
+pub fn get_ignore_patterns(project_root: &Path) -> Result<Vec<IgnorePattern>> {
+    let mut patterns = Vec::new();
 
-    let git_ignore_file_contents = read_to_string(gitignore_file_path)?;
+    for file_name in [COMMITIGNORE_FILE_PATH, GITIGNORE_FILE_PATH] {
+        let path = project_root.join(file_name);
 
-    extract_filenames(&git_ignore_file_contents, r"^([^#]\S*)$")
+        if path.exists() {
+            patterns.extend(parse_ignore_patterns(&read_to_string(&path)?));
+        }
+    }
+
+    Ok(patterns)
 }
 
-// Use the shared extract_filenames function from the parent module
-use super::extract_filenames;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    fn ignored(pattern: &str, path: &str) -> bool {
+        IgnorePattern::parse(pattern).unwrap().matches(path)
+    }
+
+    #[test]
+    fn test_ignore_pattern_names() {
+        assert!(ignored("Cargo.lock", "Cargo.lock"));
+        assert!(ignored("Cargo.lock", "crates/core/Cargo.lock"));
+        assert!(ignored("*.lock", "web/yarn.lock"));
+        assert!(!ignored("*.lock", "src/lock.rs"));
+        assert!(ignored("target", "target/debug/rona"));
+    }
+
+    #[test]
+    fn test_ignore_pattern_anchored() {
+        assert!(ignored("/build", "build/out.js"));
+        assert!(!ignored("/build", "web/build/out.js"));
+        assert!(ignored("docs/*.md", "docs/guide.md"));
+        assert!(!ignored("docs/*.md", "docs/api/index.md"));
+        assert!(ignored("docs/**/*.md", "docs/api/index.md"));
+    }
+
+    #[test]
+    fn test_ignore_pattern_directories() {
+        assert!(ignored("generated/", "src/generated/schema.rs"));
+        assert!(!ignored("generated/", "generated"));
+        assert!(ignored("snapshots/", "tests/snapshots/cli.snap"));
+    }
+
+    #[test]
+    fn test_parse_ignore_patterns() {
+        let patterns = parse_ignore_patterns("# lock files\n*.lock\n\n!keep.lock\n\\#notes\n");
+
+        assert_eq!(patterns.len(), 2);
+        assert!(is_ignored("#notes", &patterns));
+    }
+
+    #[test]
+    fn test_get_ignore_patterns_reads_both_files() {
+        let root = TempDir::new().unwrap();
+        std::fs::write(root.path().join(COMMITIGNORE_FILE_PATH), "*.lock\n").unwrap();
+        std::fs::write(root.path().join(GITIGNORE_FILE_PATH), "/dist/\n").unwrap();
+
+        let patterns = get_ignore_patterns(root.path()).unwrap();
+
+        assert!(is_ignored("Cargo.lock", &patterns));
+        assert!(is_ignored("dist/app.js", &patterns));
+        assert!(!is_ignored("src/main.rs", &patterns));
+    }
+
+    #[test]
+    fn test_write_commit_message_keeps_backup() {
+        let root = TempDir::new().unwrap();
+        let message_path = root.path().join("rona/commit_message.md");
+
+        write_commit_message(&message_path, "first").unwrap();
+        assert!(!backup_path(&message_path).exists());
+
+        write_commit_message(&message_path, "second").unwrap();
+        assert_eq!(read_to_string(&message_path).unwrap(), "second");
+        assert_eq!(read_to_string(backup_path(&message_path)).unwrap(), "first");
+        assert_eq!(
+            std::fs::read_dir(root.path().join("rona")).unwrap().count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_get_ignore_patterns_without_files() {
+        let root = TempDir::new().unwrap();
+        assert!(get_ignore_patterns(root.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_worktree_relative_path() {
+        let root = Path::new("/project");
+
+        assert_eq!(
+            worktree_relative_path(root, &root.join("notes").join("commit_message.md")),
+            Some("notes/commit_message.md".to_string())
+        );
+        assert_eq!(
+            worktree_relative_path(root, &root.join(".git/rona/commit_message.md")),
+            None
+        );
+        assert_eq!(
+            worktree_relative_path(root, Path::new("/tmp/commit_message.md")),
+            None
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Git Hooks
+//!
+//! Discovery of the hooks `git commit` runs, honouring `core.hooksPath` (e.g. husky
+//! or hooksmith setups), so that `rona commit --verbose` states which hooks ran and
+//! which were skipped with `--no-verify`, and generation of the `commit-msg` hook
+//! validating messages of plain `git commit`s with `rona lint`.
+
+use std::{
+    fmt,
+    fs::{create_dir_all, write},
+    path::{Path, PathBuf},
+};
+
+use log::debug;
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::exec::GitCommand;
+
+/// Hooks run by `git commit`, in the order git runs them.
+pub const COMMIT_HOOKS: [&str; 4] = [
+    "pre-commit",
+    "prepare-commit-msg",
+    "commit-msg",
+    "post-commit",
+];
+
+/// Hooks bypassed by `git commit --no-verify`.
+pub const NO_VERIFY_HOOKS: [&str; 2] = ["pre-commit", "commit-msg"];
+
+/// The `commit-msg` hook validating the message with `rona lint`, skipped when
+/// rona is not installed.
+pub const COMMIT_MSG_HOOK: &str = r#"#!/bin/sh
+# Generated by `rona hooks generate commit-msg`: validates the commit message.
+if ! command -v rona >/dev/null 2>&1; then
+    echo "rona is not installed, skipping the commit message validation" >&2
+    exit 0
+fi
+
+exec rona lint --file "$1"
+"#;
+
+/// Whether a hook runs on commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookStatus {
+    /// The hook is installed and runs
+    Run,
+    /// The hook is installed but bypassed with `--no-verify`
+    Skipped,
+    /// The hook file exists but git ignores it, as it is not executable
+    NotExecutable,
+}
+
+impl fmt::Display for HookStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Run => write!(f, "runs"),
+            Self::Skipped => write!(f, "skipped (--no-verify)"),
+            Self::NotExecutable => write!(f, "ignored (not executable)"),
+        }
+    }
+}
+
+/// Resolves the hooks directory, honouring `core.hooksPath`.
+///
+/// # Errors
+/// * If the git command fails
+pub fn hooks_dir(project_root: &Path) -> Result<PathBuf> {
+    let output = GitCommand::new()
+        .current_dir(project_root)
+        .args(["rev-parse", "--git-path", "hooks"])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: "git rev-parse --git-path hooks".to_string(),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    Ok(project_root.join(String::from_utf8_lossy(&output.stdout).trim()))
+}
+
+/// Whether the `git commit` arguments bypass the hooks (`--no-verify` or `-n`).
+#[must_use]
+pub fn skips_hooks(args: &[String]) -> bool {
+    args.iter().any(|arg| arg == "--no-verify" || arg == "-n")
+}
+
+/// Returns the installed commit hooks of `dir` with whether they run.
+///
+/// Hooks that are not installed are left out.
+#[must_use]
+pub fn commit_hooks(dir: &Path, no_verify: bool) -> Vec<(&'static str, HookStatus)> {
+    COMMIT_HOOKS
+        .into_iter()
+        .filter_map(|name| {
+            let path = dir.join(name);
+            if !path.is_file() {
+                return None;
+            }
+
+            let status = if !is_executable(&path) {
+                HookStatus::NotExecutable
+            } else if no_verify && NO_VERIFY_HOOKS.contains(&name) {
+                HookStatus::Skipped
+            } else {
+                HookStatus::Run
+            };
+            Some((name, status))
+        })
+        .collect()
+}
+
+/// Logs the commit hooks of the repository rooted at `project_root` (shown with `--verbose`).
+///
+/// # Errors
+/// * If the hooks directory cannot be resolved
+pub fn report_commit_hooks(project_root: &Path, no_verify: bool) -> Result<()> {
+    let dir = hooks_dir(project_root)?;
+    let hooks = commit_hooks(&dir, no_verify);
+
+    if hooks.is_empty() {
+        debug!("No commit hooks in {}", dir.display());
+        return Ok(());
+    }
+
+    debug!("Commit hooks in {}:", dir.display());
+    for (name, status) in hooks {
+        debug!("   {name}: {status}");
+    }
+
+    Ok(())
+}
+
+/// Writes a hook into the hooks directory of the repository rooted at `project_root`
+/// and marks it executable.
+///
+/// # Arguments
+/// * `project_root` - The root of the repository
+/// * `name` - The name of the hook (e.g. `commit-msg`)
+/// * `contents` - The script of the hook
+/// * `force` - Whether to overwrite an existing hook
+/// * `dry_run` - If true, only plan the write
+///
+/// # Errors
+/// * If the hook already exists and `force` is not set
+/// * If the hooks directory cannot be resolved or the hook cannot be written
+pub fn write_hook(
+    project_root: &Path,
+    name: &str,
+    contents: &str,
+    force: bool,
+    dry_run: bool,
+) -> Result<Plan> {
+    let path = hooks_dir(project_root)?.join(name);
+
+    if path.exists() && !force {
+        return Err(RonaError::InvalidInput(format!(
+            "{} already exists - pass --force to overwrite it",
+            path.display()
+        )));
+    }
+
+    let mut plan = Plan::new();
+    let target = path.strip_prefix(project_root).unwrap_or(&path);
+    plan.push(
+        ActionKind::CreateFile,
+        target.display().to_string(),
+        Vec::new(),
+    );
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(&path, contents)?;
+    make_executable(&path)?;
+
+    Ok(plan)
+}
+
+/// Marks a hook as executable.
+///
+/// # Errors
+/// * If the permissions cannot be changed
+#[cfg(unix)]
+pub fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+    Ok(())
+}
+
+/// Hooks need no permission change on non-Unix platforms.
+///
+/// # Errors
+/// * Never fails
+#[cfg(not(unix))]
+pub fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// Whether git would run the hook at `path`.
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.metadata()
+        .is_ok_and(|metadata| metadata.permissions().mode() & 0o111 != 0)
+}
+
+/// Hooks need no executable bit on non-Unix platforms.
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_skips_hooks() {
+        assert!(skips_hooks(&["--no-verify".to_string()]));
+        assert!(skips_hooks(&["-n".to_string()]));
+        assert!(!skips_hooks(&["--amend".to_string()]));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_commit_hooks() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        for (name, mode) in [
+            ("pre-commit", 0o755),
+            ("commit-msg", 0o644),
+            ("post-commit", 0o755),
+            ("pre-push", 0o755),
+        ] {
+            let path = dir.path().join(name);
+            std::fs::write(&path, "#!/bin/sh\n").unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+        }
+
+        assert_eq!(
+            commit_hooks(dir.path(), false),
+            vec![
+                ("pre-commit", HookStatus::Run),
+                ("commit-msg", HookStatus::NotExecutable),
+                ("post-commit", HookStatus::Run),
+            ]
+        );
+        assert_eq!(
+            commit_hooks(dir.path(), true),
+            vec![
+                ("pre-commit", HookStatus::Skipped),
+                ("commit-msg", HookStatus::NotExecutable),
+                ("post-commit", HookStatus::Run),
+            ]
+        );
+    }
+}
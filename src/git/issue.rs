@@ -0,0 +1,261 @@
+//! Issue References
+//!
+//! Looks up GitHub and GitLab issues referenced by generated commit messages,
+//! through the `gh`/`glab` CLIs or, when a token is configured, the REST APIs.
+
+use std::process::{Command, Output};
+
+use serde_json::Value;
+
+use crate::{
+    errors::{Result, RonaError},
+    utils,
+};
+
+use super::remote::{RemoteHost, RemoteRepository, get_remote_url, parse_remote_url};
+
+/// Maximum number of open issues offered for interactive selection.
+const ISSUE_LIST_LIMIT: &str = "50";
+
+/// An issue of the remote repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub number: u64,
+    pub title: Option<String>,
+}
+
+impl Issue {
+    /// The footer closing the issue when the commit lands on the default branch.
+    #[must_use]
+    pub fn closing_footer(&self) -> String {
+        format!("Closes #{}", self.number)
+    }
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.title {
+            Some(title) => write!(f, "#{} {title}", self.number),
+            None => write!(f, "#{}", self.number),
+        }
+    }
+}
+
+/// Parses an issue from the JSON returned by `gh`, `glab` or the REST APIs.
+///
+/// GitHub uses `number` while GitLab uses `iid` for the project-level number.
+fn parse_issue(value: &Value) -> Option<Issue> {
+    let number = value
+        .get("iid")
+        .or_else(|| value.get("number"))
+        .and_then(Value::as_u64)?;
+    let title = value
+        .get("title")
+        .and_then(Value::as_str)
+        .map(ToString::to_string);
+
+    Some(Issue { number, title })
+}
+
+/// Runs a command and parses its stdout as JSON, returning `None` if it fails.
+fn run_json(command: &mut Command) -> Option<Value> {
+    parse_json_output(&command.output().ok()?)
+}
+
+/// Parses the stdout of a command as JSON, returning `None` if the command failed.
+fn parse_json_output(output: &Output) -> Option<Value> {
+    if !output.status.success() {
+        return None;
+    }
+
+    serde_json::from_slice(&output.stdout).ok()
+}
+
+/// Resolves the hosted repository of `origin`, defaulting to GitHub tooling when unknown.
+fn origin_repository() -> Option<RemoteRepository> {
+    get_remote_url("origin")
+        .ok()
+        .and_then(|url| parse_remote_url(&url).ok())
+}
+
+/// Splits a repository web URL into its host and `owner/repo` path.
+fn split_web_url(repository: &RemoteRepository) -> Option<(&str, &str)> {
+    repository
+        .web_url
+        .strip_prefix("https://")
+        .and_then(|rest| rest.split_once('/'))
+}
+
+/// Queries a REST API endpoint with curl, authenticated with the given token.
+///
+/// The token goes in the curl configuration read from stdin, never on the command line.
+fn fetch_rest(repository: &RemoteRepository, endpoint: &str, token: &str) -> Option<Value> {
+    let (host, path) = split_web_url(repository)?;
+
+    let (url, header) = match repository.host {
+        RemoteHost::GitHub => (
+            format!("https://api.github.com/repos/{path}/{endpoint}"),
+            format!("Authorization: Bearer {token}"),
+        ),
+        RemoteHost::GitLab => (
+            format!(
+                "https://{host}/api/v4/projects/{}/{endpoint}",
+                path.replace('/', "%2F")
+            ),
+            format!("PRIVATE-TOKEN: {token}"),
+        ),
+        RemoteHost::Bitbucket => return None,
+    };
+
+    let output = utils::curl(&["-fsSL"], &[("url", &url), ("header", &header)]).ok()?;
+    parse_json_output(&output)
+}
+
+/// Fetches an issue of the `origin` repository.
+///
+/// The `gh` (GitHub) or `glab` (GitLab) CLI is tried first; when it is not
+/// available and a token is configured, the REST API is queried instead.
+/// If the issue cannot be looked up, it is returned without title so the
+/// commit message can still reference it.
+///
+/// # Arguments
+/// * `number` - The issue number
+/// * `token` - Optional API token for the REST fallback
+///
+/// # Returns
+/// * `Issue` - The issue, with its title when it could be fetched
+#[must_use]
+pub fn fetch_issue(number: u64, token: Option<&str>) -> Issue {
+    let repository = origin_repository();
+    let host = repository.as_ref().map_or(RemoteHost::GitHub, |r| r.host);
+    let number_arg = number.to_string();
+
+    let from_cli = match host {
+        RemoteHost::GitHub => run_json(Command::new("gh").args([
+            "issue",
+            "view",
+            &number_arg,
+            "--json",
+            "number,title",
+        ])),
+        RemoteHost::GitLab => {
+            run_json(Command::new("glab").args(["issue", "view", &number_arg, "--output", "json"]))
+        }
+        RemoteHost::Bitbucket => None,
+    };
+
+    let value = from_cli.or_else(|| {
+        let repository = repository.as_ref()?;
+        fetch_rest(repository, &format!("issues/{number}"), token?)
+    });
+
+    value.as_ref().and_then(parse_issue).unwrap_or(Issue {
+        number,
+        title: None,
+    })
+}
+
+/// Lists the open issues of the `origin` repository for interactive selection.
+///
+/// # Arguments
+/// * `token` - Optional API token for the REST fallback
+///
+/// # Errors
+/// * If neither the CLI nor the REST API could list the issues
+///
+/// # Returns
+/// * `Vec<Issue>` - The open issues, most recent first
+pub fn list_open_issues(token: Option<&str>) -> Result<Vec<Issue>> {
+    let repository = origin_repository();
+    let host = repository.as_ref().map_or(RemoteHost::GitHub, |r| r.host);
+
+    let from_cli = match host {
+        RemoteHost::GitHub => run_json(Command::new("gh").args([
+            "issue",
+            "list",
+            "--state",
+            "open",
+            "--limit",
+            ISSUE_LIST_LIMIT,
+            "--json",
+            "number,title",
+        ])),
+        RemoteHost::GitLab => run_json(Command::new("glab").args([
+            "issue",
+            "list",
+            "--per-page",
+            ISSUE_LIST_LIMIT,
+            "--output",
+            "json",
+        ])),
+        RemoteHost::Bitbucket => None,
+    };
+
+    let value = from_cli.or_else(|| {
+        let repository = repository.as_ref()?;
+        let endpoint = match repository.host {
+            RemoteHost::GitLab => format!("issues?state=opened&per_page={ISSUE_LIST_LIMIT}"),
+            _ => format!("issues?state=open&per_page={ISSUE_LIST_LIMIT}"),
+        };
+        fetch_rest(repository, &endpoint, token?)
+    });
+
+    let issues = value
+        .as_ref()
+        .and_then(Value::as_array)
+        .ok_or_else(|| {
+            RonaError::InvalidInput(
+                "Could not list issues - install `gh`/`glab` or set `issue_token` in the config"
+                    .to_string(),
+            )
+        })?
+        .iter()
+        // The GitHub REST API lists pull requests as issues too
+        .filter(|value| value.get("pull_request").is_none())
+        .filter_map(parse_issue)
+        .collect();
+
+    Ok(issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_issue() {
+        let github: Value = serde_json::from_str(r#"{"number": 12, "title": "Crash"}"#).unwrap();
+        assert_eq!(
+            parse_issue(&github),
+            Some(Issue {
+                number: 12,
+                title: Some("Crash".to_string())
+            })
+        );
+
+        let gitlab: Value =
+            serde_json::from_str(r#"{"id": 9000, "iid": 7, "title": "Bug"}"#).unwrap();
+        assert_eq!(parse_issue(&gitlab).unwrap().number, 7);
+
+        assert!(parse_issue(&serde_json::json!({"title": "no number"})).is_none());
+    }
+
+    #[test]
+    fn test_issue_display_and_footer() {
+        let issue = Issue {
+            number: 3,
+            title: Some("Add flag".to_string()),
+        };
+
+        assert_eq!(issue.to_string(), "#3 Add flag");
+        assert_eq!(issue.closing_footer(), "Closes #3");
+        assert_eq!(
+            Issue {
+                number: 3,
+                title: None
+            }
+            .to_string(),
+            "#3"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! Large and Binary Files
+//!
+//! Check of the files about to be committed, run by `rona add` and `rona generate`:
+//! binary files and files over the `large_file_threshold` (5 MB by default) are listed
+//! with a warning, so build artifacts and datasets are caught before they land in the
+//! history. Files stored with Git LFS (`filter=lfs`) are left out.
+
+use std::{fmt, fs::File, io::Read, path::Path, str::FromStr};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Result, RonaError};
+
+use super::exec::GitCommand;
+
+/// Size from which files are flagged when no `large_file_threshold` is configured.
+pub const DEFAULT_LARGE_FILE_THRESHOLD: FileSize = FileSize(5 * 1024 * 1024);
+
+/// Number of leading bytes searched for a NUL byte to tell binary files, as git does.
+const BINARY_CHECK_LENGTH: u64 = 8000;
+
+/// Units of file sizes, from the largest.
+const UNITS: [(&str, u64); 4] = [
+    ("GB", 1024 * 1024 * 1024),
+    ("MB", 1024 * 1024),
+    ("KB", 1024),
+    ("B", 1),
+];
+
+/// A file size, written with a `B`, `KB`, `MB` or `GB` unit (powers of 1024) in the
+/// configuration, e.g. `"5MB"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(try_from = "SizeValue", into = "String")]
+pub struct FileSize(pub u64);
+
+/// A file size as written in the configuration: a number of bytes or a size with a unit.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SizeValue {
+    Bytes(u64),
+    Text(String),
+}
+
+impl TryFrom<SizeValue> for FileSize {
+    type Error = RonaError;
+
+    fn try_from(value: SizeValue) -> Result<Self> {
+        match value {
+            SizeValue::Bytes(bytes) => Ok(Self(bytes)),
+            SizeValue::Text(text) => text.parse(),
+        }
+    }
+}
+
+impl FromStr for FileSize {
+    type Err = RonaError;
+
+    /// Parses a size such as `5MB`, `512 kb` or `1048576` (bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::git::large_files::FileSize;
+    ///
+    /// assert_eq!("5MB".parse::<FileSize>()?, FileSize(5 * 1024 * 1024));
+    /// assert_eq!("512 kb".parse::<FileSize>()?, FileSize(512 * 1024));
+    /// assert_eq!("100".parse::<FileSize>()?, FileSize(100));
+    /// assert!("5 parsecs".parse::<FileSize>().is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn from_str(value: &str) -> Result<Self> {
+        let value = value.trim();
+        let digits = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(digits);
+        let unit = unit.trim().to_ascii_uppercase();
+
+        let multiplier = if unit.is_empty() {
+            Some(1)
+        } else {
+            UNITS
+                .iter()
+                .find(|(name, _)| *name == unit)
+                .map(|(_, multiplier)| *multiplier)
+        };
+
+        number
+            .parse::<u64>()
+            .ok()
+            .zip(multiplier)
+            .and_then(|(number, multiplier)| number.checked_mul(multiplier))
+            .map(Self)
+            .ok_or_else(|| {
+                RonaError::InvalidInput(format!(
+                    "Invalid file size '{value}' - expected a number with a B, KB, MB or GB unit (e.g. 5MB)"
+                ))
+            })
+    }
+}
+
+impl From<FileSize> for String {
+    /// The size in the largest unit dividing it, as written in the configuration.
+    fn from(size: FileSize) -> Self {
+        UNITS
+            .iter()
+            .find(|(_, multiplier)| size.0 != 0 && size.0.is_multiple_of(*multiplier))
+            .map_or_else(
+                || "0B".to_string(),
+                |(unit, multiplier)| format!("{}{unit}", size.0 / multiplier),
+            )
+    }
+}
+
+impl fmt::Display for FileSize {
+    /// The size in the largest unit it reaches, with one decimal (e.g. `12.3 MB`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (unit, multiplier) = UNITS
+            .iter()
+            .find(|(_, multiplier)| self.0 >= *multiplier)
+            .unwrap_or(&("B", 1));
+
+        if *multiplier == 1 {
+            return write!(f, "{} B", self.0);
+        }
+        let tenths = self.0 * 10 / multiplier;
+        write!(f, "{}.{} {unit}", tenths / 10, tenths % 10)
+    }
+}
+
+/// A file flagged by the check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlaggedFile {
+    /// Path of the file, relative to the top of the working tree
+    pub path: String,
+    /// Size of the file
+    pub size: FileSize,
+    /// Whether the file has binary content
+    pub binary: bool,
+}
+
+impl fmt::Display for FlaggedFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.binary {
+            write!(f, "{} ({}, binary)", self.path, self.size)
+        } else {
+            write!(f, "{} ({})", self.path, self.size)
+        }
+    }
+}
+
+/// Whether content is binary: it has a NUL byte in its first 8000 bytes, as git decides.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::large_files::is_binary;
+///
+/// assert!(is_binary(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"));
+/// assert!(!is_binary("fn main() {}\n".as_bytes()));
+/// ```
+#[must_use]
+pub fn is_binary(content: &[u8]) -> bool {
+    content
+        .iter()
+        .take(usize::try_from(BINARY_CHECK_LENGTH).unwrap_or(usize::MAX))
+        .any(|byte| *byte == 0)
+}
+
+/// The files among `paths` stored with Git LFS (`filter=lfs` in `.gitattributes`).
+///
+/// # Errors
+/// * If `git check-attr` cannot be run
+fn lfs_files(project_root: &Path, paths: &[&str]) -> Result<Vec<String>> {
+    let output = GitCommand::new()
+        .current_dir(project_root)
+        .args(["check-attr", "-z", "filter", "--"])
+        .args(paths)
+        .output()?;
+
+    // `<path>\0filter\0<value>\0` records
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let fields: Vec<&str> = stdout.split('\0').collect();
+    Ok(fields
+        .chunks(3)
+        .filter(|record| record.get(2) == Some(&"lfs"))
+        .map(|record| record[0].to_string())
+        .collect())
+}
+
+/// The binary files and the files of at least `threshold` bytes among `paths` (relative to
+/// `project_root`), as they are in the working tree. Missing files, directories (e.g.
+/// submodules) and files stored with Git LFS are skipped.
+///
+/// # Errors
+/// * If `git check-attr` cannot be run
+pub fn flag_files(
+    project_root: &Path,
+    paths: &[&str],
+    threshold: FileSize,
+) -> Result<Vec<FlaggedFile>> {
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+    let lfs = lfs_files(project_root, paths)?;
+
+    let mut flagged = Vec::new();
+    for path in paths
+        .iter()
+        .filter(|path| !lfs.iter().any(|lfs| lfs == *path))
+    {
+        let Ok(file) = File::open(project_root.join(path)) else {
+            continue;
+        };
+        let Ok(metadata) = file.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let mut head = Vec::new();
+        file.take(BINARY_CHECK_LENGTH).read_to_end(&mut head)?;
+
+        let size = FileSize(metadata.len());
+        let binary = is_binary(&head);
+        if binary || size >= threshold {
+            flagged.push(FlaggedFile {
+                path: (*path).to_string(),
+                size,
+                binary,
+            });
+        }
+    }
+
+    Ok(flagged)
+}
+
+/// Warns about the binary and large files among `paths` (see [`flag_files`]), suggesting
+/// Git LFS or leaving them out.
+///
+/// # Errors
+/// * If `git check-attr` cannot be run
+pub fn warn_large_files(project_root: &Path, paths: &[&str], threshold: FileSize) -> Result<()> {
+    let flagged = flag_files(project_root, paths, threshold)?;
+    if flagged.is_empty() {
+        return Ok(());
+    }
+
+    warn!(
+        "⚠️  {} file(s) are binary or {threshold} and larger:",
+        flagged.len()
+    );
+    for file in &flagged {
+        warn!("   - {file}");
+    }
+    warn!("   Store them with Git LFS (`git lfs track <pattern>`), or leave them out of the");
+    warn!("   commit (`rona -a <pattern>`, `git restore --staged <file>`, `.gitignore`).");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_file_size() {
+        assert_eq!("1GB".parse::<FileSize>().unwrap(), FileSize(1 << 30));
+        assert!("".parse::<FileSize>().is_err());
+        assert!("MB".parse::<FileSize>().is_err());
+        assert!("99999999999999GB".parse::<FileSize>().is_err());
+
+        assert_eq!(String::from(FileSize(5 * 1024 * 1024)), "5MB");
+        assert_eq!(String::from(FileSize(1536)), "1536B");
+        assert_eq!(FileSize(12_900_000).to_string(), "12.3 MB");
+        assert_eq!(FileSize(1536).to_string(), "1.5 KB");
+        assert_eq!(FileSize(42).to_string(), "42 B");
+    }
+
+    #[test]
+    fn test_flag_files() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("notes.md"), "# Notes\n").unwrap();
+        std::fs::write(dir.path().join("logo.png"), b"\x89PNG\0\0").unwrap();
+        std::fs::write(dir.path().join("data.csv"), "a,b\n".repeat(512)).unwrap();
+
+        let flagged = flag_files(
+            dir.path(),
+            &["notes.md", "logo.png", "data.csv", "missing.bin"],
+            FileSize(1024),
+        )
+        .unwrap();
+
+        assert_eq!(
+            flagged.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["logo.png (6 B, binary)", "data.csv (2.0 KB)"]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Commit Message Lock
+//!
+//! Advisory lock held by `generate` and `commit` while they write, edit or commit the
+//! commit message, so two rona processes (e.g. an editor plugin and a terminal) do not
+//! clobber `commit_message.md`. The lock is an OS file lock on `rona.lock` in the `rona`
+//! directory of the git directory: it is released when the process exits, even on a crash.
+
+use std::{
+    fs::{File, OpenOptions, TryLockError, create_dir_all, read_to_string},
+    io::Write,
+    path::Path,
+    process,
+};
+
+use log::debug;
+
+use crate::errors::{GitError, Result};
+
+use super::{commit::COMMIT_MESSAGE_DIR, repository::find_git_root};
+
+/// Name of the lock file, in the `rona` directory of the git directory.
+pub const LOCK_FILE_NAME: &str = "rona.lock";
+
+/// Lock on the commit message, released when dropped.
+#[derive(Debug)]
+pub struct MessageLock {
+    _file: File,
+}
+
+/// Acquires the commit message lock of the current repository.
+///
+/// # Errors
+/// * If not in a git repository
+/// * If another process holds the lock ([`GitError::CommitMessageLocked`])
+/// * If the lock file cannot be created
+pub fn lock_commit_message() -> Result<MessageLock> {
+    lock_in(&find_git_root()?.join(COMMIT_MESSAGE_DIR))
+}
+
+/// Acquires the lock file of the directory, recording the process id in it.
+///
+/// # Errors
+/// * If another process holds the lock ([`GitError::CommitMessageLocked`])
+/// * If the lock file cannot be created
+pub fn lock_in(dir: &Path) -> Result<MessageLock> {
+    create_dir_all(dir)?;
+    let path = dir.join(LOCK_FILE_NAME);
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&path)?;
+
+    match file.try_lock() {
+        Ok(()) => {
+            file.set_len(0)?;
+            write!(file, "{}", process::id())?;
+            debug!("Acquired {}", path.display());
+            Ok(MessageLock { _file: file })
+        }
+        Err(TryLockError::WouldBlock) => {
+            let pid = read_to_string(&path).unwrap_or_default();
+            let pid = pid.trim();
+            Err(GitError::CommitMessageLocked {
+                pid: if pid.is_empty() { "unknown" } else { pid }.to_string(),
+            }
+            .into())
+        }
+        Err(TryLockError::Error(e)) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::RonaError;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_lock_in() {
+        let dir = TempDir::new().unwrap();
+        let lock = lock_in(dir.path()).unwrap();
+
+        match lock_in(dir.path()) {
+            Err(RonaError::Git(GitError::CommitMessageLocked { pid })) => {
+                assert_eq!(pid, process::id().to_string());
+            }
+            other => panic!("Expected the lock to be held, got {other:?}"),
+        }
+
+        drop(lock);
+        assert!(lock_in(dir.path()).is_ok());
+    }
+}
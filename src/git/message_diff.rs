@@ -0,0 +1,400 @@
+//! Commit Message Regeneration Diff
+//!
+//! Regenerating `commit_message.md` replaces the pending message. Before it does, the
+//! changes are shown: the header before and after, the files added to and removed from
+//! the list, and the lines written by hand that the new message does not keep.
+//!
+//! The hand-written subject and file descriptions are reused by the new message (see
+//! [`draft_from_message`]), so only the edits it cannot place are discarded. Updating the
+//! message instead (`generate --update`, see [`update_message_entries`]) keeps everything
+//! but the entries of the files no longer listed.
+
+use std::{collections::HashMap, fmt::Write};
+
+use console::Style;
+
+use super::commit::CommitDraft;
+
+/// Template text following the file of a bullet, which is not a hand-written edit.
+const TEMPLATE_DESCRIPTIONS: [&str; 3] = ["", "deleted", "submodule updated"];
+
+/// What regenerating a commit message changes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageChanges {
+    /// The previous and the new header, when they differ
+    pub header: Option<(String, String)>,
+    /// Files listed in the new message only
+    pub added: Vec<String>,
+    /// Files listed in the previous message only
+    pub removed: Vec<String>,
+    /// Hand-written lines of the previous message missing from the new one
+    pub discarded: Vec<String>,
+}
+
+impl MessageChanges {
+    /// Whether the new message is the same as the previous one, as far as it is compared.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.header.is_none()
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.discarded.is_empty()
+    }
+
+    /// Renders the changes, one per line, `colored` with red and green.
+    #[must_use]
+    pub fn render(&self, colored: bool) -> String {
+        let red = Style::new().red().force_styling(colored);
+        let green = Style::new().green().force_styling(colored);
+        let dim = Style::new().dim().force_styling(colored);
+        let mut rendered = String::new();
+
+        if let Some((previous, next)) = &self.header {
+            let _ = writeln!(
+                rendered,
+                "  header  {}  ->  {}",
+                red.apply_to(previous),
+                green.apply_to(next)
+            );
+        }
+        for file in &self.added {
+            let _ = writeln!(rendered, "  {}", green.apply_to(format!("+ {file}")));
+        }
+        for file in &self.removed {
+            let _ = writeln!(rendered, "  {}", red.apply_to(format!("- {file}")));
+        }
+        if !self.discarded.is_empty() {
+            let _ = writeln!(rendered, "  {}", dim.apply_to("edits not kept:"));
+            for line in &self.discarded {
+                let _ = writeln!(rendered, "  {}", red.apply_to(format!("│ {line}")));
+            }
+        }
+
+        rendered.trim_end().to_string()
+    }
+}
+
+/// The file and the description of a ``- `file`: description`` bullet line.
+pub(super) fn parse_bullet(line: &str) -> Option<(&str, &str)> {
+    let rest = line.trim_start().strip_prefix("- `")?;
+    let (file, description) = rest.split_once('`')?;
+    let description = description.strip_prefix(':').unwrap_or(description);
+    Some((file, description.trim()))
+}
+
+/// The subject of a header: the text after its `(type on branch)` group.
+fn header_subject(header: &str) -> &str {
+    header
+        .find('(')
+        .and_then(|start| header[start..].find(')').map(|end| start + end + 1))
+        .map_or(header, |end| &header[end..])
+        .trim()
+}
+
+/// The hand-written subject and file descriptions of a commit message, to pre-fill the
+/// message replacing it.
+///
+/// The description of a file is its text after the bullet and the indented lines
+/// following it; leading emoji (e.g. a gitmoji) are left out of the subject.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::message_diff::draft_from_message;
+///
+/// let draft = draft_from_message("[3] (feat on main) ✨ Add parser\n\n- `src/parser.rs`:\n\n\tParse tokens\n");
+///
+/// assert_eq!(draft.subject.as_deref(), Some("Add parser"));
+/// assert_eq!(draft.files["src/parser.rs"], "Parse tokens");
+/// ```
+#[must_use]
+pub fn draft_from_message(message: &str) -> CommitDraft {
+    let (header, body) = message.split_once('\n').unwrap_or((message, ""));
+    let subject = header_subject(header.trim())
+        .trim_start_matches(|c: char| !c.is_ascii() && !c.is_alphanumeric())
+        .trim();
+
+    let mut files: HashMap<String, Vec<&str>> = HashMap::new();
+    let mut current = None;
+    for line in body.lines() {
+        if let Some((file, description)) = parse_bullet(line) {
+            let lines = files.entry(file.to_string()).or_default();
+            if !TEMPLATE_DESCRIPTIONS.contains(&description) {
+                lines.push(description);
+            }
+            current = Some(file.to_string());
+        } else if line.starts_with([' ', '\t']) {
+            if let Some(file) = &current
+                && !line.trim().is_empty()
+            {
+                files.entry(file.clone()).or_default().push(line.trim());
+            }
+        } else if !line.trim().is_empty() {
+            current = None;
+        }
+    }
+
+    CommitDraft {
+        subject: (!subject.is_empty()).then(|| subject.to_string()),
+        files: files
+            .into_iter()
+            .filter(|(_, lines)| !lines.is_empty())
+            .map(|(file, lines)| (file, lines.join("\n\t")))
+            .collect(),
+    }
+}
+
+/// The template description of an entry (empty for changed files), telling whether a
+/// file is listed as changed, deleted or as an updated submodule.
+fn entry_kind(description: &str) -> &str {
+    if TEMPLATE_DESCRIPTIONS.contains(&description) {
+        description
+    } else {
+        ""
+    }
+}
+
+/// Updates the file entries of a pending commit message to the files listed now, keeping
+/// the rest of the message as written.
+///
+/// The entry of a file listed the same way (changed, deleted or submodule) is kept with
+/// its description, the entry of a file listed another way is replaced, and the entry of
+/// a file no longer listed is dropped. The entries of new files are added after the last
+/// entry, or after the header when there is none.
+///
+/// # Arguments
+/// * `previous` - The pending commit message
+/// * `entries` - The file and generated entry of each file to list, in order
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::message_diff::update_message_entries;
+///
+/// let previous = "[3] (feat on main) Add parser\n\n- `a.rs`:\n\n\tParse tokens\n\n- `old.rs`:\n\n\tGone\n\nCloses #4\n";
+/// let entries = vec![
+///     ("a.rs".to_string(), "- `a.rs`:\n\n\t\n\n".to_string()),
+///     ("b.rs".to_string(), "- `b.rs`:\n\n\t\n\n".to_string()),
+/// ];
+///
+/// assert_eq!(
+///     update_message_entries(previous, &entries),
+///     "[3] (feat on main) Add parser\n\n- `a.rs`:\n\n\tParse tokens\n\n- `b.rs`:\n\n\t\n\nCloses #4\n"
+/// );
+/// ```
+#[must_use]
+pub fn update_message_entries(previous: &str, entries: &[(String, String)]) -> String {
+    let mut updated = String::new();
+    let mut listed = Vec::new();
+    let mut after_entries = None;
+    let mut lines = previous.split_inclusive('\n').peekable();
+
+    while let Some(line) = lines.next() {
+        let Some((file, description)) = parse_bullet(line) else {
+            updated.push_str(line);
+            continue;
+        };
+
+        // The entry: the bullet, then the blank and indented lines of its description
+        let mut entry = line.to_string();
+        while let Some(next) =
+            lines.next_if(|next| next.trim().is_empty() || next.starts_with([' ', '\t']))
+        {
+            entry.push_str(next);
+        }
+
+        let current = entries.iter().find(|(listed_file, _)| listed_file == file);
+        if let Some((_, generated)) = current {
+            let same_kind = parse_bullet(generated)
+                .is_some_and(|(_, generated)| entry_kind(generated) == entry_kind(description));
+            updated.push_str(if same_kind { &entry } else { generated });
+        }
+        listed.push(file.to_string());
+        after_entries = Some(updated.len());
+    }
+
+    let added: String = entries
+        .iter()
+        .filter(|(file, _)| !listed.contains(file))
+        .map(|(_, entry)| entry.as_str())
+        .collect();
+    if added.is_empty() {
+        return updated;
+    }
+
+    // Without entries, the new ones follow the header and its blank lines
+    let position = after_entries.unwrap_or_else(|| {
+        let header_end = updated.find('\n').map_or(updated.len(), |end| end + 1);
+        let blank_lines = updated[header_end..].len()
+            - updated[header_end..].trim_start_matches(['\n', '\r']).len();
+        header_end + blank_lines
+    });
+    let separator = if updated[..position].ends_with("\n\n") || position == 0 {
+        ""
+    } else if updated[..position].ends_with('\n') {
+        "\n"
+    } else {
+        "\n\n"
+    };
+    updated.insert_str(position, &format!("{separator}{added}"));
+
+    updated
+}
+
+/// Whether a line of the previous message was written by hand rather than generated.
+fn is_edit(line: &str) -> bool {
+    parse_bullet(line).is_none_or(|(_, description)| !TEMPLATE_DESCRIPTIONS.contains(&description))
+}
+
+/// Compares a pending commit message with the one replacing it.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::message_diff::compare_messages;
+///
+/// let previous = "[3] (feat on main) Add parser\n\n- `src/lexer.rs`:\n\n\tTokens\n";
+/// let next = "[3] (feat on main)\n\n- `src/parser.rs`:\n\n\t\n";
+/// let changes = compare_messages(previous, next);
+///
+/// assert_eq!(changes.added, vec!["src/parser.rs"]);
+/// assert_eq!(changes.removed, vec!["src/lexer.rs"]);
+/// assert_eq!(changes.discarded, vec!["[3] (feat on main) Add parser", "Tokens"]);
+/// ```
+#[must_use]
+pub fn compare_messages(previous: &str, next: &str) -> MessageChanges {
+    let (previous_header, previous_body) = previous.split_once('\n').unwrap_or((previous, ""));
+    let (next_header, next_body) = next.split_once('\n').unwrap_or((next, ""));
+
+    let files = |body: &str| -> Vec<String> {
+        body.lines()
+            .filter_map(parse_bullet)
+            .map(|(file, _)| file.to_string())
+            .collect()
+    };
+    let previous_files = files(previous_body);
+    let next_files = files(next_body);
+
+    let mut changes = MessageChanges {
+        added: next_files
+            .iter()
+            .filter(|file| !previous_files.contains(file))
+            .cloned()
+            .collect(),
+        removed: previous_files
+            .iter()
+            .filter(|file| !next_files.contains(file))
+            .cloned()
+            .collect(),
+        ..MessageChanges::default()
+    };
+
+    let (previous_header, next_header) = (previous_header.trim(), next_header.trim());
+    if previous_header != next_header {
+        changes.header = Some((previous_header.to_string(), next_header.to_string()));
+
+        let subject = header_subject(previous_header);
+        if !subject.is_empty() && !next_header.contains(subject) {
+            changes.discarded.push(previous_header.to_string());
+        }
+    }
+
+    let kept: Vec<&str> = next_body.lines().map(str::trim).collect();
+    changes.discarded.extend(
+        previous_body
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && is_edit(line) && !kept.contains(line))
+            .map(ToString::to_string),
+    );
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_messages_have_no_edits() {
+        let previous = "[2] (fix on main)\n\n- `a.rs`:\n\n\t\n\n- `old.rs`: deleted\n";
+        let next = "[2] (fix on main)\n\n- `a.rs`:\n\n\t\n\n- `b.rs`:\n\n\t\n";
+        let changes = compare_messages(previous, next);
+
+        assert_eq!(changes.header, None);
+        assert_eq!(changes.added, vec!["b.rs"]);
+        assert_eq!(changes.removed, vec!["old.rs"]);
+        assert!(changes.discarded.is_empty());
+        assert!(compare_messages(next, next).is_empty());
+    }
+
+    #[test]
+    fn test_header_change_keeping_the_subject() {
+        let changes = compare_messages(
+            "[2] (fix on main) Fix login\n",
+            "[2] (feat on main) Fix login\n",
+        );
+
+        assert_eq!(
+            changes.header,
+            Some((
+                "[2] (fix on main) Fix login".to_string(),
+                "[2] (feat on main) Fix login".to_string()
+            ))
+        );
+        assert!(changes.discarded.is_empty());
+    }
+
+    #[test]
+    fn test_draft_from_message() {
+        let message = "[2] (fix on main)\n\n- `a.rs`: Guard the input\n\n\tand the output\n\n- `b.rs`:\n\n\t\n\n- `c.rs`: deleted\n\nCloses #4\n";
+        let draft = draft_from_message(message);
+
+        assert_eq!(draft.subject, None);
+        assert_eq!(
+            draft.files,
+            HashMap::from([(
+                "a.rs".to_string(),
+                "Guard the input\n\tand the output".to_string()
+            )])
+        );
+    }
+
+    #[test]
+    fn test_update_message_entries() {
+        let entries = vec![
+            ("a.rs".to_string(), "- `a.rs`: deleted\n\n".to_string()),
+            ("b.rs".to_string(), "- `b.rs`:\n\n\t\n\n".to_string()),
+        ];
+
+        // A changed file now deleted is replaced, a new file added after the header
+        assert_eq!(
+            update_message_entries("(fix on main) Fix\n\n- `a.rs`: Guard the input\n", &entries),
+            "(fix on main) Fix\n\n- `a.rs`: deleted\n\n- `b.rs`:\n\n\t\n\n"
+        );
+        assert_eq!(
+            update_message_entries("(fix on main) Fix\n\n\nCloses #4\n", &entries),
+            "(fix on main) Fix\n\n\n- `a.rs`: deleted\n\n- `b.rs`:\n\n\t\n\nCloses #4\n"
+        );
+        assert_eq!(
+            update_message_entries("(fix on main)\n\n- `c.rs`:\n\n\tOld\n", &[]),
+            "(fix on main)\n\n"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let changes = MessageChanges {
+            header: Some(("(fix on main)".to_string(), "(feat on main)".to_string())),
+            added: vec!["b.rs".to_string()],
+            removed: vec!["a.rs".to_string()],
+            discarded: vec!["Handle the empty input".to_string()],
+        };
+
+        assert_eq!(
+            changes.render(false),
+            "  header  (fix on main)  ->  (feat on main)\n  + b.rs\n  - a.rs\n  edits not kept:\n  │ Handle the empty input"
+        );
+        assert!(changes.render(true).contains("\x1b[32m+ b.rs\x1b[0m"));
+    }
+}
@@ -5,115 +5,182 @@
 //!
 //! ## Submodules
 //!
-//! - [`repository`] - Core repository operations (finding git root, top level path)
-//! - [`branch`] - Branch operations (current branch, branch name formatting)
+//! - [`repository`] - Core repository operations (finding git root, top level path, state of HEAD, operation in progress, last commit, stashes)
+//! - [`branch`] - Branch operations (current branch, branch name formatting and templates, upstream and last push, checkout of local or remote branches, branch descriptions)
+//! - [`cherry_pick`] - Cherry-pick commits of another branch with their headers adapted to the current one
 //! - [`commit`] - Commit operations (commit counting, committing, commit message generation)
-//! - [`status`] - Git status parsing and processing
-//! - [`staging`] - File staging operations with pattern exclusion
-//! - [`remote`] - Remote operations (git push)
+//! - [`conflicts`] - Conflict resolution (conflicted files, continuing or aborting a merge/rebase)
+//! - [`diff`] - Staged changes viewer with a files/insertions/deletions summary
+//! - [`exec`] - Running git subprocesses (`GitCommand`, swappable `GitRunner`), traced with `--trace-git`
+//! - [`emoji`] - Emoji policy of commit subjects (gitmoji headers or strict ASCII)
+//! - [`status`] - Git status parsing and processing, including submodule entries
+//! - [`staging`] - File staging operations with pattern exclusion, and unstaging by pattern
+//! - [`remote`] - Remote operations (git push and fetch, remote web URLs)
 //! - [`files`] - File and exclusion handling utilities
+//! - [`file_ops`] - `git mv` and `git rm` keeping the pending commit message in sync
+//! - [`hooks`] - Commit hooks discovery honouring `core.hooksPath`
+//! - [`large_files`] - Warnings about binary and large files before they are committed
+//! - [`lock`] - Commit message lock against concurrent rona processes
+//! - [`message_diff`] - Changes to a pending commit message when it is regenerated
+//! - [`issue`] - Issue lookup for `Closes #N` references (`gh`/`glab` or REST API)
+//! - [`tag`] - Tag operations (annotated and signed tags, tag messages, listing and pushing tags)
+//! - [`trailers`] - Commit trailers (`Signed-off-by`, `Co-authored-by`) closing the message
+//! - [`rebase`] - Interactive rebase with the commits picked from prompts
+//! - [`release`] - Release flow (version bump, release commit, tag and push)
+//! - [`revert`] - Revert a commit with a rona-format revert message
+//! - [`rewrite`] - Rewording of existing commits into the rona format through an interactive rebase
+//! - [`secrets`] - Scan of the staged changes for secrets before committing
+//! - [`squash`] - Squash the last commits into one with a merged message
+//! - [`suggest`] - Commit type suggestion from the staged files
+//! - [`submodule`] - `git submodule` passthrough
+//! - [`validation`] - Commit message validation (subject length, empty bullets, typos)
+//! - [`wip`] - Quick `wip: <timestamp>` checkpoint commits, popping them back and branch checkpoints
+//! - [`audit`] - Audit log of the rona invocations changing the repository, shown by `rona history`
 
-use crate::errors::{GitError, Result, RonaError};
-use regex::Regex;
+use crate::errors::{Result, RonaError};
+use log::{debug, error, info};
 use std::process::Output;
 
+pub mod audit;
 pub mod branch;
+pub mod cherry_pick;
 pub mod commit;
+pub mod conflicts;
+pub mod diff;
+pub mod emoji;
+pub mod exec;
+pub mod file_ops;
 pub mod files;
+pub mod hooks;
+pub mod issue;
+pub mod large_files;
+pub mod lock;
+pub mod message_diff;
+pub mod rebase;
+pub mod release;
 pub mod remote;
 pub mod repository;
+pub mod revert;
+pub mod rewrite;
+pub mod secrets;
+pub mod squash;
 pub mod staging;
 pub mod status;
+pub mod submodule;
+pub mod suggest;
+pub mod tag;
+pub mod trailers;
+pub mod validation;
+pub mod wip;
 
 // Re-export commonly used functions for convenience
-pub use branch::{format_branch_name, get_current_branch};
+pub use audit::{AuditEntry, AuditRecord, audit_log_path, read_audit_log};
+pub use branch::{
+    BRANCH_DESCRIPTION_FILE_NAME, CheckoutTarget, DEFAULT_BRANCH_TEMPLATE, TicketPosition,
+    Upstream, branch_description_path, branch_name_from_template, branch_ticket, checkout_targets,
+    count_ahead_behind, create_branch, format_branch_name, get_branch_description,
+    get_current_branch, get_default_branch, get_last_push_time, get_upstream, git_checkout,
+    head_name, is_head_detached, is_protected_branch, is_upstream_in_reflog, list_branches,
+    list_checkout_targets, parse_branch_description, read_branch_description,
+    set_branch_description, slugify, with_branch_description, with_ticket,
+    write_branch_description,
+};
+pub use cherry_pick::{adapt_message, cherry_pick_no_commit, list_picked_commits};
 pub use commit::{
-    COMMIT_MESSAGE_FILE_PATH, COMMIT_TYPES, generate_commit_message, get_current_commit_nb,
-    git_commit,
+    COMMIT_MESSAGE_DIR, COMMIT_MESSAGE_FILE_NAME, COMMIT_TYPES, CommitChecks, CommitDraft,
+    CommitNumbering, CommitSigning, FileGrouping, MessageFiles, ShallowClone, authorship_args,
+    commit_message_path, commit_number_at, generate_commit_message, get_current_commit_nb,
+    git_commit, git_commit_checked, git_commit_in, git_commit_with_message, next_commit_number,
+    render_commit_message, update_commit_message,
+};
+pub use conflicts::{
+    FinishAction, finish_operation, has_conflict_markers, list_conflicted_files, mark_resolved,
+};
+pub use diff::{DiffColor, DiffOptions, DiffStat, staged_diff};
+pub use emoji::{EmojiPolicy, apply_emoji_policy, strip_non_ascii_subject};
+pub use exec::{GitCommand, GitRunner, SystemRunner, with_runner};
+pub use file_ops::{
+    RemoveOptions, committed_files, git_mv, git_rm, move_message_entries, remove_message_entries,
+};
+pub use files::{backup_path, create_needed_files, write_commit_message};
+pub use hooks::{HookStatus, commit_hooks, hooks_dir, report_commit_hooks, skips_hooks};
+pub use issue::{Issue, fetch_issue, list_open_issues};
+pub use large_files::{DEFAULT_LARGE_FILE_THRESHOLD, FileSize, FlaggedFile, warn_large_files};
+pub use lock::{MessageLock, lock_commit_message};
+pub use message_diff::{
+    MessageChanges, compare_messages, draft_from_message, update_message_entries,
+};
+pub use rebase::{
+    RebaseAction, RebaseCommit, build_todo, git_rebase, git_rebase_interactive, list_rebase_commits,
+};
+pub use release::{DEFAULT_RELEASE_FILES, ReleaseOptions, git_release, normalize_version};
+pub use remote::{
+    FetchOptions, bypasses_upstream_check, default_push_remote, discarded_remote_commits,
+    force_push_args, forces_push, get_remote_url, git_fetch, git_pull_rebase, git_push,
+    git_push_remotes, git_unshallow, list_remotes, network_timeout, parse_remote_url,
+    set_network_timeout,
+};
+pub use repository::{
+    LastCommit, OperationInProgress, Repository, RepositoryState, find_git_common_dir,
+    find_git_root, get_last_commit, get_last_commit_in, get_stash_count, has_staged_changes,
+    is_shallow_repository, operation_in_progress, repository_state, repository_state_in,
+    rerere_enabled,
 };
-pub use files::create_needed_files;
-pub use remote::git_push;
-pub use repository::find_git_root;
-pub use staging::git_add_with_exclude_patterns;
-pub use status::get_status_files;
+pub use revert::{RevertPreparation, RevertedCommit, prepare_revert, read_reverted_commit};
+pub use rewrite::{
+    RewriteEntry, build_rewrite_todo, conventional_subject, is_rona_header, parse_rona_header,
+    read_commit_message, rewrite_commits, rewrite_message, rewrite_message_path,
+};
+pub use squash::{SquashPreparation, prepare_squash};
+pub use staging::{
+    git_add_with_exclude_patterns, git_add_with_fuzzy_exclusions, git_unstage, git_unstage_patterns,
+};
+pub use status::{
+    FileState, StatusEntry, StatusSet, StatusSnapshot, SubmoduleState, get_status_files,
+};
+pub use submodule::git_submodule;
+pub use suggest::{TypeRule, default_type_rules, suggest_commit_type};
+pub use tag::{
+    TagInfo, create_annotated_tag, list_tags, push_tags, read_tag_message, tag_message_path,
+    write_tag_message,
+};
+pub use trailers::{Trailer, TrailerValues, append_trailers};
+pub use validation::{MessageIssue, ValidationRules, validate_commit_message};
+pub use wip::{check_wip_branch, git_wip, git_wip_pop, git_wip_snapshot};
 
 /// Handles the output of git commands, providing consistent error handling and success messaging.
 ///
 /// This function processes the output of git commands and:
-/// - Prints success messages when verbose mode is enabled
+/// - Logs success messages at debug level (shown with `--verbose`)
 /// - Displays command output if present
 /// - Formats and prints error messages with suggestions when commands fail
 ///
 /// # Arguments
 /// * `method_name` - The name of the git command being executed (e.g., "commit", "push")
 /// * `output` - The `Output` struct containing the command's stdout, stderr, and status
-/// * `verbose` - Whether to print verbose output during the operation
 ///
 /// # Returns
-/// * `Result<()>` - `Ok(())` if the command succeeded, `Err(RonaError)` if it failed
+/// * `Result<()>` - `Ok(())` if the command succeeded, `Err(RonaError::CommandFailed)` if it failed
 #[doc(hidden)]
-pub fn handle_output(method_name: &str, output: &Output, verbose: bool) -> Result<()> {
+pub fn handle_output(method_name: &str, output: &Output) -> Result<()> {
     use crate::errors::pretty_print_error;
 
     if output.status.success() {
-        if verbose {
-            println!("{method_name} successful!");
-        }
+        debug!("{method_name} successful!");
 
         if !output.stdout.is_empty() {
-            println!("{}", String::from_utf8_lossy(&output.stdout).trim());
+            info!("{}", String::from_utf8_lossy(&output.stdout).trim());
         }
 
         Ok(())
     } else {
         let error_message = String::from_utf8_lossy(&output.stderr);
 
-        println!("\n🚨 Git {method_name} failed:");
+        error!("\n🚨 Git {method_name} failed:");
         pretty_print_error(&error_message);
 
-        Err(RonaError::Io(std::io::Error::other(format!(
-            "Git {method_name} failed"
-        ))))
-    }
-}
-
-/// Extracts filenames from git status output using regex patterns.
-///
-/// This function compiles a regex pattern and extracts matching filenames from
-/// the provided message. It handles renamed files by preferring the new filename
-/// when multiple capture groups are available.
-///
-/// # Arguments
-/// * `message` - The git status output message to parse
-/// * `pattern` - The regex pattern to match filenames
-///
-/// # Returns
-/// * `Result<Vec<String>>` - The extracted filenames or an error message
-///
-/// # Errors
-/// * If the regex pattern fails to compile
-#[doc(hidden)]
-pub fn extract_filenames(message: &str, pattern: &str) -> Result<Vec<String>> {
-    let regex = Regex::new(pattern).map_err(|e| {
-        RonaError::Git(GitError::InvalidStatus {
-            output: format!("Failed to compile regex pattern: {e}"),
+        Err(RonaError::CommandFailed {
+            command: format!("git {method_name}"),
         })
-    })?;
-
-    let mut result = Vec::new();
-    for line in message.lines() {
-        if regex.is_match(line)
-            && let Some(captures) = regex.captures(line)
-        {
-            // If we have a second capture group (renamed file), use that
-            // Otherwise use the first capture group
-            if let Some(new_name) = captures.get(2) {
-                result.push(new_name.as_str().to_string());
-            } else if let Some(file_name) = captures.get(1) {
-                result.push(file_name.as_str().to_string());
-            }
-        }
     }
-
-    Ok(result)
 }
@@ -0,0 +1,293 @@
+//! Git Rebase Operations
+//!
+//! Interactive rebase driven by rona: the commits of `base..HEAD` are picked,
+//! squashed, fixed up or dropped (and reordered) from prompts, then the rebase
+//! todo list is generated and handed to `git rebase -i` through a prepared
+//! `GIT_SEQUENCE_EDITOR`, so git's own todo editor is never opened.
+
+use std::{
+    fmt::{self, Write},
+    fs::{create_dir_all, write},
+    path::Path,
+};
+
+use log::debug;
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::{COMMIT_MESSAGE_DIR, exec::GitCommand, repository::find_git_root};
+
+/// Name of the generated todo list, in the `rona` directory of the git directory.
+const TODO_FILE_NAME: &str = "rebase-todo";
+
+/// What happens to a commit during the rebase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RebaseAction {
+    /// Keep the commit
+    Pick,
+    /// Meld the commit into the previous one, editing the combined message
+    Squash,
+    /// Meld the commit into the previous one, keeping the previous message
+    Fixup,
+    /// Remove the commit
+    Drop,
+}
+
+impl RebaseAction {
+    /// All actions, in the order they are offered.
+    pub const ALL: [Self; 4] = [Self::Pick, Self::Squash, Self::Fixup, Self::Drop];
+
+    /// The todo list command of the action.
+    #[must_use]
+    pub const fn command(self) -> &'static str {
+        match self {
+            Self::Pick => "pick",
+            Self::Squash => "squash",
+            Self::Fixup => "fixup",
+            Self::Drop => "drop",
+        }
+    }
+
+    /// Whether the action melds the commit into the previous one.
+    #[must_use]
+    pub const fn melds(self) -> bool {
+        matches!(self, Self::Squash | Self::Fixup)
+    }
+}
+
+impl fmt::Display for RebaseAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.command())
+    }
+}
+
+/// A commit of the rebased range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RebaseCommit {
+    /// Full hash of the commit
+    pub hash: String,
+    /// First line of the message
+    pub subject: String,
+}
+
+impl fmt::Display for RebaseCommit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let short = self.hash.get(..7).unwrap_or(&self.hash);
+        write!(f, "{short} {}", self.subject)
+    }
+}
+
+/// Parses the `%H%x09%s` lines of `git log`.
+fn parse_commits(output: &str) -> Vec<RebaseCommit> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (hash, subject) = line.split_once('\t')?;
+            Some(RebaseCommit {
+                hash: hash.to_string(),
+                subject: subject.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Lists the commits of `base..HEAD`, oldest first like the rebase todo list.
+///
+/// # Errors
+/// * If `base` is not a valid revision
+pub fn list_rebase_commits(base: &str) -> Result<Vec<RebaseCommit>> {
+    let output = GitCommand::new()
+        .args([
+            "log",
+            "--reverse",
+            "--no-merges",
+            "--format=%H%x09%s",
+            &format!("{base}..HEAD"),
+            "--",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git log {base}..HEAD"),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    Ok(parse_commits(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Builds the rebase todo list of the chosen actions, in order.
+///
+/// # Errors
+/// * If the first commit kept is squashed or fixed up, having no previous commit to meld into
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::rebase::{RebaseAction, RebaseCommit, build_todo};
+///
+/// let commit = |hash: &str, subject: &str| RebaseCommit {
+///     hash: hash.to_string(),
+///     subject: subject.to_string(),
+/// };
+/// let todo = build_todo(&[
+///     (RebaseAction::Pick, commit("a1", "Add parser")),
+///     (RebaseAction::Fixup, commit("b2", "Fix typo")),
+/// ])?;
+///
+/// assert_eq!(todo, "pick a1 Add parser\nfixup b2 Fix typo\n");
+/// # Ok::<(), rona::errors::RonaError>(())
+/// ```
+pub fn build_todo(entries: &[(RebaseAction, RebaseCommit)]) -> Result<String> {
+    let first_kept = entries
+        .iter()
+        .find(|(action, _)| *action != RebaseAction::Drop);
+
+    if let Some((action, commit)) = first_kept.filter(|(action, _)| action.melds()) {
+        return Err(RonaError::InvalidInput(format!(
+            "Cannot {action} {commit}: there is no previous commit to meld it into"
+        )));
+    }
+
+    Ok(entries
+        .iter()
+        .fold(String::new(), |mut todo, (action, commit)| {
+            let _ = writeln!(todo, "{action} {} {}", commit.hash, commit.subject);
+            todo
+        }))
+}
+
+/// Quotes a path for the shell running `GIT_SEQUENCE_EDITOR`.
+fn shell_quote(path: &Path) -> String {
+    format!("'{}'", path.display().to_string().replace('\'', r"'\''"))
+}
+
+/// Rebases the current branch onto `base` with the given todo list.
+///
+/// The todo list is written to `.git/rona/rebase-todo` and copied over git's own
+/// by the `GIT_SEQUENCE_EDITOR`. The rebase inherits the terminal, so squashed
+/// messages are edited in the git editor and conflicts stop it as usual.
+///
+/// # Arguments
+/// * `base` - The revision the commits are replayed onto
+/// * `todo` - The todo list, as built by [`build_todo`]
+/// * `dry_run` - If true, only plan the rebase
+///
+/// # Errors
+/// * If the todo list cannot be written
+/// * If the rebase fails or stops (e.g. on conflicts)
+pub fn git_rebase_interactive(base: &str, todo: &str, dry_run: bool) -> Result<Plan> {
+    let mut plan = Plan::new();
+    plan.push(
+        ActionKind::Rebase,
+        base,
+        todo.lines().map(ToString::to_string).collect(),
+    );
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    let todo_path = find_git_root()?
+        .join(COMMIT_MESSAGE_DIR)
+        .join(TODO_FILE_NAME);
+    if let Some(parent) = todo_path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(&todo_path, todo)?;
+
+    debug!("Rebasing onto {base}...");
+
+    let status = GitCommand::new()
+        .args(["rebase", "-i", base])
+        .env(
+            "GIT_SEQUENCE_EDITOR",
+            format!("cp {}", shell_quote(&todo_path)),
+        )
+        .status()?;
+
+    if !status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git rebase -i {base}"),
+            output: format!("exited with {status}"),
+        }));
+    }
+
+    Ok(plan)
+}
+
+/// Rebases the current branch onto `base` (`git rebase <base>`).
+///
+/// # Errors
+/// * If the rebase fails or stops (e.g. on conflicts)
+pub fn git_rebase(base: &str, dry_run: bool) -> Result<Plan> {
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Rebase, base, Vec::new());
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    let status = GitCommand::new().args(["rebase", base]).status()?;
+
+    if !status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git rebase {base}"),
+            output: format!("exited with {status}"),
+        }));
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit(hash: &str, subject: &str) -> RebaseCommit {
+        RebaseCommit {
+            hash: hash.to_string(),
+            subject: subject.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_commits() {
+        assert_eq!(
+            parse_commits("a1b2c3d4e5\tAdd parser\nf6a7b8c9d0\tFix\tparser\n"),
+            vec![
+                commit("a1b2c3d4e5", "Add parser"),
+                commit("f6a7b8c9d0", "Fix\tparser")
+            ]
+        );
+        assert_eq!(
+            commit("a1b2c3d4e5", "Add parser").to_string(),
+            "a1b2c3d Add parser"
+        );
+    }
+
+    #[test]
+    fn test_build_todo_first_kept_commit_cannot_meld() {
+        let entries = [
+            (RebaseAction::Drop, commit("a1", "Add parser")),
+            (RebaseAction::Squash, commit("b2", "Fix parser")),
+        ];
+
+        assert!(matches!(
+            build_todo(&entries),
+            Err(RonaError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(
+            shell_quote(Path::new("/repo/it's/.git/rona/rebase-todo")),
+            r"'/repo/it'\''s/.git/rona/rebase-todo'"
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Release Operations
+//!
+//! Release flow combining a version bump in project manifests, a release commit,
+//! an annotated tag and a push of both.
+
+use std::{
+    fmt::Write,
+    fs::{read_to_string, write},
+};
+
+use log::{info, warn};
+use regex::Regex;
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+    utils::find_project_root,
+};
+
+use super::{
+    commit::{CommitSigning, git_commit_with_message},
+    exec::GitCommand,
+    handle_output,
+    remote::git_push,
+    tag::create_annotated_tag,
+};
+
+/// Files whose version is bumped when no `release_files` are configured.
+pub const DEFAULT_RELEASE_FILES: [&str; 2] = ["Cargo.toml", "package.json"];
+
+/// Options controlling the release flow.
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseOptions {
+    /// Create a GPG-signed tag instead of a plain annotated one
+    pub sign_tag: bool,
+    /// How the release commit is signed
+    pub signing: CommitSigning,
+    /// Push the release commit and tag once created
+    pub push: bool,
+}
+
+/// Validates a release version and strips an optional leading `v`.
+///
+/// Accepts semantic versions such as `1.2.3`, `v1.2.3`, `1.2.3-rc.1` or `1.2.3+build.5`.
+///
+/// # Errors
+/// * If the version is not a valid semantic version
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::release::normalize_version;
+///
+/// assert_eq!(normalize_version("v1.2.3").unwrap(), "1.2.3");
+/// assert!(normalize_version("1.2").is_err());
+/// ```
+pub fn normalize_version(version: &str) -> Result<String> {
+    let version = version.trim();
+    let version = version.strip_prefix('v').unwrap_or(version);
+
+    let semver = Regex::new(r"^\d+\.\d+\.\d+(?:-[0-9A-Za-z.-]+)?(?:\+[0-9A-Za-z.-]+)?$")
+        .map_err(|e| RonaError::InvalidInput(e.to_string()))?;
+
+    if semver.is_match(version) {
+        Ok(version.to_string())
+    } else {
+        Err(RonaError::InvalidInput(format!(
+            "'{version}' is not a valid semantic version (expected e.g. 1.2.3)"
+        )))
+    }
+}
+
+/// Replaces the first top-level `version` entry of a manifest.
+///
+/// Handles both TOML (`version = "1.0.0"`) and JSON (`"version": "1.0.0"`) forms.
+/// Inline tables such as `foo = { version = "1" }` are left untouched.
+///
+/// # Arguments
+/// * `content` - The manifest content
+/// * `version` - The new version
+///
+/// # Returns
+/// * `Option<(String, String)>` - The previous version and the updated content,
+///   or `None` if no version entry was found
+#[must_use]
+pub fn bump_version_in_content(content: &str, version: &str) -> Option<(String, String)> {
+    let regex = Regex::new(r#"(?m)^(\s*"?version"?\s*[=:]\s*")([^"]*)(")"#).ok()?;
+    let captures = regex.captures(content)?;
+
+    let previous = captures.get(2)?.as_str().to_string();
+    let range = captures.get(2)?.range();
+
+    let mut updated = String::with_capacity(content.len());
+    updated.push_str(&content[..range.start]);
+    updated.push_str(version);
+    updated.push_str(&content[range.end..]);
+
+    Some((previous, updated))
+}
+
+/// Bumps the version in each existing release file of the project.
+///
+/// Files are resolved relative to the project root; missing files are skipped.
+///
+/// # Arguments
+/// * `files` - The release files to update
+/// * `version` - The new version
+/// * `dry_run` - If true, only plan the changes
+///
+/// # Errors
+/// * If a release file cannot be read or written
+/// * If none of the release files exist or contain a version
+///
+/// # Returns
+/// * `Plan` - One version bump action per file updated (or that would be)
+pub fn bump_version_files(files: &[String], version: &str, dry_run: bool) -> Result<Plan> {
+    let project_root = find_project_root()?;
+    let mut plan = Plan::new();
+
+    for file in files {
+        let path = project_root.join(file);
+
+        if !path.exists() {
+            continue;
+        }
+
+        let content = read_to_string(&path)?;
+
+        let Some((previous, updated)) = bump_version_in_content(&content, version) else {
+            warn!("⚠️  No version entry found in {file}, skipping");
+            continue;
+        };
+
+        if !dry_run {
+            write(&path, updated)?;
+            info!("Bumped version in {file}: {previous} -> {version}");
+        }
+
+        plan.push(
+            ActionKind::BumpVersion,
+            file,
+            vec![previous, version.to_string()],
+        );
+    }
+
+    if plan.is_empty() {
+        return Err(RonaError::InvalidInput(format!(
+            "No version found in any release file ({})",
+            files.join(", ")
+        )));
+    }
+
+    Ok(plan)
+}
+
+/// Builds the release commit message from the header and the bumped files.
+#[must_use]
+pub fn build_release_message(header: &str, version: &str, files: &[String]) -> String {
+    let mut message = format!("{header}\n\n");
+
+    for file in files {
+        let _ = writeln!(message, "- `{file}`: bump version to {version}");
+    }
+
+    message
+}
+
+/// Ensures there are no pending changes to tracked files before releasing.
+fn ensure_clean_tree() -> Result<()> {
+    let output = GitCommand::new()
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: "git status --porcelain --untracked-files=no".to_string(),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    if output.stdout.is_empty() {
+        Ok(())
+    } else {
+        Err(RonaError::Git(GitError::DirtyWorkingDirectory))
+    }
+}
+
+/// Runs the full release flow: bump, commit, tag and push.
+///
+/// # Arguments
+/// * `version` - The normalized version (see [`normalize_version`])
+/// * `header` - The release commit header, in the project's rona format
+/// * `files` - The release files to update
+/// * `options` - Tag signing, commit signing and push options
+/// * `dry_run` - If true, plan every step without changing anything
+///
+/// # Returns
+/// * `Plan` - Every step performed (or that would be, in dry-run mode)
+///
+/// # Errors
+/// * If the working tree has pending changes to tracked files
+/// * If no release file could be bumped
+/// * If any of the git add, commit, tag or push commands fail
+pub fn git_release(
+    version: &str,
+    header: &str,
+    files: &[String],
+    options: &ReleaseOptions,
+    dry_run: bool,
+) -> Result<Plan> {
+    ensure_clean_tree()?;
+
+    let tag = format!("v{version}");
+    let mut plan = bump_version_files(files, version, dry_run)?;
+    let bumped: Vec<String> = plan
+        .targets(ActionKind::BumpVersion)
+        .into_iter()
+        .map(ToString::to_string)
+        .collect();
+    let message = build_release_message(header, version, &bumped);
+
+    for file in &bumped {
+        plan.push(ActionKind::Stage, file, Vec::new());
+    }
+
+    if !dry_run {
+        let output = GitCommand::new()
+            .current_dir(find_project_root()?)
+            .arg("add")
+            .args(&bumped)
+            .output()?;
+
+        handle_output("add", &output)?;
+    }
+
+    plan.extend(git_commit_with_message(
+        &message,
+        &[],
+        &options.signing,
+        dry_run,
+    )?);
+    plan.extend(create_annotated_tag(
+        &tag,
+        &format!("Release {tag}"),
+        options.sign_tag,
+        dry_run,
+    )?);
+
+    if options.push {
+        plan.extend(git_push(&["--follow-tags".to_string()], dry_run)?);
+    }
+
+    if !dry_run {
+        info!("✅ Released {tag}");
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_version() {
+        assert_eq!(normalize_version("1.2.3").unwrap(), "1.2.3");
+        assert_eq!(normalize_version("v2.0.0").unwrap(), "2.0.0");
+        assert_eq!(normalize_version("1.0.0-rc.1").unwrap(), "1.0.0-rc.1");
+        assert_eq!(normalize_version("1.0.0+build.7").unwrap(), "1.0.0+build.7");
+
+        assert!(normalize_version("1.2").is_err());
+        assert!(normalize_version("latest").is_err());
+        assert!(normalize_version("").is_err());
+    }
+
+    #[test]
+    fn test_bump_version_in_cargo_toml() {
+        let content = "[package]\nname = \"rona\"\nversion = \"2.10.3\"\n\n[dependencies]\nclap = { version = \"4.5\" }\n";
+        let (previous, updated) = bump_version_in_content(content, "2.11.0").unwrap();
+
+        assert_eq!(previous, "2.10.3");
+        assert!(updated.contains("version = \"2.11.0\""));
+        assert!(updated.contains("clap = { version = \"4.5\" }"));
+    }
+
+    #[test]
+    fn test_bump_version_in_package_json() {
+        let content =
+            "{\n  \"name\": \"app\",\n  \"version\": \"0.1.0\",\n  \"dependencies\": {}\n}\n";
+        let (previous, updated) = bump_version_in_content(content, "0.2.0").unwrap();
+
+        assert_eq!(previous, "0.1.0");
+        assert!(updated.contains("\"version\": \"0.2.0\""));
+    }
+
+    #[test]
+    fn test_bump_version_missing_entry() {
+        assert!(bump_version_in_content("[package]\nname = \"x\"\n", "1.0.0").is_none());
+    }
+
+    #[test]
+    fn test_build_release_message() {
+        let message = build_release_message(
+            "[42] (chore on main) Release v1.0.0",
+            "1.0.0",
+            &["Cargo.toml".to_string()],
+        );
+
+        assert_eq!(
+            message,
+            "[42] (chore on main) Release v1.0.0\n\n- `Cargo.toml`: bump version to 1.0.0\n"
+        );
+    }
+}
@@ -1,10 +1,109 @@
 //! Git Remote Operations
 //!
-//! Remote repository operations including push functionality with dry-run support.
+//! Remote repository operations including push (to one remote or to several in turn)
+//! and fetch functionality with dry-run support, and web URLs of repositories hosted on
+//! GitHub, GitLab or Bitbucket. Failures caused by authentication, credential helpers,
+//! proxies, missing remote repositories or rejected pushes are mapped to dedicated
+//! errors suggesting a fix instead of printing git's raw output.
+//!
+//! Pushes, pulls and fetches are stopped after `network_timeout` seconds (120 by default),
+//! a hung connection failing with [`GitError::Timeout`] instead of blocking rona forever.
+
+use std::{
+    env, fmt, io,
+    process::Output,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use log::{debug, error, info};
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::exec::GitCommand;
+
+/// Lines of git (and curl) output telling HTTPS authentication failed.
+const HTTP_AUTH_FAILURES: [&str; 5] = [
+    "Authentication failed for",
+    "could not read Username",
+    "could not read Password",
+    "Invalid username or password",
+    "The requested URL returned error: 403",
+];
+
+/// Lines of git output telling the remote repository does not exist (or is not visible).
+const REMOTE_GONE_FAILURES: [&str; 3] = [
+    "Repository not found",
+    "does not appear to be a git repository",
+    "could not be found",
+];
+
+/// Lowercase markers of pushes refused by branch protection rules (GitHub's GH006 and
+/// GH013, GitLab's and Bitbucket's protected branches).
+const PROTECTED_BRANCH_REJECTIONS: [&str; 3] = ["protected branch", "gh006", "gh013"];
+
+/// Reasons git gives for rejecting a push of a branch behind its remote counterpart.
+const NON_FAST_FORWARD_REJECTIONS: [&str; 3] =
+    ["(fetch first)", "(non-fast-forward)", "(stale info)"];
+
+/// Lines of curl output telling a server (possibly the proxy) could not be reached.
+const PROXY_CONNECTION_FAILURES: [&str; 3] = [
+    "Failed to connect to",
+    "Could not resolve host",
+    "Connection timed out",
+];
 
-use std::process::Command;
+/// Environment variables read by curl for the proxy, by priority.
+const PROXY_VARIABLES: [&str; 5] = [
+    "https_proxy",
+    "HTTPS_PROXY",
+    "http_proxy",
+    "all_proxy",
+    "ALL_PROXY",
+];
+
+/// Time a push, pull or fetch may take when no `network_timeout` is configured.
+pub const DEFAULT_NETWORK_TIMEOUT: Duration = Duration::from_mins(2);
+
+/// Seconds of the network timeout, 0 for none.
+static NETWORK_TIMEOUT_SECONDS: AtomicU64 = AtomicU64::new(DEFAULT_NETWORK_TIMEOUT.as_secs());
+
+/// Sets the time pushes, pulls and fetches may take, in seconds: `network_timeout` of the
+/// configuration, 0 for no limit.
+pub fn set_network_timeout(seconds: u64) {
+    NETWORK_TIMEOUT_SECONDS.store(seconds, Ordering::Relaxed);
+}
+
+/// The time pushes, pulls and fetches may take, `None` when they are not limited.
+#[must_use]
+pub fn network_timeout() -> Option<Duration> {
+    match NETWORK_TIMEOUT_SECONDS.load(Ordering::Relaxed) {
+        0 => None,
+        seconds => Some(Duration::from_secs(seconds)),
+    }
+}
+
+/// Runs a command reaching a remote, stopping it after `timeout`.
+///
+/// # Errors
+/// * `GitError::Timeout` - If git did not finish within `timeout`
+/// * If git cannot be run
+fn network_output(command: &mut GitCommand, timeout: Option<Duration>) -> Result<Output> {
+    if let Some(timeout) = timeout {
+        command.timeout(timeout);
+    }
 
-use crate::errors::Result;
+    command.output().map_err(|e| match (e.kind(), timeout) {
+        (io::ErrorKind::TimedOut, Some(timeout)) => RonaError::Git(GitError::Timeout {
+            command: command.to_string(),
+            seconds: timeout.as_secs(),
+        }),
+        _ => e.into(),
+    })
+}
 
 /// Pushes committed changes to the remote repository.
 ///
@@ -13,14 +112,22 @@ use crate::errors::Result;
 ///
 /// # Arguments
 /// * `args` - Additional arguments to pass to the git push command (e.g., `--force`, `origin main`)
-/// * `verbose` - Whether to print verbose output during the operation
-/// * `dry_run` - If true, only show what would be pushed without actually pushing
+/// * `dry_run` - If true, only plan the push without actually pushing
+///
+/// # Returns
+/// * `Plan` - The push action performed (or that would be, in dry-run mode)
 ///
 /// # Errors
 /// * If the git push command fails
+/// * `GitError::Timeout` - If the push takes longer than the network timeout
 /// * If not in a git repository
 /// * If no remote repository is configured
-/// * If authentication fails
+/// * `GitError::AuthenticationFailed` / `SshAuthenticationFailed` - If authentication fails
+/// * `GitError::CredentialHelperMissing` - If the configured credential helper is not installed
+/// * `GitError::ProxyError` - If the remote cannot be reached through the proxy
+/// * `GitError::NonFastForward` - If the remote branch has commits the local branch does not have
+/// * `GitError::ProtectedBranch` - If the remote refuses updates of the branch
+/// * `GitError::RemoteGone` - If the remote repository does not exist anymore
 ///
 /// # Examples
 ///
@@ -28,49 +135,1097 @@ use crate::errors::Result;
 /// use rona::git::remote::git_push;
 ///
 /// // Basic push
-/// git_push(&vec![], false, false)?;
+/// git_push(&vec![], false)?;
 ///
 /// // Push with force
-/// git_push(&vec!["--force".to_string()], true, false)?;
+/// git_push(&vec!["--force".to_string()], false)?;
 ///
 /// // Push to specific remote and branch
-/// git_push(&vec!["origin".to_string(), "main".to_string()], false, false)?;
+/// git_push(&vec!["origin".to_string(), "main".to_string()], false)?;
 ///
 /// // Dry run to preview the push
-/// git_push(&vec![], false, true)?;
+/// git_push(&vec![], true)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn git_push(args: &[String], dry_run: bool) -> Result<Plan> {
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Push, "", args.to_vec());
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    debug!("\nPushing...");
+
+    let output = network_output(GitCommand::new().arg("push").args(args), network_timeout())?;
+
+    handle_remote_output("push", &output)?;
+
+    Ok(plan)
+}
+
+/// Pushes the current branch to each remote in turn (`git push <args> <remote> HEAD`),
+/// going on after a failure so that the other remotes still get the commits.
+///
+/// Each failure is logged as it happens, and a summary of the pushes closes the output.
+///
+/// # Arguments
+/// * `remotes` - The remotes to push to, in order
+/// * `args` - Additional arguments passed to every push (e.g. `--force-with-lease`)
+/// * `dry_run` - If true, only plan the pushes
+///
+/// # Returns
+/// * `Plan` - A push action per remote
+///
+/// # Errors
+/// * `GitError::PushFailedOnRemotes` - If the push failed on any of the remotes
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::remote::git_push_remotes;
+///
+/// let remotes = vec!["origin".to_string(), "mirror".to_string()];
+/// git_push_remotes(&remotes, &[], false)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn git_push_remotes(remotes: &[String], args: &[String], dry_run: bool) -> Result<Plan> {
+    let mut plan = Plan::new();
+    let mut failed = Vec::new();
+
+    for remote in remotes {
+        let mut remote_args = args.to_vec();
+        remote_args.extend([remote.clone(), "HEAD".to_string()]);
+
+        match git_push(&remote_args, dry_run) {
+            Ok(remote_plan) => {
+                if !dry_run {
+                    info!("✅ Pushed to {remote}");
+                }
+                plan.extend(remote_plan);
+            }
+            Err(err) => {
+                error!("❌ Push to {remote} failed: {err}");
+                failed.push(remote.as_str());
+            }
+        }
+    }
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    info!(
+        "Pushed to {} of {} remote(s)",
+        remotes.len() - failed.len(),
+        remotes.len()
+    );
+
+    if failed.is_empty() {
+        Ok(plan)
+    } else {
+        Err(GitError::PushFailedOnRemotes {
+            remotes: failed.join(", "),
+        }
+        .into())
+    }
+}
+
+/// Extracts the first `'quoted'` part of a line (git quotes URLs this way).
+fn quoted(line: &str) -> Option<&str> {
+    let (_, rest) = line.split_once('\'')?;
+    let (value, _) = rest.split_once('\'')?;
+    Some(value)
+}
+
+/// The destination branch of a `! [rejected] src -> dst (reason)` line of `git push`.
+fn rejected_branch(line: &str) -> Option<&str> {
+    let rest = line
+        .strip_prefix("! [rejected]")
+        .or_else(|| line.strip_prefix("! [remote rejected]"))?;
+    let (_, destination) = rest.split_once(" -> ")?;
+    destination.split_whitespace().next()
+}
+
+/// Recognizes the failures of a command talking to a remote that the user can fix:
+/// missing credential helpers, proxy errors, SSH or HTTPS authentication failures,
+/// missing remote repositories and pushes rejected for being behind the remote branch
+/// or for updating a protected branch.
+///
+/// Connection failures are only blamed on the proxy when one is configured (`proxy`),
+/// as curl does not mention it when the proxy itself cannot be reached.
+///
+/// Returns `None` for other failures (e.g. pushes declined by a hook), whose output is
+/// shown as is.
+///
+/// # Examples
+///
+/// ```
+/// use rona::{errors::GitError, git::remote::classify_remote_failure};
+///
+/// let stderr = "git@github.com: Permission denied (publickey).\n\
+///               fatal: Could not read from remote repository.";
+/// assert!(matches!(
+///     classify_remote_failure(stderr, None),
+///     Some(GitError::SshAuthenticationFailed)
+/// ));
+/// assert!(matches!(
+///     classify_remote_failure("! [rejected]        main -> main (fetch first)", None),
+///     Some(GitError::NonFastForward { branch }) if branch == "main"
+/// ));
+/// assert!(classify_remote_failure("! [remote rejected] main -> main (hook declined)", None).is_none());
+/// ```
+#[must_use]
+pub fn classify_remote_failure(stderr: &str, proxy: Option<&str>) -> Option<GitError> {
+    let lines: Vec<&str> = stderr.lines().map(str::trim).collect();
+
+    // Reported first: git falls back to prompting (and failing) afterwards
+    if let Some(helper) = lines.iter().find_map(|line| {
+        line.strip_prefix("git: 'credential-")?
+            .split_once("' is not a git command")
+            .map(|(helper, _)| helper.to_string())
+    }) {
+        return Some(GitError::CredentialHelperMissing { helper });
+    }
+
+    if let Some(line) = lines.iter().find(|line| {
+        line.starts_with("fatal: unable to access")
+            && (line.to_lowercase().contains("proxy")
+                || proxy.is_some() && PROXY_CONNECTION_FAILURES.iter().any(|f| line.contains(f)))
+    }) {
+        let reason = line.rsplit_once("': ").map_or(*line, |(_, reason)| reason);
+        let reason = match proxy {
+            Some(proxy) => format!("{reason} (proxy: {proxy})"),
+            None => reason.to_string(),
+        };
+        return Some(GitError::ProxyError { reason });
+    }
+
+    if lines.iter().any(|line| {
+        line.contains("Permission denied (publickey")
+            || line.contains("agent refused operation")
+            || line.contains("Host key verification failed")
+    }) {
+        return Some(GitError::SshAuthenticationFailed);
+    }
+
+    if let Some(line) = lines
+        .iter()
+        .find(|line| REMOTE_GONE_FAILURES.iter().any(|f| line.contains(f)))
+    {
+        let url = quoted(line)
+            .or_else(|| lines.iter().find_map(|line| quoted(line)))
+            .unwrap_or("the remote")
+            .to_string();
+        return Some(GitError::RemoteGone { url });
+    }
+
+    let rejected = lines.iter().find_map(|line| rejected_branch(line));
+    if lines.iter().any(|line| {
+        let line = line.to_lowercase();
+        PROTECTED_BRANCH_REJECTIONS.iter().any(|r| line.contains(r))
+    }) {
+        let branch = rejected.unwrap_or("the branch").to_string();
+        return Some(GitError::ProtectedBranch { branch });
+    }
+    if let Some(branch) = lines.iter().find_map(|line| {
+        rejected_branch(line).filter(|_| {
+            NON_FAST_FORWARD_REJECTIONS
+                .iter()
+                .any(|r| line.ends_with(r))
+        })
+    }) {
+        return Some(GitError::NonFastForward {
+            branch: branch.to_string(),
+        });
+    }
+
+    let auth_line = lines.iter().find(|line| {
+        HTTP_AUTH_FAILURES
+            .iter()
+            .any(|failure| line.contains(failure))
+    })?;
+    let url = quoted(auth_line)
+        .or_else(|| lines.iter().find_map(|line| quoted(line)))
+        .unwrap_or("the remote")
+        .to_string();
+
+    Some(GitError::AuthenticationFailed { url })
+}
+
+/// The proxy git goes through: `http.proxy`, then the proxy environment variables.
+fn configured_proxy() -> Option<String> {
+    let from_config = GitCommand::new()
+        .args(["config", "--get", "http.proxy"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+    from_config
+        .into_iter()
+        .chain(
+            PROXY_VARIABLES
+                .iter()
+                .filter_map(|name| env::var(name).ok()),
+        )
+        .find(|proxy| !proxy.is_empty())
+}
+
+/// Handles the output of a command talking to a remote, mapping known failures
+/// to actionable errors (see [`classify_remote_failure`]).
+///
+/// # Errors
+/// * The classified error, or `RonaError::CommandFailed` for other failures
+fn handle_remote_output(method_name: &str, output: &Output) -> Result<()> {
+    if !output.status.success()
+        && let Some(error) = classify_remote_failure(
+            &String::from_utf8_lossy(&output.stderr),
+            configured_proxy().as_deref(),
+        )
+    {
+        debug!("git {method_name} failed:");
+        for line in String::from_utf8_lossy(&output.stderr).lines() {
+            debug!("   {line}");
+        }
+
+        return Err(RonaError::Git(error));
+    }
+
+    handle_output(method_name, output)
+}
+
+/// Makes `--force` (or `-f`) pushes safe by mapping them to `--force-with-lease`, which
+/// fails if the remote branch moved since it was last fetched. With `unsafe_force`,
+/// the push is forced as is, adding `--force` if needed.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::remote::force_push_args;
+///
+/// let args = vec!["-f".to_string(), "origin".to_string()];
+/// assert_eq!(force_push_args(&args, false), ["--force-with-lease", "origin"]);
+/// assert_eq!(force_push_args(&[], true), ["--force"]);
+/// ```
+#[must_use]
+pub fn force_push_args(args: &[String], unsafe_force: bool) -> Vec<String> {
+    if unsafe_force {
+        let mut args = args.to_vec();
+        if !args.iter().any(|arg| arg == "--force" || arg == "-f") {
+            args.push("--force".to_string());
+        }
+        return args;
+    }
+
+    args.iter()
+        .map(|arg| match arg.as_str() {
+            "--force" | "-f" => "--force-with-lease".to_string(),
+            _ => arg.clone(),
+        })
+        .collect()
+}
+
+/// Whether the push arguments force the push (`--force*`, `-f` or a `+` refspec).
+#[must_use]
+pub fn forces_push(args: &[String]) -> bool {
+    args.iter()
+        .any(|arg| arg.starts_with("--force") || arg == "-f" || arg.starts_with('+'))
+}
+
+/// Lists the upstream commits a force push of the current branch would discard
+/// (`git log @{u} --not HEAD`), as `<hash> <subject>` lines.
+///
+/// # Errors
+/// * If the git log command cannot be run
+///
+/// # Returns
+/// * `Vec<String>` - The discarded commits, empty without an upstream
+pub fn discarded_remote_commits() -> Result<Vec<String>> {
+    let output = GitCommand::new()
+        .args(["log", "--format=%h %s", "@{upstream}", "--not", "HEAD"])
+        .output()?;
+
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect())
+}
+
+/// Whether the push arguments name what is pushed or force it, in which case the
+/// current branch is not compared with its upstream before pushing.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::remote::bypasses_upstream_check;
+///
+/// assert!(!bypasses_upstream_check(&["--no-verify".to_string()]));
+/// assert!(bypasses_upstream_check(&["origin".to_string(), "main".to_string()]));
+/// assert!(bypasses_upstream_check(&["--force-with-lease".to_string()]));
+/// ```
+#[must_use]
+pub fn bypasses_upstream_check(args: &[String]) -> bool {
+    args.iter().any(|arg| {
+        !arg.starts_with('-')
+            || arg.starts_with("--force")
+            || matches!(
+                arg.as_str(),
+                "-f" | "--all" | "--mirror" | "--tags" | "--delete" | "-d"
+            )
+    })
+}
+
+/// Rebases the current branch onto its upstream (`git pull --rebase`).
+///
+/// # Arguments
+/// * `dry_run` - If true, only plan the pull without actually pulling
+///
+/// # Returns
+/// * `Plan` - The pull action performed (or that would be, in dry-run mode)
+///
+/// # Errors
+/// * If the git pull command fails (e.g. on conflicts)
+/// * `GitError::Timeout` - If the pull takes longer than the network timeout
+pub fn git_pull_rebase(dry_run: bool) -> Result<Plan> {
+    let args = vec!["--rebase".to_string()];
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Pull, "", args.clone());
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    debug!("\nPulling...");
+
+    let output = network_output(GitCommand::new().arg("pull").args(&args), network_timeout())?;
+
+    handle_remote_output("pull", &output)?;
+
+    Ok(plan)
+}
+
+/// Options of `git fetch`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FetchOptions {
+    /// Fetch all remotes (`--all`)
+    pub all: bool,
+    /// Remove remote-tracking branches deleted on the remote (`--prune`)
+    pub prune: bool,
+    /// Fetch all tags (`--tags`)
+    pub tags: bool,
+}
+
+impl FetchOptions {
+    /// The git arguments matching the options.
+    #[must_use]
+    pub fn to_args(self) -> Vec<String> {
+        [
+            (self.all, "--all"),
+            (self.prune, "--prune"),
+            (self.tags, "--tags"),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, flag)| flag.to_string())
+        .collect()
+    }
+}
+
+/// The remote-tracking refs changed by a fetch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FetchSummary {
+    /// Remote branches seen for the first time
+    pub new_branches: Vec<String>,
+    /// Remote branches moved forward or force-updated
+    pub updated_branches: Vec<String>,
+    /// Remote-tracking branches pruned
+    pub deleted_branches: Vec<String>,
+    /// Tags fetched for the first time
+    pub new_tags: Vec<String>,
+}
+
+impl FetchSummary {
+    /// Whether the fetch changed nothing.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.new_branches.is_empty()
+            && self.updated_branches.is_empty()
+            && self.deleted_branches.is_empty()
+            && self.new_tags.is_empty()
+    }
+}
+
+impl fmt::Display for FetchSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "Already up to date");
+        }
+
+        let sections = [
+            ("New branches", &self.new_branches, '+'),
+            ("Updated branches", &self.updated_branches, '~'),
+            ("Deleted branches", &self.deleted_branches, '-'),
+            ("New tags", &self.new_tags, '+'),
+        ];
+
+        let mut first = true;
+        for (title, refs, marker) in sections {
+            if refs.is_empty() {
+                continue;
+            }
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+
+            write!(f, "{title} ({}):", refs.len())?;
+            for reference in refs {
+                write!(f, "\n  {marker} {reference}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the ref update lines written by `git fetch` to stderr.
+///
+/// # Arguments
+/// * `output` - The stderr of `git fetch`
+///
+/// # Returns
+/// * `FetchSummary` - The new, updated and deleted branches and the new tags
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::remote::parse_fetch_output;
+///
+/// let output = [
+///     "From github.com:owner/repo",
+///     " * [new branch]      feature    -> origin/feature",
+///     "   1a2b3c4..5d6e7f8  main       -> origin/main",
+///     " - [deleted]         (none)     -> origin/old",
+/// ]
+/// .join("\n");
+///
+/// let summary = parse_fetch_output(&output);
+/// assert_eq!(summary.new_branches, vec!["origin/feature"]);
+/// assert_eq!(summary.updated_branches, vec!["origin/main"]);
+/// assert_eq!(summary.deleted_branches, vec!["origin/old"]);
+/// ```
+#[must_use]
+pub fn parse_fetch_output(output: &str) -> FetchSummary {
+    let mut summary = FetchSummary::default();
+
+    for line in output.lines() {
+        let line = line.trim();
+        let Some((flag, rest)) = line.split_once(' ') else {
+            continue;
+        };
+        let Some((_, reference)) = rest.rsplit_once(" -> ") else {
+            continue;
+        };
+        // Forced updates end with "(forced update)"
+        let reference = reference
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        match flag {
+            "*" if rest.trim_start().starts_with("[new tag]") => summary.new_tags.push(reference),
+            "*" => summary.new_branches.push(reference),
+            "-" => summary.deleted_branches.push(reference),
+            "+" => summary.updated_branches.push(reference),
+            _ if flag.contains("..") => summary.updated_branches.push(reference),
+            _ => {}
+        }
+    }
+
+    summary
+}
+
+/// Fetches from a remote, or from all remotes, and reports the changed refs.
+///
+/// # Arguments
+/// * `remote` - The remote to fetch from (the default remote when `None`)
+/// * `options` - `--all`, `--prune` and `--tags` options
+/// * `dry_run` - If true, only plan the fetch without actually fetching
+///
+/// # Returns
+/// * `Plan` - The fetch action performed (or that would be, in dry-run mode)
+///
+/// # Errors
+/// * If the git fetch command fails
+/// * `GitError::Timeout` - If the fetch takes longer than the network timeout
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::remote::{FetchOptions, git_fetch};
+///
+/// // Fetch all remotes and prune deleted branches
+/// let options = FetchOptions { all: true, prune: true, tags: false };
+/// git_fetch(None, options, false)?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-pub fn git_push(args: &[String], verbose: bool, dry_run: bool) -> Result<()> {
-    if verbose {
-        println!("\nPushing...");
+pub fn git_fetch(remote: Option<&str>, options: FetchOptions, dry_run: bool) -> Result<Plan> {
+    let args = options.to_args();
+
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Fetch, remote.unwrap_or_default(), args.clone());
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    debug!("Fetching...");
+
+    let output = network_output(
+        GitCommand::new().arg("fetch").args(&args).args(remote),
+        network_timeout(),
+    )?;
+
+    handle_remote_output("fetch", &output)?;
+
+    // Ref updates are written to stderr
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for line in stderr.lines() {
+        debug!("{line}");
     }
+    info!("{}", parse_fetch_output(&stderr));
+
+    Ok(plan)
+}
+
+/// Fetches the full history of a shallow clone (`git fetch --unshallow`).
+///
+/// # Arguments
+/// * `dry_run` - If true, only plan the fetch without actually fetching
+///
+/// # Errors
+/// * If the git fetch command fails
+/// * `GitError::Timeout` - If the fetch takes longer than the network timeout
+pub fn git_unshallow(dry_run: bool) -> Result<Plan> {
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Fetch, "", vec!["--unshallow".to_string()]);
 
     if dry_run {
-        println!("Would push to remote repository");
-        if !args.is_empty() {
-            println!("With args: {args:?}");
+        return Ok(plan);
+    }
+
+    debug!("Fetching the full history...");
+
+    let output = network_output(
+        GitCommand::new().args(["fetch", "--unshallow"]),
+        network_timeout(),
+    )?;
+    handle_remote_output("fetch", &output)?;
+
+    Ok(plan)
+}
+
+/// Hosting services whose web pages can be opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteHost {
+    GitHub,
+    GitLab,
+    Bitbucket,
+}
+
+/// A repository hosted on a known service, identified by its web URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteRepository {
+    pub host: RemoteHost,
+    /// The repository home page, e.g. `https://github.com/owner/repo`
+    pub web_url: String,
+}
+
+impl RemoteRepository {
+    /// URL of the tree of a branch.
+    #[must_use]
+    pub fn branch_url(&self, branch: &str) -> String {
+        match self.host {
+            RemoteHost::GitHub => format!("{}/tree/{branch}", self.web_url),
+            RemoteHost::GitLab => format!("{}/-/tree/{branch}", self.web_url),
+            RemoteHost::Bitbucket => format!("{}/src/{branch}", self.web_url),
+        }
+    }
+
+    /// URL of a file on a branch, `path` being relative to the repository root.
+    #[must_use]
+    pub fn file_url(&self, branch: &str, path: &str) -> String {
+        match self.host {
+            RemoteHost::GitHub => format!("{}/blob/{branch}/{path}", self.web_url),
+            RemoteHost::GitLab => format!("{}/-/blob/{branch}/{path}", self.web_url),
+            RemoteHost::Bitbucket => format!("{}/src/{branch}/{path}", self.web_url),
         }
+    }
+
+    /// URL of the page creating a pull (or merge) request from a branch.
+    #[must_use]
+    pub fn pull_request_url(&self, branch: &str) -> String {
+        match self.host {
+            RemoteHost::GitHub => format!("{}/pull/new/{branch}", self.web_url),
+            RemoteHost::GitLab => format!(
+                "{}/-/merge_requests/new?merge_request[source_branch]={branch}",
+                self.web_url
+            ),
+            RemoteHost::Bitbucket => {
+                format!("{}/pull-requests/new?source={branch}", self.web_url)
+            }
+        }
+    }
+}
+
+/// Parses a remote URL into the web URL of the repository.
+///
+/// Supports the SSH (`git@host:owner/repo.git`, `ssh://git@host:22/owner/repo.git`)
+/// and HTTPS (`https://user@host/owner/repo.git`) forms of GitHub, GitLab
+/// (including self-hosted instances with `gitlab` in their name) and Bitbucket.
+///
+/// # Arguments
+/// * `url` - The remote URL, as returned by `git remote get-url`
+///
+/// # Errors
+/// * If the URL cannot be parsed or the host is not supported
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::remote::{RemoteHost, parse_remote_url};
+///
+/// let repository = parse_remote_url("git@github.com:TomPlanche/rona.git")?;
+/// assert_eq!(repository.host, RemoteHost::GitHub);
+/// assert_eq!(repository.web_url, "https://github.com/TomPlanche/rona");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn parse_remote_url(url: &str) -> Result<RemoteRepository> {
+    let invalid = || RonaError::InvalidInput(format!("Unsupported remote URL: {url}"));
+    let trimmed = url.trim();
+
+    let (host, path) = if let Some((_, rest)) = trimmed.split_once("://") {
+        // ssh://git@host:22/owner/repo or https://user@host/owner/repo
+        let (authority, path) = rest.split_once('/').ok_or_else(invalid)?;
+        let host = authority.rsplit('@').next().unwrap_or(authority);
+        let host = host.split(':').next().unwrap_or(host);
+        (host, path)
+    } else {
+        // scp-like syntax: git@host:owner/repo
+        let (authority, path) = trimmed.split_once(':').ok_or_else(invalid)?;
+        let host = authority.rsplit('@').next().unwrap_or(authority);
+        (host, path)
+    };
+
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+
+    if host.is_empty() || !path.contains('/') {
+        return Err(invalid());
+    }
+
+    let lowercase_host = host.to_lowercase();
+    let remote_host = if lowercase_host.contains("github") {
+        RemoteHost::GitHub
+    } else if lowercase_host.contains("gitlab") {
+        RemoteHost::GitLab
+    } else if lowercase_host.contains("bitbucket") {
+        RemoteHost::Bitbucket
+    } else {
+        return Err(invalid());
+    };
+
+    Ok(RemoteRepository {
+        host: remote_host,
+        web_url: format!("https://{host}/{path}"),
+    })
+}
+
+/// Retrieves the URL of a remote.
+///
+/// # Arguments
+/// * `remote` - The remote name (e.g. `origin`)
+///
+/// # Errors
+/// * `GitError::NoRemoteConfigured` - If the remote does not exist
+pub fn get_remote_url(remote: &str) -> Result<String> {
+    let output = GitCommand::new()
+        .args(["remote", "get-url", remote])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::NoRemoteConfigured));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Checks that a remote can be read with the current credentials, without prompting for
+/// them (`git ls-remote <remote> HEAD`).
+///
+/// # Errors
+/// * `GitError::AuthenticationFailed` / `SshAuthenticationFailed` - If authentication fails
+/// * `GitError::CredentialHelperMissing` - If the configured credential helper is not installed
+/// * `GitError::ProxyError` - If the remote cannot be reached through the proxy
+/// * `GitError::RemoteGone` - If the remote repository does not exist
+/// * `GitError::Timeout` - If the remote does not answer within `timeout`
+/// * `GitError::CommandFailed` - If the remote cannot be read otherwise
+pub fn check_remote_reachable(remote: &str, timeout: Duration) -> Result<()> {
+    let mut command = GitCommand::new();
+    command
+        .args(["ls-remote", "--quiet", remote, "HEAD"])
+        .env("GIT_TERMINAL_PROMPT", "0");
+
+    // SSH would ask for a password or a passphrase on the terminal
+    if env::var_os("GIT_SSH_COMMAND").is_none() && env::var_os("GIT_SSH").is_none() {
+        command.env("GIT_SSH_COMMAND", "ssh -o BatchMode=yes");
+    }
+
+    let output = network_output(&mut command, Some(timeout))?;
+
+    if output.status.success() {
         return Ok(());
     }
 
-    let output = Command::new("git").arg("push").args(args).output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    Err(RonaError::Git(
+        classify_remote_failure(&stderr, configured_proxy().as_deref()).unwrap_or(
+            GitError::CommandFailed {
+                command: format!("git ls-remote {remote}"),
+                output: stderr,
+            },
+        ),
+    ))
+}
+
+/// Picks the remote a branch without upstream is pushed to: `remote.pushDefault` when
+/// set, else `origin`, else the only remote.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::remote::pick_push_remote;
+///
+/// let remotes = vec!["fork".to_string(), "origin".to_string()];
+/// assert_eq!(pick_push_remote(None, &remotes), Some("origin"));
+/// assert_eq!(pick_push_remote(Some("fork"), &remotes), Some("fork"));
+/// assert_eq!(pick_push_remote(None, &remotes[..1]), Some("fork"));
+/// assert_eq!(pick_push_remote(None, &["fork".to_string(), "mirror".to_string()]), None);
+/// ```
+#[must_use]
+pub fn pick_push_remote<'a>(
+    push_default: Option<&'a str>,
+    remotes: &'a [String],
+) -> Option<&'a str> {
+    if let Some(remote) = push_default {
+        return Some(remote);
+    }
+
+    match remotes {
+        [remote] => Some(remote),
+        _ => remotes
+            .iter()
+            .find(|remote| *remote == "origin")
+            .map(String::as_str),
+    }
+}
+
+/// The remote a branch without upstream is pushed to (see [`pick_push_remote`]).
+///
+/// # Errors
+/// * If the remotes cannot be listed
+///
+/// # Returns
+/// * `None` - If no remote is configured, or several without `origin` or `remote.pushDefault`
+pub fn default_push_remote() -> Result<Option<String>> {
+    let output = GitCommand::new()
+        .args(["config", "--get", "remote.pushDefault"])
+        .output()?;
+    let push_default = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let remotes = list_remotes()?;
+
+    Ok(pick_push_remote(
+        (!push_default.is_empty()).then_some(push_default.as_str()),
+        &remotes,
+    )
+    .map(ToString::to_string))
+}
+
+/// Lists the configured remotes.
+///
+/// # Errors
+/// * If the git command fails
+///
+/// # Returns
+/// * `Vec<String>` - The remote names (e.g. `origin`, `upstream`)
+pub fn list_remotes() -> Result<Vec<String>> {
+    let output = GitCommand::new().arg("remote").output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: "git remote".to_string(),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
 
-    handle_output("push", &output, verbose)
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(ToString::to_string)
+        .collect())
 }
 
 /// Handles the output of git commands, providing consistent error handling and success messaging.
 ///
 /// This function processes the output of git commands and:
-/// - Prints success messages when verbose mode is enabled
+/// - Logs success messages at debug level (shown with `--verbose`)
 /// - Displays command output if present
 /// - Formats and prints error messages with suggestions when commands fail
 ///
 /// # Arguments
 /// * `method_name` - The name of the git command being executed (e.g., "commit", "push")
 /// * `output` - The `Output` struct containing the command's stdout, stderr, and status
-/// * `verbose` - Whether to print verbose output during the operation
 ///
 /// # Returns
 /// * `Result<()>` - `Ok(())` if the command succeeded, `Err(RonaError)` if it failed
 // Use the shared handle_output function from the parent module
 use super::handle_output;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bypasses_upstream_check() {
+        let bypasses = |args: &[&str]| {
+            bypasses_upstream_check(&args.iter().map(ToString::to_string).collect::<Vec<_>>())
+        };
+
+        for args in [
+            &["origin"][..],
+            &["--no-verify", "origin", "main"],
+            &["--force"],
+            &["--force-with-lease=main:abc123"],
+            &["--force-if-includes"],
+            &["-f"],
+            &["--all"],
+            &["--mirror"],
+            &["--tags"],
+            &["--delete"],
+            &["-d"],
+        ] {
+            assert!(bypasses(args), "{args:?} should skip the upstream check");
+        }
+        for args in [&[][..], &["--no-verify"], &["-u"], &["--dry-run", "-v"]] {
+            assert!(!bypasses(args), "{args:?} should be checked");
+        }
+    }
+
+    #[test]
+    fn test_parse_fetch_output() {
+        let output = "From github.com:owner/repo
+ * [new branch]      feature    -> origin/feature
+ * [new tag]         v1.0.0     -> v1.0.0
+   1a2b3c4..5d6e7f8  main       -> origin/main
+ + 9a8b7c6...1f2e3d4 rebased    -> origin/rebased  (forced update)
+ - [deleted]         (none)     -> origin/old
+ = [up to date]      stable     -> origin/stable
+";
+        let summary = parse_fetch_output(output);
+
+        assert_eq!(summary.new_branches, vec!["origin/feature"]);
+        assert_eq!(summary.new_tags, vec!["v1.0.0"]);
+        assert_eq!(
+            summary.updated_branches,
+            vec!["origin/main", "origin/rebased"]
+        );
+        assert_eq!(summary.deleted_branches, vec!["origin/old"]);
+        assert_eq!(
+            summary.to_string(),
+            "New branches (1):\n  + origin/feature\n\
+             Updated branches (2):\n  ~ origin/main\n  ~ origin/rebased\n\
+             Deleted branches (1):\n  - origin/old\n\
+             New tags (1):\n  + v1.0.0"
+        );
+    }
+
+    #[test]
+    fn test_classify_remote_failure() {
+        let classify = |stderr: &str| classify_remote_failure(stderr, None).map(|e| e.to_string());
+
+        assert!(matches!(
+            classify_remote_failure(
+                "git: 'credential-osxkeychain' is not a git command. See 'git --help'.\n\
+                 fatal: could not read Username for 'https://github.com': terminal prompts disabled",
+                None
+            ),
+            Some(GitError::CredentialHelperMissing { helper }) if helper == "osxkeychain"
+        ));
+        assert!(matches!(
+            classify_remote_failure(
+                "fatal: unable to access 'https://github.com/o/r.git/': \
+                 Received HTTP code 407 from proxy after CONNECT",
+                None
+            ),
+            Some(GitError::ProxyError { reason })
+                if reason == "Received HTTP code 407 from proxy after CONNECT"
+        ));
+        assert!(matches!(
+            classify_remote_failure(
+                "remote: Invalid username or password.\n\
+                 fatal: Authentication failed for 'https://github.com/o/r.git/'",
+                None
+            ),
+            Some(GitError::AuthenticationFailed { url }) if url == "https://github.com/o/r.git/"
+        ));
+        assert!(
+            classify("fatal: could not read Username for 'https://gitlab.com': No such device")
+                .is_some_and(|message| message.contains("https://gitlab.com"))
+        );
+        assert!(classify("error: failed to push some refs to 'origin'").is_none());
+
+        assert!(matches!(
+            classify_remote_failure(
+                "To github.com:o/r.git\n \
+                 ! [rejected]        feat/x -> feat/x (non-fast-forward)\n\
+                 error: failed to push some refs to 'github.com:o/r.git'",
+                None
+            ),
+            Some(GitError::NonFastForward { branch }) if branch == "feat/x"
+        ));
+        assert!(matches!(
+            classify_remote_failure(
+                "remote: error: GH006: Protected branch update failed for refs/heads/main.\n\
+                 ! [remote rejected] HEAD -> main (protected branch hook declined)",
+                None
+            ),
+            Some(GitError::ProtectedBranch { branch }) if branch == "main"
+        ));
+        assert!(matches!(
+            classify_remote_failure(
+                "remote: GitLab: You are not allowed to push code to protected branches on this project.\n\
+                 ! [remote rejected] main -> main (pre-receive hook declined)",
+                None
+            ),
+            Some(GitError::ProtectedBranch { branch }) if branch == "main"
+        ));
+        assert!(matches!(
+            classify_remote_failure(
+                "remote: Repository not found.\n\
+                 fatal: repository 'https://github.com/o/gone.git/' not found",
+                None
+            ),
+            Some(GitError::RemoteGone { url }) if url == "https://github.com/o/gone.git/"
+        ));
+
+        let unreachable = "fatal: unable to access 'https://github.com/o/r.git/': \
+                           Failed to connect to 127.0.0.1 port 9: Couldn't connect to server";
+        assert!(classify(unreachable).is_none());
+        assert!(matches!(
+            classify_remote_failure(unreachable, Some("http://127.0.0.1:9")),
+            Some(GitError::ProxyError { reason }) if reason.ends_with("(proxy: http://127.0.0.1:9)")
+        ));
+    }
+
+    #[test]
+    fn test_fetch_options_args() {
+        let options = FetchOptions {
+            all: true,
+            prune: true,
+            tags: false,
+        };
+        assert_eq!(options.to_args(), vec!["--all", "--prune"]);
+        assert!(FetchOptions::default().to_args().is_empty());
+        assert_eq!(FetchSummary::default().to_string(), "Already up to date");
+    }
+
+    #[test]
+    fn test_parse_remote_url_forms() {
+        let expected = "https://github.com/TomPlanche/rona";
+
+        for url in [
+            "git@github.com:TomPlanche/rona.git",
+            "ssh://git@github.com:22/TomPlanche/rona.git",
+            "https://github.com/TomPlanche/rona.git",
+            "https://user@github.com/TomPlanche/rona",
+        ] {
+            let repository = parse_remote_url(url).unwrap();
+            assert_eq!(repository.host, RemoteHost::GitHub, "{url}");
+            assert_eq!(repository.web_url, expected, "{url}");
+        }
+
+        let gitlab = parse_remote_url("git@gitlab.example.com:group/sub/app.git").unwrap();
+        assert_eq!(gitlab.host, RemoteHost::GitLab);
+        assert_eq!(gitlab.web_url, "https://gitlab.example.com/group/sub/app");
+
+        let bitbucket = parse_remote_url("https://me@bitbucket.org/team/app.git").unwrap();
+        assert_eq!(bitbucket.host, RemoteHost::Bitbucket);
+        assert_eq!(bitbucket.web_url, "https://bitbucket.org/team/app");
+    }
+
+    #[test]
+    fn test_parse_remote_url_unsupported() {
+        assert!(parse_remote_url("https://example.com/owner/repo.git").is_err());
+        assert!(parse_remote_url("/local/path/repo").is_err());
+        assert!(parse_remote_url("git@github.com:repo").is_err());
+    }
+
+    #[test]
+    fn test_web_urls() {
+        let github = parse_remote_url("git@github.com:o/r.git").unwrap();
+        assert_eq!(
+            github.branch_url("feat/x"),
+            "https://github.com/o/r/tree/feat/x"
+        );
+        assert_eq!(
+            github.file_url("main", "src/main.rs"),
+            "https://github.com/o/r/blob/main/src/main.rs"
+        );
+        assert_eq!(
+            github.pull_request_url("feat/x"),
+            "https://github.com/o/r/pull/new/feat/x"
+        );
+
+        let gitlab = parse_remote_url("git@gitlab.com:o/r.git").unwrap();
+        assert_eq!(
+            gitlab.branch_url("dev"),
+            "https://gitlab.com/o/r/-/tree/dev"
+        );
+        assert_eq!(
+            gitlab.pull_request_url("dev"),
+            "https://gitlab.com/o/r/-/merge_requests/new?merge_request[source_branch]=dev"
+        );
+
+        let bitbucket = parse_remote_url("git@bitbucket.org:o/r.git").unwrap();
+        assert_eq!(
+            bitbucket.file_url("dev", "a.rs"),
+            "https://bitbucket.org/o/r/src/dev/a.rs"
+        );
+        assert_eq!(
+            bitbucket.pull_request_url("dev"),
+            "https://bitbucket.org/o/r/pull-requests/new?source=dev"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_network_output_timeout() {
+        let error = network_output(
+            GitCommand::new().args(["-c", "alias.hang=!sleep 10", "hang"]),
+            Some(Duration::from_millis(100)),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            RonaError::Git(GitError::Timeout { ref command, seconds: 0 })
+                if command == "git -c 'alias.hang=!sleep 10' hang"
+        ));
+        assert_eq!(error.exit_code(), 4);
+    }
+}
@@ -1,16 +1,28 @@
 //! Repository Operations
 //!
 //! Core repository-level operations for Git repositories including repository detection,
-//! path resolution, and basic repository information.
+//! path resolution, and basic repository information (state of HEAD, last commit,
+//! stashes), gathered behind the [`Repository`] handle of the library API.
 
-use std::{path::PathBuf, process::Command};
+use std::{
+    fmt,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
 
-use crate::errors::{GitError, Result, RonaError};
+use crate::{
+    errors::{GitError, Result, RonaError},
+    utils::{RepositoryLocation, discover_repository, discover_repository_from},
+};
+
+use super::{exec::GitCommand, status::StatusSnapshot};
 
 /// Finds the root directory of the git repository.
 ///
-/// This function uses `git rev-parse --git-dir` to locate the `.git` directory
-/// of the current repository. It works from any subdirectory within a git repository.
+/// The git directory is resolved from the `GIT_DIR` override, a `.git` directory,
+/// a `.git` file pointing to the real directory (linked worktrees, submodules) or a
+/// bare repository. As a last resort, `git rev-parse --absolute-git-dir` is used.
+/// It works from any subdirectory within a git repository.
 ///
 /// # Errors
 ///
@@ -35,8 +47,14 @@ use crate::errors::{GitError, Result, RonaError};
 /// }
 /// ```
 pub fn find_git_root() -> Result<PathBuf> {
-    let output = Command::new("git")
-        .args(["rev-parse", "--git-dir"])
+    if let Ok(location) = discover_repository()
+        && location.git_dir.exists()
+    {
+        return Ok(location.git_dir);
+    }
+
+    let output = GitCommand::new()
+        .args(["rev-parse", "--absolute-git-dir"])
         .output()?;
 
     if output.status.success() {
@@ -52,6 +70,30 @@ pub fn find_git_root() -> Result<PathBuf> {
     }
 }
 
+/// Finds the git directory shared by all worktrees of the repository.
+///
+/// Linked worktrees have their own git directory (`.git/worktrees/<name>`) holding
+/// a `commondir` file that points to the main one, where shared files such as
+/// `info/exclude` live. For other repositories this is the git directory itself.
+///
+/// # Errors
+/// * If not in a git repository
+/// * If the `commondir` file cannot be read
+///
+/// # Returns
+/// * `PathBuf` - Path to the common git directory
+pub fn find_git_common_dir() -> Result<PathBuf> {
+    let git_dir = find_git_root()?;
+    let commondir = git_dir.join("commondir");
+
+    if commondir.is_file() {
+        let pointer = read_to_string(&commondir)?;
+        Ok(git_dir.join(pointer.trim()))
+    } else {
+        Ok(git_dir)
+    }
+}
+
 /// Retrieves the top-level path of the git repository.
 ///
 /// This function returns the root directory of the git working tree,
@@ -73,15 +115,15 @@ pub fn find_git_root() -> Result<PathBuf> {
 ///
 /// ```no_run
 /// use rona::git::repository::get_top_level_path;
-/// use std::env;
+/// use std::process::Command;
 ///
 /// let repo_root = get_top_level_path()?;
-/// env::set_current_dir(&repo_root)?;
-/// println!("Changed to repository root: {}", repo_root.display());
+/// Command::new("git").current_dir(&repo_root).arg("status").status()?;
+/// println!("Repository root: {}", repo_root.display());
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn get_top_level_path() -> Result<PathBuf> {
-    let output = Command::new("git")
+    let output = GitCommand::new()
         .args(["rev-parse", "--show-toplevel"])
         .output()?;
 
@@ -90,3 +132,503 @@ pub fn get_top_level_path() -> Result<PathBuf> {
 
     Ok(git_top_level_path)
 }
+
+/// What HEAD points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepositoryState {
+    /// A branch with commits
+    Branch(String),
+    /// A branch without commits yet: a fresh repository or an orphan branch
+    Unborn(String),
+    /// A commit checked out without a branch
+    Detached,
+}
+
+impl RepositoryState {
+    /// The branch checked out, `None` when HEAD is detached.
+    #[must_use]
+    pub fn branch(&self) -> Option<&str> {
+        match self {
+            Self::Branch(branch) | Self::Unborn(branch) => Some(branch),
+            Self::Detached => None,
+        }
+    }
+
+    /// Whether the next commit is the first one of the branch.
+    #[must_use]
+    pub const fn is_unborn(&self) -> bool {
+        matches!(self, Self::Unborn(_))
+    }
+}
+
+/// Reads what HEAD points to.
+///
+/// # Errors
+/// * If not in a git repository, or the git commands cannot be run
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::repository::{RepositoryState, repository_state};
+///
+/// if let RepositoryState::Unborn(branch) = repository_state()? {
+///     println!("The next commit is the first one of {branch}");
+/// }
+/// # Ok::<(), rona::errors::RonaError>(())
+/// ```
+pub fn repository_state() -> Result<RepositoryState> {
+    repository_state_in(Path::new("."))
+}
+
+/// Reads what HEAD points to in the repository containing `dir`.
+///
+/// Same as [`repository_state`], without relying on the current working directory.
+///
+/// # Errors
+/// * Same as [`repository_state`]
+pub fn repository_state_in(dir: &Path) -> Result<RepositoryState> {
+    let output = GitCommand::new()
+        .current_dir(dir)
+        .args(["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .output()?;
+
+    // `git symbolic-ref --quiet` exits with 1 when HEAD is not a symbolic ref
+    match output.status.code() {
+        Some(0) => {}
+        Some(1) => return Ok(RepositoryState::Detached),
+        _ => {
+            return Err(RonaError::Git(GitError::CommandFailed {
+                command: "git symbolic-ref --quiet --short HEAD".to_string(),
+                output: String::from_utf8_lossy(&output.stderr).to_string(),
+            }));
+        }
+    }
+
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let has_commits = GitCommand::new()
+        .current_dir(dir)
+        .args(["rev-parse", "--verify", "--quiet", "HEAD"])
+        .output()?
+        .status
+        .success();
+
+    Ok(if has_commits {
+        RepositoryState::Branch(branch)
+    } else {
+        RepositoryState::Unborn(branch)
+    })
+}
+
+/// A multi-step git operation stopped in the middle, typically on conflicts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationInProgress {
+    /// `git rebase` (`rebase-merge/` or `rebase-apply/`)
+    Rebase,
+    /// `git am` (`rebase-apply/applying`)
+    Am,
+    /// `git merge` (`MERGE_HEAD`)
+    Merge,
+    /// `git cherry-pick` (`CHERRY_PICK_HEAD`)
+    CherryPick,
+    /// `git revert` (`REVERT_HEAD`)
+    Revert,
+}
+
+impl OperationInProgress {
+    /// The git subcommand driving the operation, accepting `--continue` and `--abort`.
+    #[must_use]
+    pub const fn command(self) -> &'static str {
+        match self {
+            Self::Rebase => "rebase",
+            Self::Am => "am",
+            Self::Merge => "merge",
+            Self::CherryPick => "cherry-pick",
+            Self::Revert => "revert",
+        }
+    }
+}
+
+impl fmt::Display for OperationInProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.command())
+    }
+}
+
+/// Detects the operation in progress from the state files of a git directory.
+///
+/// Besides the `*_HEAD` files of a stopped step, the sequencer todo list of a cherry-pick
+/// or a revert of several commits is looked at: it is left once the conflicting step has
+/// been committed, the next commits waiting for `--continue`.
+fn detect_operation(git_dir: &Path) -> Option<OperationInProgress> {
+    if git_dir.join("rebase-apply").join("applying").is_file() {
+        Some(OperationInProgress::Am)
+    } else if git_dir.join("rebase-merge").is_dir() || git_dir.join("rebase-apply").is_dir() {
+        Some(OperationInProgress::Rebase)
+    } else if git_dir.join("MERGE_HEAD").is_file() {
+        Some(OperationInProgress::Merge)
+    } else if git_dir.join("CHERRY_PICK_HEAD").is_file() {
+        Some(OperationInProgress::CherryPick)
+    } else if git_dir.join("REVERT_HEAD").is_file() {
+        Some(OperationInProgress::Revert)
+    } else {
+        sequencer_operation(git_dir)
+    }
+}
+
+/// The cherry-pick or revert of the sequencer todo list (`sequencer/todo`), from the
+/// command of its first step.
+fn sequencer_operation(git_dir: &Path) -> Option<OperationInProgress> {
+    let todo = read_to_string(git_dir.join("sequencer").join("todo")).ok()?;
+    let command = todo
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))?
+        .split_whitespace()
+        .next()?;
+
+    match command {
+        "pick" | "p" => Some(OperationInProgress::CherryPick),
+        "revert" => Some(OperationInProgress::Revert),
+        _ => None,
+    }
+}
+
+/// Returns the merge, rebase, am, cherry-pick or revert in progress in the repository, if any.
+///
+/// # Errors
+/// * If not in a git repository
+pub fn operation_in_progress() -> Result<Option<OperationInProgress>> {
+    Ok(detect_operation(&find_git_root()?))
+}
+
+/// Whether the repository is a shallow clone, its history being truncated (e.g. a CI
+/// checkout with `--depth`).
+///
+/// # Errors
+/// * If `git rev-parse` cannot be run
+pub fn is_shallow_repository() -> Result<bool> {
+    let output = GitCommand::new()
+        .args(["rev-parse", "--is-shallow-repository"])
+        .output()?;
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim() == "true")
+}
+
+/// Whether the index has changes to commit, an already applied commit leaving none.
+///
+/// # Errors
+/// * If `git diff --cached` cannot be run
+pub fn has_staged_changes() -> Result<bool> {
+    let status = GitCommand::new()
+        .args(["diff", "--cached", "--quiet"])
+        .status()?;
+
+    Ok(!status.success())
+}
+
+/// Whether `git rerere` records conflict resolutions to replay them: `rerere.enabled`,
+/// or, when it is not set, the presence of the `rr-cache` directory, as git decides.
+///
+/// # Errors
+/// * If not in a git repository
+pub fn rerere_enabled() -> Result<bool> {
+    let output = GitCommand::new()
+        .args(["config", "--bool", "rerere.enabled"])
+        .output()?;
+
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Ok(find_git_common_dir()?.join("rr-cache").is_dir()),
+    }
+}
+
+/// The latest commit of the current branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastCommit {
+    /// Abbreviated hash
+    pub hash: String,
+    /// Author name
+    pub author: String,
+    /// Unix timestamp of the commit
+    pub timestamp: i64,
+    /// First line of the message
+    pub subject: String,
+}
+
+/// Parses the `%h%x09%an%x09%ct%x09%s` output of `git log -1`.
+fn parse_last_commit(output: &str) -> Option<LastCommit> {
+    let mut fields = output.trim_end_matches('\n').splitn(4, '\t');
+
+    Some(LastCommit {
+        hash: fields.next()?.to_string(),
+        author: fields.next()?.to_string(),
+        timestamp: fields.next()?.parse().ok()?,
+        subject: fields.next()?.to_string(),
+    })
+}
+
+/// Gets the latest commit of the current branch.
+///
+/// # Errors
+/// * If the git command cannot be run
+///
+/// # Returns
+/// * `Option<LastCommit>` - The latest commit, or `None` on a branch without commits
+pub fn get_last_commit() -> Result<Option<LastCommit>> {
+    get_last_commit_in(Path::new("."))
+}
+
+/// Gets the latest commit of the current branch of the repository containing `dir`.
+///
+/// Same as [`get_last_commit`], without relying on the current working directory.
+///
+/// # Errors
+/// * Same as [`get_last_commit`]
+pub fn get_last_commit_in(dir: &Path) -> Result<Option<LastCommit>> {
+    let output = GitCommand::new()
+        .current_dir(dir)
+        .args(["log", "-1", "--format=%h%x09%an%x09%ct%x09%s"])
+        .output()?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    Ok(parse_last_commit(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Counts the stash entries of the repository.
+///
+/// # Errors
+/// * If the git command fails
+pub fn get_stash_count() -> Result<usize> {
+    let output = GitCommand::new()
+        .args(["stash", "list", "--format=%gd"])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: "git stash list".to_string(),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).lines().count())
+}
+
+/// A git repository, found from the current directory or from a path.
+///
+/// The repository is found with git's own rules (`GIT_DIR`/`GIT_WORK_TREE`, `.git` files
+/// of linked worktrees and submodules, bare repositories). Its methods run git from its
+/// working tree (its git directory when bare) rather than from the current directory,
+/// so handles on different repositories can be used side by side, e.g. in parallel tests.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::Repository;
+///
+/// let repository = Repository::discover()?;
+/// let status = repository.status()?;
+/// println!("{} staged files", status.staged_files().len());
+/// # Ok::<(), rona::errors::RonaError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repository {
+    location: RepositoryLocation,
+}
+
+impl Repository {
+    /// Discovers the repository containing the current directory.
+    ///
+    /// # Errors
+    /// * `GitError::RepositoryNotFound` - If the current directory is not in a repository
+    /// * If the current directory or a `.git` file cannot be read
+    pub fn discover() -> Result<Self> {
+        let location = discover_repository().map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => RonaError::Git(GitError::RepositoryNotFound),
+            _ => RonaError::Io(e),
+        })?;
+
+        Ok(Self { location })
+    }
+
+    /// Opens the repository containing `path`.
+    ///
+    /// # Errors
+    /// * `GitError::RepositoryNotFound` - If `path` is not in a repository
+    /// * If `path` or a `.git` file cannot be read
+    pub fn open(path: &Path) -> Result<Self> {
+        let location = discover_repository_from(path, None, None).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => RonaError::Git(GitError::RepositoryNotFound),
+            _ => RonaError::Io(e),
+        })?;
+
+        Ok(Self { location })
+    }
+
+    /// The directory git is run from: the working tree, or the git directory when bare.
+    fn dir(&self) -> &Path {
+        self.work_tree().unwrap_or_else(|| self.git_dir())
+    }
+
+    /// The git directory (`.git`, `.git/worktrees/<name>` or the bare repository itself).
+    #[must_use]
+    pub fn git_dir(&self) -> &Path {
+        &self.location.git_dir
+    }
+
+    /// The working tree, `None` for bare repositories.
+    #[must_use]
+    pub fn work_tree(&self) -> Option<&Path> {
+        self.location.work_tree.as_deref()
+    }
+
+    /// Reads the status of the working tree.
+    ///
+    /// # Errors
+    /// * If the git status command fails
+    pub fn status(&self) -> Result<StatusSnapshot> {
+        StatusSnapshot::read_in(self.dir())
+    }
+
+    /// The current branch (`HEAD` when detached).
+    ///
+    /// # Errors
+    /// * If the branch cannot be determined
+    pub fn current_branch(&self) -> Result<String> {
+        Ok(match self.state()? {
+            RepositoryState::Branch(branch) | RepositoryState::Unborn(branch) => branch,
+            RepositoryState::Detached => "HEAD".to_string(),
+        })
+    }
+
+    /// What HEAD points to.
+    ///
+    /// # Errors
+    /// * If the git commands cannot be run
+    pub fn state(&self) -> Result<RepositoryState> {
+        repository_state_in(self.dir())
+    }
+
+    /// The latest commit of the current branch, `None` before the first commit.
+    ///
+    /// # Errors
+    /// * If the git command cannot be run
+    pub fn last_commit(&self) -> Result<Option<LastCommit>> {
+        get_last_commit_in(self.dir())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        git::exec::with_runner,
+        testing::{MockRunner, TestRepo},
+    };
+    use std::{
+        fs::{create_dir, write},
+        sync::Arc,
+    };
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_repository_open() {
+        let repo = TestRepo::new().unwrap();
+        let repository = repo.repository().unwrap();
+
+        assert_eq!(
+            repository.state().unwrap(),
+            RepositoryState::Unborn("main".to_string())
+        );
+        assert_eq!(repository.last_commit().unwrap(), None);
+
+        repo.commit_file("src/lib.rs", "pub fn a() {}\n", "Add the library")
+            .unwrap();
+        repo.write("notes.md", "todo\n").unwrap();
+        repo.write("src/lib.rs", "pub fn b() {}\n").unwrap();
+        repo.stage(&["src/lib.rs"]).unwrap();
+
+        // Opened from a subdirectory, git still runs from the working tree
+        let repository = Repository::open(&repo.file("src")).unwrap();
+        assert_eq!(repository.work_tree(), Some(repo.path()));
+        assert_eq!(repository.current_branch().unwrap(), "main");
+        assert_eq!(
+            repository.last_commit().unwrap().unwrap().subject,
+            "Add the library"
+        );
+
+        let status = repository.status().unwrap();
+        assert_eq!(status.staged_files(), ["src/lib.rs"]);
+        assert_eq!(status.changed_files(), ["src/lib.rs", "notes.md"]);
+    }
+
+    #[test]
+    fn test_detect_operation() {
+        let git_dir = TempDir::new().unwrap();
+        assert_eq!(detect_operation(git_dir.path()), None);
+
+        write(git_dir.path().join("MERGE_HEAD"), "abc\n").unwrap();
+        assert_eq!(
+            detect_operation(git_dir.path()),
+            Some(OperationInProgress::Merge)
+        );
+
+        create_dir(git_dir.path().join("rebase-merge")).unwrap();
+        assert_eq!(
+            detect_operation(git_dir.path()),
+            Some(OperationInProgress::Rebase)
+        );
+
+        let git_dir = TempDir::new().unwrap();
+        create_dir(git_dir.path().join("sequencer")).unwrap();
+        write(
+            git_dir.path().join("sequencer").join("todo"),
+            "revert 1a2b3c4 Add the parser\nrevert 5d6e7f8 Add the lexer\n",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_operation(git_dir.path()),
+            Some(OperationInProgress::Revert)
+        );
+
+        create_dir(git_dir.path().join("rebase-apply")).unwrap();
+        write(git_dir.path().join("rebase-apply").join("applying"), "").unwrap();
+        assert_eq!(
+            detect_operation(git_dir.path()),
+            Some(OperationInProgress::Am)
+        );
+    }
+
+    #[test]
+    fn test_parse_last_commit() {
+        assert_eq!(
+            parse_last_commit("abc1234\tJane Doe\t1760600000\tfeat: add\tthe info command\n"),
+            Some(LastCommit {
+                hash: "abc1234".to_string(),
+                author: "Jane Doe".to_string(),
+                timestamp: 1_760_600_000,
+                subject: "feat: add\tthe info command".to_string(),
+            })
+        );
+        assert_eq!(parse_last_commit(""), None);
+    }
+
+    #[test]
+    fn test_operation_command() {
+        assert_eq!(OperationInProgress::CherryPick.command(), "cherry-pick");
+        assert_eq!(OperationInProgress::Rebase.to_string(), "rebase");
+    }
+
+    #[test]
+    fn test_is_shallow_repository() {
+        for (answer, shallow) in [("true\n", true), ("false\n", false)] {
+            let mock = Arc::new(MockRunner::new());
+            mock.respond(&["rev-parse", "--is-shallow-repository"], answer);
+
+            assert_eq!(with_runner(mock, is_shallow_repository).unwrap(), shallow);
+        }
+    }
+}
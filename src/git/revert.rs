@@ -0,0 +1,231 @@
+//! Revert Operations
+//!
+//! Reverting a commit with `git revert --no-commit`, then writing a rona-format revert
+//! message to the commit message file instead of opening git's default revert editor.
+
+use std::path::Path;
+
+use log::debug;
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::{
+    commit::{CommitNumbering, MessageFiles, render_commit_message},
+    emoji::{EmojiPolicy, apply_emoji_policy},
+    exec::GitCommand,
+    files::write_commit_message,
+    repository::has_staged_changes,
+    squash::strip_commit_number,
+    trailers::{Trailer, append_trailers},
+};
+
+/// Commit type of the revert message header.
+pub const REVERT_TYPE: &str = "revert";
+
+/// The commit reverted by [`prepare_revert`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevertedCommit {
+    /// Full hash of the commit
+    pub hash: String,
+    /// Subject of the commit (its rona header)
+    pub header: String,
+    /// Files the revert changes back
+    pub files: MessageFiles,
+}
+
+/// The revert prepared by [`prepare_revert`], ready to be committed.
+#[derive(Debug, Clone)]
+pub struct RevertPreparation {
+    /// The revert and message-writing actions performed (or that would be)
+    pub plan: Plan,
+    /// The revert message
+    pub message: String,
+}
+
+/// Parses the `git diff-tree --name-status -z` output of a commit into the files its
+/// revert changes: added files are deleted back, deleted ones restored, and renamed
+/// ones renamed back.
+fn parse_reverted_files(output: &str) -> MessageFiles {
+    let mut files = MessageFiles::default();
+    let mut fields = output.split('\0').filter(|field| !field.is_empty());
+
+    while let Some(status) = fields.next() {
+        let Some(path) = fields.next() else {
+            break;
+        };
+
+        match status.chars().next() {
+            Some('A') => files.deleted.push(path.to_string()),
+            Some('R') => {
+                files.changed.push(path.to_string());
+                if let Some(renamed) = fields.next() {
+                    files.deleted.push(renamed.to_string());
+                }
+            }
+            Some('C') => {
+                if let Some(copy) = fields.next() {
+                    files.deleted.push(copy.to_string());
+                }
+            }
+            _ => files.changed.push(path.to_string()),
+        }
+    }
+
+    files
+}
+
+/// Reads the commit to revert.
+///
+/// # Errors
+/// * If the revision does not name a commit
+/// * If the commit is a merge commit (its mainline would be ambiguous)
+pub fn read_reverted_commit(revision: &str) -> Result<RevertedCommit> {
+    let commit = format!("{revision}^{{commit}}");
+    let info = GitCommand::new()
+        .args(["log", "-1", "--format=%H%x00%P%x00%s", &commit])
+        .stdout()?;
+    let mut fields = info.trim_end().split('\0');
+    let hash = fields.next().unwrap_or_default().to_string();
+    let parents = fields.next().unwrap_or_default();
+    let header = fields.next().unwrap_or_default().to_string();
+
+    if parents.split_whitespace().count() > 1 {
+        return Err(RonaError::InvalidInput(format!(
+            "Cannot revert merge commit {revision} - use `git revert -m <parent>` instead"
+        )));
+    }
+
+    let files = parse_reverted_files(
+        &GitCommand::new()
+            .args([
+                "diff-tree",
+                "--no-commit-id",
+                "--name-status",
+                "-r",
+                "-z",
+                "--root",
+                &hash,
+            ])
+            .stdout()?,
+    );
+
+    Ok(RevertedCommit {
+        hash,
+        header,
+        files,
+    })
+}
+
+/// Builds the rona-format message of a revert: a `revert` header quoting the reverted
+/// header (without its commit number), the files changed back, then git's
+/// `This reverts commit <hash>.` line and the trailers. The emoji policy applies to
+/// the header.
+///
+/// # Errors
+/// * If the current branch, the commit number or the ignore patterns cannot be read
+pub fn build_revert_message(
+    commit: &RevertedCommit,
+    numbering: CommitNumbering,
+    trailers: &[Trailer],
+    emoji: EmojiPolicy,
+) -> Result<String> {
+    let header = strip_commit_number(&commit.header).unwrap_or(&commit.header);
+    let subject = format!("Revert \"{header}\"");
+    let message = render_commit_message(
+        &commit.files,
+        REVERT_TYPE,
+        Some(&subject),
+        numbering,
+        None,
+        None,
+        &[],
+    )?;
+    let message = format!(
+        "{}\n\nThis reverts commit {}.\n",
+        message.trim_end(),
+        commit.hash
+    );
+
+    Ok(apply_emoji_policy(
+        &append_trailers(&message, trailers),
+        REVERT_TYPE,
+        emoji,
+    ))
+}
+
+/// Reverts the commit in the index and working tree without committing, and writes the
+/// revert message to the commit message file, ready to be edited and committed.
+///
+/// # Arguments
+/// * `commit` - The commit to revert (see [`read_reverted_commit`])
+/// * `numbering` - How the number of the revert commit is computed
+/// * `trailers` - Trailers closing the message
+/// * `emoji` - Emoji policy of the revert header
+/// * `message_path` - Path of the commit message file
+/// * `dry_run` - If true, only plan the revert without changing anything
+///
+/// # Errors
+/// * If changes are already staged (they would end up in the revert commit)
+/// * If `git revert` fails, e.g. on conflicts
+/// * If the message cannot be built or written
+pub fn prepare_revert(
+    commit: &RevertedCommit,
+    numbering: CommitNumbering,
+    trailers: &[Trailer],
+    emoji: EmojiPolicy,
+    message_path: &Path,
+    dry_run: bool,
+) -> Result<RevertPreparation> {
+    if has_staged_changes()? {
+        return Err(RonaError::Git(GitError::DirtyWorkingDirectory));
+    }
+
+    let message = build_revert_message(commit, numbering, trailers, emoji)?;
+
+    let mut plan = Plan::new();
+    plan.push(
+        ActionKind::Revert,
+        format!("{} {}", short_hash(&commit.hash), commit.header),
+        vec!["--no-commit".to_string()],
+    );
+    plan.push(
+        ActionKind::WriteMessage,
+        message_path.display().to_string(),
+        Vec::new(),
+    );
+
+    if !dry_run {
+        debug!("Reverting {}...", commit.hash);
+
+        GitCommand::new()
+            .args(["revert", "--no-commit", &commit.hash])
+            .stdout()?;
+        write_commit_message(message_path, &message)?;
+    }
+
+    Ok(RevertPreparation { plan, message })
+}
+
+/// The abbreviated form of a commit hash.
+fn short_hash(hash: &str) -> &str {
+    hash.get(..7).unwrap_or(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reverted_files() {
+        let output = "M\0src/lib.rs\0A\0new.rs\0D\0old.rs\0R087\0before.rs\0after.rs\0";
+
+        let files = parse_reverted_files(output);
+
+        assert_eq!(files.changed, ["src/lib.rs", "old.rs", "before.rs"]);
+        assert_eq!(files.deleted, ["new.rs", "after.rs"]);
+        assert!(files.submodules.is_empty());
+    }
+}
@@ -0,0 +1,293 @@
+//! History Rewriting
+//!
+//! Rewording of existing commits into the rona format, for repositories adopting it: the
+//! subject of each commit of `base..HEAD` becomes the subject of a `[N] (type on branch)`
+//! header, numbered as the commit would have been, and the rest of the message is kept.
+//! The chosen messages are applied by `git rebase -i`, each reworded commit being
+//! followed by an `exec git commit --amend` of its new message in the todo list.
+
+use std::{
+    fmt::Write,
+    fs::{create_dir_all, remove_dir_all, write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::{
+    COMMIT_MESSAGE_DIR,
+    commit::commit_header,
+    exec::{GitCommand, quote_arg},
+    rebase::{RebaseCommit, git_rebase_interactive},
+    repository::find_git_root,
+    squash::strip_commit_number,
+};
+
+/// Directory of the reworded messages, in the `rona` directory of the git directory.
+const REWRITE_DIR_NAME: &str = "rewrite";
+
+/// A commit of the rewritten range with its new message, if it is reworded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewriteEntry {
+    /// The commit
+    pub commit: RebaseCommit,
+    /// The new message, `None` to keep the original one
+    pub message: Option<String>,
+}
+
+/// The full message of a commit.
+///
+/// # Errors
+/// * If the revision does not name a commit
+pub fn read_commit_message(revision: &str) -> Result<String> {
+    let mut command = GitCommand::new();
+    command.args(["log", "-1", "--format=%B", revision, "--"]);
+    let output = command.output()?;
+
+    if !output.status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: command.to_string(),
+            output: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string())
+}
+
+/// The commit type and the title of a rona header, `[N] (type on branch) title` with or
+/// without the `[N]` commit number.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::rewrite::parse_rona_header;
+///
+/// assert_eq!(parse_rona_header("[12] (feat on main) Add the parser"), Some(("feat", "Add the parser")));
+/// assert_eq!(parse_rona_header("(fix on parser)"), Some(("fix", "")));
+/// assert_eq!(parse_rona_header("fix: handle empty input"), None);
+/// ```
+#[must_use]
+pub fn parse_rona_header(subject: &str) -> Option<(&str, &str)> {
+    let subject = strip_commit_number(subject).unwrap_or(subject);
+    let (group, title) = subject.strip_prefix('(')?.split_once(')')?;
+    let (commit_type, _) = group.split_once(" on ")?;
+
+    Some((commit_type, title.trim()))
+}
+
+/// Whether a subject is already a rona header: `(type on branch) ...`, with or without
+/// the `[N]` commit number.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::rewrite::is_rona_header;
+///
+/// assert!(is_rona_header("[12] (feat on main) Add the parser"));
+/// assert!(is_rona_header("(fix on parser)"));
+/// assert!(!is_rona_header("fix: handle empty input"));
+/// ```
+#[must_use]
+pub fn is_rona_header(subject: &str) -> bool {
+    parse_rona_header(subject).is_some()
+}
+
+/// The type and the description of a conventional commit subject (`type(scope)!: text`).
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::rewrite::conventional_subject;
+///
+/// assert_eq!(conventional_subject("fix(parser)!: handle empty input"), Some(("fix", "handle empty input")));
+/// assert_eq!(conventional_subject("Handle empty input: tests"), None);
+/// ```
+#[must_use]
+pub fn conventional_subject(subject: &str) -> Option<(&str, &str)> {
+    let (prefix, description) = subject.split_once(": ")?;
+    let prefix = prefix.trim_end_matches('!');
+    let commit_type = match prefix.split_once('(') {
+        Some((commit_type, scope)) if scope.ends_with(')') => commit_type,
+        Some(_) => return None,
+        None => prefix,
+    };
+
+    (!commit_type.is_empty() && commit_type.chars().all(|c| c.is_ascii_lowercase()))
+        .then(|| (commit_type, description.trim()))
+}
+
+/// The rona-format message of an existing commit: its subject (without a conventional
+/// commit prefix) completes the header, and the rest of the message is kept as the body.
+///
+/// # Arguments
+/// * `original` - The message of the commit
+/// * `commit_type` - The commit type of the header
+/// * `commit_number` - The number of the commit, if commits are numbered
+/// * `branch` - The branch of the header
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::rewrite::rewrite_message;
+///
+/// let original = "fix: handle empty input\n\nThe parser panicked on empty files.";
+/// assert_eq!(
+///     rewrite_message(original, "fix", Some(7), "main"),
+///     "[7] (fix on main) handle empty input\n\nThe parser panicked on empty files.\n"
+/// );
+/// ```
+#[must_use]
+pub fn rewrite_message(
+    original: &str,
+    commit_type: &str,
+    commit_number: Option<u64>,
+    branch: &str,
+) -> String {
+    let (subject, body) = original.split_once('\n').unwrap_or((original, ""));
+    let subject = subject.trim();
+    let subject = conventional_subject(subject).map_or(subject, |(_, description)| description);
+    let title = Some(subject).filter(|subject| !subject.is_empty());
+
+    let mut message = commit_header(commit_type, commit_number, branch, title)
+        .trim_end()
+        .to_string();
+    let body = body.trim();
+    if !body.is_empty() {
+        let _ = write!(message, "\n\n{body}");
+    }
+    message.push('\n');
+
+    message
+}
+
+/// Path of the file holding the new message of a commit.
+fn message_path(dir: &Path, commit: &RebaseCommit) -> PathBuf {
+    dir.join(format!("{}.md", commit.hash))
+}
+
+/// Directory of the reworded messages: `.git/rona/rewrite`.
+///
+/// # Errors
+/// * If the git directory cannot be found
+fn rewrite_dir() -> Result<PathBuf> {
+    Ok(find_git_root()?
+        .join(COMMIT_MESSAGE_DIR)
+        .join(REWRITE_DIR_NAME))
+}
+
+/// Path of the new message of a commit, `.git/rona/rewrite/<hash>.md`, where it can be
+/// edited before [`rewrite_commits`] rewrites it.
+///
+/// # Errors
+/// * If the git directory cannot be found
+pub fn rewrite_message_path(commit: &RebaseCommit) -> Result<PathBuf> {
+    Ok(message_path(&rewrite_dir()?, commit))
+}
+
+/// Builds the todo list rewording the commits: every commit is picked, and a reworded
+/// commit is amended with the message file named after its hash in `dir`.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::{rebase::RebaseCommit, rewrite::{RewriteEntry, build_rewrite_todo}};
+/// use std::path::Path;
+///
+/// let entry = |hash: &str, message: Option<&str>| RewriteEntry {
+///     commit: RebaseCommit { hash: hash.to_string(), subject: "Add parser".to_string() },
+///     message: message.map(ToString::to_string),
+/// };
+/// let todo = build_rewrite_todo(
+///     &[entry("a1", Some("[1] (feat on main) Add parser\n")), entry("b2", None)],
+///     Path::new("/repo/.git/rona/rewrite"),
+/// );
+///
+/// assert_eq!(
+///     todo,
+///     "pick a1 Add parser\n\
+///      exec git commit --amend --allow-empty --no-verify -F /repo/.git/rona/rewrite/a1.md\n\
+///      pick b2 Add parser\n"
+/// );
+/// ```
+#[must_use]
+pub fn build_rewrite_todo(entries: &[RewriteEntry], dir: &Path) -> String {
+    entries.iter().fold(String::new(), |mut todo, entry| {
+        let commit = &entry.commit;
+        let _ = writeln!(todo, "pick {} {}", commit.hash, commit.subject);
+        if entry.message.is_some() {
+            let path = message_path(dir, commit);
+            let _ = writeln!(
+                todo,
+                "exec git commit --amend --allow-empty --no-verify -F {}",
+                quote_arg(&path.to_string_lossy())
+            );
+        }
+        todo
+    })
+}
+
+/// Rewords the commits of `base..HEAD` with their new messages, through `git rebase -i`.
+///
+/// The messages are written to `.git/rona/rewrite/<hash>.md`, removed once the rebase
+/// succeeded. A rebase stopping (e.g. on conflicts) keeps them for `git rebase --continue`.
+///
+/// # Arguments
+/// * `base` - The revision the commits are replayed onto
+/// * `entries` - The commits of `base..HEAD`, oldest first, with their new messages
+/// * `dry_run` - If true, only plan the rewording
+///
+/// # Errors
+/// * If the messages or the todo list cannot be written
+/// * If the rebase fails or stops
+pub fn rewrite_commits(base: &str, entries: &[RewriteEntry], dry_run: bool) -> Result<Plan> {
+    let dir = rewrite_dir()?;
+
+    let mut plan = Plan::new();
+    for entry in entries {
+        if let Some(message) = &entry.message {
+            let path = message_path(&dir, &entry.commit);
+            if !dry_run {
+                create_dir_all(&dir)?;
+                write(&path, message)?;
+            }
+            plan.push(
+                ActionKind::WriteMessage,
+                path.display().to_string(),
+                Vec::new(),
+            );
+        }
+    }
+
+    let todo = build_rewrite_todo(entries, &dir);
+    plan.extend(git_rebase_interactive(base, &todo, dry_run)?);
+
+    if !dry_run && dir.exists() {
+        remove_dir_all(&dir)?;
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rewrite_message() {
+        assert_eq!(
+            rewrite_message("Initial commit", "chore", None, "feat/parser"),
+            "(chore on parser) Initial commit\n"
+        );
+        assert_eq!(
+            rewrite_message("", "chore", Some(1), "main"),
+            "[1] (chore on main)\n"
+        );
+        assert_eq!(conventional_subject("feat(cli: oops"), None);
+        assert_eq!(conventional_subject("Fix: the parser"), None);
+    }
+}
@@ -0,0 +1,311 @@
+//! Squash Operations
+//!
+//! Squashing of the last commits into one: soft reset, then a fresh commit message file
+//! merging the rona-format messages of the squashed commits.
+
+use std::{fmt::Write, path::Path};
+
+use log::debug;
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::{
+    commit::{CommitNumbering, get_current_commit_nb, get_numbered_commit_count},
+    exec::GitCommand,
+    files::write_commit_message,
+    repository::has_staged_changes,
+};
+
+/// The squash prepared by [`prepare_squash`], ready to be committed.
+#[derive(Debug, Clone)]
+pub struct SquashPreparation {
+    /// The reset and message-writing actions performed (or that would be)
+    pub plan: Plan,
+    /// The merged commit message
+    pub message: String,
+    /// The commit HEAD pointed to before the reset, to restore it if needed
+    pub previous_head: String,
+}
+
+/// A file bullet of a rona-format message with its description lines.
+struct FileEntry {
+    file: String,
+    inline: Vec<String>,
+    lines: Vec<String>,
+}
+
+/// Strips the leading `[N]` commit number of a subject, if any.
+pub(super) fn strip_commit_number(subject: &str) -> Option<&str> {
+    let rest = subject.strip_prefix('[')?;
+    let (number, rest) = rest.split_once(']')?;
+
+    if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
+
+/// Pushes a line once, keeping the first occurrence order.
+fn push_unique(lines: &mut Vec<String>, line: &str) {
+    if !lines.iter().any(|existing| existing == line) {
+        lines.push(line.to_string());
+    }
+}
+
+/// Merges rona-format commit messages into a single message.
+///
+/// The header of the oldest message is kept, renumbered with `commit_number` when
+/// it starts with `[N]` (or stripped of its number when `commit_number` is `None`).
+/// File bullets are merged per file with their descriptions
+/// deduplicated; other lines (later subjects, footers) are kept once, at the end.
+///
+/// # Arguments
+/// * `messages` - The messages, oldest first
+/// * `commit_number` - The number of the squashed commit, if commits are numbered
+///
+/// # Returns
+/// * `String` - The merged message, in the format written by `rona generate`
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::squash::build_squash_message;
+///
+/// let messages = vec![
+///     "[4] (feat on main) Add parser\n\n- `a.rs`:\n\n\tAdd parser\n".to_string(),
+///     "[5] (feat on main) Add parser\n\n- `a.rs`:\n\n\tFix typo\n".to_string(),
+/// ];
+///
+/// assert_eq!(
+///     build_squash_message(&messages, Some(4)),
+///     "[4] (feat on main) Add parser\n\n- `a.rs`:\n\n\tAdd parser\n\tFix typo\n"
+/// );
+/// ```
+#[must_use]
+pub fn build_squash_message(messages: &[String], commit_number: Option<u64>) -> String {
+    let mut header = String::new();
+    let mut entries: Vec<FileEntry> = Vec::new();
+    let mut others: Vec<String> = Vec::new();
+
+    for (index, message) in messages.iter().enumerate() {
+        let mut lines = message.lines();
+        let subject = lines.next().unwrap_or_default().trim();
+
+        if index == 0 {
+            header = match (strip_commit_number(subject), commit_number) {
+                (Some(rest), Some(commit_number)) => format!("[{commit_number}] {rest}"),
+                (Some(rest), None) => rest.to_string(),
+                (None, _) => subject.to_string(),
+            };
+        } else {
+            let subject = strip_commit_number(subject).unwrap_or(subject);
+            let first_subject = strip_commit_number(&header).unwrap_or(&header);
+            if !subject.is_empty() && subject != first_subject {
+                push_unique(&mut others, subject);
+            }
+        }
+
+        let mut current: Option<usize> = None;
+
+        for line in lines {
+            if let Some(rest) = line.strip_prefix("- `")
+                && let Some((file, inline)) = rest.split_once("`:")
+            {
+                let position = entries.iter().position(|entry| entry.file == file);
+                let position = position.unwrap_or_else(|| {
+                    entries.push(FileEntry {
+                        file: file.to_string(),
+                        inline: Vec::new(),
+                        lines: Vec::new(),
+                    });
+                    entries.len() - 1
+                });
+
+                let inline = inline.trim();
+                if !inline.is_empty() {
+                    push_unique(&mut entries[position].inline, inline);
+                }
+
+                current = Some(position);
+            } else if line.trim().is_empty() {
+                // Blank lines only separate blocks
+            } else if line.starts_with(char::is_whitespace)
+                && let Some(position) = current
+            {
+                push_unique(&mut entries[position].lines, line.trim());
+            } else {
+                current = None;
+                push_unique(&mut others, line.trim());
+            }
+        }
+    }
+
+    let mut squashed = format!("{header}\n\n");
+
+    for entry in &entries {
+        if entry.inline.is_empty() {
+            let _ = writeln!(squashed, "- `{}`:\n", entry.file);
+        } else {
+            let _ = writeln!(
+                squashed,
+                "- `{}`: {}\n",
+                entry.file,
+                entry.inline.join(", ")
+            );
+        }
+
+        if !entry.lines.is_empty() {
+            for line in &entry.lines {
+                let _ = writeln!(squashed, "\t{line}");
+            }
+            squashed.push('\n');
+        }
+    }
+
+    for line in &others {
+        let _ = writeln!(squashed, "{line}");
+    }
+
+    format!("{}\n", squashed.trim_end())
+}
+
+/// Soft-resets the last `count` commits and writes their merged message to
+/// the commit message file, ready to be edited and committed.
+///
+/// # Arguments
+/// * `count` - The number of commits to squash (at least 2)
+/// * `numbering` - How the number of the squashed commit is computed
+/// * `message_path` - Path of the commit message file
+/// * `dry_run` - If true, only plan the squash without resetting anything
+///
+/// # Errors
+/// * If `count` is lower than 2 or greater than the number of commits
+/// * If changes are already staged (they would end up in the squashed commit)
+/// * If one of the commits is a merge commit
+/// * If a git command fails
+pub fn prepare_squash(
+    count: u32,
+    numbering: CommitNumbering,
+    message_path: &Path,
+    dry_run: bool,
+) -> Result<SquashPreparation> {
+    let total = get_current_commit_nb()?;
+
+    if count < 2 || u64::from(count) >= total {
+        return Err(RonaError::InvalidInput(format!(
+            "Can only squash between 2 and {} commits (got {count})",
+            total.saturating_sub(1)
+        )));
+    }
+
+    if has_staged_changes()? {
+        return Err(RonaError::Git(GitError::DirtyWorkingDirectory));
+    }
+
+    let range = format!("HEAD~{count}..HEAD");
+    if !GitCommand::new()
+        .args(["rev-list", "--merges", &range])
+        .stdout()?
+        .trim()
+        .is_empty()
+    {
+        return Err(RonaError::InvalidInput(
+            "Cannot squash merge commits - use an interactive rebase instead".to_string(),
+        ));
+    }
+
+    let messages: Vec<String> = GitCommand::new()
+        .args(["log", "--reverse", "--format=%B%x00", &range])
+        .stdout()?
+        .split('\0')
+        .map(str::trim)
+        .filter(|message| !message.is_empty())
+        .map(ToString::to_string)
+        .collect();
+
+    let commit_number = get_numbered_commit_count(numbering)?
+        .map(|numbered| numbered.saturating_sub(u64::from(count)) + 1);
+    let message = build_squash_message(&messages, commit_number);
+    let previous_head = GitCommand::new()
+        .args(["rev-parse", "HEAD"])
+        .stdout()?
+        .trim()
+        .to_string();
+    let target = format!("HEAD~{count}");
+
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Reset, &target, vec!["--soft".to_string()]);
+    plan.push(
+        ActionKind::WriteMessage,
+        message_path.display().to_string(),
+        Vec::new(),
+    );
+
+    if !dry_run {
+        debug!("Squashing the last {count} commits...");
+
+        GitCommand::new()
+            .args(["reset", "--soft", &target])
+            .stdout()?;
+        write_commit_message(message_path, &message)?;
+    }
+
+    Ok(SquashPreparation {
+        plan,
+        message,
+        previous_head,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_squash_message_merges_files() {
+        let messages = vec![
+            "[10] (feat on parser) Add parser\n\n- `src/parser.rs`:\n\n\tAdd parser\n\n- `old.rs`: deleted\n".to_string(),
+            "[11] (fix on parser) Handle empty input\n\n- `src/parser.rs`:\n\n\tAdd parser\n\tHandle empty input\n\n- `README.md`:\n\n\tDocument parser\n\nCloses #4\n".to_string(),
+        ];
+
+        assert_eq!(
+            build_squash_message(&messages, Some(10)),
+            "[10] (feat on parser) Add parser\n\n\
+             - `src/parser.rs`:\n\n\tAdd parser\n\tHandle empty input\n\n\
+             - `old.rs`: deleted\n\n\
+             - `README.md`:\n\n\tDocument parser\n\n\
+             (fix on parser) Handle empty input\n\
+             Closes #4\n"
+        );
+    }
+
+    #[test]
+    fn test_build_squash_message_without_numbers() {
+        let messages = vec![
+            "(chore on main) wip".to_string(),
+            "(chore on main) wip".to_string(),
+        ];
+
+        assert_eq!(
+            build_squash_message(&messages, Some(3)),
+            "(chore on main) wip\n"
+        );
+    }
+
+    #[test]
+    fn test_build_squash_message_unnumbered() {
+        let messages = vec![
+            "[7] (feat on ui) Add button".to_string(),
+            "[8] (feat on ui) Add button".to_string(),
+        ];
+
+        assert_eq!(
+            build_squash_message(&messages, None),
+            "(feat on ui) Add button\n"
+        );
+    }
+}
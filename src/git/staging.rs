@@ -1,20 +1,47 @@
 //! Git Staging Operations
 //!
-//! File staging functionality with pattern exclusion and dry-run capabilities.
+//! File staging functionality with pattern or fuzzy exclusion and dry-run capabilities.
+//! Exclusion patterns apply in order like `.gitignore` lines, `!pattern` including back
+//! the files excluded by the patterns before it. Submodules are staged when they check out
+//! a new commit and skipped when their only changes are uncommitted content inside them.
+//! Files are handed to `git add` by their exact bytes, so names that are not valid UTF-8
+//! are staged too.
+//!
+//! A file deleted and another added with the same content are staged as a rename, which
+//! the summary and the dry-run plan list as `old -> new` (previewed by staging into a copy
+//! of the index) instead of counting an addition and a deletion.
+//!
+//! Unstaging (`rona unadd`) mirrors it: the staged files matching the patterns are
+//! restored to their last committed state in the index with `git restore --staged`.
 
-use std::process::Command;
+use std::{
+    collections::HashMap,
+    ffi::{OsStr, OsString},
+    fs,
+    path::{Path, PathBuf},
+};
 
+use fuzzy_matcher::{FuzzyMatcher, skim::SkimMatcherV2};
 use glob::Pattern;
+use log::{debug, info, warn};
 
-use crate::errors::Result;
+use crate::{
+    errors::Result,
+    performance::batch_process,
+    plan::{ActionKind, Plan},
+};
 
 use super::{
-    repository::get_top_level_path,
-    status::{
-        count_renamed_files, get_status_files, process_deleted_files_for_staging, read_git_status,
-    },
+    exec::GitCommand,
+    handle_output,
+    repository::{get_top_level_path, repository_state_in},
+    status::{FileState, StatusEntry, StatusSet, StatusSnapshot},
 };
 
+/// Maximum number of paths passed to a single `git add`, keeping the command line
+/// under the OS argument length limit on huge change sets.
+const GIT_ADD_BATCH_SIZE: usize = 100;
+
 /// Adds files to the git index.
 ///
 /// # Errors
@@ -26,10 +53,11 @@ use super::{
 /// ```no_run
 /// use std::error::Error;
 /// use glob::Pattern;
+/// use rona::git::staging::git_add_with_exclude_patterns;
 ///
 /// // Exclude all Rust source files
 /// let patterns = vec![Pattern::new("*.rs").unwrap()];
-/// git_add_with_exclude_patterns(&patterns, true)?;
+/// git_add_with_exclude_patterns(&patterns, false)?;
 ///
 /// // Exclude an entire directory
 /// let patterns = vec![Pattern::new("target/**/*").unwrap()];
@@ -41,7 +69,11 @@ use super::{
 ///     Pattern::new("temp/*").unwrap(),
 ///     Pattern::new("**/*.tmp").unwrap()
 /// ];
-/// git_add_with_exclude_patterns(&patterns, true)?;
+/// git_add_with_exclude_patterns(&patterns, false)?;
+///
+/// // Exclude src/ except main.rs: the last matching pattern wins, `!` includes back
+/// let patterns = vec![Pattern::new("src/*").unwrap(), Pattern::new("!src/main.rs").unwrap()];
+/// git_add_with_exclude_patterns(&patterns, false)?;
 ///
 /// // Complex wildcard pattern
 /// let patterns = vec![Pattern::new("src/**/*_test.{rs,txt}").unwrap()];
@@ -49,7 +81,7 @@ use super::{
 ///
 /// // No exclusions (empty pattern list)
 /// let patterns = vec![];
-/// git_add_with_exclude_patterns(&patterns, true)?;
+/// git_add_with_exclude_patterns(&patterns, false)?;
 ///
 /// // Pattern with special characters
 /// let patterns = vec![Pattern::new("[abc]*.rs").unwrap()];
@@ -58,126 +90,533 @@ use super::{
 /// // Error handling example
 /// fn handle_git_add() -> Result<(), Box<dyn Error>> {
 ///     let patterns = vec![Pattern::new("*.rs")?];
-///     git_add_with_exclude_patterns(&patterns, true)?;
+///     git_add_with_exclude_patterns(&patterns, false)?;
 ///     Ok(())
 /// }
+/// # Ok::<(), rona::errors::RonaError>(())
 /// ```
 ///
 /// In these examples:
 /// - `"*.rs"` excludes all Rust source files
 /// - `"target/**/*"` excludes everything in the target directory and subdirectories
 /// - Multiple patterns show how to exclude logs, temp files, and .tmp files
+/// - `"!src/main.rs"` after `"src/*"` stages `src/main.rs` while excluding the rest of src/
 /// - `"src/**/*_test.{rs,txt}"` excludes test files with .rs or .txt extensions in src/
 /// - Empty vector shows how to add all files without exclusions
 /// - `"[abc]*.rs"` excludes Rust files starting with a, b, or c
 /// - Error handling shows proper pattern creation with error propagation
 ///
 /// # Arguments
-/// * `exclude_patterns` - List of patterns to exclude
-/// * `verbose` - Whether to print verbose output
-/// * `dry_run` - If true, only show what would be added without actually staging files
-pub fn git_add_with_exclude_patterns(
-    exclude_patterns: &[Pattern],
-    verbose: bool,
-    dry_run: bool,
-) -> Result<()> {
-    if verbose {
-        println!("Adding files...");
+/// * `exclude_patterns` - Patterns to exclude, in order (see [`excluding_pattern`])
+/// * `dry_run` - If true, only compute what would be added without actually staging files
+///
+/// # Returns
+/// * `Plan` - The files staged, deleted and excluded (or that would be, in dry-run mode)
+pub fn git_add_with_exclude_patterns(exclude_patterns: &[Pattern], dry_run: bool) -> Result<Plan> {
+    stage_files_excluding(
+        &StatusSnapshot::read()?,
+        |file| {
+            excluding_pattern(exclude_patterns, file).map(|pattern| pattern.as_str().to_string())
+        },
+        dry_run,
+    )
+}
+
+/// The pattern excluding a file, `None` when the file is staged.
+///
+/// Patterns apply in order like `.gitignore` lines: the last pattern matching the file
+/// decides, and a pattern starting with `!` includes back the files excluded by the
+/// patterns before it.
+///
+/// # Examples
+///
+/// ```
+/// use glob::Pattern;
+/// use rona::git::staging::excluding_pattern;
+///
+/// let patterns = [Pattern::new("src/*").unwrap(), Pattern::new("!src/main.rs").unwrap()];
+///
+/// assert_eq!(excluding_pattern(&patterns, "src/lib.rs").map(Pattern::as_str), Some("src/*"));
+/// assert_eq!(excluding_pattern(&patterns, "src/main.rs"), None);
+/// assert_eq!(excluding_pattern(&patterns, "README.md"), None);
+/// ```
+#[must_use]
+pub fn excluding_pattern<'a>(patterns: &'a [Pattern], file: &str) -> Option<&'a Pattern> {
+    patterns
+        .iter()
+        .rev()
+        .find_map(|pattern| match pattern.as_str().strip_prefix('!') {
+            Some(negated) => Pattern::new(negated)
+                .is_ok_and(|negated| negated.matches(file))
+                .then_some(None),
+            None => pattern.matches(file).then_some(Some(pattern)),
+        })
+        .flatten()
+}
+
+/// Fuzzy-matches exclusion arguments against files, skim-style.
+///
+/// An argument matches the files containing its characters in order (e.g. `src/tst`
+/// matches `src/test.rs`). Only the files scoring at least half of the argument's
+/// best score are kept, so loose matches spread over long paths are dropped.
+///
+/// # Arguments
+/// * `queries` - The exclusion arguments typed by the user
+/// * `files` - The candidate files
+///
+/// # Returns
+/// * `Vec<(String, Vec<String>)>` - Each argument with the files it matched, best first
+#[must_use]
+pub fn fuzzy_match_files(queries: &[String], files: &[String]) -> Vec<(String, Vec<String>)> {
+    let skim = SkimMatcherV2::default();
+
+    queries
+        .iter()
+        .map(|query| {
+            let mut scored: Vec<(i64, &String)> = files
+                .iter()
+                .filter_map(|file| skim.fuzzy_match(file, query).map(|score| (score, file)))
+                .collect();
+            scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(b.1)));
+
+            let threshold = scored.first().map_or(0, |(best, _)| best / 2);
+            let matched = scored
+                .into_iter()
+                .take_while(|(score, _)| *score >= threshold)
+                .map(|(_, file)| file.clone())
+                .collect();
+
+            (query.clone(), matched)
+        })
+        .collect()
+}
+
+/// Adds files to the git index, excluding the files fuzzy-matched by the arguments.
+///
+/// # Arguments
+/// * `queries` - Fuzzy exclusion arguments (see [`fuzzy_match_files`])
+/// * `dry_run` - If true, only compute what would be added without actually staging files
+///
+/// # Errors
+/// * If reading git status fails
+/// * If adding files to git fails
+///
+/// # Returns
+/// * `Plan` - The files staged, deleted and excluded (or that would be, in dry-run mode)
+pub fn git_add_with_fuzzy_exclusions(queries: &[String], dry_run: bool) -> Result<Plan> {
+    let snapshot = StatusSnapshot::read()?;
+    let matches = fuzzy_match_files(queries, &snapshot.changed_files());
+
+    let mut excluded_by: HashMap<String, String> = HashMap::new();
+    for (query, matched) in &matches {
+        if matched.is_empty() {
+            debug!("`{query}` matched no file");
+        } else {
+            debug!("`{query}` matched: {}", matched.join(", "));
+        }
+
+        for file in matched {
+            excluded_by
+                .entry(file.clone())
+                .or_insert_with(|| query.clone());
+        }
     }
 
-    let git_status = read_git_status()?;
-    let deleted_files = process_deleted_files_for_staging(&git_status)?;
-    let deleted_files_count = deleted_files.len();
+    stage_files_excluding(&snapshot, |file| excluded_by.get(file).cloned(), dry_run)
+}
 
-    let staged_files = get_status_files()?;
-    let staged_files_len = staged_files.len();
+/// Stages every changed file of the status snapshot except those for which
+/// `exclusion_for` returns the pattern or argument excluding them.
+fn stage_files_excluding<F>(
+    snapshot: &StatusSnapshot,
+    exclusion_for: F,
+    dry_run: bool,
+) -> Result<Plan>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    debug!("Adding files...");
 
-    let files_to_add: Vec<String> = staged_files
-        .into_iter()
-        .filter(|file| !exclude_patterns.iter().any(|pattern| pattern.matches(file)))
+    let deleted_files: Vec<&StatusEntry> = snapshot
+        .entries
+        .iter()
+        .filter(|entry| entry.is_unstaged_deletion())
         .collect();
+    let deleted_files_count = deleted_files.len();
 
-    if files_to_add.is_empty() && deleted_files.is_empty() {
-        println!("No files to add or delete");
-        return Ok(());
+    let mut files_to_add = Vec::new();
+    let mut submodules_to_add = Vec::new();
+    let mut skipped_submodules = Vec::new();
+    let mut excluded_files = Vec::new();
+    for entry in snapshot.entries.iter().filter(|entry| !entry.is_deleted()) {
+        match (exclusion_for(&entry.path), entry.submodule) {
+            (Some(rule), _) => excluded_files.push((entry.path.clone(), rule)),
+            (None, Some(state)) if entry.is_dirty_submodule() => {
+                skipped_submodules.push((entry.path.clone(), state.describe()));
+            }
+            (None, Some(_)) => submodules_to_add.push(entry),
+            (None, None) => files_to_add.push(entry),
+        }
     }
 
-    if dry_run {
-        print_dry_run_summary(&files_to_add, &deleted_files, staged_files_len);
-        return Ok(());
+    let mut plan = Plan::new();
+    for entry in &files_to_add {
+        plan.push(ActionKind::Stage, &entry.path, Vec::new());
+    }
+    for entry in &submodules_to_add {
+        plan.push(ActionKind::StageSubmodule, &entry.path, Vec::new());
+    }
+    for (file, changes) in &skipped_submodules {
+        plan.push(ActionKind::SkipSubmodule, file, vec![changes.clone()]);
+    }
+    for entry in &deleted_files {
+        plan.push(ActionKind::Delete, &entry.path, Vec::new());
+    }
+    for (file, rule) in &excluded_files {
+        plan.push(ActionKind::Exclude, file, vec![rule.clone()]);
     }
 
     let top_level_dir = get_top_level_path()?;
 
-    let _ = Command::new("git")
-        .current_dir(&top_level_dir)
-        .arg("add")
-        .args(&files_to_add)
-        .args(&deleted_files)
-        .output()?;
-
-    // Get the new git status after staging to count renamed files
-    let new_git_status = read_git_status()?;
-    let renamed_count = count_renamed_files(&new_git_status);
-
-    let staged = Command::new("git")
-        .args(["diff", "--cached", "--numstat"])
-        .output()?;
-
-    // Calculate counts:
-    // - git diff --cached --numstat shows 2 lines per renamed file (deletion + addition)
-    // - We need to subtract renamed_count to get the actual number of added files
-    // - We also subtract deleted_files_count since those appear separately
-    let staged_count = String::from_utf8_lossy(&staged.stdout).lines().count()
-        - deleted_files_count
-        - renamed_count;
-    let excluded_count = staged_files_len - files_to_add.len();
-
-    println!(
-        "Added {staged_count} files, deleted {deleted_files_count}, renamed {renamed_count} while excluding {excluded_count} files for commit."
+    let pathspecs: Vec<OsString> = files_to_add
+        .iter()
+        .chain(&submodules_to_add)
+        .chain(&deleted_files)
+        .map(|entry| literal_os_pathspec(&entry.os_path))
+        .collect();
+    let added: Vec<&str> = files_to_add
+        .iter()
+        .map(|entry| entry.path.as_str())
+        .collect();
+    let deleted: Vec<&str> = deleted_files
+        .iter()
+        .map(|entry| entry.path.as_str())
+        .collect();
+
+    if dry_run {
+        if !deleted.is_empty() && !added.is_empty() {
+            for (from, to) in preview_renames(&top_level_dir, &pathspecs, &added, &deleted)? {
+                plan.push(ActionKind::Rename, from, vec![to]);
+            }
+        }
+        return Ok(plan);
+    }
+
+    for (file, changes) in &skipped_submodules {
+        warn!("Skipping submodule {file} ({changes}): commit inside it first to record it");
+    }
+
+    if files_to_add.is_empty() && submodules_to_add.is_empty() && deleted_files.is_empty() {
+        info!("No files to add or delete");
+        return Ok(plan);
+    }
+
+    add_pathspecs(&top_level_dir, &pathspecs, None)?;
+
+    // Renames only show up once both sides are staged, so they are found on a
+    // snapshot of the index rather than on the one taken before adding
+    let renames = renamed_files(&StatusSnapshot::read()?, &added, &deleted);
+    let renamed_count = renames.len();
+    let staged_count = files_to_add.len() + submodules_to_add.len() - renamed_count;
+    let deleted_count = deleted_files_count - renamed_count;
+    let excluded_count = excluded_files.len();
+
+    info!(
+        "Added {staged_count} files, deleted {deleted_count}, renamed {renamed_count} while excluding {excluded_count} files for commit."
     );
+    for (from, to) in renames {
+        info!("   {from} -> {to}");
+        plan.push(ActionKind::Rename, from, vec![to]);
+    }
+
+    Ok(plan)
+}
+
+/// Stages the pathspecs in batches, into `index` instead of the index of the repository
+/// when given.
+fn add_pathspecs(top_level_dir: &Path, pathspecs: &[OsString], index: Option<&Path>) -> Result<()> {
+    let outputs = batch_process(pathspecs, GIT_ADD_BATCH_SIZE, |batch| {
+        let mut command = GitCommand::new();
+        command
+            .current_dir(top_level_dir)
+            .args(["add", "--"])
+            .args(batch);
+        if let Some(index) = index {
+            command.env("GIT_INDEX_FILE", index);
+        }
+        vec![command.output()]
+    });
+    for output in outputs {
+        handle_output("add", &output?)?;
+    }
 
     Ok(())
 }
 
-/// Prints a detailed summary of files that would be affected by a git add operation in dry run mode.
+/// The renames git detected in the index among the files staged by a `rona add`: each
+/// pairs one of the `deleted` files with one of the `added` ones, which git status lists
+/// as a single entry.
 ///
-/// This function provides a clear overview of:
-/// - Files that would be added to the staging area
-/// - Files that would be deleted
-/// - Number of files that would be excluded based on patterns
+/// # Returns
+/// * `Vec<(String, String)>` - The old and new paths of the renamed files
+///
+/// # Examples
 ///
-/// The output is formatted as follows:
 /// ```
-/// Would add N files:
-///   + file1.txt
-///   + file2.rs
-/// Would delete M files:
-///   - deleted_file1.txt
-///   - deleted_file2.rs
-/// Would exclude K files
+/// use rona::git::{StatusSnapshot, staging::renamed_files};
+///
+/// let staged = StatusSnapshot::parse(
+///     "2 R. N... 100644 100644 100644 1a2b3c4 1a2b3c4 R100 src/parser.rs\tsrc/parse.rs\n\
+///      1 A. N... 000000 100644 100644 0000000 5d6e7f8 src/lexer.rs\n",
+/// );
+/// assert_eq!(
+///     renamed_files(&staged, &["src/parser.rs", "src/lexer.rs"], &["src/parse.rs"]),
+///     [("src/parse.rs".to_string(), "src/parser.rs".to_string())]
+/// );
 /// ```
+#[must_use]
+pub fn renamed_files(
+    staged: &StatusSnapshot,
+    added: &[&str],
+    deleted: &[&str],
+) -> Vec<(String, String)> {
+    staged
+        .entries
+        .iter()
+        .filter(|entry| entry.index_state == FileState::Renamed)
+        .filter_map(|entry| {
+            let from = entry.orig_path.as_deref()?;
+            (added.contains(&entry.path.as_str()) && deleted.contains(&from))
+                .then(|| (from.to_string(), entry.path.clone()))
+        })
+        .collect()
+}
+
+/// The renames staging the pathspecs would record, found by staging them into a copy of
+/// the index (the blobs of the files are written to the object database).
 ///
-/// # Arguments
-/// * `files_to_add` - List of files that would be added to the staging area
-/// * `deleted_files` - List of files that would be marked as deleted
-/// * `staged_files_len` - Total number of files that would be staged (including excluded ones)
+/// # Errors
+/// * If the index cannot be copied or the files cannot be staged into the copy
+fn preview_renames(
+    top_level_dir: &Path,
+    pathspecs: &[OsString],
+    added: &[&str],
+    deleted: &[&str],
+) -> Result<Vec<(String, String)>> {
+    let index = GitCommand::new()
+        .current_dir(top_level_dir)
+        .args(["rev-parse", "--git-path", "index"])
+        .stdout()?;
+    let index = top_level_dir.join(index.trim());
+    let mut preview = index.clone().into_os_string();
+    preview.push(".rona-preview");
+    let preview = PathBuf::from(preview);
+
+    if index.exists() {
+        fs::copy(&index, &preview)?;
+    }
+    let staged = add_pathspecs(top_level_dir, pathspecs, Some(&preview)).and_then(|()| {
+        GitCommand::new()
+            .current_dir(top_level_dir)
+            .env("GIT_INDEX_FILE", &preview)
+            .args([
+                "-c",
+                "core.quotePath=true",
+                "status",
+                "--porcelain=v2",
+                "-u",
+            ])
+            .stdout()
+    });
+    let _ = fs::remove_file(&preview);
+
+    Ok(renamed_files(
+        &StatusSnapshot::parse(&staged?),
+        added,
+        deleted,
+    ))
+}
+
+/// Unstages the staged files matching the patterns, or every staged file when no pattern
+/// is given.
+///
+/// Patterns apply as in [`excluding_pattern`]: a file is unstaged when the last pattern
+/// matching it does not start with `!`.
+///
+/// # Errors
+/// * If reading git status fails
+/// * If unstaging the files fails
+///
+/// # Examples
+/// ```no_run
+/// use glob::Pattern;
+/// use rona::git::staging::git_unstage_patterns;
+///
+/// // Unstage the lock files staged by mistake
+/// let plan = git_unstage_patterns(&[Pattern::new("*.lock").unwrap()], false)?;
+/// println!("{}", plan.render_text());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-fn print_dry_run_summary(
-    files_to_add: &[String],
-    deleted_files: &[String],
-    staged_files_len: usize,
-) {
-    println!("Would add {} files:", files_to_add.len());
-    for file in files_to_add {
-        println!("  + {file}");
+///
+/// # Returns
+/// * `Plan` - The files unstaged (or that would be, in dry-run mode)
+pub fn git_unstage_patterns(patterns: &[Pattern], dry_run: bool) -> Result<Plan> {
+    let snapshot = StatusSnapshot::read()?;
+    let entries: Vec<&StatusEntry> = staged_entries(&snapshot)
+        .filter(|entry| patterns.is_empty() || excluding_pattern(patterns, &entry.path).is_some())
+        .collect();
+
+    unstage_entries(&entries, dry_run)
+}
+
+/// Unstages staged files, given by their paths relative to the top of the working tree
+/// (e.g. picked from [`StatusSnapshot::files_in`] with [`StatusSet::Staged`]). Files
+/// without staged changes are ignored.
+///
+/// # Errors
+/// * If reading git status fails
+/// * If unstaging the files fails
+pub fn git_unstage(files: &[String], dry_run: bool) -> Result<Plan> {
+    let snapshot = StatusSnapshot::read()?;
+    let entries: Vec<&StatusEntry> = staged_entries(&snapshot)
+        .filter(|entry| files.contains(&entry.path))
+        .collect();
+
+    unstage_entries(&entries, dry_run)
+}
+
+/// The entries of the status snapshot with staged changes.
+fn staged_entries(snapshot: &StatusSnapshot) -> impl Iterator<Item = &StatusEntry> {
+    snapshot
+        .entries
+        .iter()
+        .filter(|entry| StatusSet::Staged.contains(entry))
+}
+
+/// Unstages the entries: `git restore --staged` restores them to their state in `HEAD`,
+/// or `git rm --cached` drops them from the index before the first commit. Both sides
+/// of a staged rename are restored.
+fn unstage_entries(entries: &[&StatusEntry], dry_run: bool) -> Result<Plan> {
+    let mut plan = Plan::new();
+    for entry in entries {
+        plan.push(ActionKind::Unstage, &entry.path, Vec::new());
+    }
+
+    if dry_run {
+        return Ok(plan);
+    }
+    if entries.is_empty() {
+        info!("No staged files to unstage");
+        return Ok(plan);
+    }
+
+    let top_level_dir = get_top_level_path()?;
+    let command: &[&str] = if repository_state_in(&top_level_dir)?.is_unborn() {
+        &["rm", "--cached", "-r", "-q", "--"]
+    } else {
+        &["restore", "--staged", "--"]
+    };
+
+    let pathspecs: Vec<OsString> = entries
+        .iter()
+        .flat_map(|entry| {
+            let orig_path = entry.orig_path.as_deref().map(OsStr::new);
+            [Some(entry.os_path.as_os_str()), orig_path]
+        })
+        .flatten()
+        .map(literal_os_pathspec)
+        .collect();
+
+    let outputs = batch_process(&pathspecs, GIT_ADD_BATCH_SIZE, |batch| {
+        vec![
+            GitCommand::new()
+                .current_dir(&top_level_dir)
+                .args(command)
+                .args(batch)
+                .output(),
+        ]
+    });
+    for output in outputs {
+        handle_output(command[0], &output?)?;
+    }
+
+    info!("Unstaged {} files.", entries.len());
+
+    Ok(plan)
+}
+
+/// Quotes a path as a literal pathspec, so that glob characters in file names
+/// (e.g. `[abc].txt`) only match that file.
+pub(crate) fn literal_pathspec(path: &str) -> String {
+    format!(":(literal){path}")
+}
+
+/// Quotes a path of the file system as a literal pathspec, keeping its exact bytes.
+fn literal_os_pathspec(path: &OsStr) -> OsString {
+    let mut pathspec = OsString::from(":(literal)");
+    pathspec.push(path);
+    pathspec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(ToString::to_string).collect()
     }
 
-    println!("Would delete {} files:", deleted_files.len());
-    for file in deleted_files {
-        println!("  - {file}");
+    #[test]
+    fn test_fuzzy_match_files() {
+        let candidates = files(&["src/test.rs", "src/main.rs", "README.md", "tests/cli.rs"]);
+        let matches = fuzzy_match_files(&files(&["src/tst", "readme"]), &candidates);
+
+        assert_eq!(matches[0].0, "src/tst");
+        assert_eq!(matches[0].1, vec!["src/test.rs"]);
+        assert_eq!(matches[1].1, vec!["README.md"]);
     }
 
-    let excluded_files_len = staged_files_len - files_to_add.len();
-    println!("Would exclude {excluded_files_len} files");
+    #[test]
+    fn test_excluding_pattern_order() {
+        let patterns: Vec<Pattern> = ["src/**/*", "!src/*.rs", "src/generated*", "*.md", "!docs/*"]
+            .iter()
+            .map(|pattern| Pattern::new(pattern).unwrap())
+            .collect();
+        let excluded_by = |file| excluding_pattern(&patterns, file).map(Pattern::as_str);
+
+        assert_eq!(excluded_by("src/main.rs"), None);
+        assert_eq!(excluded_by("src/cli/mod.rs"), None);
+        assert_eq!(excluded_by("src/data.json"), Some("src/**/*"));
+        assert_eq!(excluded_by("src/generated.rs"), Some("src/generated*"));
+        assert_eq!(excluded_by("README.md"), Some("*.md"));
+        assert_eq!(excluded_by("docs/guide.md"), None);
+        assert_eq!(excluded_by("Cargo.toml"), None);
+    }
+
+    #[test]
+    fn test_negation_without_exclusion() {
+        let patterns = [Pattern::new("!src/main.rs").unwrap()];
+
+        assert_eq!(excluding_pattern(&patterns, "src/main.rs"), None);
+        assert_eq!(excluding_pattern(&patterns, "src/lib.rs"), None);
+        assert_eq!(excluding_pattern(&[], "src/lib.rs"), None);
+    }
+
+    #[test]
+    fn test_literal_pathspec() {
+        assert_eq!(literal_pathspec("[abc].txt"), ":(literal)[abc].txt");
+        assert_eq!(literal_pathspec("my file.txt"), ":(literal)my file.txt");
+        assert_eq!(
+            literal_os_pathspec(OsStr::new("café.txt")),
+            ":(literal)café.txt"
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_match_files_no_match() {
+        let candidates = files(&["src/main.rs"]);
+        let matches = fuzzy_match_files(&files(&["zzz"]), &candidates);
+
+        assert_eq!(matches, vec![("zzz".to_string(), Vec::new())]);
+    }
 }
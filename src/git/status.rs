@@ -2,22 +2,61 @@
 //!
 //! Git status parsing and processing functionality for handling different
 //! file states and contexts.
+//!
+//! The status is read with `git status --porcelain=v2`, whose entries carry the
+//! index and working tree states separately as well as the original path and
+//! similarity score of renamed and copied files. Submodule entries are recognized
+//! by their `S<c><m><u>` field, telling a recorded commit change apart from
+//! uncommitted content inside the submodule. Status is read with `core.quotePath` on,
+//! whatever the configuration, so paths with special or non-ASCII characters always come
+//! quoted in the manner of a C string literal; they are unquoted to their exact bytes,
+//! which file names that are not valid UTF-8 keep in [`StatusEntry::os_path`].
+//!
+//! Entries are parsed field by field into typed [`StatusEntry`] values, with no regular
+//! expression; property tests round-trip generated entries through [`StatusEntry::parse`].
+
+use std::{ffi::OsString, fmt::Write, path::Path};
 
-use regex::Regex;
-use std::{collections::HashSet, io, process::Command};
+use crate::{
+    errors::{GitError, Result, RonaError},
+    utils::find_project_root,
+};
 
-use crate::errors::{GitError, Result, RonaError};
+use super::exec::GitCommand;
 
-/// Reads the git status.
+/// Reads the git status in porcelain v2 format.
+///
+/// Git is run from the repository root so paths are relative to it,
+/// whatever the current working directory, and with `core.quotePath` on so that
+/// the output is ASCII and file names that are not valid UTF-8 survive it.
 ///
 /// # Errors
+/// * If not in a git repository
 /// * If the git command fails
 ///
 /// # Returns
 /// * `Result<String>` - The git status or an error message
 pub fn read_git_status() -> Result<String> {
-    let args = vec!["status", "--porcelain", "-u"];
-    let command = Command::new("git").args(&args).output()?;
+    read_git_status_in(&find_project_root()?)
+}
+
+/// Reads the git status in porcelain v2 format of the working tree rooted at
+/// `project_root`, without relying on the current working directory.
+///
+/// # Errors
+/// * Same as [`read_git_status`]
+pub fn read_git_status_in(project_root: &Path) -> Result<String> {
+    let args = vec![
+        "-c",
+        "core.quotePath=true",
+        "status",
+        "--porcelain=v2",
+        "-u",
+    ];
+    let command = GitCommand::new()
+        .current_dir(project_root)
+        .args(&args)
+        .output()?;
 
     if command.status.success() {
         let output = String::from_utf8_lossy(&command.stdout);
@@ -25,198 +64,937 @@ pub fn read_git_status() -> Result<String> {
     } else {
         let error_message = String::from_utf8_lossy(&command.stderr);
         Err(RonaError::Git(GitError::CommandFailed {
-            command: "git status --porcelain -u".to_string(),
+            command: "git -c core.quotePath=true status --porcelain=v2 -u".to_string(),
             output: error_message.to_string(),
         }))
     }
 }
 
-/// Returns a list of all files that appear in git status
-/// (modified, untracked, staged - but not deleted)
-///
-/// # Errors
-/// * If reading git status fails
-/// * If a regex pattern fails to compile
-///
-/// # Returns
-/// * `Vec<String>` - List of files from git status
-pub fn get_status_files() -> Result<Vec<String>> {
-    let status = read_git_status()?;
-
-    // Regex to match any file in git status except deleted files
-    // Matches patterns like:
-    // MM file.txt
-    // M  file.txt
-    //  M file.txt
-    // ?? file.txt
-    // R  old_file.txt -> new_file.txt
-    //  R old_file.txt -> new_file.txt
-    let regex_rule = Regex::new(r"^[MARCU?\s][MARCU?\s]\s(.+?)(?:\s->\s(.+))?$")
-        .map_err(|e| RonaError::Io(io::Error::new(io::ErrorKind::InvalidData, e.to_string())))?;
-
-    // Use a HashSet to avoid duplicates
-    let files: HashSet<String> = status
-        .lines()
-        .filter_map(|line| {
-            // Skip if it's a deleted file
-            if line.starts_with(" D") || line.starts_with("D ") {
-                return None;
+/// State of a file in the index or in the working tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileState {
+    /// Unchanged (`.`)
+    Unmodified,
+    /// Modified (`M`)
+    Modified,
+    /// File type changed (`T`)
+    TypeChanged,
+    /// Added (`A`)
+    Added,
+    /// Deleted (`D`)
+    Deleted,
+    /// Renamed (`R`)
+    Renamed,
+    /// Copied (`C`)
+    Copied,
+    /// Updated but unmerged (`U`)
+    Unmerged,
+    /// Not tracked by git (`?`)
+    Untracked,
+    /// Ignored (`!`)
+    Ignored,
+}
+
+impl FileState {
+    /// Parses a porcelain state code, `.` and ` ` both meaning unmodified.
+    #[must_use]
+    pub const fn from_code(code: char) -> Option<Self> {
+        match code {
+            '.' | ' ' => Some(Self::Unmodified),
+            'M' => Some(Self::Modified),
+            'T' => Some(Self::TypeChanged),
+            'A' => Some(Self::Added),
+            'D' => Some(Self::Deleted),
+            'R' => Some(Self::Renamed),
+            'C' => Some(Self::Copied),
+            'U' => Some(Self::Unmerged),
+            '?' => Some(Self::Untracked),
+            '!' => Some(Self::Ignored),
+            _ => None,
+        }
+    }
+
+    /// The porcelain state code, the inverse of [`from_code`](Self::from_code).
+    #[must_use]
+    pub const fn code(self) -> char {
+        match self {
+            Self::Unmodified => '.',
+            Self::Modified => 'M',
+            Self::TypeChanged => 'T',
+            Self::Added => 'A',
+            Self::Deleted => 'D',
+            Self::Renamed => 'R',
+            Self::Copied => 'C',
+            Self::Unmerged => 'U',
+            Self::Untracked => '?',
+            Self::Ignored => '!',
+        }
+    }
+}
+
+/// State of a submodule, from the `S<c><m><u>` field of a porcelain v2 entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubmoduleState {
+    /// The submodule checks out a different commit than the recorded one (`C`)
+    pub commit_changed: bool,
+    /// The submodule has modified tracked files (`M`)
+    pub tracked_changes: bool,
+    /// The submodule has untracked files (`U`)
+    pub untracked_changes: bool,
+}
+
+impl SubmoduleState {
+    /// Parses the `<sub>` field of a porcelain v2 entry, `None` for regular files (`N...`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::git::status::SubmoduleState;
+    ///
+    /// let state = SubmoduleState::from_field("SC.U").unwrap();
+    /// assert!(state.commit_changed && state.untracked_changes);
+    /// assert!(!state.tracked_changes);
+    /// assert_eq!(SubmoduleState::from_field("N..."), None);
+    /// ```
+    #[must_use]
+    pub fn from_field(field: &str) -> Option<Self> {
+        let flags = field.strip_prefix('S')?.as_bytes();
+
+        Some(Self {
+            commit_changed: flags.first() == Some(&b'C'),
+            tracked_changes: flags.get(1) == Some(&b'M'),
+            untracked_changes: flags.get(2) == Some(&b'U'),
+        })
+    }
+
+    /// Describes the changes of the submodule (e.g. `new commits, untracked content`).
+    #[must_use]
+    pub fn describe(&self) -> String {
+        [
+            (self.commit_changed, "new commits"),
+            (self.tracked_changes, "modified content"),
+            (self.untracked_changes, "untracked content"),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, label)| *label)
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+/// A file listed by `git status --porcelain=v2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusEntry {
+    /// State of the file in the index (staged changes)
+    pub index_state: FileState,
+    /// State of the file in the working tree (unstaged changes)
+    pub worktree_state: FileState,
+    /// Path of the file, the new path for renamed and copied files
+    pub path: String,
+    /// Exact bytes of `path`, which only differ from it when the file name is not
+    /// valid UTF-8 (`path` then holds replacement characters)
+    pub os_path: OsString,
+    /// Original path of a renamed or copied file
+    pub orig_path: Option<String>,
+    /// Similarity score (0-100) of a renamed or copied file
+    pub score: Option<u8>,
+    /// State of the submodule, when the entry is a submodule
+    pub submodule: Option<SubmoduleState>,
+}
+
+impl StatusEntry {
+    /// Parses a porcelain v2 status line.
+    ///
+    /// Handles ordinary (`1`), renamed or copied (`2`), unmerged (`u`), untracked (`?`)
+    /// and ignored (`!`) entries, including submodule entries. Header lines (`#`) and
+    /// malformed lines return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::git::status::{FileState, StatusEntry};
+    ///
+    /// let entry = StatusEntry::parse(
+    ///     "2 R. N... 100644 100644 100644 0123abc 0123abc R100 new.rs\told.rs",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(entry.index_state, FileState::Renamed);
+    /// assert_eq!(entry.path, "new.rs");
+    /// assert_eq!(entry.orig_path.as_deref(), Some("old.rs"));
+    /// assert_eq!(entry.score, Some(100));
+    /// ```
+    #[must_use]
+    pub fn parse(line: &str) -> Option<Self> {
+        let (kind, rest) = line.split_once(' ')?;
+
+        match kind {
+            "1" => {
+                let fields: Vec<&str> = rest.splitn(8, ' ').collect();
+                let (index_state, worktree_state) = parse_states(fields.first()?)?;
+
+                Some(Self {
+                    index_state,
+                    worktree_state,
+                    path: unquote_path(fields.get(7)?),
+                    os_path: unquote_os_path(fields.get(7)?),
+                    orig_path: None,
+                    score: None,
+                    submodule: SubmoduleState::from_field(fields.get(1)?),
+                })
             }
+            "2" => {
+                let fields: Vec<&str> = rest.splitn(9, ' ').collect();
+                let (index_state, worktree_state) = parse_states(fields.first()?)?;
+                let score = fields.get(7)?.get(1..)?.parse().ok();
+                let (path, orig_path) = fields.get(8)?.split_once('\t')?;
 
-            if regex_rule.is_match(line) {
-                let captures = regex_rule.captures(line)?;
+                Some(Self {
+                    index_state,
+                    worktree_state,
+                    path: unquote_path(path),
+                    os_path: unquote_os_path(path),
+                    orig_path: Some(unquote_path(orig_path)),
+                    score,
+                    submodule: SubmoduleState::from_field(fields.get(1)?),
+                })
+            }
+            "u" => {
+                let fields: Vec<&str> = rest.splitn(10, ' ').collect();
+                let (index_state, worktree_state) = parse_states(fields.first()?)?;
 
-                // If we have a second capture group, it means we have a renamed file
-                // In this case, we want to use the new filename (after the ->)
-                if let Some(new_name) = captures.get(2) {
-                    Some(new_name.as_str().to_string())
-                } else {
-                    Some(captures.get(1)?.as_str().to_string())
-                }
-            } else {
-                None
+                Some(Self {
+                    index_state,
+                    worktree_state,
+                    path: unquote_path(fields.get(9)?),
+                    os_path: unquote_os_path(fields.get(9)?),
+                    orig_path: None,
+                    score: None,
+                    submodule: SubmoduleState::from_field(fields.get(1)?),
+                })
             }
-        })
-        .collect();
+            "?" | "!" => {
+                let state = FileState::from_code(kind.chars().next()?)?;
+
+                Some(Self {
+                    index_state: state,
+                    worktree_state: state,
+                    path: unquote_path(rest),
+                    os_path: unquote_os_path(rest),
+                    orig_path: None,
+                    score: None,
+                    submodule: None,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the file is deleted in the index or in the working tree.
+    #[must_use]
+    pub fn is_deleted(&self) -> bool {
+        self.index_state == FileState::Deleted || self.worktree_state == FileState::Deleted
+    }
 
-    let files = files.into_iter().collect();
+    /// Whether the file is deleted in the working tree without the deletion being staged.
+    #[must_use]
+    pub fn is_unstaged_deletion(&self) -> bool {
+        self.worktree_state == FileState::Deleted && self.index_state != FileState::Deleted
+    }
 
-    Ok(files)
+    /// Whether the entry is a submodule whose only changes are uncommitted content
+    /// inside it, which staging cannot record in the superproject.
+    #[must_use]
+    pub fn is_dirty_submodule(&self) -> bool {
+        self.submodule.is_some_and(|state| !state.commit_changed)
+            && self.index_state == FileState::Unmodified
+    }
 }
 
-/// Processes deleted files that need to be staged for deletion.
-/// Only returns files that are deleted in the working directory but not yet staged.
-///
-/// # Arguments
-/// * `message` - The git status output string
+/// Parses the `XY` field of a porcelain v2 entry.
+fn parse_states(code: &str) -> Option<(FileState, FileState)> {
+    let mut chars = code.chars();
+    let index_state = FileState::from_code(chars.next()?)?;
+    let worktree_state = FileState::from_code(chars.next()?)?;
+
+    Some((index_state, worktree_state))
+}
+
+/// Decodes a path quoted by git in the manner of a C string literal.
 ///
-/// # Errors
-/// * If the extracted filenames cannot be parsed
+/// Paths that are not enclosed in double quotes are returned as-is. Octal escapes
+/// are decoded as bytes, so quoted UTF-8 paths (e.g. `"\303\251.txt"`) are restored;
+/// bytes that are not valid UTF-8 become replacement characters, see
+/// [`unquote_path_bytes`] for the exact bytes.
 ///
-/// # Returns
-/// * `Result<Vec<String>>` - Files that need to be staged for deletion
-pub fn process_deleted_files_for_staging(message: &str) -> Result<Vec<String>> {
-    // Regex to match files deleted in working directory but not yet staged for deletion
-    // Git status format: XY filename
-    // Where X = index status, Y = working tree status
-    // We want files where Y = 'D' (deleted in working tree) but X ≠ 'D'
-    // This includes:
-    // - " D file.txt" (not in index, deleted in working tree)
-    // - "MD file.txt" (modified in index, deleted in working tree)
-    // - "AD file.txt" (added in index, deleted in working tree)
-    // But excludes:
-    // - "D  file.txt" (already staged for deletion)
-    // - "DD file.txt" (deleted in both index and working tree - already staged)
-    extract_filenames(message, r"^[^D]D\s+(.+)$")
-}
-
-/// Processes deleted files for commit message generation.
-/// Returns all deleted files, only those that are staged or modified in the working tree.
-///
-/// # Arguments
-/// * `message` - The git status output string
+/// # Examples
 ///
-/// # Errors
-/// * If the extracted filenames cannot be parsed
+/// ```
+/// use rona::git::status::unquote_path;
 ///
-/// # Returns
-/// * `Result<Vec<String>>` - All deleted files for the commit message
-pub fn process_deleted_files_for_commit_message(message: &str) -> Result<Vec<String>> {
-    // Regex to match all deleted files in git status output
-    // This includes only staged deletions:
-    // - " D file.txt" (deleted in the working tree only, not staged, so not included)
-    // - "D  file.txt" (staged for deletion)
-    // - "MD file.txt" (modified in index, deleted in the working tree)
-    // - "AD file.txt" (added in index, deleted in the working tree)
-    // - "DD file.txt" (deleted in both index and working tree)
-    extract_filenames(message, r"^[D][D\s]\s+(.+)$")
+/// assert_eq!(unquote_path(r#""tab\tx""#), "tab\tx");
+/// assert_eq!(unquote_path(r#""\303\251.txt""#), "é.txt");
+/// assert_eq!(unquote_path("my file.txt"), "my file.txt");
+/// ```
+#[must_use]
+pub fn unquote_path(path: &str) -> String {
+    String::from_utf8_lossy(&unquote_path_bytes(path)).into_owned()
 }
 
-/// Processes the git status.
-/// It will parse the git status to prepare the git commit message.
+/// Decodes a path quoted by git in the manner of a C string literal to its exact bytes,
+/// which may not be valid UTF-8.
 ///
-/// # Arguments
-/// * `message` - The git status output string
+/// # Examples
 ///
-/// # Errors
-/// * If the extracted filenames cannot be parsed
+/// ```
+/// use rona::git::status::unquote_path_bytes;
 ///
-/// # Returns
-/// * `Result<Vec<String>, String>` - The modified/added files or an error message
-pub fn process_git_status(message: &str) -> Result<Vec<String>> {
-    // Regex to match the modified files, added files, and renamed files
-    // For renamed files, captures the new filename after '->'
-    extract_filenames(message, r"^[MTARCU][A-Z\?\! ]\s(.+?)(?:\s->\s(.+))?$")
+/// assert_eq!(unquote_path_bytes(r#""caf\351.txt""#), b"caf\xe9.txt");
+/// assert_eq!(unquote_path_bytes("plain.txt"), b"plain.txt");
+/// ```
+#[must_use]
+pub fn unquote_path_bytes(path: &str) -> Vec<u8> {
+    let Some(quoted) = path
+        .strip_prefix('"')
+        .and_then(|path| path.strip_suffix('"'))
+    else {
+        return path.as_bytes().to_vec();
+    };
+
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut chars = quoted.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+
+        match chars.next() {
+            Some('a') => bytes.push(0x07),
+            Some('b') => bytes.push(0x08),
+            Some('t') => bytes.push(b'\t'),
+            Some('n') => bytes.push(b'\n'),
+            Some('v') => bytes.push(0x0b),
+            Some('f') => bytes.push(0x0c),
+            Some('r') => bytes.push(b'\r'),
+            Some(digit @ '0'..='7') => {
+                let mut value = digit.to_digit(8).unwrap_or_default();
+                for _ in 0..2 {
+                    if let Some(next) = chars.peek().and_then(|c| c.to_digit(8)) {
+                        value = value * 8 + next;
+                        chars.next();
+                    }
+                }
+                bytes.push(u8::try_from(value).unwrap_or(u8::MAX));
+            }
+            Some(other) => {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(other.encode_utf8(&mut buffer).as_bytes());
+            }
+            None => bytes.push(b'\\'),
+        }
+    }
+
+    bytes
+}
+
+/// Decodes a path quoted by git to a path of the file system, keeping bytes that are
+/// not valid UTF-8 on Unix (git only writes UTF-8 paths on other platforms).
+fn unquote_os_path(path: &str) -> OsString {
+    let bytes = unquote_path_bytes(path);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+        OsString::from_vec(bytes)
+    }
+    #[cfg(not(unix))]
+    {
+        OsString::from(String::from_utf8_lossy(&bytes).into_owned())
+    }
 }
 
-/// Counts the number of renamed files in the git status output.
+/// Quotes a path the way git does with `core.quotePath` (the default), the inverse of
+/// [`unquote_path`].
 ///
-/// Renamed files show up as "R  `old_path` -> `new_path`" in git status --porcelain.
-/// This function helps with accurate file counting since renamed files appear
-/// as 2 lines in `git diff --cached --numstat` (one deletion, one addition).
+/// Paths with double quotes, backslashes, control or non-ASCII characters are enclosed
+/// in double quotes, these characters being escaped (non-ASCII ones as octal bytes).
+/// Other paths are returned as-is.
 ///
-/// # Arguments
-/// * `message` - The git status output string
+/// # Examples
 ///
-/// # Returns
-/// * `usize` - The count of renamed files
+/// ```
+/// use rona::git::status::quote_path;
+///
+/// assert_eq!(quote_path("my file.txt"), "my file.txt");
+/// assert_eq!(quote_path("tab\tx"), r#""tab\tx""#);
+/// assert_eq!(quote_path("é.txt"), r#""\303\251.txt""#);
+/// ```
 #[must_use]
-pub fn count_renamed_files(message: &str) -> usize {
-    message
-        .lines()
-        .filter(|line| line.starts_with("R ") || line.starts_with("R\t"))
-        .count()
+pub fn quote_path(path: &str) -> String {
+    let needs_quotes = path
+        .bytes()
+        .any(|byte| byte == b'"' || byte == b'\\' || !(0x20..0x7f).contains(&byte));
+    if !needs_quotes {
+        return path.to_string();
+    }
+
+    let mut quoted = String::with_capacity(path.len() + 2);
+    quoted.push('"');
+    for byte in path.bytes() {
+        match byte {
+            0x07 => quoted.push_str("\\a"),
+            0x08 => quoted.push_str("\\b"),
+            b'\t' => quoted.push_str("\\t"),
+            b'\n' => quoted.push_str("\\n"),
+            0x0b => quoted.push_str("\\v"),
+            0x0c => quoted.push_str("\\f"),
+            b'\r' => quoted.push_str("\\r"),
+            b'"' => quoted.push_str("\\\""),
+            b'\\' => quoted.push_str("\\\\"),
+            0x20..0x7f => quoted.push(char::from(byte)),
+            _ => {
+                let _ = write!(quoted, "\\{byte:03o}");
+            }
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// A set of files of git status, selected by `rona list-status` flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusSet {
+    /// Files with changes in the index, deletions included
+    Staged,
+    /// Tracked files with changes in the working tree, deletions included
+    Unstaged,
+    /// Files not tracked by git
+    Untracked,
+    /// Files deleted in the index or in the working tree
+    Deleted,
 }
 
-// Use the shared extract_filenames function from the parent module
-use super::extract_filenames;
+impl StatusSet {
+    /// Whether the entry belongs to the set.
+    #[must_use]
+    pub fn contains(self, entry: &StatusEntry) -> bool {
+        let changed = |state: FileState| {
+            !matches!(
+                state,
+                FileState::Unmodified | FileState::Untracked | FileState::Ignored
+            )
+        };
+
+        match self {
+            Self::Staged => changed(entry.index_state),
+            Self::Unstaged => changed(entry.worktree_state),
+            Self::Untracked => entry.index_state == FileState::Untracked,
+            Self::Deleted => entry.is_deleted(),
+        }
+    }
+}
+
+/// A single read of `git status --porcelain=v2 -u`, parsed once and shared by the
+/// staging decisions, the deleted-file detection and the post-add summary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusSnapshot {
+    /// The entries of git status, in the order git listed them
+    pub entries: Vec<StatusEntry>,
+}
+
+impl StatusSnapshot {
+    /// Reads and parses the git status of the current repository.
+    ///
+    /// # Errors
+    /// * If reading git status fails
+    pub fn read() -> Result<Self> {
+        Ok(Self::parse(&read_git_status()?))
+    }
+
+    /// Reads and parses the git status of the working tree rooted at `project_root`.
+    ///
+    /// # Errors
+    /// * If reading git status fails
+    pub fn read_in(project_root: &Path) -> Result<Self> {
+        Ok(Self::parse(&read_git_status_in(project_root)?))
+    }
+
+    /// Parses `git status --porcelain=v2` output, skipping header and unknown lines.
+    #[must_use]
+    pub fn parse(status: &str) -> Self {
+        Self {
+            entries: status.lines().filter_map(StatusEntry::parse).collect(),
+        }
+    }
+
+    /// Files that appear in git status (modified, untracked, staged, renamed, copied -
+    /// but not deleted), with the new path for renamed and copied files.
+    #[must_use]
+    pub fn changed_files(&self) -> Vec<String> {
+        self.paths(|entry| !entry.is_deleted())
+    }
+
+    /// Files in any of the sets, in the order git listed them.
+    ///
+    /// Without sets, these are the [`changed_files`](Self::changed_files).
+    #[must_use]
+    pub fn files_in(&self, sets: &[StatusSet]) -> Vec<String> {
+        if sets.is_empty() {
+            return self.changed_files();
+        }
+
+        self.paths(|entry| sets.iter().any(|set| set.contains(entry)))
+    }
+
+    /// Files deleted in the working directory but not yet staged for deletion.
+    ///
+    /// This includes files unchanged (`.D`), modified (`MD`) or added (`AD`) in the index,
+    /// but excludes deletions already staged (`D.`).
+    #[must_use]
+    pub fn unstaged_deletions(&self) -> Vec<String> {
+        self.paths(StatusEntry::is_unstaged_deletion)
+    }
+
+    /// Files with staged changes (modified, type changed, added, renamed, copied or
+    /// unmerged in the index), listed in the commit message.
+    #[must_use]
+    pub fn staged_files(&self) -> Vec<String> {
+        self.paths(|entry| {
+            matches!(
+                entry.index_state,
+                FileState::Modified
+                    | FileState::TypeChanged
+                    | FileState::Added
+                    | FileState::Renamed
+                    | FileState::Copied
+                    | FileState::Unmerged
+            )
+        })
+    }
+
+    /// Staged files that are submodules, listed as submodule updates in the commit message.
+    #[must_use]
+    pub fn staged_submodules(&self) -> Vec<String> {
+        self.paths(|entry| entry.submodule.is_some() && entry.index_state != FileState::Unmodified)
+    }
+
+    /// Files whose rename is staged (both sides of the rename are in the index), with
+    /// their new path.
+    #[must_use]
+    pub fn staged_renames(&self) -> Vec<String> {
+        self.paths(|entry| entry.index_state == FileState::Renamed)
+    }
+
+    /// Files whose deletion is staged, listed as deleted in the commit message.
+    #[must_use]
+    pub fn staged_deletions(&self) -> Vec<String> {
+        self.paths(|entry| entry.index_state == FileState::Deleted)
+    }
+
+    /// Paths of the entries matching `filter`.
+    fn paths<F>(&self, filter: F) -> Vec<String>
+    where
+        F: Fn(&StatusEntry) -> bool,
+    {
+        self.entries
+            .iter()
+            .filter(|entry| filter(entry))
+            .map(|entry| entry.path.clone())
+            .collect()
+    }
+}
+
+/// Returns a list of all files that appear in git status
+/// (modified, untracked, staged - but not deleted)
+///
+/// # Errors
+/// * If reading git status fails
+///
+/// # Returns
+/// * `Vec<String>` - List of files from git status
+pub fn get_status_files() -> Result<Vec<String>> {
+    Ok(StatusSnapshot::read()?.changed_files())
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::{prelude::*, sample::select};
+
+    const STATUS: &str = "\
+1 .M N... 100644 100644 100644 1111111 1111111 src/main.rs
+1 MD N... 100644 100644 000000 1111111 2222222 staged_then_removed.rs
+1 .D N... 100644 100644 000000 1111111 1111111 removed.txt
+1 D. N... 100644 000000 000000 1111111 0000000 staged_removal.txt
+1 A. N... 000000 100644 100644 0000000 3333333 added.rs
+2 R. N... 100644 100644 100644 4444444 4444444 R100 renamed.txt\told.txt
+2 C. N... 100644 100644 100644 5555555 5555555 C75 copy.rs\tsource.rs
+u UU N... 100644 100644 100644 100644 6666666 7777777 8888888 conflict.rs
+? new.txt
+1 .M SC.. 160000 160000 160000 9999999 9999999 vendor/bumped
+1 .M S.MU 160000 160000 160000 aaaaaaa aaaaaaa vendor/dirty
+1 M. SC.. 160000 160000 160000 bbbbbbb ccccccc vendor/staged
+";
 
     #[test]
-    fn test_count_renamed_files() {
-        // Test with no renamed files
-        let status = " M file1.txt\n?? file2.txt\n";
-        assert_eq!(count_renamed_files(status), 0);
+    fn test_unquote_path() {
+        assert_eq!(unquote_path("plain.txt"), "plain.txt");
+        assert_eq!(unquote_path("my file.txt"), "my file.txt");
+        assert_eq!(unquote_path(r#""quo\"te""#), "quo\"te");
+        assert_eq!(unquote_path(r#""back\\slash""#), "back\\slash");
+        assert_eq!(unquote_path(r#""line\nbreak""#), "line\nbreak");
+        assert_eq!(unquote_path(r#""\303\251t\303\251.md""#), "été.md");
+        assert_eq!(unquote_path("\""), "\"");
+    }
 
-        // Test with one renamed file
-        let status = "R  old_name.txt -> new_name.txt\n M file1.txt\n";
-        assert_eq!(count_renamed_files(status), 1);
+    #[test]
+    fn test_parse_non_utf8_path() {
+        let entry = StatusEntry::parse(r#"? "caf\351 \303\251t\303\251.txt""#).unwrap();
 
-        // Test with multiple renamed files
-        let status = "R  old1.txt -> new1.txt\nR  old2.txt -> new2.txt\n M file1.txt\n";
-        assert_eq!(count_renamed_files(status), 2);
+        assert_eq!(entry.path, "caf\u{fffd} été.txt");
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            assert_eq!(entry.os_path.as_bytes(), b"caf\xe9 \xc3\xa9t\xc3\xa9.txt");
+        }
+    }
 
-        // Test with tab separator (alternative git format)
-        let status = "R\told_name.txt -> new_name.txt\n M file1.txt\n";
-        assert_eq!(count_renamed_files(status), 1);
+    #[test]
+    fn test_parse_renamed_entry_with_score() {
+        let entry = StatusEntry::parse(
+            "2 R. N... 100644 100644 100644 4444444 4444444 R100 .github/workflows/publish.yaml\t.github/workflows/publish",
+        )
+        .unwrap();
 
-        // Test real-world case from the issue
-        let status = "R  .github/workflows/publish -> .github/workflows/publish.yaml\n";
-        assert_eq!(count_renamed_files(status), 1);
+        assert_eq!(entry.index_state, FileState::Renamed);
+        assert_eq!(entry.worktree_state, FileState::Unmodified);
+        assert_eq!(entry.path, ".github/workflows/publish.yaml");
+        assert_eq!(
+            entry.orig_path.as_deref(),
+            Some(".github/workflows/publish")
+        );
+        assert_eq!(entry.score, Some(100));
     }
 
     #[test]
-    fn test_get_status_files_with_renamed() {
-        // This test verifies that get_status_files correctly handles renamed files
-        // by returning the new filename
-        let status = "R  old_file.txt -> new_file.txt\n M modified.txt\n?? untracked.txt\n";
-
-        // We can't directly test get_status_files without a real git repo,
-        // but we can verify the regex pattern works
-        let regex = regex::Regex::new(r"^[MARCU?\s][MARCU?\s]\s(.+?)(?:\s->\s(.+))?$").unwrap();
-
-        for line in status.lines() {
-            if let Some(captures) = regex.captures(line)
-                && let Some(new_name) = captures.get(2)
-            {
-                // For renamed files, should get the new name
-                if line.starts_with('R') {
-                    assert_eq!(new_name.as_str(), "new_file.txt");
-                }
-            }
+    fn test_parse_entries() {
+        let copy = StatusEntry::parse(
+            "2 CM N... 100644 100644 100644 5555555 5555555 C75 copy.rs\tsource.rs",
+        )
+        .unwrap();
+        assert_eq!(copy.index_state, FileState::Copied);
+        assert_eq!(copy.worktree_state, FileState::Modified);
+        assert_eq!(copy.score, Some(75));
+
+        let spaced =
+            StatusEntry::parse("1 .M N... 100644 100644 100644 1111111 1111111 my file.txt")
+                .unwrap();
+        assert_eq!(spaced.path, "my file.txt");
+
+        let untracked = StatusEntry::parse("? notes/todo.md").unwrap();
+        assert_eq!(untracked.index_state, FileState::Untracked);
+        assert_eq!(untracked.path, "notes/todo.md");
+
+        let quoted = StatusEntry::parse(
+            "2 R. N... 100644 100644 100644 4444444 4444444 R90 \"new\\tname.txt\"\t\"\\303\\251.txt\"",
+        )
+        .unwrap();
+        assert_eq!(quoted.path, "new\tname.txt");
+        assert_eq!(quoted.orig_path.as_deref(), Some("é.txt"));
+
+        assert_eq!(StatusEntry::parse("# branch.oid abc"), None);
+        assert_eq!(StatusEntry::parse(" M file.txt"), None);
+    }
+
+    #[test]
+    fn test_status_snapshot_changed_files() {
+        let snapshot = StatusSnapshot::parse(STATUS);
+
+        assert_eq!(
+            snapshot.changed_files(),
+            vec![
+                "src/main.rs",
+                "added.rs",
+                "renamed.txt",
+                "copy.rs",
+                "conflict.rs",
+                "new.txt",
+                "vendor/bumped",
+                "vendor/dirty",
+                "vendor/staged"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_snapshot_deletions() {
+        let snapshot = StatusSnapshot::parse(STATUS);
+
+        assert_eq!(
+            snapshot.unstaged_deletions(),
+            vec!["staged_then_removed.rs", "removed.txt"]
+        );
+        assert_eq!(snapshot.staged_deletions(), vec!["staged_removal.txt"]);
+    }
+
+    #[test]
+    fn test_status_snapshot_staged_files() {
+        let snapshot = StatusSnapshot::parse(STATUS);
+
+        assert_eq!(
+            snapshot.staged_files(),
+            vec![
+                "staged_then_removed.rs",
+                "added.rs",
+                "renamed.txt",
+                "copy.rs",
+                "conflict.rs",
+                "vendor/staged"
+            ]
+        );
+        assert_eq!(snapshot.staged_submodules(), vec!["vendor/staged"]);
+    }
+
+    #[test]
+    fn test_status_snapshot_files_in() {
+        let snapshot = StatusSnapshot::parse(STATUS);
+
+        assert_eq!(snapshot.files_in(&[]), snapshot.changed_files());
+        assert_eq!(snapshot.files_in(&[StatusSet::Untracked]), vec!["new.txt"]);
+        assert_eq!(
+            snapshot.files_in(&[StatusSet::Deleted]),
+            vec![
+                "staged_then_removed.rs",
+                "removed.txt",
+                "staged_removal.txt"
+            ]
+        );
+        assert_eq!(
+            snapshot.files_in(&[StatusSet::Unstaged]),
+            vec![
+                "src/main.rs",
+                "staged_then_removed.rs",
+                "removed.txt",
+                "conflict.rs",
+                "vendor/bumped",
+                "vendor/dirty"
+            ]
+        );
+        assert_eq!(
+            snapshot.files_in(&[StatusSet::Staged, StatusSet::Untracked]),
+            vec![
+                "staged_then_removed.rs",
+                "staged_removal.txt",
+                "added.rs",
+                "renamed.txt",
+                "copy.rs",
+                "conflict.rs",
+                "new.txt",
+                "vendor/staged"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_submodule_entries() {
+        let snapshot = StatusSnapshot::parse(STATUS);
+        let submodules: Vec<&StatusEntry> = snapshot
+            .entries
+            .iter()
+            .filter(|entry| entry.submodule.is_some())
+            .collect();
+
+        assert_eq!(submodules.len(), 3);
+        assert!(submodules[0].submodule.unwrap().commit_changed);
+        assert!(!submodules[0].is_dirty_submodule());
+        assert!(submodules[1].is_dirty_submodule());
+        assert_eq!(
+            submodules[1].submodule.unwrap().describe(),
+            "modified content, untracked content"
+        );
+        assert!(
+            snapshot
+                .entries
+                .iter()
+                .filter(|entry| entry.submodule.is_none())
+                .all(|entry| !entry.is_dirty_submodule())
+        );
+    }
+
+    #[test]
+    fn test_quote_path() {
+        assert_eq!(quote_path("plain.txt"), "plain.txt");
+        assert_eq!(quote_path("quo\"te"), r#""quo\"te""#);
+        assert_eq!(quote_path("back\\slash"), r#""back\\slash""#);
+        assert_eq!(quote_path("bell\u{7}"), r#""bell\a""#);
+        assert_eq!(quote_path("del\u{7f}"), r#""del\177""#);
+    }
+
+    #[test]
+    fn test_staged_renames() {
+        assert_eq!(
+            StatusSnapshot::parse(STATUS).staged_renames(),
+            vec!["renamed.txt"]
+        );
+    }
+
+    /// Renders an entry as a porcelain v2 line of the given kind (`1`, `2`, `u`, `?` or
+    /// `!`), the inverse of [`StatusEntry::parse`].
+    fn render(kind: char, entry: &StatusEntry) -> String {
+        let states = format!(
+            "{}{}",
+            entry.index_state.code(),
+            entry.worktree_state.code()
+        );
+        let submodule = entry.submodule.map_or_else(
+            || "N...".to_string(),
+            |state| {
+                let flag = |set: bool, code: char| if set { code } else { '.' };
+                format!(
+                    "S{}{}{}",
+                    flag(state.commit_changed, 'C'),
+                    flag(state.tracked_changes, 'M'),
+                    flag(state.untracked_changes, 'U')
+                )
+            },
+        );
+        let path = quote_path(&entry.path);
+
+        match kind {
+            '1' => format!("1 {states} {submodule} 100644 100644 100644 1111111 2222222 {path}"),
+            '2' => format!(
+                "2 {states} {submodule} 100644 100644 100644 1111111 2222222 {}{} {path}\t{}",
+                entry.index_state.code(),
+                entry.score.unwrap_or_default(),
+                quote_path(entry.orig_path.as_deref().unwrap_or_default())
+            ),
+            'u' => format!(
+                "u {states} {submodule} 100644 100644 100644 100644 1111111 2222222 3333333 {path}"
+            ),
+            _ => format!("{kind} {path}"),
+        }
+    }
+
+    /// Paths git can list: any characters but NUL, not empty.
+    fn path() -> impl Strategy<Value = String> {
+        "[^\u{0}]{1,24}"
+    }
+
+    fn submodule() -> impl Strategy<Value = Option<SubmoduleState>> {
+        proptest::option::of((any::<bool>(), any::<bool>(), any::<bool>()).prop_map(
+            |(commit_changed, tracked_changes, untracked_changes)| SubmoduleState {
+                commit_changed,
+                tracked_changes,
+                untracked_changes,
+            },
+        ))
+    }
+
+    /// Entries paired with the kind of line they are listed on.
+    fn entry() -> impl Strategy<Value = (char, StatusEntry)> {
+        use FileState::{
+            Added, Copied, Deleted, Ignored, Modified, Renamed, TypeChanged, Unmerged, Unmodified,
+            Untracked,
+        };
+        let changed = [Unmodified, Modified, TypeChanged, Added, Deleted];
+
+        let ordinary = (
+            select(changed.to_vec()),
+            select(changed.to_vec()),
+            submodule(),
+            path(),
+        )
+            .prop_map(|(index_state, worktree_state, submodule, path)| {
+                let entry = StatusEntry {
+                    index_state,
+                    worktree_state,
+                    os_path: OsString::from(&path),
+                    path,
+                    orig_path: None,
+                    score: None,
+                    submodule,
+                };
+                ('1', entry)
+            });
+        let renamed = (
+            select(vec![Renamed, Copied]),
+            select(changed.to_vec()),
+            0..=100u8,
+            path(),
+            path(),
+        )
+            .prop_map(|(index_state, worktree_state, score, path, orig_path)| {
+                let entry = StatusEntry {
+                    index_state,
+                    worktree_state,
+                    os_path: OsString::from(&path),
+                    path,
+                    orig_path: Some(orig_path),
+                    score: Some(score),
+                    submodule: None,
+                };
+                ('2', entry)
+            });
+        let unmerged = (
+            select(vec![Unmerged, Added, Deleted]),
+            select(vec![Unmerged, Added, Deleted]),
+            path(),
+        )
+            .prop_map(|(index_state, worktree_state, path)| {
+                let entry = StatusEntry {
+                    index_state,
+                    worktree_state,
+                    os_path: OsString::from(&path),
+                    path,
+                    orig_path: None,
+                    score: None,
+                    submodule: None,
+                };
+                ('u', entry)
+            });
+        let other = (select(vec![Untracked, Ignored]), path()).prop_map(|(state, path)| {
+            let entry = StatusEntry {
+                index_state: state,
+                worktree_state: state,
+                os_path: OsString::from(&path),
+                path,
+                orig_path: None,
+                score: None,
+                submodule: None,
+            };
+            (state.code(), entry)
+        });
+
+        prop_oneof![ordinary, renamed, unmerged, other]
+    }
+
+    proptest! {
+        #[test]
+        fn prop_quote_path_round_trip(path in path()) {
+            prop_assert_eq!(unquote_path(&quote_path(&path)), path);
+        }
+
+        #[test]
+        fn prop_parse_round_trip((kind, entry) in entry()) {
+            prop_assert_eq!(StatusEntry::parse(&render(kind, &entry)), Some(entry));
+        }
+
+        #[test]
+        fn prop_snapshot_keeps_every_entry(entries in proptest::collection::vec(entry(), 0..12)) {
+            let lines: Vec<String> = entries
+                .iter()
+                .map(|(kind, entry)| render(*kind, entry))
+                .collect();
+            let parsed = StatusSnapshot::parse(&format!("# branch.oid abc\n{}\n", lines.join("\n")));
+
+            prop_assert_eq!(
+                parsed.entries,
+                entries.into_iter().map(|(_, entry)| entry).collect::<Vec<_>>()
+            );
         }
     }
 }
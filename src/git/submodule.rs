@@ -0,0 +1,59 @@
+//! Git Submodule Operations
+//!
+//! A `git submodule` passthrough, so that `rona submodule update --init --recursive`
+//! runs with the same dry-run and output handling as the other commands.
+
+use log::debug;
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::exec::GitCommand;
+
+/// Runs `git submodule` with the given arguments.
+///
+/// The command inherits the terminal, so the clone and checkout progress of
+/// the submodules is shown as git prints it.
+///
+/// # Arguments
+/// * `args` - The arguments passed to `git submodule` (e.g. `update --init --recursive`)
+/// * `dry_run` - If true, only plan the command
+///
+/// # Returns
+/// * `Plan` - The command run (or that would be, in dry-run mode)
+///
+/// # Errors
+/// * If the git command fails
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::submodule::git_submodule;
+///
+/// let args = ["update", "--init", "--recursive"].map(String::from);
+/// git_submodule(&args, false)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn git_submodule(args: &[String], dry_run: bool) -> Result<Plan> {
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Submodule, "", args.to_vec());
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    debug!("Running git submodule {}...", args.join(" "));
+
+    let status = GitCommand::new().arg("submodule").args(args).status()?;
+
+    if !status.success() {
+        return Err(RonaError::Git(GitError::CommandFailed {
+            command: format!("git submodule {}", args.join(" ")),
+            output: format!("exited with {status}"),
+        }));
+    }
+
+    Ok(plan)
+}
@@ -0,0 +1,168 @@
+//! Commit Type Suggestion
+//!
+//! Suggests the commit type of the staged files (only tests changed → `test`, only
+//! documentation → `docs`, only dependency manifests → `chore`...) to pre-select it in
+//! the commit type prompt. Rules configured in `[[type_rules]]` are tried before the
+//! built-in ones.
+
+use serde::{Deserialize, Serialize};
+
+use super::files::{IgnorePattern, is_ignored};
+
+/// A commit type suggested when every staged file matches one of the patterns.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TypeRule {
+    /// The suggested commit type (e.g. `test`)
+    #[serde(rename = "type")]
+    pub commit_type: String,
+    /// Patterns in the `.gitignore` syntax (e.g. `tests/` or `*.md`)
+    pub patterns: Vec<String>,
+}
+
+impl TypeRule {
+    fn new(commit_type: &str, patterns: &[&str]) -> Self {
+        Self {
+            commit_type: commit_type.to_string(),
+            patterns: patterns.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    /// Whether every file matches one of the patterns (and there are files).
+    #[must_use]
+    pub fn matches(&self, files: &[String]) -> bool {
+        let patterns: Vec<IgnorePattern> = self
+            .patterns
+            .iter()
+            .filter_map(|pattern| IgnorePattern::parse(pattern))
+            .collect();
+
+        !files.is_empty() && files.iter().all(|file| is_ignored(file, &patterns))
+    }
+}
+
+/// The built-in rules, tried after the configured ones.
+#[must_use]
+pub fn default_type_rules() -> Vec<TypeRule> {
+    vec![
+        TypeRule::new(
+            "test",
+            &[
+                "tests/",
+                "test/",
+                "__tests__/",
+                "*_test.*",
+                "*.test.*",
+                "*.spec.*",
+                "test_*.py",
+            ],
+        ),
+        TypeRule::new(
+            "docs",
+            &["docs/", "doc/", "*.md", "*.rst", "*.adoc", "LICENSE*"],
+        ),
+        TypeRule::new("ci", &[".github/", ".gitlab-ci.yml", ".circleci/"]),
+        TypeRule::new(
+            "chore",
+            &[
+                "Cargo.toml",
+                "Cargo.lock",
+                "package.json",
+                "package-lock.json",
+                "yarn.lock",
+                "pnpm-lock.yaml",
+                "go.mod",
+                "go.sum",
+                "rust-toolchain.toml",
+                ".gitignore",
+            ],
+        ),
+    ]
+}
+
+/// Suggests the commit type of the staged files.
+///
+/// The first rule matching every file wins, the configured `rules` being tried
+/// before the [`default_type_rules`]. Rules whose type is not one of the
+/// `commit_types` are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::suggest::suggest_commit_type;
+///
+/// let files = vec!["tests/cli.rs".to_string(), "src/parser_test.rs".to_string()];
+/// let types = ["chore", "feat", "fix", "test"];
+///
+/// assert_eq!(suggest_commit_type(&files, &[], &types), Some("test"));
+/// assert_eq!(suggest_commit_type(&["src/main.rs".to_string()], &[], &types), None);
+/// ```
+#[must_use]
+pub fn suggest_commit_type<'a>(
+    files: &[String],
+    rules: &[TypeRule],
+    commit_types: &[&'a str],
+) -> Option<&'a str> {
+    rules
+        .iter()
+        .cloned()
+        .chain(default_type_rules())
+        .filter(|rule| rule.matches(files))
+        .find_map(|rule| {
+            commit_types
+                .iter()
+                .find(|commit_type| **commit_type == rule.commit_type)
+                .copied()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_suggest_commit_type() {
+        let types = ["chore", "docs", "feat", "fix", "test"];
+
+        assert_eq!(
+            suggest_commit_type(&files(&["README.md", "docs/guide.md"]), &[], &types),
+            Some("docs")
+        );
+        assert_eq!(
+            suggest_commit_type(&files(&["Cargo.toml", "Cargo.lock"]), &[], &types),
+            Some("chore")
+        );
+        assert_eq!(
+            suggest_commit_type(&files(&["Cargo.toml", "src/lib.rs"]), &[], &types),
+            None
+        );
+        assert_eq!(suggest_commit_type(&[], &[], &types), None);
+
+        // Documentation only is not suggested when `docs` is not a commit type
+        assert_eq!(
+            suggest_commit_type(&files(&["README.md"]), &[], &["feat", "fix"]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_configured_rules_come_first() {
+        let rules = vec![
+            TypeRule::new("style", &["*.css"]),
+            TypeRule::new("docs", &["*.md"]),
+        ];
+        let types = ["docs", "style"];
+
+        assert_eq!(
+            suggest_commit_type(&files(&["web/app.css"]), &rules, &types),
+            Some("style")
+        );
+        assert_eq!(
+            suggest_commit_type(&files(&["README.md"]), &rules, &types),
+            Some("docs")
+        );
+    }
+}
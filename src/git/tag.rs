@@ -0,0 +1,266 @@
+//! Tag Operations
+//!
+//! Git tag-related functionality including annotated and signed tag creation,
+//! the tag message file edited before creating a tag, listing and pushing tags.
+
+use std::{
+    fmt::Write as _,
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+use log::debug;
+
+use crate::{
+    errors::{Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::{
+    commit::COMMIT_MESSAGE_DIR, exec::GitCommand, handle_output, remote::git_push,
+    repository::find_git_root,
+};
+
+/// Name of the tag message file, kept next to the default commit message file.
+pub const TAG_MESSAGE_FILE_NAME: &str = "tag_message.md";
+
+/// A tag with the subject of its annotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagInfo {
+    /// The tag name
+    pub name: String,
+    /// The first line of the tag message (the commit subject for lightweight tags)
+    pub subject: String,
+}
+
+/// Creates an annotated tag on the current HEAD.
+///
+/// # Arguments
+/// * `name` - The tag name (e.g. `v1.2.0`)
+/// * `message` - The tag annotation message
+/// * `sign` - Whether to create a GPG-signed tag (`-s`) instead of a plain annotated one (`-a`)
+/// * `dry_run` - If true, only plan the tag without creating it
+///
+/// # Returns
+/// * `Plan` - The tag action performed (or that would be, in dry-run mode)
+///
+/// # Errors
+/// * If the git tag command fails (e.g. the tag already exists)
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::git::tag::create_annotated_tag;
+///
+/// create_annotated_tag("v1.2.0", "Release v1.2.0", false, false)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn create_annotated_tag(name: &str, message: &str, sign: bool, dry_run: bool) -> Result<Plan> {
+    let tag_args = vec![
+        if sign { "-s" } else { "-a" }.to_string(),
+        "-m".to_string(),
+        message.to_string(),
+    ];
+
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Tag, name, tag_args.clone());
+
+    if dry_run {
+        return Ok(plan);
+    }
+
+    debug!("Creating tag {name}...");
+
+    let output = GitCommand::new()
+        .arg("tag")
+        .arg(name)
+        .args(&tag_args)
+        .output()?;
+
+    handle_output("tag", &output)?;
+
+    Ok(plan)
+}
+
+/// Path of the tag message file, `.git/rona/tag_message.md`.
+///
+/// # Errors
+/// * If not in a git repository
+pub fn tag_message_path() -> Result<PathBuf> {
+    Ok(find_git_root()?
+        .join(COMMIT_MESSAGE_DIR)
+        .join(TAG_MESSAGE_FILE_NAME))
+}
+
+/// Builds the initial message of a tag: the tag name as subject, then the subjects
+/// of the commits since the previous tag, followed by editing instructions.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::tag::build_tag_message;
+///
+/// let message = build_tag_message("v1.1.0", Some("v1.0.0"), &["Add parser".to_string()]);
+/// assert!(message.starts_with("v1.1.0\n\nChanges since v1.0.0:\n\n- Add parser\n"));
+/// ```
+#[must_use]
+pub fn build_tag_message(name: &str, previous_tag: Option<&str>, subjects: &[String]) -> String {
+    let mut message = format!("{name}\n");
+
+    if !subjects.is_empty() {
+        match previous_tag {
+            Some(previous) => {
+                let _ = write!(message, "\nChanges since {previous}:\n\n");
+            }
+            None => message.push_str("\nChanges:\n\n"),
+        }
+        for subject in subjects {
+            let _ = writeln!(message, "- {subject}");
+        }
+    }
+
+    let _ = write!(
+        message,
+        "\n# Write the message of tag {name}.\n# Lines starting with '#' are ignored, \
+         an empty message aborts the tag.\n"
+    );
+
+    message
+}
+
+/// Removes the comment lines of an edited tag message.
+///
+/// # Errors
+/// * If the message is empty once comments are removed
+pub fn parse_tag_message(content: &str) -> Result<String> {
+    let message = content
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let message = message.trim();
+
+    if message.is_empty() {
+        return Err(RonaError::InvalidInput(
+            "Empty tag message - tag not created".to_string(),
+        ));
+    }
+
+    Ok(message.to_string())
+}
+
+/// Writes the initial message of tag `name` to `path`, listing the commits since the previous tag.
+///
+/// # Returns
+/// * `String` - The message written
+///
+/// # Errors
+/// * If the commits cannot be listed
+/// * If the file cannot be written
+pub fn write_tag_message(path: &Path, name: &str) -> Result<String> {
+    let previous_tag = GitCommand::new()
+        .args(["describe", "--tags", "--abbrev=0"])
+        .stdout()
+        .ok()
+        .map(|tag| tag.trim().to_string());
+    let range = previous_tag.as_ref().map_or_else(
+        || "HEAD".to_string(),
+        |previous| format!("{previous}..HEAD"),
+    );
+    let subjects: Vec<String> = GitCommand::new()
+        .args(["log", "--format=%s", &range])
+        .stdout()
+        .unwrap_or_default()
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+
+    let message = build_tag_message(name, previous_tag.as_deref(), &subjects);
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(path, &message)?;
+
+    Ok(message)
+}
+
+/// Reads the edited tag message file.
+///
+/// # Errors
+/// * If the file cannot be read
+/// * If the message is empty once comments are removed
+pub fn read_tag_message(path: &Path) -> Result<String> {
+    parse_tag_message(&read_to_string(path)?)
+}
+
+/// Lists the tags, newest first, with the subject of their message.
+///
+/// # Arguments
+/// * `pattern` - Glob pattern the tag names must match (e.g. `v1.*`, `*` for all tags)
+///
+/// # Errors
+/// * If the git command fails
+pub fn list_tags(pattern: &str) -> Result<Vec<TagInfo>> {
+    Ok(GitCommand::new()
+        .args([
+            "tag",
+            "--list",
+            "--sort=-creatordate",
+            "--format=%(refname:short)%09%(contents:subject)",
+            pattern,
+        ])
+        .stdout()?
+        .lines()
+        .filter_map(|line| {
+            let (name, subject) = line.split_once('\t').unwrap_or((line, ""));
+            (!name.is_empty()).then(|| TagInfo {
+                name: name.to_string(),
+                subject: subject.to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Pushes a tag, or every tag, to a remote.
+///
+/// # Arguments
+/// * `remote` - The remote to push to
+/// * `name` - The tag to push, `None` for all tags (`--tags`)
+/// * `dry_run` - If true, only plan the push
+///
+/// # Errors
+/// * If the git push command fails
+pub fn push_tags(remote: &str, name: Option<&str>, dry_run: bool) -> Result<Plan> {
+    let refspec = name.map_or_else(|| "--tags".to_string(), |name| format!("refs/tags/{name}"));
+
+    git_push(&[remote.to_string(), refspec], dry_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_tag_message() {
+        let subjects = vec!["Add parser".to_string(), "Fix lexer".to_string()];
+
+        assert_eq!(
+            build_tag_message("v2.0.0", Some("v1.0.0"), &subjects),
+            "v2.0.0\n\nChanges since v1.0.0:\n\n- Add parser\n- Fix lexer\n\n\
+             # Write the message of tag v2.0.0.\n\
+             # Lines starting with '#' are ignored, an empty message aborts the tag.\n"
+        );
+        assert!(build_tag_message("v1.0.0", None, &subjects).contains("\nChanges:\n"));
+        assert!(build_tag_message("v1.0.0", None, &[]).starts_with("v1.0.0\n\n# Write"));
+    }
+
+    #[test]
+    fn test_parse_tag_message() {
+        assert_eq!(
+            parse_tag_message("v1.0.0\n\n- Add parser\n\n# Write the message\n").unwrap(),
+            "v1.0.0\n\n- Add parser"
+        );
+        assert!(parse_tag_message("# only comments\n\n").is_err());
+    }
+}
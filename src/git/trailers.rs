@@ -0,0 +1,322 @@
+//! Commit Trailers
+//!
+//! `Key: value` lines closing a commit message, such as `Signed-off-by` and
+//! `Co-authored-by`. Trailers are appended as the last paragraph of the message,
+//! joining an existing trailer block instead of duplicating its lines.
+//! The `BREAKING CHANGE` footer of conventional commits is a trailer too, the only
+//! key with a space.
+
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Result, RonaError};
+
+use super::exec::GitCommand;
+
+/// The key of the footer describing a breaking change.
+pub const BREAKING_CHANGE: &str = "BREAKING CHANGE";
+
+/// One value or a list of values of a configured trailer.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum TrailerValues {
+    /// A single value, e.g. `Reviewed-by = "Jane Doe <jane@example.com>"`
+    One(String),
+    /// Several values, e.g. `Co-authored-by = ["Jane <jane@a.b>", "John <john@a.b>"]`
+    Many(Vec<String>),
+}
+
+/// A `Key: value` commit trailer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trailer {
+    /// The trailer key (e.g. `Signed-off-by`)
+    pub key: String,
+    /// The trailer value (e.g. `Jane Doe <jane@example.com>`)
+    pub value: String,
+}
+
+impl Trailer {
+    /// Creates a trailer.
+    #[must_use]
+    pub fn new(key: &str, value: &str) -> Self {
+        Self {
+            key: key.trim().to_string(),
+            value: value.trim().to_string(),
+        }
+    }
+
+    /// Creates a `Co-authored-by` trailer from a `Name <email>` identity.
+    ///
+    /// # Errors
+    /// * If the identity is not in the `Name <email>` format
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::git::trailers::Trailer;
+    ///
+    /// let trailer = Trailer::co_author("Jane Doe <jane@example.com>")?;
+    /// assert_eq!(trailer.to_string(), "Co-authored-by: Jane Doe <jane@example.com>");
+    /// assert!(Trailer::co_author("jane@example.com").is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn co_author(identity: &str) -> Result<Self> {
+        if !is_identity(identity.trim()) {
+            return Err(RonaError::InvalidInput(format!(
+                "Invalid co-author '{identity}': expected 'Name <email>'"
+            )));
+        }
+
+        Ok(Self::new("Co-authored-by", identity))
+    }
+
+    /// Creates a `Signed-off-by` trailer with the `user.name` and `user.email` of the git configuration.
+    ///
+    /// # Errors
+    /// * If `user.name` or `user.email` is not configured
+    pub fn signoff() -> Result<Self> {
+        let read = |key: &str| -> Result<String> {
+            let output = GitCommand::new().args(["config", "--get", key]).output()?;
+            let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+            if output.status.success() && !value.is_empty() {
+                Ok(value)
+            } else {
+                Err(RonaError::InvalidInput(format!(
+                    "`--signoff` requires `{key}` to be set in the git configuration"
+                )))
+            }
+        };
+
+        let identity = format!("{} <{}>", read("user.name")?, read("user.email")?);
+        Ok(Self::new("Signed-off-by", &identity))
+    }
+
+    /// Creates a `BREAKING CHANGE` footer describing what breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::git::trailers::Trailer;
+    ///
+    /// let trailer = Trailer::breaking_change("`--json` prints one object per line");
+    /// assert_eq!(trailer.to_string(), "BREAKING CHANGE: `--json` prints one object per line");
+    /// assert!(trailer.is_breaking_change());
+    /// ```
+    #[must_use]
+    pub fn breaking_change(description: &str) -> Self {
+        Self::new(BREAKING_CHANGE, description)
+    }
+
+    /// Whether the trailer is a `BREAKING CHANGE` footer.
+    #[must_use]
+    pub fn is_breaking_change(&self) -> bool {
+        self.key == BREAKING_CHANGE
+    }
+
+    /// Builds the trailers of the `trailers` configuration section, in key order.
+    ///
+    /// Configuration keys are read lowercased, so the first letter of each key is
+    /// capitalized back (`reviewed-by` becomes `Reviewed-by`).
+    #[must_use]
+    pub fn from_config(trailers: &BTreeMap<String, TrailerValues>) -> Vec<Self> {
+        trailers
+            .iter()
+            .map(|(key, values)| (capitalize(key), values))
+            .flat_map(|(key, values)| match values {
+                TrailerValues::One(value) => vec![Self::new(&key, value)],
+                TrailerValues::Many(values) => {
+                    values.iter().map(|value| Self::new(&key, value)).collect()
+                }
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Trailer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.value)
+    }
+}
+
+/// Uppercases the first letter of a trailer key.
+fn capitalize(key: &str) -> String {
+    let key = key.trim();
+    let mut chars = key.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// Whether a value is a `Name <email>` identity.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::trailers::is_identity;
+///
+/// assert!(is_identity("Jane Doe <jane@example.com>"));
+/// assert!(!is_identity("Jane Doe <jane>"));
+/// ```
+#[must_use]
+pub fn is_identity(value: &str) -> bool {
+    let Some((name, email)) = value.split_once(" <") else {
+        return false;
+    };
+    let Some(email) = email.strip_suffix('>') else {
+        return false;
+    };
+
+    !name.trim().is_empty()
+        && !name.contains(['<', '>'])
+        && email.split_once('@').is_some_and(|(user, domain)| {
+            !user.is_empty() && !domain.is_empty() && !domain.contains(['<', '>', '@', ' '])
+        })
+}
+
+/// Whether a line is a `Key: value` trailer.
+fn is_trailer_line(line: &str) -> bool {
+    line.split_once(": ").is_some_and(|(key, value)| {
+        (key == BREAKING_CHANGE
+            || (!key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')))
+            && !value.trim().is_empty()
+    })
+}
+
+/// Appends trailers to a commit message.
+///
+/// Trailers already present in the message are skipped. When the last paragraph
+/// (other than the subject) is a trailer block, the new trailers join it; otherwise
+/// they are separated from the message by a blank line. The message is returned
+/// unchanged when there is nothing to add.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::trailers::{Trailer, append_trailers};
+///
+/// let message = append_trailers(
+///     "(feat on main) Add parser\n\n- `a.rs`:\n\n\tAdd parser\n",
+///     &[Trailer::new("Signed-off-by", "Jane <jane@example.com>")],
+/// );
+/// assert_eq!(
+///     message,
+///     "(feat on main) Add parser\n\n- `a.rs`:\n\n\tAdd parser\n\nSigned-off-by: Jane <jane@example.com>\n"
+/// );
+/// ```
+#[must_use]
+pub fn append_trailers(message: &str, trailers: &[Trailer]) -> String {
+    let body = message.trim_end();
+    let mut new_lines: Vec<String> = Vec::new();
+
+    for trailer in trailers {
+        let line = trailer.to_string();
+        if !body.lines().any(|existing| existing.trim() == line) && !new_lines.contains(&line) {
+            new_lines.push(line);
+        }
+    }
+
+    if new_lines.is_empty() {
+        return message.to_string();
+    }
+
+    let paragraphs: Vec<&str> = body.split("\n\n").collect();
+    let ends_with_trailers = paragraphs.len() > 1
+        && paragraphs
+            .last()
+            .is_some_and(|paragraph| paragraph.trim().lines().all(is_trailer_line));
+
+    let separator = if body.is_empty() {
+        ""
+    } else if ends_with_trailers {
+        "\n"
+    } else {
+        "\n\n"
+    };
+
+    format!("{body}{separator}{}\n", new_lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signoff() -> Trailer {
+        Trailer::new("Signed-off-by", "Jane Doe <jane@example.com>")
+    }
+
+    #[test]
+    fn test_append_trailers_after_footer() {
+        let message = "[3] (fix on main) Fix parser\n\n- `a.rs`:\n\n\tFix\n\nCloses #4\n";
+
+        assert_eq!(
+            append_trailers(message, &[signoff()]),
+            "[3] (fix on main) Fix parser\n\n- `a.rs`:\n\n\tFix\n\nCloses #4\n\n\
+             Signed-off-by: Jane Doe <jane@example.com>\n"
+        );
+    }
+
+    #[test]
+    fn test_append_trailers_joins_block() {
+        let message = "Fix parser\n\nCo-authored-by: John <john@example.com>\n";
+
+        assert_eq!(
+            append_trailers(message, &[signoff()]),
+            "Fix parser\n\nCo-authored-by: John <john@example.com>\n\
+             Signed-off-by: Jane Doe <jane@example.com>\n"
+        );
+    }
+
+    #[test]
+    fn test_append_trailers_skips_existing() {
+        let message = "Fix parser\n\nSigned-off-by: Jane Doe <jane@example.com>\n";
+
+        assert_eq!(append_trailers(message, &[signoff(), signoff()]), message);
+        assert_eq!(
+            append_trailers("Fix: parser", &[signoff()]),
+            "Fix: parser\n\nSigned-off-by: Jane Doe <jane@example.com>\n"
+        );
+    }
+
+    #[test]
+    fn test_append_trailers_after_breaking_change() {
+        let message = "Fix parser\n\nBREAKING CHANGE: tokens are borrowed\n";
+
+        assert_eq!(
+            append_trailers(message, &[signoff()]),
+            "Fix parser\n\nBREAKING CHANGE: tokens are borrowed\n\
+             Signed-off-by: Jane Doe <jane@example.com>\n"
+        );
+    }
+
+    #[test]
+    fn test_co_author() {
+        assert!(Trailer::co_author("Jane Doe <jane@example.com>").is_ok());
+        assert!(Trailer::co_author("<jane@example.com>").is_err());
+        assert!(Trailer::co_author("Jane <jane>").is_err());
+    }
+
+    #[test]
+    fn test_from_config() {
+        let config: BTreeMap<String, TrailerValues> = toml::from_str(
+            "reviewed-by = \"Ann <ann@example.com>\"\n\
+             Co-authored-by = [\"Bob <bob@example.com>\", \"Eve <eve@example.com>\"]\n",
+        )
+        .unwrap();
+
+        let lines: Vec<String> = Trailer::from_config(&config)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "Co-authored-by: Bob <bob@example.com>",
+                "Co-authored-by: Eve <eve@example.com>",
+                "Reviewed-by: Ann <ann@example.com>",
+            ]
+        );
+    }
+}
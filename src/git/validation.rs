@@ -0,0 +1,417 @@
+//! Commit Message Validation
+//!
+//! Checks run on a commit message before committing: subject length, non-ASCII
+//! subject characters, trailing whitespace, file bullets left empty after generation
+//! and common typos.
+
+use std::{
+    fmt,
+    io::{IsTerminal, stdin},
+};
+
+use inquire::Confirm;
+use log::{error, warn};
+
+use crate::{
+    errors::{Result, RonaError},
+    i18n::tr,
+};
+
+use super::emoji::non_ascii_characters;
+
+/// Maximum subject length used when none is configured.
+pub const DEFAULT_MAX_SUBJECT_LENGTH: usize = 72;
+
+/// Common misspellings and their correction, matched as whole lowercase words.
+const COMMON_TYPOS: &[(&str, &str)] = &[
+    ("teh", "the"),
+    ("adn", "and"),
+    ("taht", "that"),
+    ("wiht", "with"),
+    ("fucntion", "function"),
+    ("funtion", "function"),
+    ("retrun", "return"),
+    ("recieve", "receive"),
+    ("seperate", "separate"),
+    ("definately", "definitely"),
+    ("occured", "occurred"),
+    ("occurence", "occurrence"),
+    ("untill", "until"),
+    ("accross", "across"),
+    ("adress", "address"),
+    ("begining", "beginning"),
+    ("enviroment", "environment"),
+    ("existant", "existent"),
+    ("paramter", "parameter"),
+    ("lenght", "length"),
+    ("widht", "width"),
+    ("heigth", "height"),
+    ("dependancy", "dependency"),
+    ("refered", "referred"),
+    ("succesful", "successful"),
+    ("sucessful", "successful"),
+    ("neccessary", "necessary"),
+    ("necesary", "necessary"),
+    ("initialise", "initialize"),
+    ("udpate", "update"),
+    ("upadte", "update"),
+    ("comit", "commit"),
+    ("commmit", "commit"),
+];
+
+/// Rules applied when validating a commit message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationRules {
+    /// Maximum number of characters allowed in the subject line
+    pub max_subject_length: usize,
+    /// Whether the subject line must be ASCII (the `ascii` emoji policy)
+    pub ascii_subject: bool,
+}
+
+impl Default for ValidationRules {
+    fn default() -> Self {
+        Self {
+            max_subject_length: DEFAULT_MAX_SUBJECT_LENGTH,
+            ascii_subject: false,
+        }
+    }
+}
+
+/// A problem found in a commit message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageIssue {
+    /// The message has no subject line
+    EmptySubject,
+    /// The subject line exceeds the configured length
+    SubjectTooLong { length: usize, max: usize },
+    /// The subject line has characters outside ASCII (emoji, accents...)
+    NonAsciiSubject { characters: String },
+    /// A line ends with spaces or tabs (1-based line number)
+    TrailingWhitespace { line: usize },
+    /// A generated file bullet was left without description
+    EmptyBullet { file: String },
+    /// A common misspelling was found
+    Typo {
+        word: String,
+        suggestion: &'static str,
+    },
+}
+
+impl fmt::Display for MessageIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptySubject => write!(f, "the subject line is empty"),
+            Self::SubjectTooLong { length, max } => {
+                write!(
+                    f,
+                    "the subject line is {length} characters long (max {max})"
+                )
+            }
+            Self::NonAsciiSubject { characters } => {
+                write!(f, "the subject line has non-ASCII characters: {characters}")
+            }
+            Self::TrailingWhitespace { line } => write!(f, "line {line} has trailing whitespace"),
+            Self::EmptyBullet { file } => write!(f, "`{file}` has no description"),
+            Self::Typo { word, suggestion } => {
+                write!(f, "possible typo: '{word}' (did you mean '{suggestion}'?)")
+            }
+        }
+    }
+}
+
+/// Validates a commit message against the given rules.
+///
+/// # Arguments
+/// * `message` - The commit message to validate
+/// * `rules` - The rules to apply
+///
+/// # Returns
+/// * `Vec<MessageIssue>` - The issues found, in the order they appear
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::validation::{MessageIssue, ValidationRules, validate_commit_message};
+///
+/// let issues = validate_commit_message("[1] (feat on main)\n\n- `a.rs`:\n\n\t\n", ValidationRules::default());
+/// assert_eq!(issues, vec![MessageIssue::EmptyBullet { file: "a.rs".to_string() }]);
+/// ```
+#[must_use]
+pub fn validate_commit_message(message: &str, rules: ValidationRules) -> Vec<MessageIssue> {
+    let mut issues = Vec::new();
+    let lines: Vec<&str> = message.lines().collect();
+
+    match lines.first().map(|line| line.trim()) {
+        None | Some("") => issues.push(MessageIssue::EmptySubject),
+        Some(subject) => {
+            let length = subject.chars().count();
+            if length > rules.max_subject_length {
+                issues.push(MessageIssue::SubjectTooLong {
+                    length,
+                    max: rules.max_subject_length,
+                });
+            }
+
+            let characters = non_ascii_characters(subject);
+            if rules.ascii_subject && !characters.is_empty() {
+                issues.push(MessageIssue::NonAsciiSubject { characters });
+            }
+        }
+    }
+
+    for (index, line) in lines.iter().enumerate() {
+        if !line.trim().is_empty() && line.trim_end() != *line {
+            issues.push(MessageIssue::TrailingWhitespace { line: index + 1 });
+        }
+    }
+
+    issues.extend(find_empty_bullets(&lines));
+    issues.extend(find_typos(message));
+
+    issues
+}
+
+/// Finds file bullets (`` - `file`: ``) with no description before the next bullet.
+fn find_empty_bullets(lines: &[&str]) -> Vec<MessageIssue> {
+    let mut issues = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let Some(file) = line
+            .trim_end()
+            .strip_prefix("- `")
+            .and_then(|rest| rest.strip_suffix("`:"))
+        else {
+            continue;
+        };
+
+        let has_description = lines[index + 1..]
+            .iter()
+            .take_while(|next| !next.starts_with("- `"))
+            .any(|next| !next.trim().is_empty());
+
+        if !has_description {
+            issues.push(MessageIssue::EmptyBullet {
+                file: file.to_string(),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Finds common misspellings outside of inline code spans.
+fn find_typos(message: &str) -> Vec<MessageIssue> {
+    let mut issues = Vec::new();
+
+    // Even-indexed segments are outside backticks
+    for prose in message.split('`').step_by(2) {
+        for word in prose.split(|c: char| !c.is_alphanumeric() && c != '\'') {
+            let lowercase = word.to_lowercase();
+
+            if let Some((_, suggestion)) = COMMON_TYPOS.iter().find(|(typo, _)| *typo == lowercase)
+            {
+                issues.push(MessageIssue::Typo {
+                    word: word.to_string(),
+                    suggestion,
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// The line under which `git commit --verbose` appends the diff.
+const SCISSORS_LINE: &str = "# ------------------------ >8 ------------------------";
+
+/// Removes what git strips from a message file before committing: `#` comment lines
+/// and everything from the scissors line of `git commit --verbose`.
+///
+/// # Examples
+///
+/// ```
+/// use rona::git::validation::strip_comments;
+///
+/// let message = "Add parser\n# Please enter the commit message\n\n- `src/parser.rs`\n";
+/// assert_eq!(strip_comments(message), "Add parser\n\n- `src/parser.rs`\n");
+/// ```
+#[must_use]
+pub fn strip_comments(message: &str) -> String {
+    message
+        .lines()
+        .take_while(|line| *line != SCISSORS_LINE)
+        .filter(|line| !line.starts_with('#'))
+        .fold(String::new(), |mut stripped, line| {
+            stripped.push_str(line);
+            stripped.push('\n');
+            stripped
+        })
+}
+
+/// Reports the issues of a commit message, failing when there are any.
+///
+/// Used by `rona lint`, typically from the `commit-msg` hook, where nobody is
+/// prompted and an invalid message must abort the commit.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the message is invalid
+pub fn lint_commit_message(message: &str, rules: ValidationRules) -> Result<()> {
+    let issues = validate_commit_message(message, rules);
+
+    if issues.is_empty() {
+        return Ok(());
+    }
+
+    error!("🚨 The commit message has {} issue(s):", issues.len());
+    for issue in &issues {
+        error!("   - {issue}");
+    }
+
+    Err(RonaError::InvalidInput(
+        "Commit message failed validation".to_string(),
+    ))
+}
+
+/// Reports the issues of a commit message and asks whether to commit anyway.
+///
+/// In dry-run mode the issues are only reported. When stdin is not a terminal,
+/// nobody can answer the prompt, so an invalid message is rejected.
+///
+/// # Arguments
+/// * `message` - The commit message to validate
+/// * `rules` - The rules to apply
+/// * `dry_run` - Whether the commit is only being planned
+///
+/// # Errors
+/// * `RonaError::UserCancelled` - If the user declines to commit
+/// * `RonaError::InvalidInput` - If the message is invalid and no one can be prompted
+pub fn confirm_commit_message(message: &str, rules: ValidationRules, dry_run: bool) -> Result<()> {
+    let issues = validate_commit_message(message, rules);
+
+    if issues.is_empty() {
+        return Ok(());
+    }
+
+    warn!("⚠️  The commit message has {} issue(s):", issues.len());
+    for issue in &issues {
+        warn!("   - {issue}");
+    }
+
+    if dry_run {
+        return Ok(());
+    }
+
+    if !stdin().is_terminal() {
+        return Err(RonaError::InvalidInput(
+            "Commit message failed validation".to_string(),
+        ));
+    }
+
+    let commit_anyway = Confirm::new(&tr("prompt.commit_anyway"))
+        .with_default(false)
+        .prompt()?;
+
+    if commit_anyway {
+        Ok(())
+    } else {
+        Err(RonaError::UserCancelled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_message() {
+        let message = "[3] (feat on main) Add parser\n\n- `src/parser.rs`:\n\n\tAdd the parser\n";
+        assert!(validate_commit_message(message, ValidationRules::default()).is_empty());
+    }
+
+    #[test]
+    fn test_strip_comments_scissors() {
+        let message = "Add parser\n\n# Changes to be committed:\n\
+                       # ------------------------ >8 ------------------------\n\
+                       diff --git a/src/parser.rs b/src/parser.rs\n";
+
+        assert_eq!(strip_comments(message), "Add parser\n\n");
+    }
+
+    #[test]
+    fn test_subject_checks() {
+        let rules = ValidationRules {
+            max_subject_length: 10,
+            ..ValidationRules::default()
+        };
+
+        assert_eq!(
+            validate_commit_message("A subject that is too long", rules),
+            vec![MessageIssue::SubjectTooLong {
+                length: 26,
+                max: 10
+            }]
+        );
+        assert_eq!(
+            validate_commit_message("", rules),
+            vec![MessageIssue::EmptySubject]
+        );
+    }
+
+    #[test]
+    fn test_ascii_subject() {
+        let rules = ValidationRules {
+            ascii_subject: true,
+            ..ValidationRules::default()
+        };
+        let message = "(feat on main) ✨ Add the café menu
+
+- `menü.rs`:
+
+	Done
+";
+
+        assert_eq!(
+            validate_commit_message(message, rules),
+            vec![MessageIssue::NonAsciiSubject {
+                characters: "✨é".to_string()
+            }]
+        );
+        assert!(validate_commit_message(message, ValidationRules::default()).is_empty());
+    }
+
+    #[test]
+    fn test_trailing_whitespace_and_empty_bullets() {
+        let message = "Subject \n\n- `a.rs`:\n\n\t\n- `b.rs`:\n\n\tDone\n- `c.rs`: deleted\n";
+        let issues = validate_commit_message(message, ValidationRules::default());
+
+        assert_eq!(
+            issues,
+            vec![
+                MessageIssue::TrailingWhitespace { line: 1 },
+                MessageIssue::EmptyBullet {
+                    file: "a.rs".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_typos_outside_code_spans() {
+        let message = "Fix teh parser\n\n- `teh.rs`:\n\n\tRecieve input\n";
+        let issues = validate_commit_message(message, ValidationRules::default());
+
+        assert_eq!(
+            issues,
+            vec![
+                MessageIssue::Typo {
+                    word: "teh".to_string(),
+                    suggestion: "the"
+                },
+                MessageIssue::Typo {
+                    word: "Recieve".to_string(),
+                    suggestion: "receive"
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,305 @@
+//! Work-In-Progress Checkpoints
+//!
+//! Quick `wip: <timestamp>` checkpoint commits of everything changed, leaving
+//! `commit_message.md` untouched, and popping the last checkpoint back into the
+//! working tree with a soft reset. `rona watch` records checkpoints of the working
+//! tree on a separate branch instead, without touching HEAD or the index.
+
+use std::{fs::create_dir_all, path::Path};
+
+use chrono::{DateTime, Local};
+use glob::Pattern;
+use log::debug;
+
+use crate::{
+    errors::{Result, RonaError},
+    plan::{ActionKind, Plan},
+};
+
+use super::{
+    commit::{COMMIT_MESSAGE_DIR, CommitSigning, git_commit_with_message},
+    exec::GitCommand,
+    repository::{find_git_root, get_top_level_path, has_staged_changes},
+    staging::{git_add_with_exclude_patterns, literal_pathspec},
+};
+
+/// Subject prefix of checkpoint commits.
+pub const WIP_PREFIX: &str = "wip: ";
+
+/// Name of the index the branch checkpoints are staged in, in the `rona` directory of the git directory.
+const SNAPSHOT_INDEX_NAME: &str = "wip-index";
+
+/// Maximum number of paths passed to a single `git add` of a branch checkpoint.
+const SNAPSHOT_ADD_BATCH_SIZE: usize = 100;
+
+/// Builds the message of a checkpoint commit created at `time`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Local, TimeZone};
+/// use rona::git::wip::wip_message;
+///
+/// let time = Local.with_ymd_and_hms(2024, 5, 1, 9, 30, 0).unwrap();
+/// assert_eq!(wip_message(time), "wip: 2024-05-01 09:30:00");
+/// ```
+#[must_use]
+pub fn wip_message(time: DateTime<Local>) -> String {
+    format!("{WIP_PREFIX}{}", time.format("%Y-%m-%d %H:%M:%S"))
+}
+
+/// Whether a commit subject is the one of a checkpoint commit.
+#[must_use]
+pub fn is_wip_subject(subject: &str) -> bool {
+    subject.trim_start().starts_with(WIP_PREFIX)
+}
+
+/// Runs a git command on the checkpoint index (`GIT_INDEX_FILE`) and returns its trimmed stdout.
+fn git_snapshot_output(top_level: &Path, index: &Path, args: &[&str]) -> Result<String> {
+    Ok(GitCommand::new()
+        .current_dir(top_level)
+        .env("GIT_INDEX_FILE", index)
+        .args(args)
+        .stdout()?
+        .trim()
+        .to_string())
+}
+
+/// The branches checked out in the worktrees of the repository (`git worktree list`).
+///
+/// # Errors
+/// * If `git worktree list` fails
+fn checked_out_branches() -> Result<Vec<String>> {
+    Ok(GitCommand::new()
+        .args(["worktree", "list", "--porcelain"])
+        .stdout()?
+        .lines()
+        .filter_map(|line| line.strip_prefix("branch refs/heads/"))
+        .map(ToString::to_string)
+        .collect())
+}
+
+/// Refuses a checkpoint branch checked out in a worktree: recording a checkpoint on it
+/// would move HEAD, leaving the index behind.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the branch is checked out
+/// * If `git worktree list` fails
+pub fn check_wip_branch(branch: &str) -> Result<()> {
+    if checked_out_branches()?
+        .iter()
+        .any(|checked_out| checked_out == branch)
+    {
+        return Err(RonaError::InvalidInput(format!(
+            "{branch} is checked out - checkpoints go on a branch of their own (e.g. wip/{branch}), leaving HEAD and the index untouched"
+        )));
+    }
+    Ok(())
+}
+
+/// Records the working tree, except the excluded patterns, as a checkpoint commit on
+/// `branch`, leaving HEAD, the index and the working tree untouched.
+///
+/// The files are staged in a separate index, so checkpoints never mix with what is
+/// staged for the next commit. The first checkpoint of `branch` starts from HEAD,
+/// the following ones from the previous checkpoint.
+///
+/// # Arguments
+/// * `branch` - The local branch the checkpoints are recorded on (e.g. `wip/main`)
+/// * `exclude_patterns` - Patterns of files left out of the checkpoint
+///
+/// # Returns
+/// * The short hash of the checkpoint, `None` when nothing changed since the previous one
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the branch is checked out (see [`check_wip_branch`])
+/// * If reading the status fails
+/// * If a git command fails
+pub fn git_wip_snapshot(branch: &str, exclude_patterns: &[Pattern]) -> Result<Option<String>> {
+    check_wip_branch(branch)?;
+
+    let staged = git_add_with_exclude_patterns(exclude_patterns, true)?;
+    let pathspecs: Vec<String> = [
+        ActionKind::Stage,
+        ActionKind::StageSubmodule,
+        ActionKind::Delete,
+    ]
+    .into_iter()
+    .flat_map(|kind| staged.targets(kind))
+    .map(literal_pathspec)
+    .collect();
+
+    let index = find_git_root()?
+        .join(COMMIT_MESSAGE_DIR)
+        .join(SNAPSHOT_INDEX_NAME);
+    if let Some(parent) = index.parent() {
+        create_dir_all(parent)?;
+    }
+    let top_level = get_top_level_path()?;
+    let git = |args: &[&str]| git_snapshot_output(&top_level, &index, args);
+
+    let head = git(&["rev-parse", "--verify", "--quiet", "HEAD"]).ok();
+    match &head {
+        Some(head) => git(&["read-tree", head])?,
+        None => git(&["read-tree", "--empty"])?,
+    };
+    for batch in pathspecs.chunks(SNAPSHOT_ADD_BATCH_SIZE) {
+        let mut args = vec!["add", "--"];
+        args.extend(batch.iter().map(String::as_str));
+        git(&args)?;
+    }
+    let tree = git(&["write-tree"])?;
+
+    let branch_ref = format!("refs/heads/{branch}");
+    let previous = git(&["rev-parse", "--verify", "--quiet", &branch_ref]).ok();
+    let parent = previous.clone().or(head);
+
+    if let Some(parent) = &parent
+        && git(&["rev-parse", &format!("{parent}^{{tree}}")])? == tree
+    {
+        return Ok(None);
+    }
+
+    debug!("Recording a checkpoint on {branch}...");
+    let message = wip_message(Local::now());
+    let mut args = vec!["commit-tree", tree.as_str(), "-m", message.as_str()];
+    if let Some(parent) = &parent {
+        args.extend(["-p", parent.as_str()]);
+    }
+    let commit = git(&args)?;
+    // The branch only moves from the checkpoint it was read at (or is created)
+    git(&[
+        "update-ref",
+        &branch_ref,
+        &commit,
+        previous.as_deref().unwrap_or_default(),
+    ])?;
+
+    Ok(Some(commit.chars().take(7).collect()))
+}
+
+/// Stages every change except the excluded patterns and commits it as a checkpoint.
+///
+/// # Arguments
+/// * `exclude_patterns` - Patterns of files left out of the checkpoint
+/// * `signing` - How the commit is signed
+/// * `dry_run` - If true, only plan the checkpoint without staging or committing
+///
+/// # Returns
+/// * `Plan` - The files staged and the commit created (or that would be, in dry-run mode)
+///
+/// # Errors
+/// * If staging the files fails
+/// * If there is nothing to commit once the files are staged
+/// * If the git commit command fails
+pub fn git_wip(
+    exclude_patterns: &[Pattern],
+    signing: &CommitSigning,
+    dry_run: bool,
+) -> Result<Plan> {
+    let mut plan = git_add_with_exclude_patterns(exclude_patterns, dry_run)?;
+
+    if !dry_run && !has_staged_changes()? {
+        return Err(RonaError::InvalidInput(
+            "Nothing to checkpoint - no changes were staged".to_string(),
+        ));
+    }
+
+    debug!("Creating a checkpoint commit...");
+    plan.extend(git_commit_with_message(
+        &wip_message(Local::now()),
+        &[],
+        signing,
+        dry_run,
+    )?);
+
+    Ok(plan)
+}
+
+/// Soft-resets the last commit if it is a checkpoint, its changes returning to the index.
+///
+/// # Arguments
+/// * `dry_run` - If true, only plan the reset
+///
+/// # Returns
+/// * `Plan` - The reset performed (or that would be, in dry-run mode)
+///
+/// # Errors
+/// * If the last commit is not a checkpoint
+/// * If the checkpoint is the first commit of the repository
+/// * If a git command fails
+pub fn git_wip_pop(dry_run: bool) -> Result<Plan> {
+    let subject = GitCommand::new()
+        .args(["log", "-1", "--format=%s"])
+        .stdout()?
+        .trim()
+        .to_string();
+
+    if !is_wip_subject(&subject) {
+        return Err(RonaError::InvalidInput(format!(
+            "The last commit is not a checkpoint: '{subject}'"
+        )));
+    }
+
+    if GitCommand::new()
+        .args(["rev-parse", "--verify", "--quiet", "HEAD~1"])
+        .stdout()
+        .is_err()
+    {
+        return Err(RonaError::InvalidInput(
+            "Cannot pop the first commit of the repository".to_string(),
+        ));
+    }
+
+    let mut plan = Plan::new();
+    plan.push(ActionKind::Reset, "HEAD~1", vec!["--soft".to_string()]);
+
+    if !dry_run {
+        debug!("Popping checkpoint '{subject}'...");
+        GitCommand::new()
+            .args(["reset", "--soft", "HEAD~1"])
+            .stdout()?;
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{git::exec::with_runner, testing::MockRunner};
+    use chrono::TimeZone;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_wip_message() {
+        let time = Local.with_ymd_and_hms(2025, 12, 31, 23, 59, 1).unwrap();
+        assert_eq!(wip_message(time), "wip: 2025-12-31 23:59:01");
+    }
+
+    #[test]
+    fn test_check_wip_branch() {
+        let mock = Arc::new(MockRunner::new());
+        mock.respond(
+            &["worktree", "list", "--porcelain"],
+            "worktree /repo\nHEAD 1a2b3c\nbranch refs/heads/main\n\n\
+             worktree /repo-hotfix\nHEAD 4d5e6f\nbranch refs/heads/hotfix\n",
+        );
+
+        with_runner(mock, || {
+            assert!(check_wip_branch("wip/main").is_ok());
+            assert!(matches!(
+                check_wip_branch("main"),
+                Err(RonaError::InvalidInput(_))
+            ));
+            assert!(check_wip_branch("hotfix").is_err());
+        });
+    }
+
+    #[test]
+    fn test_is_wip_subject() {
+        assert!(is_wip_subject("wip: 2025-12-31 23:59:01"));
+        assert!(!is_wip_subject("[3] (feat on main) wip: parser"));
+        assert!(!is_wip_subject("wipe the cache"));
+    }
+}
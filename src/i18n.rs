@@ -0,0 +1,247 @@
+//! Localization Module for Rona
+//!
+//! User-facing strings (prompts, dry-run summaries and the `utils` print helpers) are
+//! looked up by key (e.g. `plan.commit`) in a TOML message catalog per language,
+//! embedded in the binary from `src/locales`.
+//!
+//! The language comes from the `language` configuration option, then from the
+//! `LC_ALL`, `LC_MESSAGES` and `LANG` environment variables. English is the default,
+//! and the fallback for the keys missing from another catalog.
+//!
+//! # Examples
+//!
+//! ```
+//! use rona::i18n::{tr, tr_args};
+//!
+//! assert_eq!(tr("plan.nothing_to_do"), "Nothing to do");
+//! assert_eq!(tr_args("plan.stage", &[("count", &2)]), "Would add 2 files:");
+//! ```
+
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    sync::{
+        OnceLock,
+        atomic::{AtomicU8, Ordering},
+    },
+};
+
+use log::warn;
+
+/// Environment variables naming the locale, by priority.
+const LOCALE_VARIABLES: [&str; 3] = ["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// Languages with a message catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Language {
+    #[default]
+    English,
+    French,
+}
+
+impl Language {
+    /// Every language with a catalog.
+    pub const ALL: [Self; 2] = [Self::English, Self::French];
+
+    /// The ISO 639-1 code of the language (e.g. `fr`).
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::French => "fr",
+        }
+    }
+
+    /// Parses a language code or a locale (e.g. `fr`, `fr-CA` or `fr_FR.UTF-8`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::i18n::Language;
+    ///
+    /// assert_eq!(Language::from_code("fr_FR.UTF-8"), Some(Language::French));
+    /// assert_eq!(Language::from_code("en"), Some(Language::English));
+    /// assert_eq!(Language::from_code("C"), None);
+    /// ```
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.split(['_', '-', '.', '@']).next().unwrap_or_default();
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.code().eq_ignore_ascii_case(language))
+    }
+
+    /// Returns the configured language, or the one of the locale environment variables.
+    ///
+    /// An unknown configured language is reported and ignored.
+    #[must_use]
+    pub fn detect(configured: Option<&str>) -> Self {
+        if let Some(configured) = configured {
+            match Self::from_code(configured) {
+                Some(language) => return language,
+                None => warn!(
+                    "⚠️  Unknown language '{configured}', expected one of: {}",
+                    Self::ALL.map(Self::code).join(", ")
+                ),
+            }
+        }
+
+        LOCALE_VARIABLES
+            .iter()
+            .filter_map(|variable| env::var(variable).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|locale| Self::from_code(&locale))
+            .unwrap_or_default()
+    }
+
+    /// The embedded TOML catalog of the language.
+    const fn source(self) -> &'static str {
+        match self {
+            Self::English => include_str!("locales/en.toml"),
+            Self::French => include_str!("locales/fr.toml"),
+        }
+    }
+
+    /// The messages of the language, by key.
+    fn catalog(self) -> &'static HashMap<String, String> {
+        static ENGLISH: OnceLock<HashMap<String, String>> = OnceLock::new();
+        static FRENCH: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+        let cell = match self {
+            Self::English => &ENGLISH,
+            Self::French => &FRENCH,
+        };
+        cell.get_or_init(|| parse_catalog(self.source()))
+    }
+}
+
+/// The language of the messages, as an index in [`Language::ALL`].
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the language of the messages.
+pub fn set_language(language: Language) {
+    let index = Language::ALL
+        .iter()
+        .position(|candidate| *candidate == language)
+        .unwrap_or_default();
+    LANGUAGE.store(u8::try_from(index).unwrap_or_default(), Ordering::Relaxed);
+}
+
+/// The language of the messages (English unless [`set_language`] was called).
+#[must_use]
+pub fn language() -> Language {
+    Language::ALL
+        .get(usize::from(LANGUAGE.load(Ordering::Relaxed)))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Flattens a TOML catalog into dotted keys (`[plan] commit = ".."` as `plan.commit`).
+///
+/// An invalid catalog is empty, every message then falling back to English.
+fn parse_catalog(source: &str) -> HashMap<String, String> {
+    fn flatten(prefix: &str, table: &toml::Table, messages: &mut HashMap<String, String>) {
+        for (key, value) in table {
+            let key = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+
+            match value {
+                toml::Value::String(message) => {
+                    messages.insert(key, message.clone());
+                }
+                toml::Value::Table(table) => flatten(&key, table, messages),
+                _ => {}
+            }
+        }
+    }
+
+    let mut messages = HashMap::new();
+    if let Ok(table) = source.parse::<toml::Table>() {
+        flatten("", &table, &mut messages);
+    }
+    messages
+}
+
+/// Returns the message of the key in the current language.
+///
+/// Keys missing from the catalog fall back to English, then to the key itself.
+#[must_use]
+pub fn tr(key: &str) -> String {
+    language()
+        .catalog()
+        .get(key)
+        .or_else(|| Language::English.catalog().get(key))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Returns the message of the key in the current language, its `{name}` placeholders
+/// replaced by the values of the arguments.
+#[must_use]
+pub fn tr_args(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter().fold(tr(key), |message, (name, value)| {
+        message.replace(&format!("{{{name}}}"), &value.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The placeholders of a message, sorted.
+    fn placeholders(message: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = message
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn test_catalogs_match_english() {
+        let english = Language::English.catalog();
+        assert!(english.contains_key("plan.commit"));
+        assert!(english.contains_key("utils.age.hours"));
+
+        for language in Language::ALL {
+            let catalog = language.catalog();
+            for (key, message) in english {
+                let translated = catalog
+                    .get(key)
+                    .unwrap_or_else(|| panic!("{key} missing from {}", language.code()));
+                assert_eq!(
+                    placeholders(translated),
+                    placeholders(message),
+                    "placeholders of {key} in {}",
+                    language.code()
+                );
+            }
+            assert_eq!(catalog.len(), english.len(), "{}", language.code());
+        }
+    }
+
+    #[test]
+    fn test_language_from_code() {
+        assert_eq!(Language::from_code("fr"), Some(Language::French));
+        assert_eq!(Language::from_code("FR-ca"), Some(Language::French));
+        assert_eq!(Language::from_code("en_US.UTF-8"), Some(Language::English));
+        assert_eq!(Language::from_code("de_DE"), None);
+        assert_eq!(Language::detect(Some("fr")), Language::French);
+    }
+
+    #[test]
+    fn test_tr_args() {
+        assert_eq!(
+            tr_args("plan.push", &[("target", &"origin/main")]),
+            "Would push to origin/main"
+        );
+        assert_eq!(tr("missing.key"), "missing.key");
+    }
+}
@@ -0,0 +1,72 @@
+//! # Rona Library
+//!
+//! The staging, commit message generation, validation and configuration logic behind
+//! the `rona` command-line tool, for tools driving the same workflow programmatically.
+//!
+//! ```no_run
+//! use rona::prelude::*;
+//!
+//! let repository = Repository::discover()?;
+//! let status = repository.status()?;
+//! println!("{} changed files", status.changed_files().len());
+//!
+//! let plan = git_add_with_exclude_patterns(&[], true)?;
+//! println!("{}", plan.render_text());
+//! # Ok::<(), RonaError>(())
+//! ```
+//!
+//! # Stability
+//!
+//! The items re-exported by [`prelude`] are the supported API: they follow semantic
+//! versioning, breaking changes only landing in major releases. Error and plan enums are
+//! `#[non_exhaustive]`, so new variants can be added in minor releases. The other public
+//! modules serve the binary and may change in any release; [`cli`] is hidden from the
+//! documentation for this reason.
+//!
+//! # Architecture
+//!
+//! The library is organized into several modules:
+//! - `ai`: AI-assisted commit message drafting (`ai` cargo feature)
+//! - `cli`: Handles command-line interface and argument parsing
+//! - `config`: Manages application configuration
+//! - `doctor`: Environment checks of `rona doctor`, with fixes
+//! - `errors`: Error handling and custom error types
+//! - `extends`: Shared base configurations extended with `extends` (URL or path)
+//! - `git`: Organized Git-related functionality with focused submodules
+//! - `i18n`: Message catalogs of the user-facing strings (English, French)
+//! - `logging`: Leveled output controlled by `--quiet` and `--verbose`
+//! - `notification`: Desktop and webhook notifications after commits and pushes
+//! - `plan`: Dry-run plans rendered as text or JSON
+//! - `prelude`: The stable API, re-exported for `use rona::prelude::*`
+//! - `scaffold`: Project setup performed by `rona init --preset`
+//! - `serve`: JSON-RPC transport of `rona serve --stdio` for editor plugins
+//! - `testing`: The `TestRepo` fixture of isolated repositories and the `MockRunner` of git (`testing` cargo feature)
+//! - `theme`: Prompt symbols and colors from the `[theme]` config, emoji switch
+//! - `utils`: Common utility functions
+//! - `watch`: Working tree watch mode with a staging preview and WIP branch checkpoints
+//! - `wizard`: First-run questions writing the configuration of a repository without one
+
+#[cfg(feature = "ai")]
+pub mod ai;
+#[doc(hidden)]
+pub mod cli;
+pub mod config;
+pub mod doctor;
+pub mod errors;
+pub mod extends;
+pub mod git;
+pub mod i18n;
+pub mod logging;
+pub mod notification;
+pub mod performance;
+pub mod plan;
+pub mod prelude;
+pub mod scaffold;
+pub mod serve;
+pub mod template;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod theme;
+pub mod utils;
+pub mod watch;
+pub mod wizard;
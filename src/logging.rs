@@ -0,0 +1,132 @@
+//! Logging Module for Rona
+//!
+//! A small logger behind the [`log`] facade. Every module reports progress through
+//! `log` macros instead of printing directly, so the global `--quiet` and `--verbose`
+//! flags suppress or increase output uniformly:
+//! - `error!`/`warn!` go to stderr
+//! - `info!` goes to stdout (default output)
+//! - `debug!` goes to stdout, only in verbose mode
+//!
+//! Command results that are the purpose of a command (dry-run plans, file lists for
+//! shell completion) are printed directly and are never suppressed.
+//!
+//! Colors are stripped from the messages when disabled for their stream (`NO_COLOR`,
+//! not a terminal), and emoji when disabled (`--no-emoji`) or not on a terminal.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+use crate::theme::{Stream, colors_enabled, decorations_enabled, strip_ansi, strip_emoji};
+
+/// How much output Rona prints.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Only errors (`--quiet`)
+    Quiet,
+    /// Errors, warnings and regular progress messages
+    #[default]
+    Normal,
+    /// Everything, including details about each operation (`--verbose`)
+    Verbose,
+}
+
+impl Verbosity {
+    /// Builds the verbosity from the global command-line flags.
+    ///
+    /// `--quiet` wins over `--verbose` when both are given.
+    #[must_use]
+    pub fn from_flags(quiet: bool, verbose: bool) -> Self {
+        if quiet {
+            Self::Quiet
+        } else if verbose {
+            Self::Verbose
+        } else {
+            Self::Normal
+        }
+    }
+
+    /// The maximum log level enabled at this verbosity.
+    #[must_use]
+    pub fn level_filter(self) -> LevelFilter {
+        match self {
+            Self::Quiet => LevelFilter::Error,
+            Self::Normal => LevelFilter::Info,
+            Self::Verbose => LevelFilter::Debug,
+        }
+    }
+}
+
+/// Logger writing plain messages, without level or timestamp prefixes.
+struct RonaLogger;
+
+impl Log for RonaLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let stream = match record.level() {
+            Level::Error | Level::Warn => Stream::Stderr,
+            Level::Info | Level::Debug | Level::Trace => Stream::Stdout,
+        };
+
+        let mut message = record.args().to_string();
+        if !colors_enabled(stream) {
+            message = strip_ansi(&message);
+        }
+        if !EMOJI.load(Ordering::Relaxed) || !decorations_enabled(stream) {
+            message = strip_emoji(&message);
+        }
+
+        match stream {
+            Stream::Stderr => eprintln!("{message}"),
+            Stream::Stdout => println!("{message}"),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: RonaLogger = RonaLogger;
+
+/// Whether logged messages keep their emoji.
+static EMOJI: AtomicBool = AtomicBool::new(true);
+
+/// Installs the logger with the given verbosity.
+///
+/// Calling it again only updates the verbosity.
+pub fn init(verbosity: Verbosity) {
+    // Fails only if a logger is already installed, which is fine
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(verbosity.level_filter());
+}
+
+/// Enables or disables emoji in the logged messages.
+pub fn set_emoji(enabled: bool) {
+    EMOJI.store(enabled, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_from_flags() {
+        assert_eq!(Verbosity::from_flags(false, false), Verbosity::Normal);
+        assert_eq!(Verbosity::from_flags(false, true), Verbosity::Verbose);
+        assert_eq!(Verbosity::from_flags(true, false), Verbosity::Quiet);
+        assert_eq!(Verbosity::from_flags(true, true), Verbosity::Quiet);
+    }
+
+    #[test]
+    fn test_level_filter() {
+        assert_eq!(Verbosity::Quiet.level_filter(), LevelFilter::Error);
+        assert_eq!(Verbosity::Normal.level_filter(), LevelFilter::Info);
+        assert_eq!(Verbosity::Verbose.level_filter(), LevelFilter::Debug);
+    }
+}
@@ -29,16 +29,8 @@
 //! ```
 //!
 //! For more detailed examples and usage instructions, see the [README.md](../README.md) file.
-//!
-//! # Architecture
-//!
-//! The application is organized into several modules:
-//! - `cli`: Handles command-line interface and argument parsing
-//! - `config`: Manages application configuration
-//! - `errors`: Error handling and custom error types
-//! - `git`: Organized Git-related functionality with focused submodules
-//! - `my_clap_theme`: Custom theme for command-line output
-//! - `utils`: Common utility functions
+//! The commands are implemented by the `rona` library crate, whose [`rona::prelude`] is
+//! meant for tools calling rona programmatically.
 //!
 //! # Error Handling
 //!
@@ -46,24 +38,24 @@
 //! 1. Initial Git repository validation
 //! 2. Main application logic error handling through `Result` types
 //!
+//! Errors exit with a status depending on their category (see [`rona::errors::RonaError::exit_code`]).
+//!
 
-pub mod cli;
-pub mod config;
-pub mod errors;
-pub mod git;
-pub mod performance;
-pub mod template;
-pub mod utils;
-
-use cli::run;
-use errors::Result;
+use rona::{
+    cli::run,
+    errors::{Result, RonaError},
+};
 use std::process::exit;
 
 fn main() {
     if let Err(e) = inner_main() {
+        if matches!(e, RonaError::UserCancelled) {
+            // A prompt interrupted by Ctrl-C may leave the cursor hidden
+            let _ = console::Term::stderr().show_cursor();
+        }
         eprintln!("{e}");
 
-        exit(1);
+        exit(e.exit_code());
     }
 }
 
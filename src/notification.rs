@@ -0,0 +1,178 @@
+//! Commit and Push Notifications
+//!
+//! Notifications fired after a successful commit or push, naming the branch and the commit
+//! subject, for when long hooks run while working in another window. They are configured in
+//! the `[notifications]` section: a desktop notification (with the `notifications` cargo
+//! feature) and/or a POST to a Slack or Discord incoming webhook, made with `curl` like the
+//! other network requests.
+//!
+//! A notification that cannot be delivered is only reported: the commit or push it
+//! announces succeeded.
+
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    errors::{Result, RonaError},
+    utils,
+};
+
+/// The `[notifications]` configuration section.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NotificationConfig {
+    /// Show a desktop notification (requires the `notifications` cargo feature)
+    pub desktop: Option<bool>,
+    /// Slack or Discord incoming webhook URL the notification is posted to
+    pub webhook: Option<String>,
+}
+
+/// What a notification announces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationEvent {
+    /// A commit was created
+    Commit,
+    /// The branch was pushed
+    Push,
+}
+
+impl NotificationEvent {
+    /// The title of the notification.
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Commit => "rona: committed",
+            Self::Push => "rona: pushed",
+        }
+    }
+}
+
+/// A notification of a commit or push.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// What happened
+    pub event: NotificationEvent,
+    /// The branch committed on or pushed
+    pub branch: String,
+    /// The subject of the (last) commit
+    pub subject: String,
+}
+
+impl Notification {
+    /// The text of the notification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::notification::{Notification, NotificationEvent};
+    ///
+    /// let notification = Notification {
+    ///     event: NotificationEvent::Push,
+    ///     branch: "main".to_string(),
+    ///     subject: "[4] (feat on main) Add parser".to_string(),
+    /// };
+    /// assert_eq!(notification.body(), "main: [4] (feat on main) Add parser");
+    /// ```
+    #[must_use]
+    pub fn body(&self) -> String {
+        format!("{}: {}", self.branch, self.subject)
+    }
+
+    /// The JSON posted to the webhook: `text` is read by Slack, `content` by Discord.
+    #[must_use]
+    pub fn webhook_payload(&self) -> String {
+        let text = format!("{} - {}", self.event.title(), self.body());
+        json!({ "text": text, "content": text }).to_string()
+    }
+}
+
+/// Sends the notification as configured, reporting the failures without returning them.
+///
+/// # Arguments
+/// * `notification` - The notification to send
+/// * `config` - The `[notifications]` section
+/// * `offline` - Whether offline mode forbids the webhook request
+pub fn send_notification(notification: &Notification, config: &NotificationConfig, offline: bool) {
+    if config.desktop.unwrap_or(false)
+        && let Err(e) = show_desktop_notification(notification)
+    {
+        warn!("⚠️  Could not show the desktop notification: {e}");
+    }
+
+    if let Some(url) = &config.webhook {
+        if offline {
+            debug!("Offline mode, not posting the notification to the webhook");
+        } else if let Err(e) = post_webhook(url, &notification.webhook_payload()) {
+            warn!("⚠️  Could not post the notification to the webhook: {e}");
+        }
+    }
+}
+
+/// Shows the notification on the desktop, returning why it could not be shown.
+#[cfg(feature = "notifications")]
+fn show_desktop_notification(notification: &Notification) -> std::result::Result<(), String> {
+    notify_rust::Notification::new()
+        .appname("rona")
+        .summary(notification.event.title())
+        .body(&notification.body())
+        .show()
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Fallback when the `notifications` feature is disabled.
+#[cfg(not(feature = "notifications"))]
+fn show_desktop_notification(_notification: &Notification) -> std::result::Result<(), String> {
+    Err(
+        "it requires rona to be built with the `notifications` feature (cargo install rona --features notifications)"
+            .to_string(),
+    )
+}
+
+/// Posts a JSON payload to a webhook with curl, the URL (a secret) passed in its
+/// configuration on stdin rather than on the command line.
+fn post_webhook(url: &str, payload: &str) -> Result<()> {
+    debug!("Posting the notification to the webhook");
+
+    let output = utils::curl(
+        &["-sS", "--fail-with-body", "-X", "POST"],
+        &[
+            ("url", url),
+            ("header", "Content-Type: application/json"),
+            ("data-binary", payload),
+        ],
+    )?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        // The webhook URL is a secret, it is left out of the error
+        Err(RonaError::CommandFailed {
+            command: format!(
+                "curl: {}{}",
+                String::from_utf8_lossy(&output.stderr).trim(),
+                String::from_utf8_lossy(&output.stdout).trim()
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_webhook_payload() {
+        let notification = Notification {
+            event: NotificationEvent::Commit,
+            branch: "feat/parser".to_string(),
+            subject: "[4] (feat on parser) Add \"quoted\" parser".to_string(),
+        };
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&notification.webhook_payload()).unwrap();
+
+        let text = "rona: committed - feat/parser: [4] (feat on parser) Add \"quoted\" parser";
+        assert_eq!(payload["text"], text);
+        assert_eq!(payload["content"], text);
+    }
+}
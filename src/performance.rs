@@ -3,7 +3,14 @@
 //! This module contains utilities to improve performance and reduce memory allocations
 //! throughout the application.
 
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    panic,
+    path::{MAIN_SEPARATOR, Path, is_separator},
+    thread,
+};
+
+use crate::git::exec::{current_runner, with_runner};
 
 /// Efficiently concatenate strings with minimal allocations.
 ///
@@ -143,6 +150,7 @@ impl StringBuilder {
 /// This function intelligently handles path concatenation by borrowing
 /// the input when possible (when base is empty or file is absolute)
 /// and only allocating when concatenation is actually needed.
+/// The platform separator is used, and trailing separators of the base are trimmed.
 ///
 /// # Arguments
 ///
@@ -158,7 +166,7 @@ impl StringBuilder {
 ///
 /// ```
 /// use rona::performance::format_file_path;
-/// use std::borrow::Cow;
+/// use std::{borrow::Cow, path::Path};
 ///
 /// // Borrows when base is empty
 /// assert_eq!(format_file_path("", "file.txt"), Cow::Borrowed("file.txt"));
@@ -168,19 +176,22 @@ impl StringBuilder {
 ///            Cow::Borrowed("/absolute/file.txt"));
 ///
 /// // Allocates when concatenation is needed
-/// assert_eq!(format_file_path("base", "file.txt"),
-///            Cow::Owned("base/file.txt".to_string()));
+/// assert_eq!(Path::new(format_file_path("base", "file.txt").as_ref()),
+///            Path::new("base").join("file.txt"));
 ///
-/// // Handles trailing slashes correctly
-/// assert_eq!(format_file_path("base/", "file.txt"),
-///            Cow::Owned("base/file.txt".to_string()));
+/// // Handles trailing separators correctly
+/// assert_eq!(Path::new(format_file_path("base/", "file.txt").as_ref()),
+///            Path::new("base").join("file.txt"));
 /// ```
 #[must_use]
 pub fn format_file_path<'a>(base: &'a str, file: &'a str) -> Cow<'a, str> {
-    if base.is_empty() || file.starts_with('/') {
+    if base.is_empty() || file.starts_with(is_separator) || Path::new(file).is_absolute() {
         Cow::Borrowed(file)
     } else {
-        Cow::Owned(format!("{}/{}", base.trim_end_matches('/'), file))
+        Cow::Owned(format!(
+            "{}{MAIN_SEPARATOR}{file}",
+            base.trim_end_matches(is_separator)
+        ))
     }
 }
 
@@ -241,9 +252,62 @@ where
     results
 }
 
+/// Run two independent closures concurrently and return both results.
+///
+/// `b` runs on a scoped thread while `a` runs on the current one, so independent
+/// subprocesses (e.g. `git status` and `git rev-list --count`) overlap instead of
+/// running one after the other. Nest calls to run more than two closures. The git
+/// runner of the current thread (see [`with_runner`]) is used by both.
+///
+/// # Arguments
+///
+/// * `a` - The closure run on the current thread
+/// * `b` - The closure run on a scoped thread
+///
+/// # Returns
+///
+/// The results of `a` and `b`, in that order
+///
+/// # Panics
+///
+/// If either closure panics, the panic is propagated once both have finished.
+///
+/// # Examples
+///
+/// ```
+/// use rona::performance::join;
+///
+/// let numbers = vec![1, 2, 3, 4];
+/// let (sum, (max, len)) = join(
+///     || numbers.iter().sum::<i32>(),
+///     || join(|| numbers.iter().max().copied(), || numbers.len()),
+/// );
+///
+/// assert_eq!((sum, max, len), (10, Some(4), 4));
+/// ```
+pub fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    // The git commands of `b` go to the runner of the calling thread, e.g. a mock in tests
+    let runner = current_runner();
+    thread::scope(|scope| {
+        let handle = scope.spawn(|| with_runner(runner, b));
+        let result_a = a();
+        let result_b = handle
+            .join()
+            .unwrap_or_else(|payload| panic::resume_unwind(payload));
+        (result_a, result_b)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::PathBuf;
 
     #[test]
     fn test_string_builder() {
@@ -257,12 +321,69 @@ mod tests {
 
     #[test]
     fn test_format_file_path() {
+        let expected = PathBuf::from("base").join("file.txt");
+
         assert_eq!(format_file_path("", "file.txt"), "file.txt");
-        assert_eq!(format_file_path("base", "file.txt"), "base/file.txt");
-        assert_eq!(format_file_path("base/", "file.txt"), "base/file.txt");
+        assert_eq!(
+            PathBuf::from(format_file_path("base", "file.txt").as_ref()),
+            expected
+        );
+        assert_eq!(
+            PathBuf::from(format_file_path("base/", "file.txt").as_ref()),
+            expected
+        );
         assert_eq!(
             format_file_path("base", "/absolute/file.txt"),
             "/absolute/file.txt"
         );
     }
+
+    #[test]
+    fn test_format_file_path_nested_base() {
+        let base = PathBuf::from("project").join("src");
+        let joined = format_file_path(base.to_str().unwrap(), "main.rs");
+
+        assert_eq!(PathBuf::from(joined.as_ref()), base.join("main.rs"));
+    }
+
+    #[test]
+    fn test_join_runs_concurrently() {
+        use std::sync::Barrier;
+
+        // Each closure waits for the other: run one after the other, they would deadlock
+        let barrier = Barrier::new(2);
+        let (a, b) = join(
+            || {
+                barrier.wait();
+                "a"
+            },
+            || {
+                barrier.wait();
+                "b"
+            },
+        );
+
+        assert_eq!((a, b), ("a", "b"));
+    }
+
+    #[test]
+    fn test_join_inherits_the_runner() {
+        use crate::{git::exec::GitCommand, testing::MockRunner};
+        use std::sync::Arc;
+
+        let mock = Arc::new(MockRunner::new());
+        mock.respond(&["rev-parse"], "main\n");
+        let run = || {
+            GitCommand::new()
+                .args(["rev-parse", "HEAD"])
+                .output()
+                .unwrap()
+        };
+
+        let (a, b) = with_runner(mock.clone(), || join(run, run));
+
+        assert_eq!(a.stdout, b"main\n");
+        assert_eq!(b.stdout, b"main\n");
+        assert_eq!(mock.calls().len(), 2);
+    }
 }
@@ -0,0 +1,482 @@
+//! Dry-Run Plan Module for Rona
+//!
+//! Operations describe what they do (or would do) as a [`Plan`]: an ordered list of
+//! [`PlannedAction`]s with a kind, a target and arguments. Plans are rendered centrally,
+//! either as human-readable text or as JSON, so `--dry-run` output is consistent across
+//! commands and easy to consume from scripts and tests. Text summaries are localized
+//! (see [`crate::i18n`]), the JSON output is not.
+
+use std::fmt::{Display, Write};
+
+use serde::Serialize;
+
+use crate::{
+    errors::{Result, RonaError},
+    i18n::{tr, tr_args},
+    utils::print_paged,
+};
+
+/// The kind of a planned action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ActionKind {
+    /// Stage a file (`git add`)
+    Stage,
+    /// Stage the deletion of a file
+    Delete,
+    /// Leave a file out because it matches an exclusion pattern (`args` holds the pattern)
+    Exclude,
+    /// Stage the new commit checked out in a submodule
+    StageSubmodule,
+    /// Leave a submodule out because its only changes are uncommitted content inside it
+    /// (`args` holds the changes)
+    SkipSubmodule,
+    /// Create a file if it does not exist
+    CreateFile,
+    /// Add entries to `.git/info/exclude`
+    GitExclude,
+    /// Write a commit or tag message file
+    WriteMessage,
+    /// Bump the version of a manifest (`args` holds the previous and new versions)
+    BumpVersion,
+    /// Create a commit (`target` holds the message, `args` the git arguments)
+    Commit,
+    /// Create a tag (`target` holds the name, `args` the git arguments)
+    Tag,
+    /// Push to a remote (`args` holds the git arguments)
+    Push,
+    /// Pull from the upstream (`args` holds the git arguments)
+    Pull,
+    /// Fetch from remotes (`target` holds the remote, empty for the default one, `args` the git arguments)
+    Fetch,
+    /// Change a configuration value (`target` holds the key, `args` the value)
+    SetConfig,
+    /// Open a URL in the browser
+    OpenUrl,
+    /// Move HEAD to another commit (`target` holds the commit, `args` the git arguments)
+    Reset,
+    /// Continue a stopped operation (`target` holds the git command, e.g. `rebase`)
+    Continue,
+    /// Abort a stopped operation (`target` holds the git command, e.g. `merge`)
+    Abort,
+    /// Run a `git submodule` command (`args` holds the git arguments)
+    Submodule,
+    /// Rebase onto a revision (`target` holds the revision, `args` the todo list lines, if interactive)
+    Rebase,
+    /// Revert a commit without committing (`target` holds the commit, `args` the git arguments)
+    Revert,
+    /// Cherry-pick a commit without committing (`target` holds the commit, `args` the git arguments)
+    CherryPick,
+    /// Create a branch from `HEAD` (`target` holds the branch)
+    CreateBranch,
+    /// Check out a branch (`target` holds the branch)
+    Checkout,
+    /// Move a file (`target` holds the source, `args` the destination)
+    Move,
+    /// Unstage a file (`git restore --staged`)
+    Unstage,
+    /// Stage a file renamed (`target` holds the old path, `args` the new one)
+    Rename,
+}
+
+/// A single action of a [`Plan`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedAction {
+    pub kind: ActionKind,
+    pub target: String,
+    pub args: Vec<String>,
+}
+
+/// Output format used to render plans.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// Machine-readable JSON
+    Json,
+}
+
+/// An ordered list of actions an operation performs or would perform.
+///
+/// # Examples
+///
+/// ```
+/// use rona::plan::{ActionKind, Plan};
+///
+/// let mut plan = Plan::new();
+/// plan.push(ActionKind::Stage, "src/main.rs", Vec::new());
+/// plan.push(ActionKind::Push, "", vec!["origin".to_string()]);
+///
+/// assert_eq!(plan.actions().len(), 2);
+/// assert!(plan.render_text().contains("+ src/main.rs"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Plan {
+    actions: Vec<PlannedAction>,
+}
+
+impl Plan {
+    /// Creates an empty plan.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an action to the plan.
+    pub fn push(&mut self, kind: ActionKind, target: impl Into<String>, args: Vec<String>) {
+        self.actions.push(PlannedAction {
+            kind,
+            target: target.into(),
+            args,
+        });
+    }
+
+    /// Appends all actions of another plan, keeping their order.
+    pub fn extend(&mut self, other: Plan) {
+        self.actions.extend(other.actions);
+    }
+
+    /// Returns the planned actions in order.
+    #[must_use]
+    pub fn actions(&self) -> &[PlannedAction] {
+        &self.actions
+    }
+
+    /// Returns the targets of all actions of the given kind.
+    #[must_use]
+    pub fn targets(&self, kind: ActionKind) -> Vec<&str> {
+        self.actions
+            .iter()
+            .filter(|action| action.kind == kind)
+            .map(|action| action.target.as_str())
+            .collect()
+    }
+
+    /// Whether the plan contains no action.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Renders the plan as JSON.
+    ///
+    /// # Errors
+    /// * If the plan cannot be serialized
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| RonaError::InvalidInput(format!("Failed to serialize plan: {e}")))
+    }
+
+    /// Renders the plan as human-readable text.
+    ///
+    /// File-level actions of the same kind that follow each other are grouped
+    /// into a single list.
+    #[must_use]
+    pub fn render_text(&self) -> String {
+        let mut output = String::new();
+        let mut index = 0;
+
+        while index < self.actions.len() {
+            let action = &self.actions[index];
+
+            if let Some((title, marker)) = file_group(action.kind) {
+                let group: Vec<&PlannedAction> = self.actions[index..]
+                    .iter()
+                    .take_while(|other| other.kind == action.kind)
+                    .collect();
+
+                let _ = writeln!(output, "{}", tr_args(title, &[("count", &group.len())]));
+                for item in &group {
+                    if item.kind == ActionKind::Rename {
+                        let destination = item.args.join(", ");
+                        let _ = writeln!(output, "  {marker} {} -> {destination}", item.target);
+                    } else if item.args.is_empty() {
+                        let _ = writeln!(output, "  {marker} {}", item.target);
+                    } else {
+                        let reason = item.args.join(", ");
+                        let _ = writeln!(output, "  {marker} {} ({reason})", item.target);
+                    }
+                }
+
+                index += group.len();
+                continue;
+            }
+
+            render_action(&mut output, action);
+            index += 1;
+        }
+
+        output.trim_end().to_string()
+    }
+
+    /// Renders the plan in the requested format.
+    ///
+    /// # Errors
+    /// * If the plan cannot be serialized to JSON
+    pub fn render(&self, format: OutputFormat) -> Result<String> {
+        match format {
+            OutputFormat::Text => Ok(self.render_text()),
+            OutputFormat::Json => self.to_json(),
+        }
+    }
+
+    /// Prints the plan to stdout in the requested format.
+    ///
+    /// An empty plan is rendered as `Nothing to do` in text mode. Plans taller than
+    /// the terminal go through the pager when `use_pager` is set.
+    ///
+    /// # Errors
+    /// * If the plan cannot be serialized to JSON
+    /// * If the pager fails
+    pub fn print(&self, format: OutputFormat, use_pager: bool) -> Result<()> {
+        if self.is_empty() && format == OutputFormat::Text {
+            println!("{}", tr("plan.nothing_to_do"));
+        } else {
+            print_paged(&self.render(format)?, use_pager)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Title message key and list marker of the file-level kinds rendered as grouped lists.
+fn file_group(kind: ActionKind) -> Option<(&'static str, char)> {
+    match kind {
+        ActionKind::Stage => Some(("plan.stage", '+')),
+        ActionKind::Delete => Some(("plan.delete", '-')),
+        ActionKind::Exclude => Some(("plan.exclude", 'x')),
+        ActionKind::StageSubmodule => Some(("plan.stage_submodule", '^')),
+        ActionKind::SkipSubmodule => Some(("plan.skip_submodule", '~')),
+        ActionKind::Unstage => Some(("plan.unstage", '<')),
+        ActionKind::Rename => Some(("plan.rename", '>')),
+        _ => None,
+    }
+}
+
+/// The target of an action, or the message describing an implicit one.
+fn target_or(target: &str, fallback_key: &str) -> String {
+    if target.is_empty() {
+        tr(fallback_key)
+    } else {
+        target.to_string()
+    }
+}
+
+/// Renders a single non-file action.
+fn render_action(output: &mut String, action: &PlannedAction) {
+    let target: &dyn Display = &action.target;
+    let args = &action.args;
+    let joined = args.join(" ");
+
+    let line = match action.kind {
+        ActionKind::CreateFile => tr_args("plan.create_file", &[("target", target)]),
+        ActionKind::GitExclude => tr_args(
+            "plan.git_exclude",
+            &[("files", &args.join(", ")), ("target", target)],
+        ),
+        ActionKind::WriteMessage => tr_args("plan.write_message", &[("target", target)]),
+        ActionKind::BumpVersion => tr_args(
+            "plan.bump_version",
+            &[("target", target), ("versions", &args.join(" -> "))],
+        ),
+        ActionKind::Commit => {
+            let _ = writeln!(
+                output,
+                "{}\n---\n{}\n---",
+                tr("plan.commit"),
+                action.target.trim()
+            );
+
+            if let Some(sign_arg) = args.iter().find(|arg| arg.starts_with("-S")) {
+                let _ = writeln!(
+                    output,
+                    "{}",
+                    tr_args("plan.sign_commit", &[("flag", sign_arg)])
+                );
+            } else {
+                let _ = writeln!(output, "{}", tr("plan.unsigned_commit"));
+            }
+
+            let extra: Vec<&String> = args.iter().filter(|arg| !arg.starts_with("-S")).collect();
+            if extra.is_empty() {
+                return;
+            }
+            tr_args("plan.extra_args", &[("args", &format!("{extra:?}"))])
+        }
+        ActionKind::Tag => {
+            let key = if args.iter().any(|arg| arg == "-s") {
+                "plan.signed_tag"
+            } else {
+                "plan.annotated_tag"
+            };
+            let message = args.last().map(String::as_str).unwrap_or_default();
+            tr_args(key, &[("target", target), ("message", &message)])
+        }
+        ActionKind::Push | ActionKind::Fetch => {
+            let (key, name, fallback) = if action.kind == ActionKind::Push {
+                ("plan.push", "target", "plan.default_push_target")
+            } else {
+                ("plan.fetch", "source", "plan.default_fetch_source")
+            };
+            let destination = target_or(&action.target, fallback);
+            let _ = writeln!(output, "{}", tr_args(key, &[(name, &destination)]));
+            if args.is_empty() {
+                return;
+            }
+            tr_args("plan.args", &[("args", &format!("{args:?}"))])
+        }
+        ActionKind::Pull => tr_args("plan.pull", &[("args", &joined)]),
+        ActionKind::SetConfig => {
+            tr_args("plan.set_config", &[("target", target), ("value", &joined)])
+        }
+        ActionKind::OpenUrl => tr_args("plan.open_url", &[("target", target)]),
+        ActionKind::Reset => tr_args("plan.reset", &[("mode", &joined), ("target", target)]),
+        ActionKind::Continue => tr_args("plan.continue", &[("target", target)]),
+        ActionKind::Abort => tr_args("plan.abort", &[("target", target)]),
+        ActionKind::Submodule => tr_args("plan.submodule", &[("args", &joined)]),
+        ActionKind::Revert => tr_args("plan.revert", &[("target", target)]),
+        ActionKind::CherryPick => tr_args("plan.cherry_pick", &[("target", target)]),
+        ActionKind::CreateBranch => tr_args("plan.create_branch", &[("target", target)]),
+        ActionKind::Checkout => tr_args("plan.checkout", &[("target", target)]),
+        ActionKind::Move => tr_args("plan.move", &[("target", target), ("destination", &joined)]),
+        ActionKind::Rebase if args.is_empty() => tr_args("plan.rebase", &[("target", target)]),
+        ActionKind::Rebase => {
+            let _ = writeln!(
+                output,
+                "{}",
+                tr_args("plan.rebase_with", &[("target", target)])
+            );
+            args.iter().fold(String::new(), |mut lines, line| {
+                let _ = writeln!(lines, "  {line}");
+                lines
+            })
+        }
+        ActionKind::Stage
+        | ActionKind::Delete
+        | ActionKind::Exclude
+        | ActionKind::StageSubmodule
+        | ActionKind::SkipSubmodule
+        | ActionKind::Unstage
+        | ActionKind::Rename => return,
+    };
+
+    let _ = writeln!(output, "{}", line.trim_end());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_plan() -> Plan {
+        let mut plan = Plan::new();
+        plan.push(ActionKind::Stage, "src/main.rs", Vec::new());
+        plan.push(ActionKind::Stage, "README.md", Vec::new());
+        plan.push(ActionKind::Delete, "old.txt", Vec::new());
+        plan.push(ActionKind::Exclude, "notes.md", vec!["*.md".to_string()]);
+        plan.push(
+            ActionKind::Commit,
+            "[1] (feat on main)\n\n- `src/main.rs`: done\n",
+            vec!["-S".to_string()],
+        );
+        plan.push(ActionKind::Push, "", vec!["origin".to_string()]);
+        plan
+    }
+
+    #[test]
+    fn test_render_text_groups_files() {
+        let text = sample_plan().render_text();
+
+        assert!(text.starts_with("Would add 2 files:\n  + src/main.rs\n  + README.md\n"));
+        assert!(text.contains("Would delete 1 files:\n  - old.txt"));
+        assert!(text.contains("Would exclude 1 files:\n  x notes.md (*.md)"));
+        assert!(text.contains("Would sign commit with -S flag"));
+        assert!(text.ends_with("With args: [\"origin\"]"));
+    }
+
+    #[test]
+    fn test_render_text_submodules() {
+        let mut plan = Plan::new();
+        plan.push(ActionKind::StageSubmodule, "vendor/lib", Vec::new());
+        plan.push(
+            ActionKind::SkipSubmodule,
+            "vendor/tool",
+            vec!["modified content".to_string()],
+        );
+
+        assert_eq!(
+            plan.render_text(),
+            "Would update 1 submodules:\n  ^ vendor/lib\n\
+             Would skip 1 submodules:\n  ~ vendor/tool (modified content)"
+        );
+    }
+
+    #[test]
+    fn test_render_text_renames() {
+        let mut plan = Plan::new();
+        plan.push(
+            ActionKind::Rename,
+            "src/parse.rs",
+            vec!["src/parser.rs".to_string()],
+        );
+
+        assert_eq!(
+            plan.render_text(),
+            "Would rename 1 files:\n  > src/parse.rs -> src/parser.rs"
+        );
+    }
+
+    #[test]
+    fn test_render_text_signing_key() {
+        let mut plan = Plan::new();
+        plan.push(
+            ActionKind::Commit,
+            "[1] (feat on main)",
+            vec!["-S3AA5C34371567BD2".to_string(), "--amend".to_string()],
+        );
+
+        let text = plan.render_text();
+
+        assert!(text.contains("Would sign commit with -S3AA5C34371567BD2 flag"));
+        assert!(text.contains("With additional args: [\"--amend\"]"));
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json = sample_plan().to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let actions = value["actions"].as_array().unwrap();
+        assert_eq!(actions.len(), 6);
+        assert_eq!(actions[0]["kind"], "stage");
+        assert_eq!(actions[0]["target"], "src/main.rs");
+        assert_eq!(actions[4]["kind"], "commit");
+        assert_eq!(actions[4]["args"][0], "-S");
+    }
+
+    #[test]
+    fn test_extend_and_targets() {
+        let mut plan = Plan::new();
+        plan.push(ActionKind::Stage, "a.rs", Vec::new());
+
+        let mut other = Plan::new();
+        other.push(ActionKind::Stage, "b.rs", Vec::new());
+        other.push(ActionKind::Exclude, "c.rs", Vec::new());
+        plan.extend(other);
+
+        assert_eq!(plan.targets(ActionKind::Stage), vec!["a.rs", "b.rs"]);
+        assert_eq!(plan.targets(ActionKind::Exclude), vec!["c.rs"]);
+        assert!(!plan.is_empty());
+        assert!(Plan::new().is_empty());
+    }
+
+    #[test]
+    fn test_render_empty_plan() {
+        assert_eq!(Plan::new().render_text(), "");
+        assert_eq!(
+            Plan::new().render(OutputFormat::Json).unwrap(),
+            "{\n  \"actions\": []\n}"
+        );
+    }
+}
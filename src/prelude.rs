@@ -0,0 +1,33 @@
+//! Stable API
+//!
+//! Re-exports the types and functions supported for programmatic use, covered by
+//! semantic versioning (see the crate documentation):
+//!
+//! - [`Repository`]: the repository containing the current directory (or a path), its status
+//!   and branch
+//! - Status types: [`StatusSnapshot`], [`StatusEntry`], [`FileState`], [`SubmoduleState`]
+//! - Staging: [`git_add_with_exclude_patterns`], previewed as a [`Plan`] in dry-run mode
+//! - Commit messages: [`generate_commit_message`] (with [`Issue`]s and [`Trailer`]s),
+//!   [`commit_message_path`], [`validate_commit_message`]
+//! - Configuration: [`Config`], [`ProjectConfig`]
+//! - Errors: [`RonaError`], [`GitError`], [`ConfigError`] and the [`Result`] alias
+//!
+//! ```no_run
+//! use rona::prelude::*;
+//!
+//! let repository = Repository::discover()?;
+//! println!("On {}", repository.current_branch()?);
+//! # Ok::<(), RonaError>(())
+//! ```
+
+pub use crate::{
+    config::{Config, ProjectConfig},
+    errors::{ConfigError, GitError, Result, RonaError},
+    git::{
+        COMMIT_TYPES, CommitDraft, CommitNumbering, FileState, Issue, LastCommit, MessageIssue,
+        Repository, StatusEntry, StatusSnapshot, SubmoduleState, Trailer, ValidationRules,
+        commit_message_path, generate_commit_message, git_add_with_exclude_patterns,
+        validate_commit_message,
+    },
+    plan::{ActionKind, Plan, PlannedAction},
+};
@@ -0,0 +1,416 @@
+//! Project Scaffolding for Rona
+//!
+//! Optional project setup performed by `rona init --preset <name>` (or `--scaffold`
+//! to pick the items interactively): a commented `.rona.toml`, a `.commitignore`
+//! seeded with the build artifacts of `.gitignore`, a commit template registered as
+//! `commit.template` and a `prepare-commit-msg` hook filling `git commit` messages
+//! from `commit_message.md`.
+
+use std::{
+    fmt,
+    fs::{read_to_string, write},
+    path::Path,
+};
+
+use clap::ValueEnum;
+use log::{info, warn};
+
+use crate::{
+    errors::Result,
+    git::{
+        COMMIT_MESSAGE_DIR, COMMIT_MESSAGE_FILE_NAME,
+        exec::GitCommand,
+        files::{COMMITIGNORE_FILE_PATH, add_to_git_exclude},
+        handle_output,
+        hooks::{hooks_dir, make_executable},
+    },
+    plan::{ActionKind, Plan},
+    utils::find_project_root,
+};
+
+/// Path of the scaffolded project configuration.
+pub const PROJECT_CONFIG_FILE_PATH: &str = ".rona.toml";
+
+/// Path of the scaffolded commit template.
+pub const COMMIT_TEMPLATE_FILE_PATH: &str = ".gitmessage";
+
+/// Name of the installed hook.
+const HOOK_NAME: &str = "prepare-commit-msg";
+
+/// `.gitignore` entries considered build artifacts, matched on their last path component.
+const BUILD_ARTIFACTS: &[&str] = &[
+    "target",
+    "build",
+    "dist",
+    "out",
+    "bin",
+    "obj",
+    "node_modules",
+    "coverage",
+    "__pycache__",
+    ".next",
+    ".nuxt",
+    ".gradle",
+    ".venv",
+    "venv",
+    "*.o",
+    "*.a",
+    "*.so",
+    "*.class",
+    "*.pyc",
+    "*.log",
+];
+
+/// An item of the project setup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaffoldItem {
+    /// `.rona.toml` with commented defaults
+    Config,
+    /// `.commitignore` seeded from the build artifacts of `.gitignore`
+    CommitIgnore,
+    /// A commit template registered as `commit.template`
+    CommitTemplate,
+    /// A `prepare-commit-msg` hook using `commit_message.md`
+    Hook,
+}
+
+impl ScaffoldItem {
+    /// Every item, in the order they are set up.
+    pub const ALL: [Self; 4] = [
+        Self::Config,
+        Self::CommitIgnore,
+        Self::CommitTemplate,
+        Self::Hook,
+    ];
+}
+
+impl fmt::Display for ScaffoldItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Config => write!(f, "Project config ({PROJECT_CONFIG_FILE_PATH})"),
+            Self::CommitIgnore => write!(f, "Commit ignore file ({COMMITIGNORE_FILE_PATH})"),
+            Self::CommitTemplate => write!(f, "Commit template ({COMMIT_TEMPLATE_FILE_PATH})"),
+            Self::Hook => write!(f, "Git hook ({HOOK_NAME})"),
+        }
+    }
+}
+
+/// Predefined sets of scaffold items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Preset {
+    /// Only `.rona.toml`
+    Minimal,
+    /// `.rona.toml`, `.commitignore` and the commit template
+    Standard,
+    /// Everything, including the git hook
+    Full,
+}
+
+impl Preset {
+    /// The items set up by the preset.
+    #[must_use]
+    pub fn items(self) -> Vec<ScaffoldItem> {
+        match self {
+            Self::Minimal => vec![ScaffoldItem::Config],
+            Self::Standard => vec![
+                ScaffoldItem::Config,
+                ScaffoldItem::CommitIgnore,
+                ScaffoldItem::CommitTemplate,
+            ],
+            Self::Full => ScaffoldItem::ALL.to_vec(),
+        }
+    }
+}
+
+/// Builds the contents of `.rona.toml`, with every other option commented out.
+#[must_use]
+pub fn project_config_contents(editor: &str) -> String {
+    format!(
+        r#"# Rona project configuration
+
+# Editor for commit messages
+editor = "{editor}"
+
+# Commit types offered by `rona generate`
+# commit_types = ["feat", "fix", "docs", "test", "chore"]
+
+# Template for interactive commit message generation
+# Available variables: {{commit_number}}, {{commit_type}}, {{branch_name}}, {{message}}, {{date}}, {{time}}, {{author}}, {{email}}, {{breaking}}
+# template = "[{{commit_number}}] ({{commit_type}} on {{branch_name}}) {{message}}"
+
+# How the [N] commit number is computed: "repo", "branch" or "none"
+# commit_number = "repo"
+
+# Emoji in commit subjects: "allow", "gitmoji" or "ascii"
+# emoji = "allow"
+
+# Group the file entries of generated messages: "none" or "directory"
+# group_by = "none"
+
+# Validate commit_message.md before committing
+# validate_commit_message = true
+# max_subject_length = 72
+
+# Size from which staged files are flagged as large (binary files always are)
+# large_file_threshold = "5MB"
+
+# Seconds a push, pull or fetch may take before it is stopped (0 for no limit)
+# network_timeout = 120
+
+# Files whose version is bumped by `rona release`
+# release_files = ["Cargo.toml", "package.json"]
+"#
+    )
+}
+
+/// Keeps the `.gitignore` entries that are build artifacts.
+///
+/// # Arguments
+/// * `gitignore` - The contents of `.gitignore`
+///
+/// # Returns
+/// * `Vec<String>` - The build artifact patterns, in their original order
+///
+/// # Examples
+///
+/// ```
+/// use rona::scaffold::build_artifact_patterns;
+///
+/// let gitignore = "# Build\n/target\nnode_modules/\n.env\n*.log\n";
+/// assert_eq!(
+///     build_artifact_patterns(gitignore),
+///     vec!["/target", "node_modules/", "*.log"]
+/// );
+/// ```
+#[must_use]
+pub fn build_artifact_patterns(gitignore: &str) -> Vec<String> {
+    gitignore
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('!'))
+        .filter(|line| {
+            let name = line
+                .trim_matches('/')
+                .rsplit('/')
+                .next()
+                .unwrap_or_default();
+            BUILD_ARTIFACTS.contains(&name)
+        })
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// The commit template describing the format `rona generate` writes.
+#[must_use]
+pub fn commit_template_contents() -> &'static str {
+    "# [N] (type on branch) Short summary
+#
+# - `path/to/file`:
+#
+#\tWhat changed in the file and why
+#
+# Lines starting with '#' are ignored.
+"
+}
+
+/// The `prepare-commit-msg` hook filling plain `git commit` messages from `commit_message.md`.
+///
+/// # Arguments
+/// * `commit_message_path` - The configured `commit_message_path`, `None` for the
+///   default `.git/rona/commit_message.md`
+#[must_use]
+pub fn hook_contents(commit_message_path: Option<&str>) -> String {
+    let message_file = match commit_message_path.map(str::trim) {
+        Some(path) if Path::new(path).is_absolute() => path.to_string(),
+        Some(path) if !path.is_empty() => format!("$(git rev-parse --show-toplevel)/{path}"),
+        _ => format!(
+            "$(git rev-parse --absolute-git-dir)/{COMMIT_MESSAGE_DIR}/{COMMIT_MESSAGE_FILE_NAME}"
+        ),
+    };
+
+    format!(
+        r#"#!/bin/sh
+# Installed by rona: uses {COMMIT_MESSAGE_FILE_NAME} as the message of plain `git commit`.
+# Messages given with -m, -F, merges or amends are left untouched.
+if [ -z "$2" ] || [ "$2" = "template" ]; then
+    message_file="{message_file}"
+    if [ -s "$message_file" ]; then
+        cat "$message_file" > "$1"
+    fi
+fi
+"#
+    )
+}
+
+/// Writes a file unless it already exists.
+///
+/// # Returns
+/// * `bool` - Whether the file was written
+fn write_new_file(path: &Path, contents: &str, dry_run: bool) -> Result<bool> {
+    if path.exists() {
+        warn!("⚠️  {} already exists, skipping", path.display());
+        return Ok(false);
+    }
+
+    if !dry_run {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        write(path, contents)?;
+        info!("Created {}", path.display());
+    }
+
+    Ok(true)
+}
+
+/// Sets up the given items in the current project.
+///
+/// Existing files are never overwritten: they are reported and skipped.
+///
+/// # Arguments
+/// * `items` - The items to set up
+/// * `editor` - The editor written to `.rona.toml`
+/// * `commit_message_path` - The configured path of the commit message file, read by the hook
+/// * `dry_run` - If true, only plan the setup without writing anything
+///
+/// # Errors
+/// * If the project root or the hooks directory cannot be found
+/// * If a file cannot be written
+/// * If `commit.template` cannot be set
+///
+/// # Returns
+/// * `Plan` - The files created and settings changed (or that would be, in dry-run mode)
+pub fn scaffold(
+    items: &[ScaffoldItem],
+    editor: &str,
+    commit_message_path: Option<&str>,
+    dry_run: bool,
+) -> Result<Plan> {
+    let project_root = find_project_root()?;
+    let mut plan = Plan::new();
+
+    for item in items {
+        match item {
+            ScaffoldItem::Config => {
+                let path = project_root.join(PROJECT_CONFIG_FILE_PATH);
+                if write_new_file(&path, &project_config_contents(editor), dry_run)? {
+                    plan.push(ActionKind::CreateFile, PROJECT_CONFIG_FILE_PATH, Vec::new());
+                }
+            }
+            ScaffoldItem::CommitIgnore => {
+                let gitignore = read_to_string(project_root.join(".gitignore")).unwrap_or_default();
+                let patterns = build_artifact_patterns(&gitignore);
+                let contents = if patterns.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}\n", patterns.join("\n"))
+                };
+
+                let path = project_root.join(COMMITIGNORE_FILE_PATH);
+                if write_new_file(&path, &contents, dry_run)? {
+                    plan.push(ActionKind::CreateFile, COMMITIGNORE_FILE_PATH, patterns);
+                    plan.push(
+                        ActionKind::GitExclude,
+                        ".git/info/exclude",
+                        vec![COMMITIGNORE_FILE_PATH.to_string()],
+                    );
+                    if !dry_run {
+                        add_to_git_exclude(&[COMMITIGNORE_FILE_PATH])?;
+                    }
+                }
+            }
+            ScaffoldItem::CommitTemplate => {
+                let path = project_root.join(COMMIT_TEMPLATE_FILE_PATH);
+                if write_new_file(&path, commit_template_contents(), dry_run)? {
+                    plan.push(
+                        ActionKind::CreateFile,
+                        COMMIT_TEMPLATE_FILE_PATH,
+                        Vec::new(),
+                    );
+                }
+
+                plan.push(
+                    ActionKind::SetConfig,
+                    "commit.template",
+                    vec![COMMIT_TEMPLATE_FILE_PATH.to_string()],
+                );
+                if !dry_run {
+                    let output = GitCommand::new()
+                        .current_dir(&project_root)
+                        .args(["config", "commit.template", COMMIT_TEMPLATE_FILE_PATH])
+                        .output()?;
+                    handle_output("config", &output)?;
+                }
+            }
+            ScaffoldItem::Hook => {
+                let path = hooks_dir(&project_root)?.join(HOOK_NAME);
+                if write_new_file(&path, &hook_contents(commit_message_path), dry_run)? {
+                    let target = path.strip_prefix(&project_root).unwrap_or(&path);
+                    plan.push(
+                        ActionKind::CreateFile,
+                        target.display().to_string(),
+                        Vec::new(),
+                    );
+                    if !dry_run {
+                        make_executable(&path)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preset_items() {
+        assert_eq!(Preset::Minimal.items(), vec![ScaffoldItem::Config]);
+        assert_eq!(Preset::Standard.items().len(), 3);
+        assert_eq!(Preset::Full.items(), ScaffoldItem::ALL.to_vec());
+    }
+
+    #[test]
+    fn test_build_artifact_patterns() {
+        let gitignore = "\
+# Generated
+/target/
+dist
+src/generated/build
+!build/keep
+.env
+*.pyc
+.DS_Store
+";
+        assert_eq!(
+            build_artifact_patterns(gitignore),
+            vec!["/target/", "dist", "src/generated/build", "*.pyc"]
+        );
+    }
+
+    #[test]
+    fn test_project_config_contents_is_valid_toml() {
+        let contents = project_config_contents("vim");
+        let value: toml::Value = toml::from_str(&contents).unwrap();
+
+        assert_eq!(
+            value.get("editor").and_then(toml::Value::as_str),
+            Some("vim")
+        );
+        assert!(value.get("template").is_none());
+    }
+
+    #[test]
+    fn test_hook_contents_message_file() {
+        assert!(hook_contents(None).contains(
+            "message_file=\"$(git rev-parse --absolute-git-dir)/rona/commit_message.md\""
+        ));
+        assert!(
+            hook_contents(Some("commit_message.md"))
+                .contains("message_file=\"$(git rev-parse --show-toplevel)/commit_message.md\"")
+        );
+    }
+}
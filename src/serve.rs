@@ -0,0 +1,244 @@
+//! Editor Integration Protocol
+//!
+//! `rona serve --stdio` answers [JSON-RPC 2.0](https://www.jsonrpc.org/specification)
+//! requests read from stdin, one JSON object per line, with one response line on stdout.
+//! Editor plugins (VS Code, Neovim...) keep a single rona process running instead of
+//! spawning one per operation. Notifications (requests without an `id`) get no response;
+//! the `exit` method, or the end of stdin, stops the server.
+//!
+//! This module is the transport: reading requests, dispatching them to a handler and
+//! writing the responses. The methods themselves are provided by the CLI.
+
+use std::io::{BufRead, Write};
+
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::errors::{Result, RonaError};
+
+/// Invalid JSON was received.
+pub const PARSE_ERROR: i64 = -32700;
+/// The JSON is not a valid request object.
+pub const INVALID_REQUEST: i64 = -32600;
+/// The method does not exist.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// The parameters of the method are invalid.
+pub const INVALID_PARAMS: i64 = -32602;
+/// The method failed, `data.exitCode` holding the exit code rona would have exited with.
+pub const METHOD_FAILED: i64 = -32000;
+
+/// A JSON-RPC request or notification.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Request {
+    /// Identifier echoed in the response, absent for notifications
+    pub id: Option<Value>,
+    /// Name of the method (e.g. `status`)
+    pub method: String,
+    /// Parameters of the method, by name
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// Deserializes the parameters of a method, missing parameters being an empty object.
+///
+/// # Errors
+/// * `RonaError::InvalidInput` - If the parameters do not match `T`
+pub fn parse_params<T: for<'de> Deserialize<'de>>(params: &Value) -> Result<T> {
+    let params = if params.is_null() {
+        json!({})
+    } else {
+        params.clone()
+    };
+
+    serde_json::from_value(params)
+        .map_err(|e| RonaError::InvalidInput(format!("Invalid parameters: {e}")))
+}
+
+/// The error object of a failed request.
+fn error_object(code: i64, message: &str, data: Option<Value>) -> Value {
+    let mut error = json!({ "code": code, "message": message });
+    if let Some(data) = data {
+        error["data"] = data;
+    }
+    error
+}
+
+/// The error object of a method that returned an error.
+fn method_error(error: &RonaError) -> Value {
+    match error {
+        RonaError::InvalidInput(message) => error_object(INVALID_PARAMS, message, None),
+        _ => error_object(
+            METHOD_FAILED,
+            &error.to_string(),
+            Some(json!({ "exitCode": error.exit_code() })),
+        ),
+    }
+}
+
+/// Handles one line of input, returning the response to write (if any) and whether
+/// the server should stop.
+fn handle_line<H>(line: &str, handler: &mut H) -> (Option<Value>, bool)
+where
+    H: FnMut(&str, &Value) -> Option<Result<Value>>,
+{
+    let value: Value = match serde_json::from_str(line) {
+        Ok(value) => value,
+        Err(e) => {
+            let error = error_object(PARSE_ERROR, &format!("Parse error: {e}"), None);
+            return (
+                Some(json!({ "jsonrpc": "2.0", "id": null, "error": error })),
+                false,
+            );
+        }
+    };
+
+    let id = value.get("id").cloned();
+    let request: Request = match serde_json::from_value(value) {
+        Ok(request) => request,
+        Err(e) => {
+            let error = error_object(INVALID_REQUEST, &format!("Invalid request: {e}"), None);
+            return (
+                Some(json!({ "jsonrpc": "2.0", "id": id, "error": error })),
+                false,
+            );
+        }
+    };
+
+    let stop = request.method == "exit";
+    let outcome = if stop {
+        Ok(Value::Null)
+    } else {
+        match handler(&request.method, &request.params) {
+            Some(Ok(result)) => Ok(result),
+            Some(Err(error)) => Err(method_error(&error)),
+            None => Err(error_object(
+                METHOD_NOT_FOUND,
+                &format!("Unknown method: {}", request.method),
+                None,
+            )),
+        }
+    };
+
+    // Notifications are not answered
+    let Some(id) = request.id else {
+        return (None, stop);
+    };
+    let response = match outcome {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+    };
+    (Some(response), stop)
+}
+
+/// Serves JSON-RPC requests read line by line from `input`, writing the responses to
+/// `output` until the `exit` method or the end of the input.
+///
+/// The `handler` runs a method with its parameters, returning `None` for unknown methods.
+///
+/// # Errors
+/// * If the input cannot be read or the output cannot be written
+///
+/// # Examples
+///
+/// ```
+/// use rona::serve::serve;
+/// use serde_json::json;
+///
+/// let input = "{\"jsonrpc\": \"2.0\", \"id\": 1, \"method\": \"ping\"}\n";
+/// let mut output = Vec::new();
+/// serve(input.as_bytes(), &mut output, |method, _| {
+///     (method == "ping").then(|| Ok(json!("pong")))
+/// })?;
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "{\"id\":1,\"jsonrpc\":\"2.0\",\"result\":\"pong\"}\n"
+/// );
+/// # Ok::<(), rona::errors::RonaError>(())
+/// ```
+pub fn serve<H>(input: impl BufRead, mut output: impl Write, mut handler: H) -> Result<()>
+where
+    H: FnMut(&str, &Value) -> Option<Result<Value>>,
+{
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (response, stop) = handle_line(&line, &mut handler);
+        if let Some(response) = response {
+            writeln!(output, "{response}")?;
+            output.flush()?;
+        }
+        if stop {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn responses(input: &str) -> Vec<Value> {
+        let mut output = Vec::new();
+        serve(
+            input.as_bytes(),
+            &mut output,
+            |method, params| match method {
+                "echo" => Some(Ok(params.clone())),
+                "fail" => Some(Err(RonaError::UserCancelled)),
+                "strict" => Some(parse_params::<Vec<u8>>(params).map(|bytes| json!(bytes.len()))),
+                _ => None,
+            },
+        )
+        .unwrap();
+
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_serve_methods() {
+        let responses = responses(
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "echo", "params": {"a": 1}}
+{"jsonrpc": "2.0", "method": "echo"}
+
+{"jsonrpc": "2.0", "id": "two", "method": "fail"}
+{"jsonrpc": "2.0", "id": 3, "method": "nope"}
+{"jsonrpc": "2.0", "id": 4, "method": "strict", "params": {"a": 1}}
+"#,
+        );
+
+        assert_eq!(responses.len(), 4);
+        assert_eq!(responses[0]["result"], json!({"a": 1}));
+        assert_eq!(responses[1]["id"], "two");
+        assert_eq!(responses[1]["error"]["code"], METHOD_FAILED);
+        assert_eq!(responses[1]["error"]["data"]["exitCode"], 130);
+        assert_eq!(responses[2]["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(responses[3]["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_serve_errors_and_exit() {
+        let responses = responses(
+            r#"{not json
+{"jsonrpc": "2.0", "id": 1}
+{"jsonrpc": "2.0", "id": 2, "method": "exit"}
+{"jsonrpc": "2.0", "id": 3, "method": "echo"}
+"#,
+        );
+
+        assert_eq!(responses.len(), 3);
+        assert_eq!(responses[0]["error"]["code"], PARSE_ERROR);
+        assert_eq!(responses[1]["error"]["code"], INVALID_REQUEST);
+        assert_eq!(responses[1]["id"], 1);
+        assert_eq!(responses[2]["result"], Value::Null);
+    }
+}
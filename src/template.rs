@@ -7,14 +7,16 @@
 use chrono::Local;
 use regex::Regex;
 use std::collections::HashMap;
-use std::process::Command;
 
-use crate::errors::{Result, RonaError};
+use crate::{
+    errors::{Result, RonaError},
+    git::exec::GitCommand,
+};
 
 /// Template variables that can be used in commit message templates
 #[derive(Debug, Clone)]
 pub struct TemplateVariables {
-    pub commit_number: Option<u32>,
+    pub commit_number: Option<u64>,
     pub commit_type: String,
     pub branch_name: String,
     pub message: String,
@@ -22,6 +24,8 @@ pub struct TemplateVariables {
     pub time: String,
     pub author: String,
     pub email: String,
+    /// Whether the commit is a breaking change, `{breaking}` then being `!`
+    pub breaking: bool,
 }
 
 impl TemplateVariables {
@@ -30,7 +34,7 @@ impl TemplateVariables {
     /// # Errors
     /// * If git author information cannot be retrieved
     pub fn new(
-        commit_number: Option<u32>,
+        commit_number: Option<u64>,
         commit_type: String,
         branch_name: String,
         message: String,
@@ -47,9 +51,16 @@ impl TemplateVariables {
             time: now.format("%H:%M:%S").to_string(),
             author,
             email,
+            breaking: false,
         })
     }
 
+    /// The variables of a breaking change (`{breaking}` being `!`) when `breaking` is set.
+    #[must_use]
+    pub fn with_breaking(self, breaking: bool) -> Self {
+        Self { breaking, ..self }
+    }
+
     /// Converts the variables to a `HashMap` for template substitution
     #[must_use]
     pub fn to_map(&self) -> HashMap<String, String> {
@@ -62,6 +73,10 @@ impl TemplateVariables {
         map.insert("time".to_string(), self.time.clone());
         map.insert("author".to_string(), self.author.clone());
         map.insert("email".to_string(), self.email.clone());
+        map.insert(
+            "breaking".to_string(),
+            if self.breaking { "!" } else { "" }.to_string(),
+        );
 
         if let Some(commit_number) = self.commit_number {
             map.insert("commit_number".to_string(), commit_number.to_string());
@@ -129,6 +144,7 @@ pub fn validate_template(template: &str) -> Result<()> {
         "time",
         "author",
         "email",
+        "breaking",
     ];
 
     let regex = Regex::new(r"\{([^}]+)\}").map_err(|e| {
@@ -154,7 +170,7 @@ pub fn validate_template(template: &str) -> Result<()> {
 
 /// Gets the current git author name and email
 fn get_git_author_info() -> Result<(String, String)> {
-    let name_output = Command::new("git")
+    let name_output = GitCommand::new()
         .args(["config", "user.name"])
         .output()
         .map_err(|e| {
@@ -163,7 +179,7 @@ fn get_git_author_info() -> Result<(String, String)> {
             )))
         })?;
 
-    let email_output = Command::new("git")
+    let email_output = GitCommand::new()
         .args(["config", "user.email"])
         .output()
         .map_err(|e| {
@@ -198,6 +214,7 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "John Doe".to_string(),
             email: "john@example.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(template, &variables).unwrap();
@@ -219,12 +236,39 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "John Doe".to_string(),
             email: "john@example.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(template, &variables).unwrap();
         assert_eq!(result, "(fix on main) Fix bug");
     }
 
+    #[test]
+    fn test_template_breaking_change() {
+        let template = "{commit_type}{breaking}: {message}";
+        let variables = TemplateVariables {
+            commit_number: None,
+            commit_type: "feat".to_string(),
+            branch_name: "main".to_string(),
+            message: "Borrow the tokens".to_string(),
+            date: "2024-01-15".to_string(),
+            time: "14:30:00".to_string(),
+            author: "John Doe".to_string(),
+            email: "john@example.com".to_string(),
+            breaking: true,
+        };
+
+        assert!(validate_template(template).is_ok());
+        assert_eq!(
+            process_template(template, &variables).unwrap(),
+            "feat!: Borrow the tokens"
+        );
+        assert_eq!(
+            process_template(template, &variables.with_breaking(false)).unwrap(),
+            "feat: Borrow the tokens"
+        );
+    }
+
     #[test]
     fn test_template_validation_valid() {
         let template = "[{commit_number}] ({commit_type} on {branch_name}) {message}";
@@ -248,6 +292,7 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "Test Author".to_string(),
             email: "test@example.com".to_string(),
+            breaking: false,
         };
 
         let map = variables.to_map();
@@ -273,6 +318,7 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "Jane Doe".to_string(),
             email: "jane@company.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(template, &variables).unwrap();
@@ -294,6 +340,7 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "John Doe".to_string(),
             email: "john@example.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(template, &variables).unwrap();
@@ -312,6 +359,7 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "John Doe".to_string(),
             email: "john@example.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(template, &variables).unwrap();
@@ -346,6 +394,7 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "John Doe".to_string(),
             email: "john@example.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(template, &variables).unwrap();
@@ -374,6 +423,7 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "John Doe".to_string(),
             email: "john@example.com".to_string(),
+            breaking: false,
         };
 
         let result = process_template(template, &variables).unwrap();
@@ -404,6 +454,7 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "Jane Doe".to_string(),
             email: "jane@example.com".to_string(),
+            breaking: false,
         };
 
         // Test template WITH commit_number placeholder (produces empty brackets - the bug)
@@ -444,6 +495,7 @@ mod tests {
             time: "14:30:00".to_string(),
             author: "Test User".to_string(),
             email: "test@example.com".to_string(),
+            breaking: false,
         };
 
         let map = variables.to_map();
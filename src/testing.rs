@@ -0,0 +1,299 @@
+//! Test Fixtures
+//!
+//! [`TestRepo`] creates an isolated git repository in a temporary directory, with
+//! helpers to write files, stage and commit them, for the tests of rona and of tools
+//! using the library (`testing` cargo feature). [`MockRunner`] answers git commands
+//! with canned outputs instead, for the cases a real repository cannot easily reach
+//! (failures, hung remotes, huge histories).
+//!
+//! Git runs from the repository directory, never from the current one, so tests using
+//! separate fixtures run in parallel safely. Pair it with the `_in` operations and
+//! [`Repository::open`] rather than the ones reading the current directory.
+//!
+//! ```
+//! use rona::testing::TestRepo;
+//!
+//! let repo = TestRepo::new()?;
+//! repo.commit_file("README.md", "# Demo\n", "Initial commit")?;
+//! repo.write("src/main.rs", "fn main() {}\n")?;
+//!
+//! let status = repo.repository()?.status()?;
+//! assert_eq!(status.changed_files(), ["src/main.rs"]);
+//! # Ok::<(), rona::errors::RonaError>(())
+//! ```
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::{ExitStatus, Output},
+    sync::Mutex,
+};
+
+use tempfile::TempDir;
+
+use crate::{
+    errors::{GitError, Result, RonaError},
+    git::{
+        Repository,
+        exec::{GitCommand, GitRunner, SystemRunner},
+    },
+};
+
+/// A git repository in a temporary directory, removed when dropped.
+///
+/// The repository is on the `main` branch, with a local identity and commit signing
+/// and hooks turned off, so commits do not depend on the user's git configuration.
+#[derive(Debug)]
+pub struct TestRepo {
+    dir: TempDir,
+}
+
+impl TestRepo {
+    /// Creates an empty repository on the `main` branch.
+    ///
+    /// # Errors
+    /// * If the temporary directory cannot be created
+    /// * If `git init` or `git config` fails
+    pub fn new() -> Result<Self> {
+        let repo = Self {
+            dir: TempDir::new()?,
+        };
+
+        repo.git(&["init", "--quiet", "--initial-branch=main"])?;
+        for (key, value) in [
+            ("user.name", "Rona Tests"),
+            ("user.email", "tests@rona.invalid"),
+            ("commit.gpgsign", "false"),
+            ("tag.gpgsign", "false"),
+            ("core.hooksPath", "/dev/null"),
+        ] {
+            repo.git(&["config", key, value])?;
+        }
+
+        Ok(repo)
+    }
+
+    /// The working tree of the repository.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// The absolute path of a file of the working tree.
+    #[must_use]
+    pub fn file(&self, path: &str) -> PathBuf {
+        self.path().join(path)
+    }
+
+    /// The [`Repository`] handle of the fixture.
+    ///
+    /// # Errors
+    /// * If the repository cannot be found (e.g. `.git` was removed)
+    pub fn repository(&self) -> Result<Repository> {
+        Repository::open(self.path())
+    }
+
+    /// Runs git in the repository, returning its standard output. Git really runs, even
+    /// when a [`MockRunner`] is installed.
+    ///
+    /// # Errors
+    /// * `GitError::CommandFailed` - If git exits with an error
+    pub fn git(&self, args: &[&str]) -> Result<String> {
+        let output = SystemRunner.output(GitCommand::new().current_dir(self.path()).args(args))?;
+
+        if !output.status.success() {
+            return Err(RonaError::Git(GitError::CommandFailed {
+                command: format!("git {}", args.join(" ")),
+                output: String::from_utf8_lossy(&output.stderr).to_string(),
+            }));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    /// Writes a file of the working tree, creating its parent directories. The file is
+    /// left untracked or modified (dirty).
+    ///
+    /// # Errors
+    /// * If the file cannot be written
+    pub fn write(&self, path: &str, contents: &str) -> Result<()> {
+        let file = self.file(path);
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(file, contents)?;
+        Ok(())
+    }
+
+    /// Stages files (`git add`).
+    ///
+    /// # Errors
+    /// * If `git add` fails (e.g. a path matches no file)
+    pub fn stage(&self, paths: &[&str]) -> Result<()> {
+        let mut args = vec!["add", "--"];
+        args.extend(paths);
+        self.git(&args).map(drop)
+    }
+
+    /// Commits the staged changes, returning the hash of the commit.
+    ///
+    /// # Errors
+    /// * If `git commit` fails (e.g. nothing is staged)
+    pub fn commit(&self, message: &str) -> Result<String> {
+        self.git(&["commit", "--quiet", "--message", message])?;
+        Ok(self.git(&["rev-parse", "HEAD"])?.trim().to_string())
+    }
+
+    /// Writes, stages and commits a file, returning the hash of the commit.
+    ///
+    /// # Errors
+    /// * If the file cannot be written, staged or committed
+    pub fn commit_file(&self, path: &str, contents: &str, message: &str) -> Result<String> {
+        self.write(path, contents)?;
+        self.stage(&[path])?;
+        self.commit(message)
+    }
+}
+
+/// A [`GitRunner`] answering git commands with canned outputs instead of running git,
+/// and recording the arguments of the commands it was asked to run. Install it with
+/// [`with_runner`](crate::git::exec::with_runner).
+///
+/// A command gets the last response registered for a prefix of its arguments; the
+/// commands without one fail with exit code 1 and an `unexpected git command` error.
+///
+/// ```
+/// use rona::{
+///     git::{exec::with_runner, get_stash_count, is_head_detached},
+///     testing::MockRunner,
+/// };
+/// use std::sync::Arc;
+///
+/// let mock = Arc::new(MockRunner::new());
+/// mock.respond(&["stash", "list"], "stash@{0}\nstash@{1}\n");
+/// mock.fail(&["symbolic-ref"], 1, "");
+///
+/// assert_eq!(with_runner(mock.clone(), get_stash_count)?, 2);
+/// assert!(with_runner(mock.clone(), is_head_detached)?);
+/// assert_eq!(mock.calls()[0], ["stash", "list", "--format=%gd"]);
+/// # Ok::<(), rona::errors::RonaError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct MockRunner {
+    responses: Mutex<Vec<MockResponse>>,
+    calls: Mutex<Vec<Vec<String>>>,
+}
+
+/// A canned answer of a [`MockRunner`].
+#[derive(Debug)]
+struct MockResponse {
+    prefix: Vec<String>,
+    code: i32,
+    stdout: String,
+    stderr: String,
+}
+
+impl MockRunner {
+    /// A runner without responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers the commands whose arguments start with `args` with `stdout` and exit code 0.
+    pub fn respond(&self, args: &[&str], stdout: &str) -> &Self {
+        self.push(args, 0, stdout, "")
+    }
+
+    /// Fails the commands whose arguments start with `args` with the exit code and `stderr`.
+    pub fn fail(&self, args: &[&str], code: i32, stderr: &str) -> &Self {
+        self.push(args, code, "", stderr)
+    }
+
+    /// The arguments of the commands run so far, in order.
+    ///
+    /// # Panics
+    /// If a thread panicked while recording a command
+    #[must_use]
+    pub fn calls(&self) -> Vec<Vec<String>> {
+        self.calls.lock().expect("mock runner poisoned").clone()
+    }
+
+    fn push(&self, args: &[&str], code: i32, stdout: &str, stderr: &str) -> &Self {
+        self.responses
+            .lock()
+            .expect("mock runner poisoned")
+            .push(MockResponse {
+                prefix: args.iter().map(ToString::to_string).collect(),
+                code,
+                stdout: stdout.to_string(),
+                stderr: stderr.to_string(),
+            });
+        self
+    }
+
+    /// Records a command and picks its response.
+    fn answer(&self, command: &GitCommand) -> Output {
+        let args: Vec<String> = command
+            .get_args()
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        self.calls
+            .lock()
+            .expect("mock runner poisoned")
+            .push(args.clone());
+
+        let responses = self.responses.lock().expect("mock runner poisoned");
+        let (code, stdout, stderr) = responses
+            .iter()
+            .rev()
+            .find(|response| args.starts_with(&response.prefix))
+            .map_or_else(
+                || {
+                    (
+                        1,
+                        String::new(),
+                        format!("unexpected git command: {command}"),
+                    )
+                },
+                |response| {
+                    (
+                        response.code,
+                        response.stdout.clone(),
+                        response.stderr.clone(),
+                    )
+                },
+            );
+
+        Output {
+            status: exit_status(code),
+            stdout: stdout.into_bytes(),
+            stderr: stderr.into_bytes(),
+        }
+    }
+}
+
+impl GitRunner for MockRunner {
+    fn output(&self, command: &GitCommand) -> io::Result<Output> {
+        Ok(self.answer(command))
+    }
+
+    fn status(&self, command: &GitCommand) -> io::Result<ExitStatus> {
+        Ok(self.answer(command).status)
+    }
+}
+
+/// An exit status with the given code.
+#[cfg(unix)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::unix::process::ExitStatusExt;
+    ExitStatus::from_raw(code << 8)
+}
+
+/// An exit status with the given code.
+#[cfg(windows)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::windows::process::ExitStatusExt;
+    ExitStatus::from_raw(code.cast_unsigned())
+}
@@ -0,0 +1,458 @@
+//! Theme Module for Rona
+//!
+//! Styling of the interactive prompts (symbols and colors) read from the `[theme]`
+//! configuration section, and the emoji switch (`--no-emoji`, `RONA_NO_EMOJI` or
+//! `emoji = false`) for terminals rendering emoji poorly. Without emoji, prompts use
+//! ASCII symbols and emoji are stripped from the logged messages.
+//!
+//! Styling also depends on where output goes: colors are disabled by `NO_COLOR`, and
+//! colors and emoji are only used on terminals unless `CLICOLOR_FORCE` is set.
+
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+use inquire::ui::{Attributes, Color, RenderConfig, StyleSheet, Styled};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+/// Environment variable disabling emoji when set to anything but `0` or `false`.
+pub const NO_EMOJI_VARIABLE: &str = "RONA_NO_EMOJI";
+
+/// The `[theme]` configuration section. Unset values keep the default theme.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ThemeConfig {
+    /// Symbol before a prompt (defaults to `$`)
+    pub prompt_prefix: Option<String>,
+    /// Symbol before the highlighted option of a list (defaults to `➠`)
+    pub highlighted_prefix: Option<String>,
+    /// Symbol before an answered prompt (defaults to `✔`)
+    pub success_glyph: Option<String>,
+    /// Symbol before a validation error (defaults to `❌`)
+    pub error_glyph: Option<String>,
+    /// Symbol of a checked option (defaults to `☑`)
+    pub selected_checkbox: Option<String>,
+    /// Symbol of an unchecked option (defaults to `☐`)
+    pub unselected_checkbox: Option<String>,
+    /// Color of the prompt labels (defaults to `light_cyan`)
+    pub prompt_color: Option<String>,
+    /// Color of the answers (defaults to `light_magenta`)
+    pub answer_color: Option<String>,
+    /// Color of the help messages (defaults to `dark_yellow`)
+    pub help_color: Option<String>,
+    /// Whether emoji are used (defaults to `true`)
+    pub emoji: Option<bool>,
+}
+
+/// The resolved theme of the interactive prompts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// Symbol before a prompt
+    pub prompt_prefix: &'static str,
+    /// Symbol before the highlighted option of a list
+    pub highlighted_prefix: &'static str,
+    /// Symbol before an answered prompt
+    pub success_glyph: &'static str,
+    /// Symbol before a validation error
+    pub error_glyph: &'static str,
+    /// Symbol of a checked option
+    pub selected_checkbox: &'static str,
+    /// Symbol of an unchecked option
+    pub unselected_checkbox: &'static str,
+    /// Symbol shown when options are hidden above
+    pub scroll_up_prefix: &'static str,
+    /// Symbol shown when options are hidden below
+    pub scroll_down_prefix: &'static str,
+    /// Color of the prompt labels
+    pub prompt_color: Color,
+    /// Color of the answers
+    pub answer_color: Color,
+    /// Color of the help messages
+    pub help_color: Color,
+    /// Whether emoji are used
+    pub emoji: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            prompt_prefix: "$",
+            highlighted_prefix: "➠",
+            success_glyph: "✔",
+            error_glyph: "❌",
+            selected_checkbox: "☑",
+            unselected_checkbox: "☐",
+            scroll_up_prefix: "⇞",
+            scroll_down_prefix: "⇟",
+            prompt_color: Color::LightCyan,
+            answer_color: Color::LightMagenta,
+            help_color: Color::DarkYellow,
+            emoji: true,
+        }
+    }
+}
+
+impl Theme {
+    /// The default theme using ASCII symbols only.
+    #[must_use]
+    pub fn ascii() -> Self {
+        Self {
+            highlighted_prefix: ">",
+            success_glyph: "+",
+            error_glyph: "x",
+            selected_checkbox: "[x]",
+            unselected_checkbox: "[ ]",
+            scroll_up_prefix: "^",
+            scroll_down_prefix: "v",
+            emoji: false,
+            ..Self::default()
+        }
+    }
+
+    /// Builds the theme from the `[theme]` configuration section.
+    ///
+    /// Emoji are disabled by `no_emoji` (the `--no-emoji` flag), the `RONA_NO_EMOJI`
+    /// environment variable or `emoji = false`, the default symbols becoming ASCII ones.
+    /// Configured symbols are kept either way; invalid colors are reported and ignored.
+    #[must_use]
+    pub fn new(config: Option<&ThemeConfig>, no_emoji: bool) -> Self {
+        let config = config.cloned().unwrap_or_default();
+        let emoji = !no_emoji && !no_emoji_from_env() && config.emoji.unwrap_or(true);
+        let base = if emoji {
+            Self::default()
+        } else {
+            Self::ascii()
+        };
+
+        let symbol = |value: Option<String>, default: &'static str| -> &'static str {
+            value.map_or(default, |value| Box::leak(value.into_boxed_str()))
+        };
+        let color = |value: Option<String>, default: Color| {
+            value.map_or(default, |value| {
+                parse_color(&value).unwrap_or_else(|| {
+                    warn!("⚠️  Unknown theme color '{value}', using the default one.");
+                    default
+                })
+            })
+        };
+
+        Self {
+            prompt_prefix: symbol(config.prompt_prefix, base.prompt_prefix),
+            highlighted_prefix: symbol(config.highlighted_prefix, base.highlighted_prefix),
+            success_glyph: symbol(config.success_glyph, base.success_glyph),
+            error_glyph: symbol(config.error_glyph, base.error_glyph),
+            selected_checkbox: symbol(config.selected_checkbox, base.selected_checkbox),
+            unselected_checkbox: symbol(config.unselected_checkbox, base.unselected_checkbox),
+            prompt_color: color(config.prompt_color, base.prompt_color),
+            answer_color: color(config.answer_color, base.answer_color),
+            help_color: color(config.help_color, base.help_color),
+            emoji,
+            ..base
+        }
+    }
+
+    /// Builds the render configuration of the inquire prompts, without any color or
+    /// text attribute when `colored` is false.
+    #[must_use]
+    pub fn render_config(&self, colored: bool) -> RenderConfig<'static> {
+        if !colored {
+            let mut render_config = RenderConfig::empty();
+
+            render_config.prompt_prefix = Styled::new(self.prompt_prefix);
+            render_config.answered_prompt_prefix = Styled::new(self.success_glyph);
+            render_config.highlighted_option_prefix = Styled::new(self.highlighted_prefix);
+            render_config.selected_checkbox = Styled::new(self.selected_checkbox);
+            render_config.unselected_checkbox = Styled::new(self.unselected_checkbox);
+            render_config.scroll_up_prefix = Styled::new(self.scroll_up_prefix);
+            render_config.scroll_down_prefix = Styled::new(self.scroll_down_prefix);
+            render_config.error_message = render_config
+                .error_message
+                .with_prefix(Styled::new(self.error_glyph));
+
+            return render_config;
+        }
+
+        let mut render_config = RenderConfig::default();
+
+        // Prefix/icons
+        render_config.prompt_prefix = Styled::new(self.prompt_prefix).with_fg(Color::LightRed);
+        render_config.answered_prompt_prefix =
+            Styled::new(self.success_glyph).with_fg(Color::LightGreen);
+        render_config.highlighted_option_prefix =
+            Styled::new(self.highlighted_prefix).with_fg(Color::LightBlue);
+        render_config.selected_checkbox =
+            Styled::new(self.selected_checkbox).with_fg(Color::LightGreen);
+        render_config.unselected_checkbox =
+            Styled::new(self.unselected_checkbox).with_fg(Color::Black);
+        render_config.scroll_up_prefix = Styled::new(self.scroll_up_prefix).with_fg(Color::Black);
+        render_config.scroll_down_prefix =
+            Styled::new(self.scroll_down_prefix).with_fg(Color::Black);
+
+        // Input prompt label
+        render_config.prompt = StyleSheet::new()
+            .with_fg(self.prompt_color)
+            .with_attr(Attributes::BOLD);
+
+        // Help under the input
+        render_config.help_message = StyleSheet::new()
+            .with_fg(self.help_color)
+            .with_attr(Attributes::ITALIC);
+
+        // Validation error
+        render_config.error_message = render_config
+            .error_message
+            .with_prefix(Styled::new(self.error_glyph).with_fg(Color::LightRed));
+
+        // Shown after submit (echoed answer)
+        render_config.answer = StyleSheet::new()
+            .with_fg(self.answer_color)
+            .with_attr(Attributes::BOLD);
+
+        // Optional: default/placeholder styles
+        render_config.default_value = StyleSheet::new().with_fg(Color::LightBlue);
+        render_config.placeholder = StyleSheet::new().with_fg(Color::Black);
+
+        render_config
+    }
+}
+
+/// An output stream of the process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    /// Standard output (messages, command results)
+    Stdout,
+    /// Standard error (errors, warnings, interactive prompts)
+    Stderr,
+}
+
+impl Stream {
+    /// Whether the stream is a terminal.
+    #[must_use]
+    pub fn is_terminal(self) -> bool {
+        match self {
+            Self::Stdout => io::stdout().is_terminal(),
+            Self::Stderr => io::stderr().is_terminal(),
+        }
+    }
+}
+
+/// Whether an environment variable is set to a value other than empty or `0`.
+fn env_flag(name: &str) -> bool {
+    env::var(name).is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Whether the output of a stream may be colored.
+///
+/// `NO_COLOR` disables colors and `CLICOLOR_FORCE` forces them; otherwise
+/// only terminals are colored.
+#[must_use]
+pub fn colors_enabled(stream: Stream) -> bool {
+    !env_flag("NO_COLOR") && (env_flag("CLICOLOR_FORCE") || stream.is_terminal())
+}
+
+/// Whether the output of a stream may be decorated with emoji: only terminals are,
+/// unless `CLICOLOR_FORCE` is set.
+#[must_use]
+pub fn decorations_enabled(stream: Stream) -> bool {
+    env_flag("CLICOLOR_FORCE") || stream.is_terminal()
+}
+
+/// Removes ANSI escape sequences (colors, cursor movements, hyperlinks) from a text.
+///
+/// # Examples
+///
+/// ```
+/// use rona::theme::strip_ansi;
+///
+/// assert_eq!(strip_ansi("\x1b[1;31merror\x1b[0m: failed"), "error: failed");
+/// ```
+#[must_use]
+pub fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            stripped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // CSI: parameters until a final byte in `@`..=`~`
+            Some('[') => for _ in chars.by_ref().take_while(|c| !('@'..='~').contains(c)) {},
+            // OSC: until BEL or the ST escape
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' || (c == '\x1b' && chars.next() == Some('\\')) {
+                        break;
+                    }
+                }
+            }
+            // Other escapes are two characters long
+            _ => {}
+        }
+    }
+
+    stripped
+}
+
+/// Whether `RONA_NO_EMOJI` disables emoji.
+fn no_emoji_from_env() -> bool {
+    env::var(NO_EMOJI_VARIABLE).is_ok_and(|value| !matches!(value.trim(), "" | "0" | "false"))
+}
+
+/// Parses a color name (`red`, `dark_red`, `grey`...) or a `#rrggbb` hex code.
+///
+/// Plain color names are the light variants.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::ui::Color;
+/// use rona::theme::parse_color;
+///
+/// assert_eq!(parse_color("dark_green"), Some(Color::DarkGreen));
+/// assert_eq!(parse_color("#ff8000"), Some(Color::Rgb { r: 255, g: 128, b: 0 }));
+/// assert_eq!(parse_color("orange"), None);
+/// ```
+#[must_use]
+pub fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim().to_lowercase().replace(['-', ' '], "_");
+
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |index: usize| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok();
+        return Some(Color::Rgb {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        });
+    }
+
+    let color = match value.as_str() {
+        "black" => Color::Black,
+        "white" => Color::White,
+        "grey" | "gray" => Color::Grey,
+        "dark_grey" | "dark_gray" => Color::DarkGrey,
+        "red" | "light_red" => Color::LightRed,
+        "dark_red" => Color::DarkRed,
+        "green" | "light_green" => Color::LightGreen,
+        "dark_green" => Color::DarkGreen,
+        "yellow" | "light_yellow" => Color::LightYellow,
+        "dark_yellow" => Color::DarkYellow,
+        "blue" | "light_blue" => Color::LightBlue,
+        "dark_blue" => Color::DarkBlue,
+        "magenta" | "light_magenta" => Color::LightMagenta,
+        "dark_magenta" => Color::DarkMagenta,
+        "cyan" | "light_cyan" => Color::LightCyan,
+        "dark_cyan" => Color::DarkCyan,
+        _ => return None,
+    };
+
+    Some(color)
+}
+
+/// Whether a character is an emoji, a pictographic symbol or an emoji modifier.
+const fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF // Pictographs, emoticons, transport, supplemental symbols
+            | 0x2600..=0x27BF // Miscellaneous symbols and dingbats
+            | 0x2B00..=0x2BFF // Miscellaneous symbols and arrows
+            | 0x2300..=0x23FF // Miscellaneous technical (⌛, ⏳...)
+            | 0x2190..=0x21FF // Arrows
+            | 0x2139 // ℹ
+            | 0x200D // Zero width joiner
+            | 0x20E3 // Combining keycap
+            | 0xFE0F // Emoji variation selector
+    )
+}
+
+/// Removes emoji from a message, with the spaces following them.
+///
+/// # Examples
+///
+/// ```
+/// use rona::theme::strip_emoji;
+///
+/// assert_eq!(strip_emoji("⚠️  Nothing to commit"), "Nothing to commit");
+/// assert_eq!(strip_emoji("\n✅ Commit message created!"), "\nCommit message created!");
+/// ```
+#[must_use]
+pub fn strip_emoji(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if is_emoji(c) {
+            while chars.next_if_eq(&' ').is_some() {}
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_emoji() {
+        assert_eq!(strip_emoji("🚨 ERROR: No file"), "ERROR: No file");
+        assert_eq!(strip_emoji("📝 Interactive mode"), "Interactive mode");
+        assert_eq!(strip_emoji("Done ✅ "), "Done ");
+        assert_eq!(strip_emoji("Plain text: a -> b"), "Plain text: a -> b");
+        assert_eq!(strip_emoji("Café"), "Café");
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[32m+ added\x1b[m"), "+ added");
+        assert_eq!(
+            strip_ansi("\x1b]8;;https://example.com\x07link\x1b]8;;\x1b\\ done"),
+            "link done"
+        );
+        assert_eq!(strip_ansi("plain [text]"), "plain [text]");
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("Light Blue"), Some(Color::LightBlue));
+        assert_eq!(parse_color("blue"), Some(Color::LightBlue));
+        assert_eq!(parse_color("dark-grey"), Some(Color::DarkGrey));
+        assert_eq!(parse_color("#00ff0"), None);
+        assert_eq!(parse_color("#gg0000"), None);
+    }
+
+    #[test]
+    fn test_theme_from_config() {
+        let config = ThemeConfig {
+            prompt_prefix: Some(">>".to_string()),
+            answer_color: Some("dark_green".to_string()),
+            emoji: Some(false),
+            ..ThemeConfig::default()
+        };
+
+        let theme = Theme::new(Some(&config), false);
+
+        assert!(!theme.emoji);
+        assert_eq!(theme.prompt_prefix, ">>");
+        assert_eq!(theme.success_glyph, "+");
+        assert_eq!(theme.answer_color, Color::DarkGreen);
+        assert_eq!(theme.help_color, Color::DarkYellow);
+    }
+
+    #[test]
+    fn test_theme_no_emoji_flag() {
+        let theme = Theme::new(None, true);
+
+        assert_eq!(theme, Theme::ascii());
+        assert_eq!(theme.render_config(true).selected_checkbox.content, "[x]");
+        assert_eq!(theme.render_config(false).prompt.fg, None);
+    }
+}
@@ -1,7 +1,7 @@
 //! Utility Functions Module for Rona
 //!
 //! This module provides common utility functions and traits used throughout the application, including
-//! - Message formatting and display
+//! - Message formatting and display, localized through [`crate::i18n`]
 //! - File and directory operations
 //! - Error handling utilities
 //!
@@ -19,6 +19,8 @@
 //! - File path validation and checking
 //! - Project root directory detection
 //! - List formatting utilities
+//! - Cross-platform path normalization, editor command building and program lookup
+//! - Paging of long output through `$GIT_PAGER`/`$PAGER`
 //!
 //! # Error Handling
 //!
@@ -28,15 +30,26 @@
 use std::{
     env,
     fmt::Display,
-    io::{Error as IoError, ErrorKind},
-    path::{Path, PathBuf},
+    io::{self, Error as IoError, ErrorKind, IsTerminal, Write},
+    path::{MAIN_SEPARATOR, Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+use log::{error, info};
+
+use crate::{
+    errors::GitError,
+    i18n::{tr, tr_args},
 };
 
 /// Trait for message types.
 #[doc(hidden)]
 trait MessageType {
-    /// The emoji prefix for each message type (e.g., "🚨 ERROR")
-    const PREFIX: &'static str;
+    /// The emoji prefix for each message type (e.g., "🚨")
+    const EMOJI: &'static str;
+
+    /// The message key of the label following the emoji (e.g., "ERROR")
+    const LABEL_KEY: &'static str;
 
     /// Whether to output to stderr (true) or stdout (false)
     const TO_STDERR: bool = false;
@@ -48,7 +61,8 @@ struct Error;
 
 // Implement the MessageType trait for each type
 impl MessageType for Error {
-    const PREFIX: &'static str = "🚨 ERROR";
+    const EMOJI: &'static str = "🚨";
+    const LABEL_KEY: &'static str = "utils.error";
     const TO_STDERR: bool = true;
 }
 
@@ -61,7 +75,7 @@ impl MessageType for Error {
 /// # Returns
 /// * String - The formatted message.
 fn format_message<T: MessageType>(title: &str, details: &str) -> String {
-    format!("{}: {title}\n\n{details}", T::PREFIX)
+    format!("{} {}: {title}\n\n{details}", T::EMOJI, tr(T::LABEL_KEY))
 }
 
 /// Formats a message with suggestion.
@@ -93,9 +107,9 @@ fn format_message_with_suggestion<T: MessageType>(
 fn print_message_with_suggestion<T: MessageType>(title: &str, details: &str, suggestion: &str) {
     let message = format_message_with_suggestion::<T>(title, details, suggestion);
     if T::TO_STDERR {
-        eprintln!("{message}");
+        error!("{message}");
     } else {
-        println!("{message}");
+        info!("{message}");
     }
 }
 
@@ -124,6 +138,37 @@ pub fn format_list<T: Display>(items: &[T]) -> String {
         .join("\n")
 }
 
+/// Formats a duration in seconds as a rough age (e.g. `3 hours ago`).
+///
+/// # Arguments
+/// - `seconds`: The elapsed time, negative values (clock skew) reading as `just now`.
+///
+/// # Returns
+/// * String - The age in the largest whole unit.
+#[must_use]
+pub fn format_age(seconds: i64) -> String {
+    const UNITS: [(i64, &str, &str); 6] = [
+        (365 * 24 * 3600, "utils.age.year", "utils.age.years"),
+        (30 * 24 * 3600, "utils.age.month", "utils.age.months"),
+        (7 * 24 * 3600, "utils.age.week", "utils.age.weeks"),
+        (24 * 3600, "utils.age.day", "utils.age.days"),
+        (3600, "utils.age.hour", "utils.age.hours"),
+        (60, "utils.age.minute", "utils.age.minutes"),
+    ];
+
+    UNITS
+        .iter()
+        .find(|(unit, ..)| seconds >= *unit)
+        .map_or_else(
+            || tr("utils.just_now"),
+            |(unit, singular, plural)| {
+                let count = seconds / unit;
+                let key = if count == 1 { singular } else { plural };
+                tr_args(key, &[("count", &count)])
+            },
+        )
+}
+
 /// Checks if a file path starts with or is contained within a folder path.
 ///
 /// # Arguments
@@ -163,43 +208,692 @@ pub fn check_for_file_in_folder(file_path: &Path, folder_path: &Path) -> Result<
     Ok(file_parent.starts_with(folder_path))
 }
 
-/// Finds the root directory of a project by searching for a `.git` directory.
+/// Location of a git repository discovered on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepositoryLocation {
+    /// The git directory (`.git`, `.git/worktrees/<name>` or the bare repository itself)
+    pub git_dir: PathBuf,
+    /// The working tree, or `None` for bare repositories
+    pub work_tree: Option<PathBuf>,
+}
+
+/// Resolves the git directory a `.git` entry points to.
+///
+/// In linked worktrees and submodules `.git` is a file containing a
+/// `gitdir: <path>` pointer, whose path may be relative to the file's directory.
+///
+/// # Arguments
+/// * `dot_git` - Path of the `.git` directory or file
+///
+/// # Errors
+/// * If the `.git` file cannot be read
+/// * If the `.git` file does not contain a `gitdir:` pointer
+///
+/// # Returns
+/// * `PathBuf` - The resolved git directory
+pub fn resolve_git_dir(dot_git: &Path) -> Result<PathBuf, IoError> {
+    if dot_git.is_dir() {
+        return Ok(dot_git.to_path_buf());
+    }
+
+    let content = std::fs::read_to_string(dot_git)?;
+    let pointer = content
+        .lines()
+        .find_map(|line| line.strip_prefix("gitdir:"))
+        .map(str::trim)
+        .filter(|pointer| !pointer.is_empty())
+        .ok_or_else(|| {
+            IoError::new(
+                ErrorKind::InvalidData,
+                format!("Invalid gitdir pointer in {}", dot_git.display()),
+            )
+        })?;
+
+    let base = dot_git.parent().unwrap_or_else(|| Path::new(""));
+
+    Ok(base.join(pointer))
+}
+
+/// Whether a directory looks like a bare repository.
+fn is_bare_repository(dir: &Path) -> bool {
+    dir.join("HEAD").is_file() && dir.join("objects").is_dir() && dir.join("refs").is_dir()
+}
+
+/// Discovers the repository containing `start`, honouring explicit overrides.
+///
+/// Mirrors git's own rules: when `git_dir` (`GIT_DIR`) is set, the working tree is
+/// `work_tree` (`GIT_WORK_TREE`) or the starting directory. Otherwise parent
+/// directories are searched for a `.git` directory or `gitdir:` file, or a bare
+/// repository.
+///
+/// # Arguments
+/// * `start` - The directory to start searching from
+/// * `git_dir` - Explicit git directory, relative paths being resolved from `start`
+/// * `work_tree` - Explicit working tree, relative paths being resolved from `start`
+///
+/// # Errors
+/// * If no repository is found
+/// * If a `.git` file cannot be resolved
+pub fn discover_repository_from(
+    start: &Path,
+    git_dir: Option<&Path>,
+    work_tree: Option<&Path>,
+) -> Result<RepositoryLocation, IoError> {
+    if let Some(git_dir) = git_dir {
+        return Ok(RepositoryLocation {
+            git_dir: start.join(git_dir),
+            work_tree: Some(start.join(work_tree.unwrap_or(start))),
+        });
+    }
+
+    for dir in start.ancestors() {
+        let dot_git = dir.join(".git");
+
+        if dot_git.exists() {
+            return Ok(RepositoryLocation {
+                git_dir: resolve_git_dir(&dot_git)?,
+                work_tree: Some(work_tree.map_or_else(|| dir.to_path_buf(), |w| start.join(w))),
+            });
+        }
+
+        if is_bare_repository(dir) {
+            return Ok(RepositoryLocation {
+                git_dir: dir.to_path_buf(),
+                work_tree: work_tree.map(|w| start.join(w)),
+            });
+        }
+    }
+
+    // Wrapped so the error keeps the "not a repository" exit status once converted
+    Err(IoError::new(
+        ErrorKind::NotFound,
+        GitError::RepositoryNotFound,
+    ))
+}
+
+/// Discovers the repository containing the current directory.
+///
+/// The `GIT_DIR` and `GIT_WORK_TREE` environment variables are honoured.
+///
+/// # Errors
+/// * If getting the current directory fails
+/// * If no repository is found
+pub fn discover_repository() -> Result<RepositoryLocation, IoError> {
+    let git_dir = env::var_os("GIT_DIR").map(PathBuf::from);
+    let work_tree = env::var_os("GIT_WORK_TREE").map(PathBuf::from);
+
+    discover_repository_from(
+        &env::current_dir()?,
+        git_dir.as_deref(),
+        work_tree.as_deref(),
+    )
+}
+
+/// Finds the root directory of the project's working tree.
+///
+/// Works from any subdirectory, in linked worktrees and submodules (where `.git`
+/// is a file) and with `GIT_DIR`/`GIT_WORK_TREE` overrides.
 ///
 /// # Errors
 /// * If getting the current directory fails
 /// * If the project root is not found
+/// * If the repository is bare and has no working tree
 ///
 /// Returns:
 /// * `Ok(PathBuf)` - The path to the project root directory
 /// * `Err(std::io::Error)` - If there's an error processing the paths
 pub fn find_project_root() -> Result<PathBuf, IoError> {
-    let mut current_dir = env::current_dir()?;
+    discover_repository()?
+        .work_tree
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "Bare repository has no working tree"))
+}
+
+/// Normalizes a user-supplied path to the forward-slash form git uses in its output.
+///
+/// On Windows, users naturally type `src\main.rs` while `git status` reports
+/// `src/main.rs`. On Unix the backslash is a valid filename (and glob escape)
+/// character, so the path is returned unchanged.
+///
+/// # Arguments
+/// * `path` - The path as typed by the user
+///
+/// # Returns
+/// * `String` - The path using `/` as separator
+#[must_use]
+pub fn to_git_path(path: &str) -> String {
+    normalize_separators(path, MAIN_SEPARATOR)
+}
 
-    while current_dir.parent().is_some() {
-        let git_dir = current_dir.join(".git");
-        if git_dir.exists() {
-            return Ok(current_dir);
+/// Replaces the given platform separator with `/`.
+fn normalize_separators(path: &str, separator: char) -> String {
+    if separator == '/' {
+        path.to_string()
+    } else {
+        path.replace(separator, "/")
+    }
+}
+
+/// Splits a command line into its program and arguments.
+///
+/// Supports editors configured with arguments (e.g. `code --wait`) and quoted
+/// program paths containing spaces (e.g. `"C:\Program Files\Editor\editor.exe" -w`).
+/// Backslashes are kept as-is so Windows paths survive untouched.
+///
+/// # Arguments
+/// * `command_line` - The command line to split
+///
+/// # Returns
+/// * `Vec<String>` - The program followed by its arguments
+#[must_use]
+pub fn split_command_line(command_line: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut has_token = false;
+
+    for character in command_line.chars() {
+        match (quote, character) {
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), c) => current.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(character);
+                has_token = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if has_token {
+                    parts.push(std::mem::take(&mut current));
+                    has_token = false;
+                }
+            }
+            (None, c) => {
+                current.push(c);
+                has_token = true;
+            }
         }
+    }
+
+    if has_token {
+        parts.push(current);
+    }
+
+    parts
+}
+
+/// Finds the executable run for a program: the path itself when it contains a
+/// separator, otherwise the first match in `PATH` (with the `PATHEXT` extensions on
+/// Windows).
+///
+/// # Returns
+/// * `None` - If the program cannot be found
+///
+/// # Examples
+///
+/// ```no_run
+/// use rona::utils::find_program;
+///
+/// if let Some(path) = find_program("git") {
+///     println!("git is {}", path.display());
+/// }
+/// ```
+#[must_use]
+pub fn find_program(program: &str) -> Option<PathBuf> {
+    let path = Path::new(program);
+    if path.components().count() > 1 {
+        return path.is_file().then(|| path.to_path_buf());
+    }
+
+    let extensions: Vec<String> = if cfg!(windows) {
+        env::var("PATHEXT")
+            .unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string())
+            .split(';')
+            .map(str::to_string)
+            .chain(std::iter::once(String::new()))
+            .collect()
+    } else {
+        vec![String::new()]
+    };
+
+    env::split_paths(&env::var_os("PATH")?)
+        .flat_map(|dir| {
+            extensions
+                .iter()
+                .map(move |extension| dir.join(format!("{program}{extension}")))
+        })
+        .find(|candidate| candidate.is_file())
+}
 
-        let parent_dir = current_dir.parent().ok_or(IoError::new(
+/// Whether a program must be launched through `cmd /C` on Windows.
+///
+/// Batch shims such as `code.cmd` (installed by VS Code, npm, scoop...) and
+/// bare names that may resolve to one cannot be spawned directly.
+fn requires_cmd_shim(program: &str) -> bool {
+    Path::new(program)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_none_or(|extension| {
+            extension.eq_ignore_ascii_case("cmd") || extension.eq_ignore_ascii_case("bat")
+        })
+}
+
+/// GUI editors returning as soon as the file is opened, with the flag making them
+/// wait until it is closed.
+const EDITOR_WAIT_FLAGS: [(&str, &str); 7] = [
+    ("code", "--wait"),
+    ("code-insiders", "--wait"),
+    ("codium", "--wait"),
+    ("cursor", "--wait"),
+    ("subl", "-w"),
+    ("zed", "--wait"),
+    ("mate", "-w"),
+];
+
+/// The flag making a known GUI editor wait until the file is closed.
+fn editor_wait_flag(program: &str) -> Option<&'static str> {
+    let name = Path::new(program).file_stem()?.to_str()?;
+
+    EDITOR_WAIT_FLAGS
+        .iter()
+        .find(|(editor, _)| name.eq_ignore_ascii_case(editor))
+        .map(|(_, flag)| *flag)
+}
+
+/// Builds the command used to open a file in the configured editor.
+///
+/// The editor setting may contain arguments (`code --wait`, `subl -w`), which are
+/// passed before the configured `editor_args` and the file path. Known GUI editors
+/// (VS Code, Sublime Text, Zed...) get their wait flag when it is not given, so the
+/// command blocks until the file is closed. On Windows, `.cmd`/`.bat` shims are
+/// launched through `cmd /C`.
+///
+/// # Arguments
+/// * `editor` - The editor setting from the configuration
+/// * `editor_args` - The `editor_args` setting from the configuration
+/// * `file` - The file to open
+///
+/// # Errors
+/// * If the editor setting is empty
+///
+/// # Returns
+/// * `Command` - The command ready to be spawned
+pub fn build_editor_command(
+    editor: &str,
+    editor_args: &[String],
+    file: &Path,
+) -> Result<Command, IoError> {
+    let mut parts = split_command_line(editor);
+    parts.extend(editor_args.iter().cloned());
+
+    if parts.is_empty() {
+        return Err(IoError::new(
             ErrorKind::InvalidInput,
-            "Invalid file path: cannot get parent directory",
-        ))?;
+            "Editor command is empty",
+        ));
+    }
+    let program = parts.remove(0);
 
-        current_dir = parent_dir.to_path_buf();
+    if let Some(flag) = editor_wait_flag(&program)
+        && !parts.iter().any(|part| part == flag)
+    {
+        parts.push(flag.to_string());
     }
 
-    Err(IoError::new(
-        ErrorKind::InvalidInput,
-        "Invalid file path: cannot get parent directory",
-    ))
+    let mut command = if cfg!(windows) && requires_cmd_shim(&program) {
+        let mut command = Command::new("cmd");
+        command.arg("/C").arg(program);
+        command
+    } else {
+        Command::new(program)
+    };
+
+    command.args(parts).arg(file);
+
+    Ok(command)
+}
+
+/// Opens a URL in the default browser.
+///
+/// Uses `open` on macOS, `cmd /C start` on Windows and `xdg-open` elsewhere.
+///
+/// # Errors
+/// * If the browser launcher cannot be spawned or fails
+pub fn open_in_browser(url: &str) -> Result<(), IoError> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        // The empty argument is the window title expected by `start`
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+
+    let status = command.arg(url).status()?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(IoError::other(format!(
+            "Failed to open {url} in the browser"
+        )))
+    }
+}
+
+/// A curl configuration (`curl --config`) setting the options, their values quoted and
+/// escaped.
+///
+/// # Examples
+///
+/// ```
+/// use rona::utils::curl_config;
+///
+/// assert_eq!(
+///     curl_config(&[("header", "Authorization: Bearer s3cr\"t"), ("url", "https://api.example.com")]),
+///     "header = \"Authorization: Bearer s3cr\\\"t\"\nurl = \"https://api.example.com\"\n"
+/// );
+/// ```
+#[must_use]
+pub fn curl_config(options: &[(&str, &str)]) -> String {
+    options
+        .iter()
+        .fold(String::new(), |mut config, (name, value)| {
+            config.push_str(name);
+            config.push_str(" = \"");
+            for c in value.chars() {
+                match c {
+                    '\\' => config.push_str("\\\\"),
+                    '"' => config.push_str("\\\""),
+                    '\n' => config.push_str("\\n"),
+                    '\r' => config.push_str("\\r"),
+                    '\t' => config.push_str("\\t"),
+                    c => config.push(c),
+                }
+            }
+            config.push_str("\"\n");
+            config
+        })
+}
+
+/// Runs curl with the options of `config` read from stdin (see [`curl_config`]), so
+/// secrets (API tokens, webhook URLs) stay off the command line, which any local user
+/// can read (`ps`, `/proc/<pid>/cmdline`).
+///
+/// # Errors
+/// * If curl cannot be run
+pub fn curl(args: &[&str], config: &[(&str, &str)]) -> Result<Output, IoError> {
+    let mut child = Command::new("curl")
+        .args(["--config", "-"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(curl_config(config).as_bytes())?;
+    }
+
+    child.wait_with_output()
+}
+
+/// Pager used when neither `GIT_PAGER` nor `PAGER` is set.
+const DEFAULT_PAGER: &str = "less -R";
+
+/// Resolves the pager command line from `GIT_PAGER`, then `PAGER`, like git does.
+///
+/// An empty value or `cat` disables paging.
+///
+/// # Arguments
+/// * `git_pager` - The value of `GIT_PAGER`, if set
+/// * `pager` - The value of `PAGER`, if set
+///
+/// # Returns
+/// * `Option<Vec<String>>` - The pager program and its arguments, `None` when paging is disabled
+#[must_use]
+pub fn resolve_pager(git_pager: Option<&str>, pager: Option<&str>) -> Option<Vec<String>> {
+    let command_line = git_pager.or(pager).unwrap_or(DEFAULT_PAGER).trim();
+
+    if command_line.is_empty() || command_line == "cat" {
+        return None;
+    }
+
+    Some(split_command_line(command_line))
+}
+
+/// Prints output, through the pager when stdout is a terminal and the output is
+/// taller than it.
+///
+/// Falls back to printing directly when the pager cannot be started.
+///
+/// # Arguments
+/// * `output` - The text to print
+/// * `use_pager` - Whether paging is allowed (`false` with `--no-pager`)
+///
+/// # Errors
+/// * If the pager fails after being started
+pub fn print_paged(output: &str, use_pager: bool) -> Result<(), IoError> {
+    let pager = if use_pager && io::stdout().is_terminal() {
+        let fits = console::Term::stdout()
+            .size_checked()
+            .is_some_and(|(rows, _)| output.lines().count() < usize::from(rows));
+
+        if fits {
+            None
+        } else {
+            resolve_pager(
+                env::var("GIT_PAGER").ok().as_deref(),
+                env::var("PAGER").ok().as_deref(),
+            )
+        }
+    } else {
+        None
+    };
+
+    let Some((program, args)) = pager.as_ref().and_then(|pager| pager.split_first()) else {
+        println!("{output}");
+        return Ok(());
+    };
+
+    let mut command = Command::new(program);
+    command.args(args).stdin(Stdio::piped());
+    if env::var_os("LESS").is_none() {
+        // Same defaults as git: quit if one screen, keep colors, no init
+        command.env("LESS", "FRX");
+    }
+
+    let Ok(mut child) = command.spawn() else {
+        println!("{output}");
+        return Ok(());
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The pager closing early (e.g. `q` in less) is not an error
+        let _ = writeln!(stdin, "{output}");
+    }
+    child.wait()?;
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(-5), "just now");
+        assert_eq!(format_age(59), "just now");
+        assert_eq!(format_age(60), "1 minute ago");
+        assert_eq!(format_age(3 * 3600 + 20), "3 hours ago");
+        assert_eq!(format_age(9 * 24 * 3600), "1 week ago");
+        assert_eq!(format_age(800 * 24 * 3600), "2 years ago");
+    }
+
+    #[test]
+    fn test_resolve_pager() {
+        assert_eq!(
+            resolve_pager(None, None),
+            Some(vec!["less".to_string(), "-R".to_string()])
+        );
+        assert_eq!(
+            resolve_pager(Some("delta --paging=always"), Some("more")),
+            Some(vec!["delta".to_string(), "--paging=always".to_string()])
+        );
+        assert_eq!(
+            resolve_pager(None, Some("more")),
+            Some(vec!["more".to_string()])
+        );
+        assert_eq!(resolve_pager(Some(""), Some("more")), None);
+        assert_eq!(resolve_pager(None, Some("cat")), None);
+    }
     use std::path::Path;
 
+    #[test]
+    fn test_normalize_separators() {
+        assert_eq!(
+            normalize_separators(r"src\cli\mod.rs", '\\'),
+            "src/cli/mod.rs"
+        );
+        assert_eq!(normalize_separators(r"src\file.rs", '/'), r"src\file.rs");
+        assert_eq!(normalize_separators("src/file.rs", '\\'), "src/file.rs");
+    }
+
+    #[test]
+    fn test_split_command_line() {
+        assert_eq!(split_command_line("nano"), vec!["nano"]);
+        assert_eq!(split_command_line("code --wait"), vec!["code", "--wait"]);
+        assert_eq!(
+            split_command_line(r#""C:\Program Files\Editor\editor.exe" -w"#),
+            vec![r"C:\Program Files\Editor\editor.exe", "-w"]
+        );
+        assert_eq!(
+            split_command_line("'/opt/my editor/bin/ed'  --flag"),
+            vec!["/opt/my editor/bin/ed", "--flag"]
+        );
+        assert!(split_command_line("   ").is_empty());
+    }
+
+    #[test]
+    fn test_requires_cmd_shim() {
+        assert!(requires_cmd_shim("code"));
+        assert!(requires_cmd_shim("code.cmd"));
+        assert!(requires_cmd_shim(r"C:\tools\editor.BAT"));
+        assert!(!requires_cmd_shim(r"C:\Program Files\Editor\editor.exe"));
+    }
+
+    #[test]
+    fn test_build_editor_command() {
+        let file = Path::new("project").join("commit_message.md");
+        let command = build_editor_command("code --wait", &[], &file).unwrap();
+        let args: Vec<_> = command
+            .get_args()
+            .map(std::ffi::OsStr::to_os_string)
+            .collect();
+
+        if cfg!(windows) {
+            assert_eq!(command.get_program(), "cmd");
+            assert_eq!(
+                args,
+                [
+                    "/C".into(),
+                    "code".into(),
+                    "--wait".into(),
+                    file.into_os_string()
+                ]
+            );
+        } else {
+            assert_eq!(command.get_program(), "code");
+            assert_eq!(args, ["--wait".into(), file.clone().into_os_string()]);
+        }
+
+        assert!(build_editor_command("", &[], Path::new("file.md")).is_err());
+    }
+
+    #[test]
+    fn test_build_editor_command_wait_flag() {
+        let file = Path::new("commit_message.md");
+        let args = |editor: &str, editor_args: &[String]| -> Vec<String> {
+            build_editor_command(editor, editor_args, file)
+                .unwrap()
+                .get_args()
+                .map(|arg| arg.to_string_lossy().to_string())
+                .filter(|arg| arg != "/C" && !arg.starts_with(editor))
+                .collect()
+        };
+
+        assert_eq!(args("subl", &[]), ["-w", "commit_message.md"]);
+        assert_eq!(args("zed --wait", &[]), ["--wait", "commit_message.md"]);
+        assert_eq!(
+            args("code", &["--new-window".to_string()]),
+            ["--new-window", "--wait", "commit_message.md"]
+        );
+        assert_eq!(args("vim", &[]), ["commit_message.md"]);
+    }
+
+    #[test]
+    fn test_discover_repository_plain_and_worktree() {
+        let temp = tempfile::tempdir().unwrap();
+        let main = temp.path().join("main");
+        let nested = main.join("src").join("deep");
+        std::fs::create_dir_all(main.join(".git").join("worktrees").join("feature")).unwrap();
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let location = discover_repository_from(&nested, None, None).unwrap();
+        assert_eq!(location.git_dir, main.join(".git"));
+        assert_eq!(location.work_tree, Some(main.clone()));
+
+        // Linked worktree with a relative gitdir pointer
+        let feature = temp.path().join("feature");
+        std::fs::create_dir_all(&feature).unwrap();
+        std::fs::write(
+            feature.join(".git"),
+            "gitdir: ../main/.git/worktrees/feature\n",
+        )
+        .unwrap();
+
+        let location = discover_repository_from(&feature, None, None).unwrap();
+        assert_eq!(
+            location.git_dir,
+            feature.join("../main/.git/worktrees/feature")
+        );
+        assert_eq!(location.work_tree, Some(feature));
+    }
+
+    #[test]
+    fn test_discover_repository_bare_and_overrides() {
+        let temp = tempfile::tempdir().unwrap();
+        let bare = temp.path().join("repo.git");
+        std::fs::create_dir_all(bare.join("objects")).unwrap();
+        std::fs::create_dir_all(bare.join("refs")).unwrap();
+        std::fs::write(bare.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+
+        let location = discover_repository_from(&bare, None, None).unwrap();
+        assert_eq!(location.git_dir, bare);
+        assert_eq!(location.work_tree, None);
+
+        let location =
+            discover_repository_from(temp.path(), Some(Path::new("repo.git")), None).unwrap();
+        assert_eq!(location.git_dir, bare);
+        assert_eq!(location.work_tree, Some(temp.path().to_path_buf()));
+
+        let location = discover_repository_from(
+            temp.path(),
+            Some(Path::new("repo.git")),
+            Some(Path::new("checkout")),
+        )
+        .unwrap();
+        assert_eq!(location.work_tree, Some(temp.path().join("checkout")));
+    }
+
+    #[test]
+    fn test_resolve_git_dir_invalid_pointer() {
+        let temp = tempfile::tempdir().unwrap();
+        let dot_git = temp.path().join(".git");
+        std::fs::write(&dot_git, "not a pointer").unwrap();
+
+        assert!(resolve_git_dir(&dot_git).is_err());
+    }
+
     #[test]
     fn test_check_for_file_in_folder() {
         // Valid cases
@@ -0,0 +1,164 @@
+//! Watch Mode
+//!
+//! `rona watch` monitors the working tree and shows again what `rona add-with-exclude`
+//! would stage each time it changes, given the exclusion patterns. With a WIP branch,
+//! the working tree is also recorded as a checkpoint on that branch on an interval,
+//! which keeps a safety net during long pairing or demo sessions.
+
+use std::{
+    io,
+    path::Path,
+    sync::mpsc::{self, RecvTimeoutError},
+    time::{Duration, Instant},
+};
+
+use chrono::Local;
+use glob::Pattern;
+use log::{debug, info, warn};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use crate::{
+    errors::{Result, RonaError},
+    git::{
+        find_git_root, git_add_with_exclude_patterns, git_wip_snapshot,
+        repository::get_top_level_path,
+    },
+};
+
+/// Time waited for a burst of events (e.g. a formatter rewriting many files) to settle.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Options of the watch mode.
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Patterns of files left out of the staging preview and of the checkpoints
+    pub exclude_patterns: Vec<Pattern>,
+    /// Branch the checkpoints are recorded on, `None` to only show the staging preview
+    pub wip_branch: Option<String>,
+    /// Time between two checkpoints
+    pub interval: Duration,
+}
+
+/// Whether an event may change the status: changes outside the git directory
+/// (running `git status` itself writes to it).
+fn is_relevant(event: &Event, git_dir: &Path) -> bool {
+    !matches!(event.kind, EventKind::Access(_))
+        && event.paths.iter().any(|path| !path.starts_with(git_dir))
+}
+
+/// Prints what would be staged when it changed since the last preview.
+///
+/// # Errors
+/// * If reading the status fails
+fn refresh(exclude_patterns: &[Pattern], last_preview: &mut String) -> Result<()> {
+    let plan = git_add_with_exclude_patterns(exclude_patterns, true)?;
+    let preview = if plan.is_empty() {
+        "Nothing to stage".to_string()
+    } else {
+        plan.render_text()
+    };
+
+    if preview != *last_preview {
+        info!("\n[{}]\n{preview}", Local::now().format("%H:%M:%S"));
+        *last_preview = preview;
+    }
+
+    Ok(())
+}
+
+/// Records a checkpoint on the WIP branch.
+///
+/// Failures are only reported, so a transient error (e.g. a concurrent `git` command
+/// holding the lock) does not stop the watch.
+fn record_checkpoint(branch: &str, exclude_patterns: &[Pattern]) {
+    match git_wip_snapshot(branch, exclude_patterns) {
+        Ok(Some(hash)) => info!("📌 Checkpoint {hash} recorded on {branch}"),
+        Ok(None) => debug!("No change since the last checkpoint on {branch}"),
+        Err(e) => warn!("Could not record a checkpoint on {branch}: {e}"),
+    }
+}
+
+/// Watches the working tree until interrupted (Ctrl-C).
+///
+/// # Errors
+/// * If the working tree cannot be watched
+/// * If reading the status fails
+pub fn watch(options: &WatchOptions) -> Result<()> {
+    let top_level = get_top_level_path()?;
+    let git_dir = find_git_root()?;
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(|e| watch_error(&e))?;
+    watcher
+        .watch(&top_level, RecursiveMode::Recursive)
+        .map_err(|e| watch_error(&e))?;
+
+    info!("👀 Watching {} (Ctrl-C to stop)", top_level.display());
+    if let Some(branch) = &options.wip_branch {
+        info!(
+            "Recording checkpoints on {branch} every {}s",
+            options.interval.as_secs()
+        );
+    }
+
+    let mut last_preview = String::new();
+    refresh(&options.exclude_patterns, &mut last_preview)?;
+
+    let mut next_checkpoint = Instant::now() + options.interval;
+    loop {
+        let timeout = next_checkpoint.saturating_duration_since(Instant::now());
+
+        match receiver.recv_timeout(timeout) {
+            Ok(Ok(event)) if is_relevant(&event, &git_dir) => {
+                while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+                refresh(&options.exclude_patterns, &mut last_preview)?;
+            }
+            Ok(Ok(_)) | Err(RecvTimeoutError::Timeout) => {}
+            Ok(Err(e)) => warn!("Watch error: {e}"),
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+
+        if Instant::now() >= next_checkpoint {
+            if let Some(branch) = &options.wip_branch {
+                record_checkpoint(branch, &options.exclude_patterns);
+            }
+            next_checkpoint = Instant::now() + options.interval;
+        }
+    }
+}
+
+/// Maps a file watching failure (e.g. the inotify watch limit being reached).
+fn watch_error(error: &notify::Error) -> RonaError {
+    RonaError::Io(io::Error::other(format!(
+        "Cannot watch the working tree: {error}"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, ModifyKind};
+    use std::path::PathBuf;
+
+    fn event(kind: EventKind, path: &str) -> Event {
+        Event::new(kind).add_path(PathBuf::from(path))
+    }
+
+    #[test]
+    fn test_is_relevant() {
+        let git_dir = Path::new("/repo/.git");
+
+        assert!(is_relevant(
+            &event(EventKind::Modify(ModifyKind::Any), "/repo/src/main.rs"),
+            git_dir
+        ));
+        assert!(!is_relevant(
+            &event(EventKind::Modify(ModifyKind::Any), "/repo/.git/index"),
+            git_dir
+        ));
+        assert!(!is_relevant(
+            &event(EventKind::Access(AccessKind::Any), "/repo/src/main.rs"),
+            git_dir
+        ));
+    }
+}
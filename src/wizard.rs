@@ -0,0 +1,281 @@
+//! First-Run Wizard
+//!
+//! A few questions asked the first time rona generates or commits in a repository without
+//! any configuration file (neither global nor `.rona.toml`): the editor, picked among the
+//! ones found in `PATH`, the format of the commit headers, the commit types and whether
+//! commits are signed. The answers are written to `.rona.toml` or to the global
+//! configuration. `--no-wizard`, or input that is not a terminal, skips it.
+
+use std::{
+    env, fmt,
+    io::{self, IsTerminal},
+};
+
+use inquire::{MultiSelect, Select, Text};
+use log::info;
+
+use crate::{
+    config::{Config, DEFAULT_COMMIT_TYPES, ProjectConfig, config_file_paths},
+    errors::{Result, RonaError},
+    git::{CommitNumbering, CommitSigning, commit::list_secret_keys, find_git_root},
+    i18n::{tr, tr_args},
+    utils::{find_program, split_command_line},
+};
+
+/// Editors looked up in `PATH`, after `VISUAL` and `EDITOR`.
+const EDITOR_CANDIDATES: [&str; 10] = [
+    "nvim", "vim", "nano", "hx", "micro", "emacs", "code", "zed", "subl", "cursor",
+];
+
+/// Commit types offered besides the default ones.
+const EXTRA_COMMIT_TYPES: [&str; 5] = ["refactor", "perf", "style", "ci", "build"];
+
+/// Format of the generated commit headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderFormat {
+    /// `[12] (feat on main) Add the parser`
+    Numbered,
+    /// `(feat on main) Add the parser`
+    Unnumbered,
+    /// `feat: Add the parser`
+    Conventional,
+}
+
+impl HeaderFormat {
+    /// Every format, the default one first.
+    pub const ALL: [Self; 3] = [Self::Numbered, Self::Unnumbered, Self::Conventional];
+
+    /// The `template` and `commit_number` settings of the format.
+    #[must_use]
+    pub const fn settings(self) -> (&'static str, CommitNumbering) {
+        match self {
+            Self::Numbered => (
+                "[{commit_number}] ({commit_type} on {branch_name}) {message}",
+                CommitNumbering::Repo,
+            ),
+            Self::Unnumbered => (
+                "({commit_type} on {branch_name}) {message}",
+                CommitNumbering::None,
+            ),
+            Self::Conventional => ("{commit_type}: {message}", CommitNumbering::None),
+        }
+    }
+}
+
+impl fmt::Display for HeaderFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Numbered => "[12] (feat on main) Add the parser",
+            Self::Unnumbered => "(feat on main) Add the parser",
+            Self::Conventional => "feat: Add the parser",
+        })
+    }
+}
+
+/// The answers to the wizard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WizardAnswers {
+    /// Editor command of the commit messages
+    pub editor: String,
+    /// Format of the commit headers
+    pub format: HeaderFormat,
+    /// Commit types offered by `rona generate`
+    pub commit_types: Vec<String>,
+    /// Whether and with which key commits are signed
+    pub signing: CommitSigning,
+}
+
+impl WizardAnswers {
+    /// Applies the answers to a configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rona::{
+    ///     config::ProjectConfig,
+    ///     git::CommitSigning,
+    ///     wizard::{HeaderFormat, WizardAnswers},
+    /// };
+    ///
+    /// let answers = WizardAnswers {
+    ///     editor: "hx".to_string(),
+    ///     format: HeaderFormat::Conventional,
+    ///     commit_types: vec!["feat".to_string(), "fix".to_string()],
+    ///     signing: CommitSigning::Disabled,
+    /// };
+    /// let mut config = ProjectConfig::default();
+    /// answers.apply(&mut config);
+    ///
+    /// assert_eq!(config.template.as_deref(), Some("{commit_type}: {message}"));
+    /// assert_eq!(config.sign_commits, Some(false));
+    /// ```
+    pub fn apply(&self, config: &mut ProjectConfig) {
+        let (template, numbering) = self.format.settings();
+
+        config.editor = Some(self.editor.clone());
+        config.template = Some(template.to_string());
+        config.commit_number = Some(numbering);
+        config.commit_types = Some(self.commit_types.clone());
+        match &self.signing {
+            CommitSigning::Auto => {}
+            CommitSigning::Key(key) => config.signing_key = Some(key.clone()),
+            CommitSigning::Disabled => config.sign_commits = Some(false),
+        }
+    }
+}
+
+/// Whether the wizard should run: from a terminal, in a git repository, without any
+/// configuration file.
+#[must_use]
+pub fn is_first_run() -> bool {
+    io::stdin().is_terminal()
+        && find_git_root().is_ok()
+        && config_file_paths().is_ok_and(|paths| paths.iter().all(|path| !path.exists()))
+}
+
+/// The editors that can be launched: `VISUAL`, `EDITOR`, then the known ones found in `PATH`.
+#[must_use]
+pub fn detect_editors() -> Vec<String> {
+    let mut editors: Vec<String> = Vec::new();
+    let configured = ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|variable| env::var(variable).ok());
+
+    for editor in configured.chain(EDITOR_CANDIDATES.iter().map(ToString::to_string)) {
+        let program = split_command_line(&editor)
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        if !program.is_empty() && find_program(&program).is_some() && !editors.contains(&editor) {
+            editors.push(editor);
+        }
+    }
+
+    editors
+}
+
+/// Asks the questions of the wizard.
+///
+/// # Errors
+/// * `RonaError::UserCancelled` - If a prompt is cancelled
+fn ask(config: &ProjectConfig) -> Result<WizardAnswers> {
+    let other = tr("prompt.wizard_other_editor");
+    let mut editors = detect_editors();
+    let editor = if editors.is_empty() {
+        Text::new(&tr("prompt.wizard_editor"))
+            .with_default("nano")
+            .prompt()?
+    } else {
+        editors.push(other.clone());
+        match Select::new(&tr("prompt.wizard_editor"), editors).prompt()? {
+            editor if editor == other => Text::new(&tr("prompt.wizard_editor")).prompt()?,
+            editor => editor,
+        }
+    };
+
+    let format = Select::new(&tr("prompt.wizard_format"), HeaderFormat::ALL.to_vec()).prompt()?;
+
+    let current_types = config.commit_types.clone().unwrap_or_else(|| {
+        DEFAULT_COMMIT_TYPES
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    });
+    let mut types: Vec<String> = DEFAULT_COMMIT_TYPES
+        .iter()
+        .chain(&EXTRA_COMMIT_TYPES)
+        .map(ToString::to_string)
+        .collect();
+    for commit_type in &current_types {
+        if !types.contains(commit_type) {
+            types.push(commit_type.clone());
+        }
+    }
+    let selected: Vec<usize> = (0..types.len())
+        .filter(|index| current_types.contains(&types[*index]))
+        .collect();
+    let commit_types = MultiSelect::new(&tr("prompt.wizard_types"), types)
+        .with_default(&selected)
+        .with_validator(inquire::min_length!(1, "Select at least one commit type"))
+        .prompt()?;
+
+    let keys: Vec<String> = list_secret_keys()
+        .iter()
+        .filter_map(|key| key.split_whitespace().next().map(ToString::to_string))
+        .collect();
+    let mut options = vec![tr("prompt.wizard_sign_auto")];
+    options.extend(
+        keys.iter()
+            .map(|key| tr_args("prompt.wizard_sign_key", &[("key", key)])),
+    );
+    options.push(tr("prompt.wizard_sign_never"));
+    let choice = Select::new(&tr("prompt.wizard_signing"), options).raw_prompt()?;
+    let signing = match choice.index {
+        0 => CommitSigning::Auto,
+        index if index <= keys.len() => CommitSigning::Key(keys[index - 1].clone()),
+        _ => CommitSigning::Disabled,
+    };
+
+    Ok(WizardAnswers {
+        editor: editor.trim().to_string(),
+        format,
+        commit_types,
+        signing,
+    })
+}
+
+/// Runs the wizard and writes the configuration, which the current command then uses.
+/// Cancelling a prompt skips the wizard, the command running with the defaults.
+///
+/// # Errors
+/// * If the configuration file cannot be written
+pub fn run_wizard(config: &mut Config) -> Result<()> {
+    info!(
+        "👋 rona has no configuration yet - a few questions set it up (Esc or --no-wizard skips them)"
+    );
+
+    let answers = match ask(&config.project_config) {
+        Err(RonaError::UserCancelled) => {
+            info!("Setup skipped - run `rona init` to create a configuration");
+            return Ok(());
+        }
+        answers => answers?,
+    };
+
+    let path = ProjectConfig::save(None, false, |file| answers.apply(file))?;
+    answers.apply(&mut config.project_config);
+
+    info!("✅ Configuration written to {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_answers() {
+        let mut answers = WizardAnswers {
+            editor: "code --wait".to_string(),
+            format: HeaderFormat::Unnumbered,
+            commit_types: vec!["feat".to_string()],
+            signing: CommitSigning::Key("3AA5C34371567BD2".to_string()),
+        };
+        let mut config = ProjectConfig::default();
+        answers.apply(&mut config);
+
+        assert_eq!(config.editor.as_deref(), Some("code --wait"));
+        assert_eq!(config.commit_number, Some(CommitNumbering::None));
+        assert_eq!(config.commit_types, Some(vec!["feat".to_string()]));
+        assert_eq!(config.signing_key.as_deref(), Some("3AA5C34371567BD2"));
+        assert_eq!(config.sign_commits, None);
+
+        answers.format = HeaderFormat::Numbered;
+        answers.signing = CommitSigning::Auto;
+        let mut config = ProjectConfig::default();
+        answers.apply(&mut config);
+
+        assert_eq!(config.commit_number, Some(CommitNumbering::Repo));
+        assert_eq!((config.signing_key, config.sign_commits), (None, None));
+    }
+}
@@ -132,7 +132,8 @@ fn test_commit_command() {
 
     // Create commit message file with proper format
     let commit_msg = "[1] (feat on main)\n\n- `test.txt`:\n\n\t\n";
-    fs::write(temp_path.join("commit_message.md"), commit_msg).unwrap();
+    fs::create_dir_all(temp_path.join(".git/rona")).unwrap();
+    fs::write(temp_path.join(".git/rona/commit_message.md"), commit_msg).unwrap();
 
     // Test rona commit
     let mut cmd = Command::cargo_bin("rona").unwrap();
@@ -150,3 +151,150 @@ fn test_commit_command() {
         .success()
         .stdout(predicate::str::contains("feat"));
 }
+
+/// Tests the first commit of a fresh repository, from generate to push.
+///
+/// Verifies that:
+/// - The unborn branch is named in the header, numbered `[1]`
+/// - Pushing before the first commit is refused
+/// - The first push sets the upstream of the branch
+#[test]
+fn test_first_commit_flow() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+    let remote_path = temp_path.join("remote.git");
+    let repo_path = temp_path.join("repo");
+
+    // Initialize a bare remote and a repository on an unborn `trunk` branch
+    let mut git_init_remote = Command::new("git");
+    git_init_remote.args(["init", "--bare"]).arg(&remote_path);
+    git_init_remote.assert().success();
+
+    let mut git_init = Command::new("git");
+    git_init.args(["init", "-b", "trunk"]).arg(&repo_path);
+    git_init.assert().success();
+
+    for args in [
+        vec!["config", "user.name", "Test User"],
+        vec!["config", "user.email", "test@example.com"],
+        vec!["remote", "add", "origin", remote_path.to_str().unwrap()],
+    ] {
+        let mut git = Command::new("git");
+        git.current_dir(&repo_path).args(args);
+        git.assert().success();
+    }
+
+    // Nothing to push yet
+    let mut push = Command::cargo_bin("rona").unwrap();
+    push.current_dir(&repo_path).arg("-p");
+    push.assert()
+        .code(6)
+        .stderr(predicate::str::contains("has no commits yet"));
+
+    // Stage, generate and commit with push
+    fs::write(repo_path.join("test.txt"), "test content").unwrap();
+    let mut add = Command::cargo_bin("rona").unwrap();
+    add.current_dir(&repo_path).arg("-a");
+    add.assert().success();
+
+    let mut generate = Command::cargo_bin("rona").unwrap();
+    generate.current_dir(&repo_path).args([
+        "-g",
+        "-t",
+        "feat",
+        "-m",
+        "Initial commit",
+        "--no-edit",
+    ]);
+    generate.assert().success();
+
+    let mut commit = Command::cargo_bin("rona").unwrap();
+    commit.current_dir(&repo_path).args(["-c", "-u", "--push"]);
+    commit.assert().success();
+
+    // Verify the header and the upstream
+    let mut git_log = Command::new("git");
+    git_log
+        .current_dir(&repo_path)
+        .args(["log", "-1", "--format=%s"]);
+    git_log.assert().success().stdout(predicate::str::contains(
+        "[1] (feat on trunk) Initial commit",
+    ));
+
+    let mut git_upstream = Command::new("git");
+    git_upstream
+        .current_dir(&repo_path)
+        .args(["rev-parse", "--abbrev-ref", "@{upstream}"]);
+    git_upstream
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("origin/trunk"));
+}
+
+/// Tests `flow` over a pending commit message edited by hand.
+///
+/// Verifies that:
+/// - Without a prompt, `flow` refuses to discard the edits and commits nothing
+/// - `--force` overwrites the edited message and commits
+#[test]
+fn test_flow_over_edited_message() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+
+    for args in [
+        vec!["init", "-b", "main"],
+        vec!["config", "user.name", "Test User"],
+        vec!["config", "user.email", "test@example.com"],
+    ] {
+        let mut git = Command::new("git");
+        git.current_dir(temp_path).args(args);
+        git.assert().success();
+    }
+
+    fs::write(temp_path.join("test.txt"), "test content").unwrap();
+    let mut add = Command::cargo_bin("rona").unwrap();
+    add.current_dir(temp_path).arg("-a");
+    add.assert().success();
+
+    let mut generate = Command::cargo_bin("rona").unwrap();
+    generate
+        .current_dir(temp_path)
+        .args(["-g", "-t", "feat", "-m", "Add test file", "--no-edit"]);
+    generate.assert().success();
+
+    let message_path = temp_path.join(".git/rona/commit_message.md");
+    let edited = fs::read_to_string(&message_path)
+        .unwrap()
+        .replace("Add test file", "Add the hand-edited test file");
+    fs::write(&message_path, &edited).unwrap();
+
+    let mut flow = Command::cargo_bin("rona").unwrap();
+    flow.current_dir(temp_path)
+        .args(["flow", "-t", "feat", "-m", "Add test", "--no-edit", "-u"]);
+    flow.assert()
+        .code(6)
+        .stderr(predicate::str::contains("pass --force to overwrite it"));
+    assert_eq!(fs::read_to_string(&message_path).unwrap(), edited);
+
+    let mut flow = Command::cargo_bin("rona").unwrap();
+    flow.current_dir(temp_path).args([
+        "flow",
+        "-t",
+        "feat",
+        "-m",
+        "Add test",
+        "--no-edit",
+        "-u",
+        "--force",
+    ]);
+    flow.assert().success();
+
+    let mut git_log = Command::new("git");
+    git_log
+        .current_dir(temp_path)
+        .args(["log", "-1", "--format=%s"]);
+    git_log
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(feat on main) Add test"));
+}